@@ -0,0 +1,17 @@
+package before_resolution
+
+import rego.v1
+
+test_registry_with_ref_attr if {
+	violations := deny with input as {"groups": [{"id": "registry.network", "attributes": [{"ref": "network.port"}]}]}
+	count(violations) == 1
+}
+
+test_empty_registry if {
+	count(deny) == 0 with input as {"groups": []}
+}
+
+# This test is expected to fail.
+test_wrong_expectation if {
+	count(deny) == 1 with input as {"groups": []}
+}
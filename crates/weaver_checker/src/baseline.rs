@@ -0,0 +1,304 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Baseline of known policy findings.
+//!
+//! A baseline records the policy findings present in a registry at a given
+//! point in time so that later checks only report findings that are new.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::finding::SEMCONV_ATTRIBUTE;
+use crate::{Error, PolicyFinding};
+
+/// The identity of a finding stored in a baseline.
+///
+/// Only the stable parts of a finding are recorded (its id, the signal it
+/// applies to and the attribute involved), not the message, so that a wording
+/// change in a policy doesn't invalidate the baseline.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct BaselineEntry {
+    /// The id of the finding e.g. "attr_stability_deprecated".
+    pub id: String,
+    /// The signal type the finding applies to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_type: Option<String>,
+    /// The signal name (or group id) the finding applies to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_name: Option<String>,
+    /// The attribute the finding applies to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attribute: Option<String>,
+}
+
+impl BaselineEntry {
+    /// Builds the baseline entry identifying the given finding.
+    #[must_use]
+    pub fn from_finding(finding: &PolicyFinding) -> Self {
+        let context = finding.context.as_ref();
+        let context_str = |key: &str| {
+            context
+                .and_then(|ctx| ctx.get(key))
+                .and_then(|v| v.as_str())
+                .map(ToOwned::to_owned)
+        };
+
+        // Legacy `semconv_attribute` findings all share the same id, the
+        // policy-specific id lives in the context.
+        let id = if finding.id == SEMCONV_ATTRIBUTE {
+            context_str("id").unwrap_or_else(|| finding.id.clone())
+        } else {
+            finding.id.clone()
+        };
+
+        BaselineEntry {
+            id,
+            signal_type: finding.signal_type.clone(),
+            signal_name: finding.signal_name.clone().or_else(|| context_str("group")),
+            attribute: attribute_from_context(context),
+        }
+    }
+}
+
+impl std::fmt::Display for BaselineEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "id={}", self.id)?;
+        if let Some(signal_type) = &self.signal_type {
+            write!(f, ", signal_type={signal_type}")?;
+        }
+        if let Some(signal_name) = &self.signal_name {
+            write!(f, ", signal_name={signal_name}")?;
+        }
+        if let Some(attribute) = &self.attribute {
+            write!(f, ", attribute={attribute}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Context keys used by policies to reference an attribute.
+const ATTRIBUTE_KEYS: [&str; 3] = ["attribute_name", "attribute", "attr"];
+
+/// Returns the attribute referenced by a finding context, if any.
+fn attribute_from_context(context: Option<&Value>) -> Option<String> {
+    let context = context?;
+    ATTRIBUTE_KEYS
+        .iter()
+        .find_map(|key| context.get(*key).and_then(Value::as_str))
+        .map(ToOwned::to_owned)
+}
+
+/// A set of known policy findings, persisted as YAML.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FindingBaseline {
+    /// The known findings.
+    #[serde(default)]
+    pub findings: BTreeSet<BaselineEntry>,
+}
+
+impl FindingBaseline {
+    /// Loads a baseline from a YAML file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let invalid_baseline = |error: String| Error::InvalidBaseline {
+            path: path.display().to_string(),
+            error,
+        };
+        let content = std::fs::read_to_string(path).map_err(|e| invalid_baseline(e.to_string()))?;
+        serde_yaml::from_str(&content).map_err(|e| invalid_baseline(e.to_string()))
+    }
+
+    /// Saves the baseline to a YAML file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let invalid_baseline = |error: String| Error::InvalidBaseline {
+            path: path.display().to_string(),
+            error,
+        };
+        let content = serde_yaml::to_string(self).map_err(|e| invalid_baseline(e.to_string()))?;
+        std::fs::write(path, content).map_err(|e| invalid_baseline(e.to_string()))
+    }
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    /// When set, every finding is recorded and suppressed.
+    recording: bool,
+    baseline: BTreeSet<BaselineEntry>,
+    matched: BTreeSet<BaselineEntry>,
+    recorded: BTreeSet<BaselineEntry>,
+    suppressed_count: usize,
+}
+
+/// Filters policy findings against a [`FindingBaseline`].
+///
+/// The tracker is shared by all the clones of a policy engine, so findings
+/// from every policy stage (and every parallel evaluation) are accounted for.
+#[derive(Debug, Clone, Default)]
+pub struct BaselineTracker {
+    state: Arc<Mutex<TrackerState>>,
+}
+
+impl BaselineTracker {
+    /// Creates a tracker suppressing the findings present in `baseline`.
+    #[must_use]
+    pub fn new(baseline: FindingBaseline) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(TrackerState {
+                baseline: baseline.findings,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Creates a tracker recording (and suppressing) every finding, used to
+    /// write a new baseline.
+    #[must_use]
+    pub fn recording() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(TrackerState {
+                recording: true,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Returns the findings that are not covered by the baseline.
+    #[must_use]
+    pub fn filter(&self, findings: Vec<PolicyFinding>) -> Vec<PolicyFinding> {
+        let mut state = self.state.lock().expect("Baseline tracker lock poisoned");
+        let mut new_findings = Vec::new();
+        for finding in findings {
+            let entry = BaselineEntry::from_finding(&finding);
+            if state.recording {
+                _ = state.recorded.insert(entry);
+                state.suppressed_count += 1;
+            } else if state.baseline.contains(&entry) {
+                _ = state.matched.insert(entry);
+                state.suppressed_count += 1;
+            } else {
+                new_findings.push(finding);
+            }
+        }
+        new_findings
+    }
+
+    /// Returns the baseline built from the findings seen so far in recording mode.
+    #[must_use]
+    pub fn recorded(&self) -> FindingBaseline {
+        let state = self.state.lock().expect("Baseline tracker lock poisoned");
+        FindingBaseline {
+            findings: state.recorded.clone(),
+        }
+    }
+
+    /// Returns the number of findings suppressed so far.
+    #[must_use]
+    pub fn suppressed_count(&self) -> usize {
+        self.state
+            .lock()
+            .expect("Baseline tracker lock poisoned")
+            .suppressed_count
+    }
+
+    /// Returns the baseline entries that didn't match any finding so far.
+    #[must_use]
+    pub fn stale_entries(&self) -> Vec<BaselineEntry> {
+        let state = self.state.lock().expect("Baseline tracker lock poisoned");
+        state.baseline.difference(&state.matched).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::FindingLevel;
+
+    fn finding(id: &str, signal_name: &str, attribute: &str) -> PolicyFinding {
+        PolicyFinding {
+            id: id.to_owned(),
+            context: Some(json!({ "attribute_name": attribute })),
+            message: format!("{id} on {attribute}"),
+            level: FindingLevel::Violation,
+            signal_type: Some("span".to_owned()),
+            signal_name: Some(signal_name.to_owned()),
+        }
+    }
+
+    #[test]
+    fn test_entry_from_semconv_attribute() {
+        let finding = PolicyFinding::new_semconv_attribute(
+            "attr_stability_deprecated".to_owned(),
+            "attribute".to_owned(),
+            "registry.http".to_owned(),
+            "http.method".to_owned(),
+        );
+        assert_eq!(
+            BaselineEntry::from_finding(&finding),
+            BaselineEntry {
+                id: "attr_stability_deprecated".to_owned(),
+                signal_type: None,
+                signal_name: Some("registry.http".to_owned()),
+                attribute: Some("http.method".to_owned()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_record_then_filter() {
+        let recorder = BaselineTracker::recording();
+        let existing = vec![
+            finding("missing_attr", "http.client", "http.method"),
+            finding("missing_attr", "http.client", "url.full"),
+        ];
+        assert!(recorder.filter(existing).is_empty());
+        let baseline = recorder.recorded();
+        assert_eq!(baseline.findings.len(), 2);
+
+        let dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let path = dir.path().join("baseline.yaml");
+        baseline.save(&path).expect("Failed to save baseline");
+        let loaded = FindingBaseline::load(&path).expect("Failed to load baseline");
+        assert_eq!(loaded, baseline);
+
+        let tracker = BaselineTracker::new(loaded);
+        let remaining = tracker.filter(vec![
+            finding("missing_attr", "http.client", "http.method"),
+            finding("missing_attr", "http.server", "http.method"),
+        ]);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].signal_name.as_deref(), Some("http.server"));
+        assert_eq!(tracker.suppressed_count(), 1);
+        assert_eq!(
+            tracker.stale_entries(),
+            vec![BaselineEntry {
+                id: "missing_attr".to_owned(),
+                signal_type: Some("span".to_owned()),
+                signal_name: Some("http.client".to_owned()),
+                attribute: Some("url.full".to_owned()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_baseline() {
+        let dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let path = dir.path().join("baseline.yaml");
+        std::fs::write(&path, "findings: 42").expect("Failed to write baseline");
+        assert!(matches!(
+            FindingBaseline::load(&path),
+            Err(Error::InvalidBaseline { .. })
+        ));
+    }
+}
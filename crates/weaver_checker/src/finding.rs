@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt::{Display, Formatter};
 
-const SEMCONV_ATTRIBUTE: &str = "semconv_attribute";
+pub(crate) const SEMCONV_ATTRIBUTE: &str = "semconv_attribute";
 
 /// Enum representing the different types of findings from enforcement policies.
 #[derive(Debug, Clone, Serialize, PartialEq, JsonSchema)]
@@ -3,10 +3,11 @@
 #![allow(rustdoc::broken_intra_doc_links)]
 #![doc = include_str!("../README.md")]
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::fmt::{Display, Formatter};
 use std::fs::metadata;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use globset::Glob;
 use miette::Diagnostic;
@@ -17,13 +18,24 @@ use walkdir::DirEntry;
 use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
 use weaver_common::error::{format_errors, handle_errors, WeaverError};
 
+use crate::policy_ast::{input_fields, rule_names};
+use crate::policy_test::test_rule_names;
+use crate::suppression::Suppressions;
 use crate::Error::CompoundError;
 
+mod baseline;
 mod finding;
+pub mod lint;
+mod policy_ast;
+mod policy_test;
+mod suppression;
 
+pub use crate::baseline::{BaselineEntry, BaselineTracker, FindingBaseline};
+pub use crate::suppression::{SuppressedFinding, SUPPRESSIONS_ANNOTATION};
 // Import finding so we don't need to expose deeper into the crate.
 pub use crate::finding::FindingLevel;
 pub use crate::finding::PolicyFinding;
+pub use crate::policy_test::PolicyTestResult;
 
 /// Default semconv rules/functions for the semantic convention registry.
 pub const SEMCONV_REGO: &str = include_str!("../../../defaults/rego/semconv.rego");
@@ -112,6 +124,47 @@ pub enum Error {
         violation: Box<PolicyFinding>,
     },
 
+    /// A policy finding suppressed by an inline `policy_suppressions` annotation.
+    #[error("Suppressed policy finding: {finding}, justification: {justification}, provenance: {provenance}")]
+    #[diagnostic(severity(Advice))]
+    SuppressedFinding {
+        /// The provenance of the finding (URL or path).
+        provenance: String,
+        /// The suppressed finding.
+        finding: Box<PolicyFinding>,
+        /// The justification given by the suppression.
+        justification: String,
+    },
+
+    /// An invalid findings baseline file.
+    #[error("Invalid findings baseline '{path}', error: {error}")]
+    #[diagnostic(help("Regenerate the baseline with `weaver registry check --write-baseline`."))]
+    InvalidBaseline {
+        /// The path of the baseline file.
+        path: String,
+        /// The error that occurred.
+        error: String,
+    },
+
+    /// A baseline entry that no longer matches any finding.
+    #[error("Baseline entry no longer matches any finding: {entry}")]
+    #[diagnostic(
+        severity(Warning),
+        help("Remove the entry from the baseline or regenerate it with `--write-baseline`.")
+    )]
+    StaleBaselineEntry {
+        /// The stale entry.
+        entry: BaselineEntry,
+    },
+
+    /// An invalid lint configuration.
+    #[error("Invalid lint configuration, error: {error}")]
+    #[diagnostic(help("Check the `[lint]` section of `.weaver.toml`."))]
+    InvalidLintConfig {
+        /// The error that occurred.
+        error: String,
+    },
+
     /// A container for multiple errors.
     #[error("{}", format_errors(.0))]
     #[diagnostic()]
@@ -179,6 +232,42 @@ impl Display for PolicyStage {
     }
 }
 
+/// Returns the policy files present in the given directory, recursively, that
+/// match the given glob pattern (Unix-style glob syntax). Hidden files and
+/// directories are skipped.
+pub fn policy_files<P: AsRef<Path>>(
+    policy_dir: P,
+    policy_glob_pattern: &str,
+) -> Result<Vec<PathBuf>, Error> {
+    fn is_hidden(entry: &DirEntry) -> bool {
+        entry
+            .file_name()
+            .to_str()
+            .map(|s| s.starts_with('.'))
+            .unwrap_or(false)
+    }
+
+    let policy_glob = Glob::new(policy_glob_pattern)
+        .map_err(|e| Error::InvalidPolicyGlobPattern {
+            pattern: policy_glob_pattern.to_owned(),
+            error: e.to_string(),
+        })?
+        .compile_matcher();
+
+    let is_policy_file = |entry: &DirEntry| -> bool {
+        let path = entry.path().to_string_lossy();
+        policy_glob.is_match(path.as_ref())
+    };
+
+    // Visit recursively all the files in the policy directory
+    Ok(walkdir::WalkDir::new(policy_dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| !is_hidden(entry) && is_policy_file(entry))
+        .map(|entry| entry.into_path())
+        .collect())
+}
+
 /// The policy engine.
 #[derive(Clone, Default)]
 pub struct Engine {
@@ -191,6 +280,15 @@ pub struct Engine {
     // Policy packages loaded. This is used to check if a policy package has been imported
     // before evaluating it.
     policy_packages: HashSet<String>,
+    // Baseline of known findings, shared by all the clones of this engine.
+    baseline_tracker: Option<BaselineTracker>,
+    // Inline suppressions declared in the current input.
+    suppressions: Suppressions,
+    // Messages of the invalid suppressions already reported, shared by all the clones
+    // of this engine. The same annotations are seen by every registry stage.
+    reported_invalid_suppressions: Arc<Mutex<HashSet<String>>>,
+    // Findings suppressed during the last check.
+    suppressed_findings: Vec<SuppressedFinding>,
 }
 
 impl Engine {
@@ -206,6 +304,12 @@ impl Engine {
         self.coverage_enabled = true;
     }
 
+    /// Sets the tracker used to filter out the findings recorded in a baseline.
+    /// Only the `before_resolution` and `after_resolution` findings are filtered.
+    pub fn set_baseline_tracker(&mut self, tracker: BaselineTracker) {
+        self.baseline_tracker = Some(tracker);
+    }
+
     /// Adds a rego policy (content) to the policy engine.
     ///
     /// # Arguments
@@ -284,22 +388,11 @@ impl Engine {
         policy_path: P,
     ) -> Result<String, Error> {
         let policy_path_str = policy_path.as_ref().to_string_lossy().to_string();
-
-        let policy_package = self
-            .engine
-            .add_policy_from_file(policy_path)
-            .map_err(|e| Error::InvalidPolicyFile {
-                file: policy_path_str.clone(),
-                error: e.to_string(),
-            })
-            .inspect(|_| {
-                self.policy_package_count += 1;
-            })?;
-        // Add the policy package defined in the imported policy file.
-        // Nothing prevent multiple policy files to import the same policy package.
-        // All the rules will be combined and evaluated together.
-        _ = self.policy_packages.insert(policy_package.clone());
-        Ok(policy_package)
+        let rego = std::fs::read_to_string(policy_path).map_err(|e| Error::InvalidPolicyFile {
+            file: policy_path_str.clone(),
+            error: e.to_string(),
+        })?;
+        self.add_policy(&policy_path_str, &rego)
     }
 
     /// Adds all the policy files present in the given directory that match the
@@ -315,40 +408,14 @@ impl Engine {
         policy_dir: P,
         policy_glob_pattern: &str,
     ) -> Result<usize, Error> {
-        fn is_hidden(entry: &DirEntry) -> bool {
-            entry
-                .file_name()
-                .to_str()
-                .map(|s| s.starts_with('.'))
-                .unwrap_or(false)
-        }
-
         let mut errors = Vec::new();
         let mut added_policy_count = 0;
 
-        let policy_glob = Glob::new(policy_glob_pattern)
-            .map_err(|e| Error::InvalidPolicyGlobPattern {
-                pattern: policy_glob_pattern.to_owned(),
-                error: e.to_string(),
-            })?
-            .compile_matcher();
-
-        let is_policy_file = |entry: &DirEntry| -> bool {
-            let path = entry.path().to_string_lossy();
-            policy_glob.is_match(path.as_ref())
-        };
-
-        // Visit recursively all the files in the policy directory
-        for entry in walkdir::WalkDir::new(policy_dir).into_iter().flatten() {
-            if is_hidden(&entry) {
-                continue;
-            }
-            if is_policy_file(&entry) {
-                if let Err(err) = self.add_policy_from_file(entry.path()) {
-                    errors.push(err);
-                } else {
-                    added_policy_count += 1;
-                }
+        for path in policy_files(policy_dir, policy_glob_pattern)? {
+            if let Err(err) = self.add_policy_from_file(&path) {
+                errors.push(err);
+            } else {
+                added_policy_count += 1;
             }
         }
 
@@ -406,6 +473,8 @@ impl Engine {
             error: e.to_string(),
         })?;
 
+        self.suppressions = Suppressions::from_input(&json_input);
+
         let value: regorus::Value =
             serde_json::from_value(json_input).map_err(|e| Error::InvalidInput {
                 error: e.to_string(),
@@ -414,6 +483,90 @@ impl Engine {
         Ok(())
     }
 
+    /// Returns the Rego unit test rules (`test_*` rules, OPA convention) defined
+    /// in the policies added so far.
+    pub fn test_rules(&self) -> Result<Vec<String>, Error> {
+        let ast = self
+            .engine
+            .get_ast_as_json()
+            .map_err(|e| Error::ViolationEvaluationError {
+                error: e.to_string(),
+            })?;
+        test_rule_names(&ast).map_err(|e| Error::ViolationEvaluationError {
+            error: e.to_string(),
+        })
+    }
+
+    /// Returns the fully qualified names of the rules and functions defined in the
+    /// policies added so far, e.g. `data.live_check_advice.deny`.
+    pub fn rule_names(&self) -> Result<Vec<String>, Error> {
+        let ast = self
+            .engine
+            .get_ast_as_json()
+            .map_err(|e| Error::ViolationEvaluationError {
+                error: e.to_string(),
+            })?;
+        rule_names(&ast).map_err(|e| Error::ViolationEvaluationError {
+            error: e.to_string(),
+        })
+    }
+
+    /// Returns the fields of the input under `path` referenced by the policies added
+    /// so far, e.g. `attribute` for `input.sample.attribute` with the path
+    /// `["sample"]`. Returns None if the policies may read any of them, e.g. when
+    /// they iterate over `input.sample`.
+    pub fn input_fields(&self, path: &[&str]) -> Result<Option<BTreeSet<String>>, Error> {
+        let ast = self
+            .engine
+            .get_ast_as_json()
+            .map_err(|e| Error::ViolationEvaluationError {
+                error: e.to_string(),
+            })?;
+        input_fields(&ast, path).map_err(|e| Error::ViolationEvaluationError {
+            error: e.to_string(),
+        })
+    }
+
+    /// Evaluates all the Rego unit test rules. A test passes if its rule
+    /// evaluates to `true`.
+    pub fn run_tests(&mut self) -> Result<Vec<PolicyTestResult>, Error> {
+        Ok(self
+            .test_rules()?
+            .into_iter()
+            .map(|name| {
+                let failure = match self.engine.eval_rule(name.clone()) {
+                    Ok(regorus::Value::Bool(true)) => None,
+                    Ok(regorus::Value::Undefined) => Some("the test rule is undefined".to_owned()),
+                    Ok(value) => Some(format!("the test rule evaluated to `{value}`")),
+                    Err(e) => Some(e.to_string()),
+                };
+                PolicyTestResult { name, failure }
+            })
+            .collect())
+    }
+
+    /// Returns the pretty-printed coverage report of the policies evaluated so
+    /// far. The coverage must be enabled with [`Engine::enable_coverage`].
+    pub fn coverage_report(&self) -> Result<String, Error> {
+        let report =
+            self.engine
+                .get_coverage_report()
+                .map_err(|e| Error::ViolationEvaluationError {
+                    error: e.to_string(),
+                })?;
+        report
+            .to_string_pretty()
+            .map_err(|e| Error::ViolationEvaluationError {
+                error: e.to_string(),
+            })
+    }
+
+    /// Returns the findings suppressed by inline annotations during the last check.
+    #[must_use]
+    pub fn suppressed_findings(&self) -> &[SuppressedFinding] {
+        &self.suppressed_findings
+    }
+
     /// Returns true if there are any policy packages for a given stage.
     #[must_use]
     pub fn has_stage(&self, stage: PolicyStage) -> bool {
@@ -422,14 +575,51 @@ impl Engine {
 
     /// Returns a list of violations based on the policies, the data, the
     /// input, and the given policy stage.
-    #[allow(clippy::print_stdout)] // Used to display the coverage (debugging purposes only)
     pub fn check(&mut self, stage: PolicyStage) -> Result<Vec<PolicyFinding>, Error> {
+        self.suppressed_findings.clear();
+
         // If we don't have any policy package that matches the stage,
         // return an empty list of violations.
         if !self.policy_packages.contains(&format!("data.{stage}")) {
             return Ok(vec![]);
         }
 
+        let violations = self.evaluate(&stage)?;
+
+        // Inline suppressions and the findings baseline only apply to the findings
+        // of the registry being checked, not to the comparison with a baseline registry.
+        match stage {
+            PolicyStage::BeforeResolution | PolicyStage::AfterResolution => {
+                let (mut violations, suppressed) = self.suppressions.apply(violations);
+                self.suppressed_findings = suppressed;
+                violations.extend(self.new_invalid_suppressions());
+                match &self.baseline_tracker {
+                    Some(tracker) => Ok(tracker.filter(violations)),
+                    None => Ok(violations),
+                }
+            }
+            _ => Ok(violations),
+        }
+    }
+
+    /// Returns the findings of the invalid suppressions of the current input that
+    /// haven't been reported yet by this engine or one of its clones.
+    fn new_invalid_suppressions(&self) -> Vec<PolicyFinding> {
+        let mut reported = self
+            .reported_invalid_suppressions
+            .lock()
+            .expect("Failed to acquire lock on reported suppressions");
+        self.suppressions
+            .invalid()
+            .iter()
+            .filter(|finding| reported.insert(finding.message.clone()))
+            .cloned()
+            .collect()
+    }
+
+    /// Evaluates the `deny` rule of the given policy stage.
+    #[allow(clippy::print_stdout)] // Used to display the coverage (debugging purposes only)
+    fn evaluate(&mut self, stage: &PolicyStage) -> Result<Vec<PolicyFinding>, Error> {
         let value = self
             .engine
             .eval_rule(format!("data.{stage}.deny"))
@@ -440,19 +630,7 @@ impl Engine {
         // Print the coverage report if enabled
         // This is useful for debugging purposes
         if self.coverage_enabled {
-            let report =
-                self.engine
-                    .get_coverage_report()
-                    .map_err(|e| Error::ViolationEvaluationError {
-                        error: e.to_string(),
-                    })?;
-            let pretty_report =
-                report
-                    .to_string_pretty()
-                    .map_err(|e| Error::ViolationEvaluationError {
-                        error: e.to_string(),
-                    })?;
-            println!("{pretty_report}");
+            println!("{}", self.coverage_report()?);
         }
 
         // convert `regorus` value to `serde_json` value
@@ -461,12 +639,9 @@ impl Engine {
         })?;
 
         // convert json value into a vector of violations
-        let violations: Vec<PolicyFinding> =
-            serde_json::from_value(json_value).map_err(|e| Error::ViolationEvaluationError {
-                error: e.to_string(),
-            })?;
-
-        Ok(violations)
+        serde_json::from_value(json_value).map_err(|e| Error::ViolationEvaluationError {
+            error: e.to_string(),
+        })
     }
 }
 
@@ -479,7 +654,7 @@ mod tests {
     use weaver_common::error::format_errors;
 
     use crate::finding::PolicyFinding;
-    use crate::{Engine, Error, PolicyStage};
+    use crate::{BaselineEntry, BaselineTracker, Engine, Error, PolicyStage};
 
     #[test]
     fn test_policy() -> Result<(), Box<dyn std::error::Error>> {
@@ -538,6 +713,104 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_inline_suppressions() -> Result<(), Box<dyn std::error::Error>> {
+        let mut engine = Engine::new();
+        _ = engine.add_policy_from_file("data/policies/otel_policies.rego")?;
+
+        let semconv = std::fs::read_to_string("data/registries/registry.network.suppressed.yaml")?;
+        let semconv: Value = serde_yaml::from_str(&semconv)?;
+        engine.set_input(&semconv)?;
+
+        let violations = engine.check(PolicyStage::BeforeResolution)?;
+        let mut violation_ids: Vec<String> = violations
+            .iter()
+            .map(|v| BaselineEntry::from_finding(v).to_string())
+            .collect();
+        violation_ids.sort();
+        // `protocol.name.2` is not suppressed as its suppression has no justification.
+        assert_eq!(
+            violation_ids,
+            vec![
+                "id=attr_stability_deprecated, signal_name=registry.network1, attribute=protocol.name.2",
+                "id=invalid_policy_suppression, signal_name=registry.network1, attribute=protocol.name.2",
+            ]
+        );
+
+        let suppressed = engine.suppressed_findings();
+        assert_eq!(suppressed.len(), 2);
+        assert!(suppressed
+            .iter()
+            .any(|s| s.justification == "Legacy name kept for compatibility."));
+
+        // Invalid suppressions are only reported once, also by the clones of the engine.
+        let mut clone = engine.clone();
+        clone.set_input(&semconv)?;
+        let violations = clone.check(PolicyStage::BeforeResolution)?;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            BaselineEntry::from_finding(&violations[0]).id,
+            "attr_stability_deprecated"
+        );
+
+        // Suppressions don't apply to the comparison stage.
+        assert!(engine
+            .check(PolicyStage::ComparisonAfterResolution)?
+            .is_empty());
+        assert!(engine.suppressed_findings().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_baseline_tracker_stages() -> Result<(), Box<dyn std::error::Error>> {
+        let mut engine = Engine::new();
+        for stage in ["after_resolution", "comparison_after_resolution"] {
+            _ = engine.add_policy(
+                &format!("{stage}.rego"),
+                &format!(
+                    r#"package {stage}
+import rego.v1
+deny contains {{"id": "known", "message": "Known finding", "level": "violation"}} if true"#
+                ),
+            )?;
+        }
+        engine.set_baseline_tracker(BaselineTracker::recording());
+        engine.set_input(&serde_json::json!({}))?;
+
+        // Only the findings of the registry being checked are filtered.
+        assert!(engine.check(PolicyStage::AfterResolution)?.is_empty());
+        assert_eq!(
+            engine.check(PolicyStage::ComparisonAfterResolution)?.len(),
+            1
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_tests() -> Result<(), Box<dyn std::error::Error>> {
+        let mut engine = Engine::new();
+        engine.enable_coverage();
+        _ = engine.add_policy_from_file("data/policies/otel_policies.rego")?;
+        _ = engine.add_policy_from_file("data/policy_tests/otel_policies_test.rego")?;
+        assert_eq!(engine.test_rules()?.len(), 3);
+
+        let results = engine.run_tests()?;
+        let outcomes: Vec<(&str, bool)> = results
+            .iter()
+            .map(|r| (r.name.as_str(), r.passed()))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("data.before_resolution.test_registry_with_ref_attr", true),
+                ("data.before_resolution.test_empty_registry", true),
+                ("data.before_resolution.test_wrong_expectation", false),
+            ]
+        );
+        assert!(engine.coverage_report()?.contains("otel_policies.rego"));
+        Ok(())
+    }
+
     #[test]
     fn test_invalid_policy() {
         let mut engine = Engine::new();
@@ -0,0 +1,889 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Native lint rules evaluated on resolved registries.
+//!
+//! These rules cover the most common naming and documentation conventions
+//! without the cost of evaluating Rego policies on large registries. They are
+//! selected and tuned in the `[lint]` section of `.weaver.toml`:
+//!
+//! ```toml
+//! [lint]
+//! rules = ["attribute_name", "brief_period", "metric_unit"]
+//!
+//! [lint.attribute_name]
+//! namespaces = { "k8s" = "^k8s(\\.[a-z0-9_]+)+$" }
+//!
+//! [lint.metric_unit]
+//! level = "improvement"
+//! ```
+//!
+//! The rules run independently of the Rego policies (also when the policies are
+//! skipped) and their findings are reported with the `after_resolution` policy
+//! findings. Inline suppressions and findings baselines apply to them.
+
+use std::collections::{BTreeMap, HashSet};
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use weaver_semconv::unit::Unit;
+
+use crate::suppression::{identity, Suppressions, GROUP_ID_FIELDS};
+use crate::{BaselineTracker, Error, FindingLevel, PolicyFinding, SuppressedFinding};
+
+/// The default pattern of attribute and metric names.
+const DEFAULT_NAME_PATTERN: &str = "^[a-z][a-z0-9]*([._][a-z0-9]+)*$";
+
+/// Signal collections of the v2 registry layout, with their signal type.
+const V2_SIGNALS: [(&str, &str); 4] = [
+    ("metrics", "metric"),
+    ("spans", "span"),
+    ("events", "event"),
+    ("entities", "entity"),
+];
+
+/// A native lint rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    /// Attribute names must match a pattern, optionally specific to their namespace.
+    AttributeName,
+    /// Attribute namespaces must not be nested deeper than a maximum depth.
+    NamespaceDepth,
+    /// Briefs must end with a period.
+    BriefPeriod,
+    /// Enum member ids must follow a casing convention.
+    EnumMemberCasing,
+    /// Stable attributes and signals must have a note.
+    StableNote,
+    /// Metric names must match a pattern and must not end with a forbidden suffix.
+    MetricName,
+    /// Metric units must be set and follow the unit conventions.
+    MetricUnit,
+}
+
+impl LintRule {
+    /// Returns the id of the findings reported by the rule.
+    #[must_use]
+    pub fn id(&self) -> &'static str {
+        match self {
+            LintRule::AttributeName => "attribute_name",
+            LintRule::NamespaceDepth => "namespace_depth",
+            LintRule::BriefPeriod => "brief_period",
+            LintRule::EnumMemberCasing => "enum_member_casing",
+            LintRule::StableNote => "stable_note",
+            LintRule::MetricName => "metric_name",
+            LintRule::MetricUnit => "metric_unit",
+        }
+    }
+}
+
+/// Configuration of the native lint rules (`[lint]` section of `.weaver.toml`).
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// The rules to run. No rule runs by default.
+    pub rules: Vec<LintRule>,
+    /// Settings of the `attribute_name` rule.
+    pub attribute_name: AttributeNameConfig,
+    /// Settings of the `namespace_depth` rule.
+    pub namespace_depth: NamespaceDepthConfig,
+    /// Settings of the `brief_period` rule.
+    pub brief_period: RuleConfig,
+    /// Settings of the `enum_member_casing` rule.
+    pub enum_member_casing: EnumMemberCasingConfig,
+    /// Settings of the `stable_note` rule.
+    pub stable_note: RuleConfig,
+    /// Settings of the `metric_name` rule.
+    pub metric_name: MetricNameConfig,
+    /// Settings of the `metric_unit` rule.
+    pub metric_unit: MetricUnitConfig,
+}
+
+/// Settings shared by all the rules.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleConfig {
+    /// The level of the findings reported by the rule.
+    pub level: Option<FindingLevel>,
+}
+
+/// Settings of the `attribute_name` rule.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct AttributeNameConfig {
+    /// The level of the findings (default: `violation`).
+    pub level: Option<FindingLevel>,
+    /// The pattern attribute names must match (default: lowercase dot-separated
+    /// namespaces of snake_case words).
+    pub pattern: Option<String>,
+    /// Patterns for the attributes of specific namespaces, overriding `pattern`.
+    /// The longest matching namespace wins.
+    pub namespaces: BTreeMap<String, String>,
+}
+
+/// Settings of the `namespace_depth` rule.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamespaceDepthConfig {
+    /// The level of the findings (default: `improvement`).
+    pub level: Option<FindingLevel>,
+    /// The maximum number of namespace segments of an attribute name, e.g. 2 for
+    /// `http.request.method` (default: 3).
+    pub max_depth: Option<usize>,
+}
+
+/// Settings of the `enum_member_casing` rule.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnumMemberCasingConfig {
+    /// The level of the findings (default: `violation`).
+    pub level: Option<FindingLevel>,
+    /// The casing of enum member ids (default: `snake_case`).
+    pub casing: Casing,
+}
+
+/// A casing convention for identifiers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Casing {
+    /// `snake_case`
+    #[default]
+    SnakeCase,
+    /// `kebab-case`
+    KebabCase,
+    /// `camelCase`
+    CamelCase,
+    /// `PascalCase`
+    PascalCase,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnakeCase,
+    /// `lowercase`
+    LowerCase,
+}
+
+impl Casing {
+    /// Returns true if the identifier follows the casing convention.
+    fn matches(self, id: &str) -> bool {
+        let mut chars = id.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        let words = |separator: char, is_word_char: fn(&char) -> bool| {
+            id.split(separator)
+                .all(|word| !word.is_empty() && word.chars().all(|c| is_word_char(&c)))
+        };
+        let is_lower = |c: &char| c.is_ascii_lowercase() || c.is_ascii_digit();
+        let is_upper = |c: &char| c.is_ascii_uppercase() || c.is_ascii_digit();
+        match self {
+            Casing::SnakeCase => first.is_ascii_lowercase() && words('_', is_lower),
+            Casing::KebabCase => first.is_ascii_lowercase() && words('-', is_lower),
+            Casing::ScreamingSnakeCase => first.is_ascii_uppercase() && words('_', is_upper),
+            Casing::LowerCase => first.is_ascii_lowercase() && chars.all(|c| is_lower(&c)),
+            Casing::CamelCase => {
+                first.is_ascii_lowercase() && chars.all(|c| c.is_ascii_alphanumeric())
+            }
+            Casing::PascalCase => {
+                first.is_ascii_uppercase() && chars.all(|c| c.is_ascii_alphanumeric())
+            }
+        }
+    }
+}
+
+/// Settings of the `metric_name` rule.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricNameConfig {
+    /// The level of the findings (default: `violation`).
+    pub level: Option<FindingLevel>,
+    /// The pattern metric names must match (default: same as attribute names).
+    pub pattern: Option<String>,
+    /// Suffixes metric names must not end with (default: `_total`, `.total`).
+    pub forbidden_suffixes: Option<Vec<String>>,
+}
+
+/// Settings of the `metric_unit` rule.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricUnitConfig {
+    /// The level of the findings (default: `violation`).
+    pub level: Option<FindingLevel>,
+    /// The allowed units. Any non-empty unit is allowed if not set. Units are compared
+    /// as UCUM units, so equivalent spellings are allowed.
+    pub allowed: Option<Vec<String>>,
+    /// The unit required for the metrics whose name ends with a given suffix
+    /// (default: `s` for `.duration`, `1` for `.utilization`).
+    pub suffix_units: Option<BTreeMap<String, String>>,
+}
+
+/// The findings of the native lint rules on a registry.
+#[derive(Debug, Default)]
+pub struct LintResult {
+    /// The findings that are neither suppressed nor recorded in the findings baseline.
+    pub findings: Vec<PolicyFinding>,
+    /// The findings suppressed by inline annotations.
+    pub suppressed: Vec<SuppressedFinding>,
+}
+
+/// The native lint rules compiled from a [`LintConfig`].
+#[derive(Debug, Clone)]
+pub struct Linter {
+    config: LintConfig,
+    attribute_pattern: Regex,
+    /// Namespace patterns, longest namespace first.
+    namespace_patterns: Vec<(String, Regex)>,
+    metric_pattern: Regex,
+    /// The allowed metric units, parsed.
+    allowed_units: Option<Vec<Unit>>,
+    /// The units required by metric name suffix, parsed.
+    suffix_units: Vec<(String, String, Unit)>,
+    baseline_tracker: Option<BaselineTracker>,
+}
+
+impl Linter {
+    /// Compiles the rules of a configuration. Returns None if no rule is selected.
+    pub fn new(config: &LintConfig) -> Result<Option<Self>, Error> {
+        if config.rules.is_empty() {
+            return Ok(None);
+        }
+        let mut namespace_patterns = config
+            .attribute_name
+            .namespaces
+            .iter()
+            .map(|(namespace, pattern)| Ok((namespace.clone(), compile(pattern)?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        namespace_patterns.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()));
+        Ok(Some(Self {
+            config: config.clone(),
+            attribute_pattern: compile(
+                config
+                    .attribute_name
+                    .pattern
+                    .as_deref()
+                    .unwrap_or(DEFAULT_NAME_PATTERN),
+            )?,
+            namespace_patterns,
+            metric_pattern: compile(
+                config
+                    .metric_name
+                    .pattern
+                    .as_deref()
+                    .unwrap_or(DEFAULT_NAME_PATTERN),
+            )?,
+            allowed_units: config
+                .metric_unit
+                .allowed
+                .as_ref()
+                .map(|allowed| allowed.iter().map(|unit| parse_unit(unit)).collect())
+                .transpose()?,
+            suffix_units: match &config.metric_unit.suffix_units {
+                Some(suffix_units) => suffix_units
+                    .iter()
+                    .map(|(suffix, unit)| Ok((suffix.clone(), unit.clone(), parse_unit(unit)?)))
+                    .collect::<Result<_, Error>>()?,
+                None => vec![
+                    (".duration".to_owned(), "s".to_owned(), parse_unit("s")?),
+                    (".utilization".to_owned(), "1".to_owned(), parse_unit("1")?),
+                ],
+            },
+            baseline_tracker: None,
+        }))
+    }
+
+    /// Sets the tracker used to filter out the findings recorded in a baseline.
+    pub fn set_baseline_tracker(&mut self, tracker: BaselineTracker) {
+        self.baseline_tracker = Some(tracker);
+    }
+
+    /// Checks a resolved registry (v1 or v2 layout). The inline suppressions of the
+    /// registry and the findings baseline apply to the findings.
+    pub fn check<T: Serialize>(&self, registry: &T) -> Result<LintResult, Error> {
+        let input = serde_json::to_value(registry).map_err(|e| Error::InvalidInput {
+            error: e.to_string(),
+        })?;
+        let (findings, suppressed) = Suppressions::from_input(&input).apply(self.lint(&input));
+        let findings = match &self.baseline_tracker {
+            Some(tracker) => tracker.filter(findings),
+            None => findings,
+        };
+        Ok(LintResult {
+            findings,
+            suppressed,
+        })
+    }
+
+    /// Returns the findings of the selected rules on a resolved registry (v1 or v2
+    /// layout).
+    fn lint(&self, input: &Value) -> Vec<PolicyFinding> {
+        let mut findings = Vec::new();
+        for attribute in attributes(input) {
+            self.lint_attribute(&attribute, &mut findings);
+        }
+        for signal in signals(input) {
+            self.lint_signal(&signal, &mut findings);
+        }
+        findings
+    }
+
+    fn enabled(&self, rule: LintRule) -> bool {
+        self.config.rules.contains(&rule)
+    }
+
+    fn lint_attribute(&self, attribute: &Item<'_>, findings: &mut Vec<PolicyFinding>) {
+        let name = attribute.name.as_str();
+        let finding = |rule: LintRule, level: FindingLevel, message: String| PolicyFinding {
+            id: rule.id().to_owned(),
+            context: Some(json!({
+                "attribute_name": name,
+                "group": attribute.group,
+            })),
+            message,
+            level,
+            signal_type: None,
+            signal_name: None,
+        };
+
+        if self.enabled(LintRule::AttributeName) {
+            let (namespace, pattern) = self
+                .namespace_patterns
+                .iter()
+                .find(|(namespace, _)| {
+                    name.strip_prefix(namespace.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+                })
+                .map(|(namespace, pattern)| (Some(namespace), pattern))
+                .unwrap_or((None, &self.attribute_pattern));
+            if !pattern.is_match(name) {
+                let scope = namespace
+                    .map(|namespace| format!(" of the `{namespace}` namespace"))
+                    .unwrap_or_default();
+                findings.push(finding(
+                    LintRule::AttributeName,
+                    level(self.config.attribute_name.level, FindingLevel::Violation),
+                    format!(
+                        "Attribute `{name}` doesn't match the naming pattern{scope} `{}`.",
+                        pattern.as_str()
+                    ),
+                ));
+            }
+        }
+
+        if self.enabled(LintRule::NamespaceDepth) {
+            let max_depth = self.config.namespace_depth.max_depth.unwrap_or(3);
+            let depth = name.split('.').count() - 1;
+            if depth > max_depth {
+                findings.push(finding(
+                    LintRule::NamespaceDepth,
+                    level(self.config.namespace_depth.level, FindingLevel::Improvement),
+                    format!(
+                        "Attribute `{name}` has {depth} namespace levels, more than the maximum of {max_depth}."
+                    ),
+                ));
+            }
+        }
+
+        if self.enabled(LintRule::BriefPeriod) && !brief_ends_with_period(attribute.value) {
+            findings.push(finding(
+                LintRule::BriefPeriod,
+                level(self.config.brief_period.level, FindingLevel::Improvement),
+                format!("The brief of attribute `{name}` doesn't end with a period."),
+            ));
+        }
+
+        if self.enabled(LintRule::EnumMemberCasing) {
+            let casing = self.config.enum_member_casing.casing;
+            let members = attribute
+                .value
+                .get("type")
+                .and_then(|t| t.get("members"))
+                .and_then(Value::as_array);
+            for id in members
+                .into_iter()
+                .flatten()
+                .filter_map(|member| member.get("id").and_then(Value::as_str))
+                .filter(|id| !casing.matches(id))
+            {
+                findings.push(finding(
+                    LintRule::EnumMemberCasing,
+                    level(
+                        self.config.enum_member_casing.level,
+                        FindingLevel::Violation,
+                    ),
+                    format!(
+                        "Enum member `{id}` of attribute `{name}` is not in {}.",
+                        casing_name(casing)
+                    ),
+                ));
+            }
+        }
+
+        if self.enabled(LintRule::StableNote) && is_stable_without_note(attribute.value) {
+            findings.push(finding(
+                LintRule::StableNote,
+                level(self.config.stable_note.level, FindingLevel::Improvement),
+                format!("Stable attribute `{name}` has no note."),
+            ));
+        }
+    }
+
+    fn lint_signal(&self, signal: &Signal<'_>, findings: &mut Vec<PolicyFinding>) {
+        let name = signal.item.name.as_str();
+        let finding = |rule: LintRule, level: FindingLevel, message: String| PolicyFinding {
+            id: rule.id().to_owned(),
+            context: Some(json!({
+                "group": name,
+                "metric_name": signal.metric_name,
+                "unit": signal.unit,
+            })),
+            message,
+            level,
+            signal_type: Some(signal.signal_type.to_owned()),
+            signal_name: Some(name.to_owned()),
+        };
+
+        if self.enabled(LintRule::BriefPeriod) && !brief_ends_with_period(signal.item.value) {
+            findings.push(finding(
+                LintRule::BriefPeriod,
+                level(self.config.brief_period.level, FindingLevel::Improvement),
+                format!(
+                    "The brief of {} `{name}` doesn't end with a period.",
+                    signal.signal_type
+                ),
+            ));
+        }
+
+        if self.enabled(LintRule::StableNote) && is_stable_without_note(signal.item.value) {
+            findings.push(finding(
+                LintRule::StableNote,
+                level(self.config.stable_note.level, FindingLevel::Improvement),
+                format!("Stable {} `{name}` has no note.", signal.signal_type),
+            ));
+        }
+
+        let Some(metric_name) = signal.metric_name else {
+            return;
+        };
+
+        if self.enabled(LintRule::MetricName) {
+            let config = &self.config.metric_name;
+            let level = level(config.level, FindingLevel::Violation);
+            if !self.metric_pattern.is_match(metric_name) {
+                findings.push(finding(
+                    LintRule::MetricName,
+                    level,
+                    format!(
+                        "Metric `{metric_name}` doesn't match the naming pattern `{}`.",
+                        self.metric_pattern.as_str()
+                    ),
+                ));
+            }
+            let default_suffixes = ["_total".to_owned(), ".total".to_owned()];
+            if let Some(suffix) = config
+                .forbidden_suffixes
+                .as_deref()
+                .unwrap_or(&default_suffixes)
+                .iter()
+                .find(|suffix| metric_name.ends_with(suffix.as_str()))
+            {
+                findings.push(finding(
+                    LintRule::MetricName,
+                    level,
+                    format!("Metric `{metric_name}` ends with the forbidden suffix `{suffix}`."),
+                ));
+            }
+        }
+
+        if self.enabled(LintRule::MetricUnit) {
+            let level = level(self.config.metric_unit.level, FindingLevel::Violation);
+            let unit = signal.unit.unwrap_or_default();
+            if unit.is_empty() {
+                findings.push(finding(
+                    LintRule::MetricUnit,
+                    level,
+                    format!("Metric `{metric_name}` has no unit."),
+                ));
+                return;
+            }
+            // Invalid units are reported when the registry is loaded.
+            let parsed = Unit::parse(unit).ok();
+            if let Some(allowed) = &self.allowed_units {
+                if !allowed
+                    .iter()
+                    .any(|allowed| parsed.as_ref() == Some(allowed))
+                {
+                    findings.push(finding(
+                        LintRule::MetricUnit,
+                        level,
+                        format!("The unit `{unit}` of metric `{metric_name}` is not allowed."),
+                    ));
+                }
+            }
+            if let Some((suffix, expected, _)) =
+                self.suffix_units.iter().find(|(suffix, _, expected)| {
+                    metric_name.ends_with(suffix.as_str()) && parsed.as_ref() != Some(expected)
+                })
+            {
+                findings.push(finding(
+                    LintRule::MetricUnit,
+                    level,
+                    format!(
+                        "Metric `{metric_name}` ends with `{suffix}` and should have the unit `{expected}` instead of `{unit}`."
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// A named attribute or signal of the input.
+struct Item<'a> {
+    name: String,
+    /// The group defining the attribute (v1 layout only).
+    group: Option<String>,
+    value: &'a Value,
+}
+
+/// A signal of the input.
+struct Signal<'a> {
+    item: Item<'a>,
+    signal_type: &'static str,
+    metric_name: Option<&'a str>,
+    unit: Option<&'a str>,
+}
+
+/// Returns the attributes of the input that are not deprecated, once per name.
+/// In the v1 layout, the attributes repeated in signals are reported on the
+/// attribute group defining them.
+fn attributes(input: &Value) -> Vec<Item<'_>> {
+    let mut items = Vec::new();
+    if let Some(registry_attributes) = input
+        .get("registry")
+        .and_then(|registry| registry.get("attributes"))
+        .and_then(Value::as_array)
+    {
+        items.extend(registry_attributes.iter().filter_map(|value| {
+            let name = value.get("key").and_then(Value::as_str)?;
+            Some(Item {
+                name: name.to_owned(),
+                group: None,
+                value,
+            })
+        }));
+    }
+
+    let mut groups = input
+        .get("groups")
+        .and_then(Value::as_array)
+        .map(|groups| groups.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    // Attribute groups first, so attributes are reported where they are defined.
+    groups
+        .sort_by_key(|group| group.get("type").and_then(Value::as_str) != Some("attribute_group"));
+    for group in groups {
+        let group_id = identity(group, &GROUP_ID_FIELDS);
+        let attributes = group.get("attributes").and_then(Value::as_array);
+        items.extend(attributes.into_iter().flatten().filter_map(|value| {
+            let name = ["name", "id"]
+                .iter()
+                .find_map(|field| value.get(*field).and_then(Value::as_str))?;
+            Some(Item {
+                name: name.to_owned(),
+                group: group_id.clone(),
+                value,
+            })
+        }));
+    }
+
+    let mut seen = HashSet::new();
+    items.retain(|item| !is_deprecated(item.value) && seen.insert(item.name.clone()));
+    items
+}
+
+/// Returns the signals of the input that are not deprecated.
+fn signals(input: &Value) -> Vec<Signal<'_>> {
+    fn signal<'a>(value: &'a Value, signal_type: &'static str) -> Option<Signal<'a>> {
+        Some(Signal {
+            item: Item {
+                name: identity(value, &GROUP_ID_FIELDS)?,
+                group: None,
+                value,
+            },
+            signal_type,
+            metric_name: None,
+            unit: value.get("unit").and_then(Value::as_str),
+        })
+    }
+
+    let mut signals = Vec::new();
+
+    for group in input
+        .get("groups")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let signal_type = match group.get("type").and_then(Value::as_str) {
+            Some("metric") => "metric",
+            Some("span") => "span",
+            Some("event") => "event",
+            Some("entity") => "entity",
+            _ => continue,
+        };
+        if let Some(mut signal) = signal(group, signal_type) {
+            signal.metric_name = group.get("metric_name").and_then(Value::as_str);
+            signals.push(signal);
+        }
+    }
+
+    for (collection, signal_type) in V2_SIGNALS {
+        let values = input
+            .get("registry")
+            .and_then(|registry| registry.get(collection))
+            .and_then(Value::as_array);
+        for value in values.into_iter().flatten() {
+            if let Some(mut signal) = signal(value, signal_type) {
+                if signal_type == "metric" {
+                    signal.metric_name = value.get("name").and_then(Value::as_str);
+                }
+                signals.push(signal);
+            }
+        }
+    }
+
+    signals.retain(|signal| !is_deprecated(signal.item.value));
+    signals
+}
+
+fn compile(pattern: &str) -> Result<Regex, Error> {
+    Regex::new(pattern).map_err(|e| Error::InvalidLintConfig {
+        error: format!("invalid pattern `{pattern}`: {e}"),
+    })
+}
+
+fn parse_unit(unit: &str) -> Result<Unit, Error> {
+    Unit::parse(unit).map_err(|e| Error::InvalidLintConfig {
+        error: e.to_string(),
+    })
+}
+
+fn level(level: Option<FindingLevel>, default: FindingLevel) -> FindingLevel {
+    level.unwrap_or(default)
+}
+
+fn is_deprecated(value: &Value) -> bool {
+    value.get("deprecated").is_some_and(|d| !d.is_null())
+}
+
+/// Returns false if the item has a brief that doesn't end with a period.
+fn brief_ends_with_period(value: &Value) -> bool {
+    value
+        .get("brief")
+        .and_then(Value::as_str)
+        .map(str::trim_end)
+        .map_or(true, |brief| brief.is_empty() || brief.ends_with('.'))
+}
+
+fn is_stable_without_note(value: &Value) -> bool {
+    value.get("stability").and_then(Value::as_str) == Some("stable")
+        && value
+            .get("note")
+            .and_then(Value::as_str)
+            .map_or(true, |note| note.trim().is_empty())
+}
+
+fn casing_name(casing: Casing) -> &'static str {
+    match casing {
+        Casing::SnakeCase => "snake_case",
+        Casing::KebabCase => "kebab-case",
+        Casing::CamelCase => "camelCase",
+        Casing::PascalCase => "PascalCase",
+        Casing::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+        Casing::LowerCase => "lowercase",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linter(toml: &str) -> Linter {
+        let config: LintConfig = toml::from_str(toml).unwrap();
+        Linter::new(&config).unwrap().unwrap()
+    }
+
+    fn ids(findings: &[PolicyFinding]) -> Vec<(&str, Option<&str>)> {
+        findings
+            .iter()
+            .map(|f| {
+                let subject = f
+                    .context
+                    .as_ref()
+                    .and_then(|ctx| ctx.get("attribute_name"))
+                    .and_then(Value::as_str)
+                    .or(f.signal_name.as_deref());
+                (f.id.as_str(), subject)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_v1_registry() {
+        let linter = linter(
+            r#"
+rules = ["attribute_name", "namespace_depth", "brief_period", "enum_member_casing", "stable_note", "metric_name", "metric_unit"]
+namespace_depth.max_depth = 2
+
+[attribute_name.namespaces]
+"k8s" = "^k8s\\.[a-z]+\\.[a-z]+$"
+"#,
+        );
+        let input = json!({
+            "groups": [
+                {
+                    "id": "metric.http.duration",
+                    "type": "metric",
+                    "brief": "Duration.",
+                    "metric_name": "http.duration",
+                    "unit": "ms",
+                    "stability": "stable",
+                    "note": "Note.",
+                    "attributes": [{"name": "http.method", "brief": "The method.", "stability": "stable"}]
+                },
+                {
+                    "id": "registry.http",
+                    "type": "attribute_group",
+                    "brief": "HTTP attributes",
+                    "attributes": [
+                        {"name": "http.method", "brief": "The method.", "stability": "stable", "note": "Note."},
+                        {"name": "http.Route", "brief": "The route."},
+                        {"name": "http.request.header.first", "brief": "A header"},
+                        {"name": "http.old", "brief": "Old", "deprecated": {"reason": "obsoleted"}},
+                        {"name": "http.kind", "brief": "The kind.", "type": {"members": [{"id": "client"}, {"id": "Server"}]}}
+                    ]
+                },
+                {
+                    "id": "registry.k8s",
+                    "type": "attribute_group",
+                    "brief": "K8s attributes.",
+                    "attributes": [
+                        {"name": "k8s.pod.name", "brief": "Pod name."},
+                        {"name": "k8s.pod_name", "brief": "Pod name."}
+                    ]
+                },
+                {
+                    "id": "metric.requests",
+                    "type": "metric",
+                    "brief": "Requests.",
+                    "metric_name": "http.requests_total",
+                    "unit": ""
+                }
+            ]
+        });
+
+        let findings = linter.lint(&input);
+        assert_eq!(
+            ids(&findings),
+            vec![
+                ("attribute_name", Some("http.Route")),
+                ("namespace_depth", Some("http.request.header.first")),
+                ("brief_period", Some("http.request.header.first")),
+                ("enum_member_casing", Some("http.kind")),
+                ("attribute_name", Some("k8s.pod_name")),
+                ("metric_unit", Some("metric.http.duration")),
+                ("metric_name", Some("metric.requests")),
+                ("metric_unit", Some("metric.requests")),
+            ]
+        );
+        // Attributes are reported on the group defining them.
+        assert_eq!(
+            findings[0].context.as_ref().unwrap()["group"],
+            json!("registry.http")
+        );
+        assert_eq!(findings[0].level, FindingLevel::Violation);
+        assert_eq!(findings[1].level, FindingLevel::Improvement);
+        assert_eq!(findings[5].signal_type.as_deref(), Some("metric"));
+    }
+
+    #[test]
+    fn test_v2_registry() {
+        let linter = linter(
+            r#"
+rules = ["brief_period", "stable_note", "metric_unit"]
+brief_period.level = "information"
+
+[metric_unit]
+allowed = ["s", "By"]
+"#,
+        );
+        let input = json!({
+            "registry": {
+                "attributes": [
+                    {"key": "db.name", "brief": "The name", "stability": "stable"}
+                ],
+                "metrics": [
+                    {"name": "db.operation.duration", "brief": "Duration.", "unit": "s", "instrument": "histogram"},
+                    {"name": "db.size", "brief": "Size.", "unit": "KiBy", "instrument": "gauge"}
+                ],
+                "spans": [
+                    {"type": "db.query", "brief": "A query", "stability": "development"}
+                ]
+            }
+        });
+
+        let findings = linter.lint(&input);
+        assert_eq!(
+            ids(&findings),
+            vec![
+                ("brief_period", Some("db.name")),
+                ("stable_note", Some("db.name")),
+                ("metric_unit", Some("db.size")),
+                ("brief_period", Some("db.query")),
+            ]
+        );
+        assert_eq!(findings[0].level, FindingLevel::Information);
+        assert_eq!(findings[3].signal_type.as_deref(), Some("span"));
+    }
+
+    #[test]
+    fn test_config() {
+        assert!(Linter::new(&LintConfig::default()).unwrap().is_none());
+        let config: LintConfig = toml::from_str(
+            r#"
+rules = ["attribute_name"]
+attribute_name.pattern = "["
+"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            Linter::new(&config),
+            Err(Error::InvalidLintConfig { .. })
+        ));
+        let config: LintConfig = toml::from_str(
+            r#"
+rules = ["metric_unit"]
+metric_unit.suffix_units = { ".duration" = "milli seconds" }
+"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            Linter::new(&config),
+            Err(Error::InvalidLintConfig { .. })
+        ));
+        assert!(toml::from_str::<LintConfig>("rules = [\"unknown\"]").is_err());
+    }
+
+    #[test]
+    fn test_casing() {
+        assert!(Casing::SnakeCase.matches("in_progress"));
+        assert!(!Casing::SnakeCase.matches("in__progress"));
+        assert!(!Casing::SnakeCase.matches("InProgress"));
+        assert!(Casing::KebabCase.matches("in-progress"));
+        assert!(Casing::CamelCase.matches("inProgress"));
+        assert!(Casing::PascalCase.matches("InProgress"));
+        assert!(Casing::ScreamingSnakeCase.matches("IN_PROGRESS"));
+        assert!(Casing::LowerCase.matches("inprogress"));
+        assert!(!Casing::LowerCase.matches("in_progress"));
+    }
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Analysis of the AST of the compiled policies, as returned by
+//! `regorus::Engine::get_ast_as_json`.
+
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+/// Returns the fully qualified names of the rules and functions defined in the
+/// policies, in order of appearance, e.g. `data.live_check_advice.deny`. Rules
+/// with several definitions are only returned once.
+pub(crate) fn rule_names(ast: &str) -> Result<Vec<String>, serde_json::Error> {
+    let modules: Vec<Value> = serde_json::from_str(ast)?;
+    let mut names: Vec<String> = Vec::new();
+    for module in &modules {
+        let module = &module["ast"];
+        let Some(package) = ref_path(&module["package"]["refr"]) else {
+            continue;
+        };
+        let rules = module["rules"].as_array().into_iter().flatten();
+        for rule in rules {
+            let head = &rule["Spec"]["head"];
+            let refr = ["Compr", "Set", "Func"]
+                .iter()
+                .map(|kind| &head[kind]["refr"])
+                .find(|refr| !refr.is_null())
+                .unwrap_or(&rule["Default"]["refr"]);
+            let Some(rule) = ref_path(refr) else {
+                continue;
+            };
+            let name = format!("data.{package}.{rule}");
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    Ok(names)
+}
+
+/// Returns the fields of `input` under `path` referenced by the policies, e.g.
+/// `attribute` for `input.sample.attribute` with the path `["sample"]`.
+///
+/// Returns None if the value at `path` (or one of its parents) is referenced as a
+/// whole, e.g. `some sample in input.sample`, or through a computed index, as any
+/// of its fields may then be read.
+pub(crate) fn input_fields(
+    ast: &str,
+    path: &[&str],
+) -> Result<Option<BTreeSet<String>>, serde_json::Error> {
+    let modules: Vec<Value> = serde_json::from_str(ast)?;
+    let mut references = Vec::new();
+    for module in &modules {
+        collect_input_references(&module["ast"]["rules"], &mut references);
+    }
+    let mut fields = BTreeSet::new();
+    for reference in references {
+        let depth = reference.len().min(path.len());
+        if reference[..depth]
+            .iter()
+            .zip(path)
+            .any(|(field, expected)| field.as_deref() != Some(*expected))
+        {
+            // Another part of the input
+            continue;
+        }
+        match reference.get(path.len()) {
+            Some(Some(field)) => {
+                let _ = fields.insert(field.clone());
+            }
+            _ => return Ok(None),
+        }
+    }
+    Ok(Some(fields))
+}
+
+/// Collects the references to `input` in a node of the AST, as the fields they
+/// access. A computed index is a None field.
+fn collect_input_references(node: &Value, references: &mut Vec<Vec<Option<String>>>) {
+    if let Some(reference) = input_reference(node) {
+        references.push(reference);
+        // The computed indexes of the reference may reference the input too
+        let mut node = node;
+        loop {
+            if let Some(brack) = node.get("RefBrack") {
+                collect_input_references(&brack["index"], references);
+                node = &brack["refr"];
+            } else if let Some(dot) = node.get("RefDot") {
+                node = &dot["refr"];
+            } else {
+                break;
+            }
+        }
+        return;
+    }
+    match node {
+        Value::Object(fields) => {
+            for value in fields.values() {
+                collect_input_references(value, references);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_input_references(item, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the fields accessed by a reference to `input`, or None if the node is not
+/// a reference to `input`.
+fn input_reference(node: &Value) -> Option<Vec<Option<String>>> {
+    if node["Var"]["value"].as_str() == Some("input") {
+        return Some(Vec::new());
+    }
+    if let Some(dot) = node.get("RefDot") {
+        let mut reference = input_reference(&dot["refr"])?;
+        reference.push(
+            dot["field"]
+                .as_array()
+                .and_then(|field| field.last())
+                .and_then(Value::as_str)
+                .map(str::to_owned),
+        );
+        return Some(reference);
+    }
+    let brack = node.get("RefBrack")?;
+    let mut reference = input_reference(&brack["refr"])?;
+    reference.push(
+        brack["index"]["String"]["value"]
+            .as_str()
+            .map(str::to_owned),
+    );
+    Some(reference)
+}
+
+/// Returns the dotted path of a reference (e.g. a package name) in the AST.
+pub(crate) fn ref_path(refr: &Value) -> Option<String> {
+    if let Some(var) = refr["Var"]["value"].as_str() {
+        return Some(var.to_owned());
+    }
+    if let Some(dot) = refr.get("RefDot") {
+        let field = dot["field"].as_array()?.last()?.as_str()?;
+        return Some(format!("{}.{field}", ref_path(&dot["refr"])?));
+    }
+    let brack = refr.get("RefBrack")?;
+    let field = brack["index"]["String"]["value"].as_str()?;
+    Some(format!("{}.{field}", ref_path(&brack["refr"])?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ast(rego: &str) -> String {
+        let mut engine = regorus::Engine::new();
+        _ = engine
+            .add_policy("test.rego".to_owned(), rego.to_owned())
+            .expect("Valid policy");
+        engine.get_ast_as_json().expect("AST")
+    }
+
+    #[test]
+    fn test_rule_names() {
+        let ast = ast(r#"package live_check_advice
+
+import rego.v1
+
+deny contains make_finding(id) if {
+    input.sample.attribute
+    id := "a"
+}
+deny contains "b" if input.sample.span
+make_finding(id) := {"id": id}
+helper := 1
+default other := false
+"#);
+        assert_eq!(
+            rule_names(&ast).expect("Valid AST"),
+            vec![
+                "data.live_check_advice.deny",
+                "data.live_check_advice.make_finding",
+                "data.live_check_advice.helper",
+                "data.live_check_advice.other",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_fields() {
+        let fields = |rego: &str| {
+            input_fields(&ast(rego), &["sample"])
+                .expect("Valid AST")
+                .map(|fields| fields.into_iter().collect::<Vec<_>>())
+        };
+        assert_eq!(
+            fields(
+                r#"package live_check_advice
+
+import rego.v1
+
+deny contains id if {
+    input.sample.attribute
+    name := input["sample"].span.name
+    id := data.ids[input.sample.metric.name]
+    input.scope.name == "io.opentelemetry.jdbc"
+}
+"#
+            ),
+            Some(vec![
+                "attribute".to_owned(),
+                "metric".to_owned(),
+                "span".to_owned()
+            ])
+        );
+        assert_eq!(
+            fields("package a\n\nimport rego.v1\n\nhelper := 1\n"),
+            Some(vec![])
+        );
+        // The sample is read as a whole
+        assert_eq!(
+            fields("package a\n\nimport rego.v1\n\ndeny contains s if some s in input.sample\n"),
+            None
+        );
+        assert_eq!(
+            fields("package a\n\nimport rego.v1\n\ndeny contains t if input.sample[t]\n"),
+            None
+        );
+        assert_eq!(
+            fields("package a\n\nimport rego.v1\n\ndeny contains x if x := input\n"),
+            None
+        );
+    }
+}
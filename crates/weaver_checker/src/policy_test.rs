@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for Rego unit tests, i.e. rules prefixed with `test_` (OPA convention).
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::policy_ast::ref_path;
+
+/// The prefix of the Rego rules considered as tests.
+pub(crate) const TEST_RULE_PREFIX: &str = "test_";
+
+/// The outcome of a Rego test rule.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PolicyTestResult {
+    /// The fully qualified test rule e.g. `data.before_resolution.test_deprecated_attr`.
+    pub name: String,
+    /// The reason of the failure, `None` if the test passed.
+    pub failure: Option<String>,
+}
+
+impl PolicyTestResult {
+    /// Returns true if the test passed.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Returns the fully qualified names of the test rules defined in the policies, in
+/// order of appearance. Rules with several definitions are only returned once.
+///
+/// The rules are read from the AST of the compiled policies, as returned by
+/// `regorus::Engine::get_ast_as_json`. Functions and `default` values named `test_*`
+/// are not tests.
+pub(crate) fn test_rule_names(ast: &str) -> Result<Vec<String>, serde_json::Error> {
+    let modules: Vec<Value> = serde_json::from_str(ast)?;
+    let mut names: Vec<String> = Vec::new();
+    for module in &modules {
+        let module = &module["ast"];
+        let Some(package) = ref_path(&module["package"]["refr"]) else {
+            continue;
+        };
+        let rules = module["rules"].as_array().into_iter().flatten();
+        for rule in rules {
+            let Some(rule) = rule["Spec"]["head"]["Compr"]["refr"]["Var"]["value"].as_str() else {
+                continue;
+            };
+            let name = format!("data.{package}.{rule}");
+            if rule.starts_with(TEST_RULE_PREFIX) && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_names_discovery() {
+        let rego = r#"package before_resolution_test
+
+import rego.v1
+
+test_deprecated_attr if {
+    count(data.before_resolution.deny) == 1 with input as {"groups": []}
+}
+
+test_other if true
+test_other if false
+  test_indented if true
+
+# test_commented if true
+message := "test_in_string"
+helper_test_value := 1
+test_function(x) := x
+default test_default := false
+"#;
+        let mut engine = regorus::Engine::new();
+        _ = engine
+            .add_policy("test.rego".to_owned(), rego.to_owned())
+            .expect("Valid policy");
+        _ = engine
+            .add_policy(
+                "other.rego".to_owned(),
+                "package a[\"b-c\"]\ntest_bracket := true".to_owned(),
+            )
+            .expect("Valid policy");
+        let ast = engine.get_ast_as_json().expect("AST");
+        assert_eq!(
+            test_rule_names(&ast).expect("Valid AST"),
+            vec![
+                "data.before_resolution_test.test_deprecated_attr",
+                "data.before_resolution_test.test_other",
+                "data.before_resolution_test.test_indented",
+                "data.a.b-c.test_bracket",
+            ]
+        );
+    }
+}
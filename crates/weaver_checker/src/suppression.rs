@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Inline policy suppressions declared in semantic convention annotations.
+//!
+//! A group or an attribute can suppress specific policy findings with a
+//! mandatory justification:
+//!
+//! ```yaml
+//! annotations:
+//!   policy_suppressions:
+//!     - id: attr_stability_deprecated
+//!       justification: Legacy name kept for compatibility.
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{BaselineEntry, FindingLevel, PolicyFinding};
+
+/// The annotation key holding the policy suppressions of a group or an attribute.
+pub const SUPPRESSIONS_ANNOTATION: &str = "policy_suppressions";
+
+/// The id of the finding reported for a malformed suppression.
+const INVALID_SUPPRESSION: &str = "invalid_policy_suppression";
+
+/// Collections of groups (or signals) in the policy inputs, v1 and v2 layouts.
+const GROUP_COLLECTIONS: [&[&str]; 6] = [
+    &["groups"],
+    &["registry", "attribute_groups"],
+    &["registry", "metrics"],
+    &["registry", "spans"],
+    &["registry", "events"],
+    &["registry", "entities"],
+];
+
+/// Fields identifying a group or a signal.
+pub(crate) const GROUP_ID_FIELDS: [&str; 3] = ["id", "name", "type"];
+
+/// Fields identifying an attribute.
+const ATTRIBUTE_ID_FIELDS: [&str; 4] = ["id", "ref", "name", "key"];
+
+/// A finding suppressed by an inline annotation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SuppressedFinding {
+    /// The suppressed finding.
+    pub finding: PolicyFinding,
+    /// The justification given by the suppression.
+    pub justification: String,
+}
+
+/// A suppression declared on a group or an attribute.
+#[derive(Debug, Clone, PartialEq)]
+struct Suppression {
+    finding_id: String,
+    justification: String,
+    group: Option<String>,
+    attribute: Option<String>,
+}
+
+impl Suppression {
+    fn matches(&self, finding: &PolicyFinding, entry: &BaselineEntry) -> bool {
+        (self.finding_id == entry.id || self.finding_id == finding.id)
+            && (self.group.is_none() || self.group == entry.signal_name)
+            && (self.attribute.is_none() || self.attribute == entry.attribute)
+    }
+}
+
+/// The suppressions declared in a policy input.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Suppressions {
+    suppressions: Vec<Suppression>,
+    /// Findings reported for malformed suppressions.
+    invalid: Vec<PolicyFinding>,
+}
+
+impl Suppressions {
+    /// Collects the suppressions declared on the groups and attributes of a
+    /// policy input (a semconv file or a resolved registry).
+    pub(crate) fn from_input(input: &Value) -> Self {
+        let mut suppressions = Suppressions::default();
+        for path in GROUP_COLLECTIONS {
+            let groups = path
+                .iter()
+                .try_fold(input, |value, key| value.get(*key))
+                .and_then(Value::as_array);
+            for group in groups.into_iter().flatten() {
+                let group_id = identity(group, &GROUP_ID_FIELDS);
+                suppressions.collect(group, group_id.clone(), None);
+                let attributes = group.get("attributes").and_then(Value::as_array);
+                for attribute in attributes.into_iter().flatten() {
+                    let attribute_id = identity(attribute, &ATTRIBUTE_ID_FIELDS);
+                    suppressions.collect(attribute, group_id.clone(), attribute_id);
+                }
+            }
+        }
+        // Attributes defined at the registry level (v2) are not part of a group.
+        let attributes = input
+            .get("registry")
+            .and_then(|registry| registry.get("attributes"))
+            .and_then(Value::as_array);
+        for attribute in attributes.into_iter().flatten() {
+            let attribute_id = identity(attribute, &ATTRIBUTE_ID_FIELDS);
+            suppressions.collect(attribute, None, attribute_id);
+        }
+        suppressions
+    }
+
+    /// Collects the suppressions declared in the annotations of a group or an attribute.
+    fn collect(&mut self, value: &Value, group: Option<String>, attribute: Option<String>) {
+        let Some(entries) = value
+            .get("annotations")
+            .and_then(|annotations| annotations.get(SUPPRESSIONS_ANNOTATION))
+        else {
+            return;
+        };
+        let Some(entries) = entries.as_array() else {
+            self.invalid.push(invalid_suppression(
+                &group,
+                &attribute,
+                "expected a list of `{ id, justification }` entries",
+            ));
+            return;
+        };
+        for entry in entries {
+            let field = |name: &str| {
+                entry
+                    .get(name)
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|v| !v.is_empty())
+                    .map(ToOwned::to_owned)
+            };
+            match (field("id"), field("justification")) {
+                (Some(finding_id), Some(justification)) => self.suppressions.push(Suppression {
+                    finding_id,
+                    justification,
+                    group: group.clone(),
+                    attribute: attribute.clone(),
+                }),
+                (None, _) => self.invalid.push(invalid_suppression(
+                    &group,
+                    &attribute,
+                    "the finding `id` is missing",
+                )),
+                (Some(finding_id), None) => self.invalid.push(invalid_suppression(
+                    &group,
+                    &attribute,
+                    &format!("the suppression of `{finding_id}` has no justification"),
+                )),
+            }
+        }
+    }
+
+    /// The findings reported for the malformed suppressions of the input.
+    pub(crate) fn invalid(&self) -> &[PolicyFinding] {
+        &self.invalid
+    }
+
+    /// Splits the findings into the reported and the suppressed ones.
+    pub(crate) fn apply(
+        &self,
+        findings: Vec<PolicyFinding>,
+    ) -> (Vec<PolicyFinding>, Vec<SuppressedFinding>) {
+        let mut reported = Vec::new();
+        let mut suppressed = Vec::new();
+        for finding in findings {
+            let entry = BaselineEntry::from_finding(&finding);
+            match self
+                .suppressions
+                .iter()
+                .find(|s| s.matches(&finding, &entry))
+            {
+                Some(suppression) => suppressed.push(SuppressedFinding {
+                    finding,
+                    justification: suppression.justification.clone(),
+                }),
+                None => reported.push(finding),
+            }
+        }
+        (reported, suppressed)
+    }
+}
+
+/// Returns the first string value among the given identifying fields.
+pub(crate) fn identity(value: &Value, fields: &[&str]) -> Option<String> {
+    fields
+        .iter()
+        .find_map(|field| value.get(*field).and_then(Value::as_str))
+        .map(ToOwned::to_owned)
+}
+
+fn invalid_suppression(
+    group: &Option<String>,
+    attribute: &Option<String>,
+    reason: &str,
+) -> PolicyFinding {
+    let location = match (group, attribute) {
+        (Some(group), Some(attribute)) => format!("attribute `{attribute}` of `{group}`"),
+        (Some(group), None) => format!("`{group}`"),
+        (None, Some(attribute)) => format!("attribute `{attribute}`"),
+        (None, None) => "unknown location".to_owned(),
+    };
+    PolicyFinding {
+        id: INVALID_SUPPRESSION.to_owned(),
+        context: Some(serde_json::json!({
+            "group": group,
+            "attribute_name": attribute,
+        })),
+        message: format!("Invalid `{SUPPRESSIONS_ANNOTATION}` annotation on {location}: {reason}."),
+        level: FindingLevel::Violation,
+        signal_type: None,
+        signal_name: group.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_v2_registry_attribute_suppression() {
+        let input = json!({
+            "registry": {
+                "attributes": [{
+                    "key": "http.method",
+                    "annotations": {
+                        "policy_suppressions": [
+                            { "id": "deprecated_name", "justification": "Kept for v1 SDKs." }
+                        ]
+                    }
+                }],
+                "spans": [{ "type": "http.client", "attributes": [{ "key": "url.full" }] }]
+            }
+        });
+        let suppressions = Suppressions::from_input(&input);
+        let finding = |id: &str, attribute: &str| PolicyFinding {
+            id: id.to_owned(),
+            context: Some(json!({ "attribute_name": attribute })),
+            message: String::new(),
+            level: FindingLevel::Violation,
+            signal_type: Some("span".to_owned()),
+            signal_name: Some("http.client".to_owned()),
+        };
+        let (reported, suppressed) = suppressions.apply(vec![
+            finding("deprecated_name", "http.method"),
+            finding("deprecated_name", "url.full"),
+            finding("other", "http.method"),
+        ]);
+        assert_eq!(reported.len(), 2);
+        assert_eq!(suppressed.len(), 1);
+        assert_eq!(suppressed[0].justification, "Kept for v1 SDKs.");
+    }
+}
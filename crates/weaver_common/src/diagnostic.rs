@@ -94,7 +94,9 @@ impl DiagnosticMessage {
         let labels = error.labels().map(|labels| labels.collect());
         let ansi_message = format!(
             "{:?}",
-            if is_future_mode_enabled() {
+            // Advice messages (e.g. suppressed findings) are informational
+            // and are never promoted to errors.
+            if is_future_mode_enabled() && severity != Some(Severity::Advice) {
                 severity = Some(Severity::Error);
                 Report::new(MietteDiagnostic {
                     message: message.clone(),
@@ -129,6 +131,22 @@ impl DiagnosticMessage {
     pub fn is_warning(&self) -> bool {
         self.diagnostic.severity == Some(Severity::Warning)
     }
+
+    /// Returns true if the diagnostic message is an error, i.e. neither a
+    /// warning nor an advice.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        !matches!(
+            self.diagnostic.severity,
+            Some(Severity::Warning | Severity::Advice)
+        )
+    }
+
+    /// Returns the message of the diagnostic, without formatting.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.diagnostic.message
+    }
 }
 
 impl DiagnosticMessages {
@@ -190,15 +208,7 @@ impl DiagnosticMessages {
     /// Returns true if at least one diagnostic message has an error severity.
     #[must_use]
     pub fn has_error(&self) -> bool {
-        let non_error_count = self
-            .0
-            .iter()
-            .filter(|message| {
-                message.diagnostic.severity == Some(Severity::Warning)
-                    || message.diagnostic.severity == Some(Severity::Advice)
-            })
-            .count();
-        self.0.len() - non_error_count > 0
+        self.0.iter().any(DiagnosticMessage::is_error)
     }
 
     /// Returns true if there are no diagnostic messages
@@ -5,6 +5,9 @@
 //! Longest URL-prefix match across the rules in an [`HttpAuthResolver`] wins.
 //! [`TokenSource`] is also the serde shape for `[[auth]]` entries in
 //! `.weaver.toml` (wrapped by `weaver_config::AuthEntry`).
+//!
+//! [`is_bearer_authorized`] checks the Bearer token of the requests received by
+//! the weaver HTTP endpoints.
 
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -170,10 +173,49 @@ fn run_token_command(argv: &[String]) -> Option<String> {
     }
 }
 
+/// Checks the value of the `Authorization` header of a request against the
+/// expected Bearer token, in a time independent of the token content.
+///
+/// An empty expected token authorizes no request.
+#[must_use]
+pub fn is_bearer_authorized(authorization: Option<&str>, expected: &str) -> bool {
+    if expected.is_empty() {
+        return false;
+    }
+    let provided = authorization
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or_default();
+    constant_time_eq(provided.as_bytes(), expected.as_bytes())
+}
+
+/// Compares two byte strings in a time independent of their content.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"s3cr3t", b"s3cr3t"));
+        assert!(!constant_time_eq(b"s3cr3t", b"s3cr3T"));
+        assert!(!constant_time_eq(b"s3cr3t", b"s3cr3"));
+        assert!(!constant_time_eq(b"", b"s3cr3t"));
+    }
+
+    #[test]
+    fn test_is_bearer_authorized() {
+        assert!(is_bearer_authorized(Some("Bearer s3cr3t"), "s3cr3t"));
+        assert!(!is_bearer_authorized(Some("Bearer other"), "s3cr3t"));
+        assert!(!is_bearer_authorized(Some("s3cr3t"), "s3cr3t"));
+        assert!(!is_bearer_authorized(None, "s3cr3t"));
+        // An empty expected token authorizes no request
+        assert!(!is_bearer_authorized(None, ""));
+        assert!(!is_bearer_authorized(Some("Bearer "), ""));
+    }
+
     #[test]
     fn empty_resolver_returns_none() {
         let r = HttpAuthResolver::empty();
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration structs for the `registry diff` subcommand.
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Configuration for the diff subcommand.
+#[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
+#[serde(default)]
+#[schemars(inline)]
+pub struct DiffConfig {
+    /// Baseline registry to compare against.
+    /// Local folder, Git repo URL, or Git archive URL.
+    pub baseline_registry: Option<String>,
+
+    /// Format used to render the schema changes: `ansi`, `json`, `markdown`, ...
+    pub format: String,
+
+    /// Path to the directory where the schema changes templates are located.
+    pub templates: PathBuf,
+
+    /// Path to the directory where the generated artifacts will be saved.
+    /// If not set, the diff report is printed to stdout.
+    pub output: Option<PathBuf>,
+}
+
+impl Default for DiffConfig {
+    fn default() -> Self {
+        Self {
+            baseline_registry: None,
+            format: "ansi".to_owned(),
+            templates: PathBuf::from("diff_templates"),
+            output: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WeaverConfig;
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_diff_config() {
+        let toml = r#"
+[diff]
+baseline_registry = "https://github.com/open-telemetry/semantic-conventions/archive/refs/tags/v1.26.0.zip[model]"
+format = "markdown"
+output = "diff_out"
+"#;
+        let config: WeaverConfig = toml::from_str(toml).expect("Failed to parse TOML");
+        let diff = &config.diff;
+        assert!(diff
+            .baseline_registry
+            .as_deref()
+            .is_some_and(|b| b.ends_with("v1.26.0.zip[model]")));
+        assert_eq!(diff.format, "markdown");
+        assert_eq!(diff.templates, Path::new("diff_templates"));
+        assert_eq!(diff.output.as_deref(), Some(Path::new("diff_out")));
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration structs for the `registry emit` subcommand.
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Configuration for the emit subcommand.
+#[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
+#[serde(default)]
+#[schemars(inline)]
+pub struct EmitConfig {
+    /// Write the telemetry to standard output instead of an OTLP receiver.
+    pub stdout: bool,
+
+    /// Endpoint for the OTLP receiver. `OTEL_EXPORTER_OTLP_ENDPOINT` overrides this.
+    pub endpoint: String,
+}
+
+impl Default for EmitConfig {
+    fn default() -> Self {
+        Self {
+            stdout: false,
+            endpoint: "http://localhost:4317".to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WeaverConfig;
+
+    #[test]
+    fn test_parse_emit_config() {
+        let config: WeaverConfig =
+            toml::from_str("[emit]\nendpoint = \"http://collector:4317\"\n").expect("parse");
+        assert!(!config.emit.stdout);
+        assert_eq!(config.emit.endpoint, "http://collector:4317");
+
+        let config: WeaverConfig = toml::from_str("").expect("parse empty");
+        assert_eq!(config.emit.endpoint, "http://localhost:4317");
+    }
+}
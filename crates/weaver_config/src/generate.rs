@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration structs for the `registry generate` subcommand.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Configuration for the generate subcommand.
+///
+/// The top-level fields apply to every invocation. Named entries under
+/// `[generate.targets.<name>]` layer on top of them when that target is
+/// selected (or when `--all` runs every configured target).
+#[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
+#[serde(default)]
+#[schemars(inline)]
+pub struct GenerateConfig {
+    /// Target to generate the artifacts for. Empty means the templates root.
+    pub target: String,
+
+    /// Path to the directory where the generated artifacts will be saved.
+    pub output: PathBuf,
+
+    /// Path to the directory where the templates are located.
+    /// Local folder, Git repo URL, or Git archive URL.
+    pub templates: String,
+
+    /// List of `weaver.yaml` configuration files to use. When there is a conflict,
+    /// the last one overrides the previous ones.
+    pub config: Option<Vec<PathBuf>>,
+
+    /// YAML file of parameters to pass to the templates.
+    pub params: Option<PathBuf>,
+
+    /// Named generate targets, run individually by name or together with `--all`.
+    pub targets: BTreeMap<String, GenerateTargetConfig>,
+}
+
+impl Default for GenerateConfig {
+    fn default() -> Self {
+        Self {
+            target: String::new(),
+            output: PathBuf::from("output"),
+            templates: "templates".to_owned(),
+            config: None,
+            params: None,
+            targets: BTreeMap::new(),
+        }
+    }
+}
+
+impl GenerateConfig {
+    /// Return the effective settings for the named target: the top-level
+    /// settings with the named entry's fields layered on top.
+    ///
+    /// The template target defaults to the entry name. Returns `None` if no
+    /// entry with this name is configured.
+    #[must_use]
+    pub fn for_target(&self, name: &str) -> Option<GenerateConfig> {
+        let entry = self.targets.get(name)?;
+        let mut config = self.clone();
+        config.targets = BTreeMap::new();
+        config.target = entry.target.clone().unwrap_or_else(|| name.to_owned());
+        if let Some(output) = &entry.output {
+            config.output.clone_from(output);
+        }
+        if let Some(templates) = &entry.templates {
+            config.templates.clone_from(templates);
+        }
+        if entry.config.is_some() {
+            config.config.clone_from(&entry.config);
+        }
+        if entry.params.is_some() {
+            config.params.clone_from(&entry.params);
+        }
+        Some(config)
+    }
+}
+
+/// A named generate target. Unset fields fall back to the `[generate]` section.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct GenerateTargetConfig {
+    /// Template target directory. Defaults to the entry name.
+    pub target: Option<String>,
+    /// Path to the directory where the generated artifacts will be saved.
+    pub output: Option<PathBuf>,
+    /// Path to the directory where the templates are located.
+    pub templates: Option<String>,
+    /// List of `weaver.yaml` configuration files to use.
+    pub config: Option<Vec<PathBuf>>,
+    /// YAML file of parameters to pass to the templates.
+    pub params: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WeaverConfig;
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_generate_config() {
+        let toml = r#"
+[generate]
+templates = "https://github.com/acme/templates.git"
+params = "params.yaml"
+
+[generate.targets.rust]
+output = "src/generated"
+
+[generate.targets.markdown-docs]
+target = "markdown"
+output = "docs/generated"
+templates = "docs_templates"
+config = ["docs/weaver.yaml"]
+"#;
+        let config: WeaverConfig = toml::from_str(toml).expect("Failed to parse TOML");
+        let gen = &config.generate;
+        assert_eq!(gen.output, Path::new("output"));
+        assert_eq!(gen.targets.len(), 2);
+
+        let rust = gen.for_target("rust").expect("rust target");
+        assert_eq!(rust.target, "rust");
+        assert_eq!(rust.output, Path::new("src/generated"));
+        assert_eq!(rust.templates, "https://github.com/acme/templates.git");
+        assert_eq!(rust.params.as_deref(), Some(Path::new("params.yaml")));
+        assert!(rust.targets.is_empty());
+
+        let md = gen.for_target("markdown-docs").expect("markdown target");
+        assert_eq!(md.target, "markdown");
+        assert_eq!(md.templates, "docs_templates");
+        assert_eq!(
+            md.config.as_deref(),
+            Some(&[Path::new("docs/weaver.yaml").to_path_buf()][..])
+        );
+
+        assert!(gen.for_target("go").is_none());
+    }
+
+    #[test]
+    fn test_generate_defaults() {
+        let config: WeaverConfig = toml::from_str("").expect("Failed to parse empty TOML");
+        assert_eq!(config.generate.target, "");
+        assert_eq!(config.generate.output, Path::new("output"));
+        assert_eq!(config.generate.templates, "templates");
+        assert!(config.generate.targets.is_empty());
+    }
+
+    #[test]
+    fn test_generate_target_rejects_unknown_fields() {
+        let toml = r#"
+[generate.targets.rust]
+outptu = "src"
+"#;
+        let err = toml::from_str::<WeaverConfig>(toml).expect_err("should fail");
+        assert!(err.to_string().contains("outptu"), "unexpected: {err}");
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration structs for the `registry infer` subcommand.
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Configuration for the infer subcommand.
+#[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
+#[serde(default)]
+#[schemars(inline)]
+pub struct InferConfig {
+    /// Output folder for generated YAML files.
+    pub output: PathBuf,
+    /// Address used by the gRPC OTLP listener.
+    pub grpc_address: String,
+    /// Port used by the gRPC OTLP listener.
+    pub grpc_port: u16,
+    /// Port used by the HTTP admin server (endpoints: `/stop`).
+    pub admin_port: u16,
+    /// Seconds of inactivity before auto-stop (0 = never).
+    pub inactivity_timeout: u64,
+}
+
+impl Default for InferConfig {
+    fn default() -> Self {
+        Self {
+            output: PathBuf::from("./inferred-registry/"),
+            grpc_address: "0.0.0.0".to_owned(),
+            grpc_port: 4317,
+            admin_port: 8080,
+            inactivity_timeout: 60,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WeaverConfig;
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_infer_config() {
+        let toml = r#"
+[infer]
+output = "inferred"
+grpc_port = 14317
+inactivity_timeout = 0
+"#;
+        let config: WeaverConfig = toml::from_str(toml).expect("parse");
+        let infer = &config.infer;
+        assert_eq!(infer.output, Path::new("inferred"));
+        assert_eq!(infer.grpc_address, "0.0.0.0");
+        assert_eq!(infer.grpc_port, 14317);
+        assert_eq!(infer.admin_port, 8080);
+        assert_eq!(infer.inactivity_timeout, 0);
+    }
+}
@@ -10,15 +10,30 @@ use serde::Deserialize;
 use std::path::{Path, PathBuf};
 
 pub mod auth;
+pub mod diff;
+pub mod emit;
+pub mod generate;
+pub mod infer;
 pub mod live_check;
+pub mod mcp;
 mod overrides;
 pub mod registry;
+pub mod serve;
+pub mod stats;
 
 // Re-export the public API so callers can use `weaver_config::LiveCheckConfig` etc.
 pub use auth::{build_resolver as build_auth_resolver, AuthEntry};
+pub use diff::DiffConfig;
+pub use emit::EmitConfig;
+pub use generate::{GenerateConfig, GenerateTargetConfig};
+pub use infer::InferConfig;
 pub use live_check::{FindingFilter, LiveCheckConfig, LiveCheckEmitConfig, LiveCheckOtlpConfig};
+pub use mcp::McpConfig;
 pub use overrides::{CliOverrides, FieldMapping};
 pub use registry::{DiagnosticsConfig, PolicyConfig, RegistryConfig};
+pub use serve::{NamedRegistryConfig, ServeConfig};
+pub use stats::StatsConfig;
+pub use weaver_checker::lint::LintConfig;
 pub use weaver_common::http_auth::TokenSource;
 
 /// The filename to search for during discovery.
@@ -34,8 +49,24 @@ pub struct WeaverConfig {
     pub policy: PolicyConfig,
     /// Shared diagnostic output settings (apply to all subcommands that accept them).
     pub diagnostics: DiagnosticsConfig,
+    /// Native lint rules checked with the `after_resolution` policies.
+    pub lint: LintConfig,
+    /// Generate specific configuration, including named targets.
+    pub generate: GenerateConfig,
+    /// Diff specific configuration.
+    pub diff: DiffConfig,
+    /// Emit specific configuration.
+    pub emit: EmitConfig,
+    /// Stats specific configuration.
+    pub stats: StatsConfig,
     /// Live-check specific configuration.
     pub live_check: LiveCheckConfig,
+    /// MCP server specific configuration.
+    pub mcp: McpConfig,
+    /// Infer specific configuration.
+    pub infer: InferConfig,
+    /// Serve specific configuration.
+    pub serve: ServeConfig,
     /// Per-URL HTTP authentication entries for downloading remote registries.
     /// See [`auth::AuthEntry`] for the schema.
     pub auth: Vec<AuthEntry>,
@@ -22,10 +22,10 @@ pub struct LiveCheckConfig {
     #[serde(default)]
     pub finding_filters: Vec<FindingFilter>,
 
-    /// Where to read the input telemetry from. `{file path}` | `stdin` | `otlp`.
+    /// Where to read the input telemetry from. `{file path}` | `{http(s) URL}` | `stdin` | `otlp`.
     pub input_source: String,
 
-    /// The format of the input telemetry. `text` | `json`. (Not used for OTLP.)
+    /// The format of the input telemetry. `text` | `json` | `prometheus`. (Not used for OTLP.)
     pub input_format: String,
 
     /// Format used to render the report.
@@ -54,6 +54,22 @@ pub struct LiveCheckConfig {
     /// being passed to rego policies.
     pub advice_preprocessor: Option<PathBuf>,
 
+    /// Number of worker threads checking the samples in parallel.
+    pub advice_workers: usize,
+
+    /// OpenTelemetry schema files used to translate telemetry produced with an older
+    /// semconv version (identified by the schema URL of the samples).
+    pub schema_files: Vec<PathBuf>,
+
+    /// Historical versions of the registry used to translate telemetry produced with an
+    /// older semconv version. Their attributes and metrics deprecated with `renamed_to`
+    /// are used like the renamings of a schema file.
+    pub historical_registries: Vec<String>,
+
+    /// Number of distinct values above which a metric attribute is reported as
+    /// `high_cardinality`.
+    pub cardinality_threshold: u64,
+
     /// OTLP listener settings (used when `input_source = "otlp"`).
     pub otlp: LiveCheckOtlpConfig,
 
@@ -74,6 +90,10 @@ impl Default for LiveCheckConfig {
             output: None,
             advice_policies: None,
             advice_preprocessor: None,
+            advice_workers: 1,
+            schema_files: Vec::new(),
+            historical_registries: Vec::new(),
+            cardinality_threshold: 100,
             otlp: LiveCheckOtlpConfig::default(),
             emit: LiveCheckEmitConfig::default(),
         }
@@ -92,6 +112,15 @@ pub struct LiveCheckOtlpConfig {
     pub admin_port: u16,
     /// Max inactivity time in seconds before stopping the listener.
     pub inactivity_timeout: u64,
+    /// Time in seconds the spans of a trace are buffered before the assembled trace
+    /// is checked as a whole. `0` disables trace assembly.
+    pub trace_window: u64,
+    /// Report the CLIENT and PRODUCER spans of the assembled traces that have no
+    /// SERVER or CONSUMER child span.
+    pub expect_child_spans: bool,
+    /// File where the received OTLP requests are recorded, to be replayed later
+    /// with `input_format = "otlp"`.
+    pub record: Option<PathBuf>,
 }
 
 impl Default for LiveCheckOtlpConfig {
@@ -101,6 +130,9 @@ impl Default for LiveCheckOtlpConfig {
             grpc_port: 4317,
             admin_port: 4320,
             inactivity_timeout: 10,
+            trace_window: 0,
+            expect_child_spans: false,
+            record: None,
         }
     }
 }
@@ -128,7 +160,8 @@ impl Default for LiveCheckEmitConfig {
 }
 
 /// A filter that drops findings by ID exclusion or minimum level.
-/// Optional `signal_type` scopes the filter to a specific signal type.
+/// Optional `signal_type` and `scope_name` scope the filter to a specific signal
+/// type and instrumentation scope.
 #[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
 pub struct FindingFilter {
     /// Drop findings with these IDs.
@@ -138,6 +171,10 @@ pub struct FindingFilter {
     /// Optional signal type scope. When set, this filter only applies to
     /// findings with a matching signal_type.
     pub signal_type: Option<String>,
+    /// Optional instrumentation scope name. When set, this filter only applies
+    /// to findings on signals produced by a matching instrumentation scope
+    /// (e.g. `io.opentelemetry.jdbc`).
+    pub scope_name: Option<String>,
     /// Drop all findings for samples with these names.
     /// For attribute samples, this matches the attribute key — e.g.
     /// `["trace.parent_id", "trace.span_id"]` suppresses all findings
@@ -164,11 +201,16 @@ min_level = "improvement"
 [[live_check.finding_filters]]
 signal_type = "span"
 exclude = ["not_stable"]
+
+# Scoped filter (with scope_name)
+[[live_check.finding_filters]]
+scope_name = "io.opentelemetry.jdbc"
+min_level = "violation"
 "#;
         let config: WeaverConfig = toml::from_str(toml).expect("Failed to parse TOML");
         let lc = &config.live_check;
 
-        assert_eq!(lc.finding_filters.len(), 2);
+        assert_eq!(lc.finding_filters.len(), 3);
 
         // Global filter (no signal_type)
         assert!(lc.finding_filters[0].signal_type.is_none());
@@ -187,6 +229,13 @@ exclude = ["not_stable"]
             lc.finding_filters[1].exclude.as_deref(),
             Some(&["not_stable".to_owned()][..])
         );
+
+        // Scoped filter (with scope_name)
+        assert_eq!(
+            lc.finding_filters[2].scope_name.as_deref(),
+            Some("io.opentelemetry.jdbc")
+        );
+        assert!(lc.finding_filters[2].signal_type.is_none());
     }
 
     #[test]
@@ -227,12 +276,15 @@ no_stats = true
 output = "reports"
 advice_policies = "policies"
 advice_preprocessor = "pre.jq"
+advice_workers = 4
 
 [live_check.otlp]
 grpc_address = "127.0.0.1"
 grpc_port = 4317
 admin_port = 4320
 inactivity_timeout = 30
+expect_child_spans = true
+record = "traffic.otlp"
 
 [live_check.emit]
 otlp_logs = true
@@ -250,11 +302,14 @@ otlp_logs_stdout = false
         assert_eq!(lc.output.as_deref(), Some(Path::new("reports")));
         assert_eq!(lc.advice_policies.as_deref(), Some(Path::new("policies")));
         assert_eq!(lc.advice_preprocessor.as_deref(), Some(Path::new("pre.jq")));
+        assert_eq!(lc.advice_workers, 4);
 
         assert_eq!(lc.otlp.grpc_address, "127.0.0.1");
         assert_eq!(lc.otlp.grpc_port, 4317);
         assert_eq!(lc.otlp.admin_port, 4320);
         assert_eq!(lc.otlp.inactivity_timeout, 30);
+        assert!(lc.otlp.expect_child_spans);
+        assert_eq!(lc.otlp.record.as_deref(), Some(Path::new("traffic.otlp")));
 
         assert!(lc.emit.otlp_logs);
         assert_eq!(lc.emit.otlp_logs_endpoint, "http://localhost:4317");
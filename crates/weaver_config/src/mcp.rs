@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration structs for the `registry mcp` subcommand.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Configuration for the MCP server subcommand.
+#[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
+#[serde(default)]
+#[schemars(inline)]
+pub struct McpConfig {
+    /// Advice policies directory. Overrides the built-in default policies.
+    pub advice_policies: Option<PathBuf>,
+
+    /// Advice preprocessor — a jq script run once over the registry data before
+    /// being passed to rego policies.
+    pub advice_preprocessor: Option<PathBuf>,
+
+    /// Namespace separator used in attribute keys.
+    pub namespace_separator: String,
+
+    /// Address to serve MCP over streamable HTTP, e.g. `127.0.0.1:8090`.
+    /// If not set, the server communicates over stdio.
+    pub bind: Option<SocketAddr>,
+
+    /// Name of the environment variable holding the bearer token required by
+    /// the HTTP transport. If not set, the HTTP endpoint is unauthenticated.
+    pub auth_token_env: Option<String>,
+}
+
+impl Default for McpConfig {
+    fn default() -> Self {
+        Self {
+            advice_policies: None,
+            advice_preprocessor: None,
+            namespace_separator: ".".to_owned(),
+            bind: None,
+            auth_token_env: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WeaverConfig;
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_mcp_config() {
+        let toml = r#"
+[mcp]
+advice_policies = "policies"
+namespace_separator = "_"
+bind = "0.0.0.0:8090"
+auth_token_env = "WEAVER_MCP_TOKEN"
+"#;
+        let config: WeaverConfig = toml::from_str(toml).expect("parse");
+        assert_eq!(
+            config.mcp.advice_policies.as_deref(),
+            Some(Path::new("policies"))
+        );
+        assert!(config.mcp.advice_preprocessor.is_none());
+        assert_eq!(config.mcp.namespace_separator, "_");
+        assert_eq!(
+            config.mcp.bind.map(|bind| bind.to_string()).as_deref(),
+            Some("0.0.0.0:8090")
+        );
+        assert_eq!(
+            config.mcp.auth_token_env.as_deref(),
+            Some("WEAVER_MCP_TOKEN")
+        );
+    }
+}
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration structs for the `serve` command.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Configuration for the serve command.
+#[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
+#[serde(default)]
+#[schemars(inline)]
+pub struct ServeConfig {
+    /// Address to bind the server to, e.g. `127.0.0.1:8080`.
+    pub bind: SocketAddr,
+
+    /// Allowed CORS origins (comma-separated). Use `*` for any origin.
+    /// If not set, CORS is disabled (same-origin only).
+    pub cors_origins: Option<String>,
+
+    /// Watch the local registries and reload them when their files change.
+    pub watch: bool,
+
+    /// Name of the environment variable holding the bearer token required by
+    /// the reload endpoints. If not set, the reload endpoints are disabled.
+    pub reload_token_env: Option<String>,
+
+    /// Additional registries served side by side with the main registry under
+    /// `/api/v1/registries/{name}/...`.
+    pub registries: Vec<NamedRegistryConfig>,
+
+    /// Registry paths (local folder, Git repo URL or Git archive URL) allowed as
+    /// the baseline of the diff endpoint, in addition to the served registries.
+    pub diff_baselines: Vec<String>,
+
+    /// Directory of the Rego advice policies run by the live-check endpoint.
+    /// If not set, the default live-check policies are used.
+    pub advice_policies: Option<PathBuf>,
+
+    /// jq script preprocessing the registry data before passing it to the
+    /// live-check Rego policies.
+    pub advice_preprocessor: Option<PathBuf>,
+
+    /// Mount the MCP server, serving the main registry over streamable HTTP,
+    /// on `/mcp`.
+    pub mcp: bool,
+
+    /// Name of the environment variable holding the bearer token required by
+    /// the MCP endpoint. If not set, the MCP endpoint is unauthenticated.
+    pub mcp_token_env: Option<String>,
+}
+
+/// A registry served under a name, e.g. a released version of the registry.
+///
+/// ```toml
+/// [[serve.registries]]
+/// name = "v1.30.0"
+/// registry = "https://github.com/open-telemetry/semantic-conventions.git@v1.30.0[model]"
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NamedRegistryConfig {
+    /// The name of the registry, used in the API paths.
+    pub name: String,
+    /// Local folder, Git repo URL, or Git archive URL of the registry.
+    pub registry: String,
+}
+
+impl Default for ServeConfig {
+    fn default() -> Self {
+        Self {
+            bind: SocketAddr::from(([127, 0, 0, 1], 8080)),
+            cors_origins: None,
+            watch: false,
+            reload_token_env: None,
+            registries: Vec::new(),
+            diff_baselines: Vec::new(),
+            advice_policies: None,
+            advice_preprocessor: None,
+            mcp: false,
+            mcp_token_env: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WeaverConfig;
+
+    #[test]
+    fn test_parse_serve_config() {
+        let toml = r#"
+[serve]
+bind = "0.0.0.0:9000"
+cors_origins = "*"
+watch = true
+reload_token_env = "WEAVER_RELOAD_TOKEN"
+diff_baselines = ["https://github.com/open-telemetry/semantic-conventions.git@v1.29.0[model]"]
+advice_policies = "policies/advice"
+mcp = true
+mcp_token_env = "WEAVER_MCP_TOKEN"
+
+[[serve.registries]]
+name = "v1.30.0"
+registry = "https://github.com/open-telemetry/semantic-conventions.git@v1.30.0[model]"
+"#;
+        let config: WeaverConfig = toml::from_str(toml).expect("parse");
+        assert_eq!(config.serve.bind.to_string(), "0.0.0.0:9000");
+        assert_eq!(config.serve.cors_origins.as_deref(), Some("*"));
+        assert!(config.serve.watch);
+        assert_eq!(
+            config.serve.reload_token_env.as_deref(),
+            Some("WEAVER_RELOAD_TOKEN")
+        );
+        assert_eq!(config.serve.diff_baselines.len(), 1);
+        assert_eq!(config.serve.advice_policies, Some("policies/advice".into()));
+        assert!(config.serve.mcp);
+        assert_eq!(
+            config.serve.mcp_token_env.as_deref(),
+            Some("WEAVER_MCP_TOKEN")
+        );
+        assert_eq!(config.serve.registries.len(), 1);
+        assert_eq!(config.serve.registries[0].name, "v1.30.0");
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration structs for the `registry stats` subcommand.
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Configuration for the stats subcommand.
+#[derive(Debug, Clone, Deserialize, PartialEq, JsonSchema)]
+#[serde(default)]
+#[schemars(inline)]
+pub struct StatsConfig {
+    /// Output format for the stats: `text`, `json`, `yaml`, `jsonl`, `mute`.
+    pub format: String,
+
+    /// Path to the directory where the stats templates are located.
+    pub templates: PathBuf,
+
+    /// Path to the directory where the generated artifacts will be saved.
+    /// If not set, the stats are printed to stdout.
+    pub output: Option<PathBuf>,
+}
+
+impl Default for StatsConfig {
+    fn default() -> Self {
+        Self {
+            format: "text".to_owned(),
+            templates: PathBuf::from("stats_templates"),
+            output: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WeaverConfig;
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_stats_config() {
+        let config: WeaverConfig = toml::from_str("[stats]\nformat = \"json\"\n").expect("parse");
+        assert_eq!(config.stats.format, "json");
+        assert_eq!(config.stats.templates, Path::new("stats_templates"));
+        assert!(config.stats.output.is_none());
+    }
+}
@@ -217,6 +217,7 @@ mod tests {
             name: name.to_owned(),
             r#type: attr_type,
             examples,
+            constraints: None,
             brief: "".to_owned(),
             tag: None,
             requirement_level: RequirementLevel::Recommended {
@@ -726,6 +727,7 @@ mod tests {
             key: "test.v2.string".to_owned(),
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             examples: Some(Examples::String("v2_example".to_owned())),
+            constraints: None,
             common: CommonFields {
                 brief: "Test v2 attribute".to_owned(),
                 note: String::new(),
@@ -296,6 +296,7 @@ mod tests {
                             "value1".to_owned(),
                             "value2".to_owned(),
                         ])),
+                        constraints: None,
                         brief: "".to_owned(),
                         tag: None,
                         requirement_level: RequirementLevel::Recommended {
@@ -317,6 +318,7 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -341,6 +343,7 @@ mod tests {
                     instrument: Some(InstrumentSpec::UpDownCounter),
                     unit: Some("1".to_owned()),
                     metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -364,6 +367,7 @@ mod tests {
                     instrument: Some(InstrumentSpec::Counter),
                     unit: Some("1".to_owned()),
                     metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -387,6 +391,7 @@ mod tests {
                     instrument: Some(InstrumentSpec::Gauge),
                     unit: Some("1".to_owned()),
                     metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -410,6 +415,7 @@ mod tests {
                     instrument: Some(InstrumentSpec::Histogram),
                     unit: Some("1".to_owned()),
                     metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -434,6 +440,7 @@ mod tests {
                     unit: Some("1".to_owned()),
                     name: None,
                     metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                    advice: None,
                     lineage: None,
                     display_name: None,
                     body: None,
@@ -456,6 +463,7 @@ mod tests {
                     instrument: Some(InstrumentSpec::Counter),
                     unit: Some("1".to_owned()),
                     metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -479,6 +487,7 @@ mod tests {
                     instrument: Some(InstrumentSpec::Gauge),
                     unit: Some("1".to_owned()),
                     metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -502,6 +511,7 @@ mod tests {
                     instrument: Some(InstrumentSpec::Histogram),
                     unit: Some("1".to_owned()),
                     metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -524,6 +534,7 @@ mod tests {
                         examples: Some(Examples::Strings(vec![
                             "00112233-4455-6677-8899-aabbccddeeff".to_owned(),
                         ])),
+                        constraints: None,
                         brief: "A unique session identifier".to_owned(),
                         tag: None,
                         requirement_level: RequirementLevel::Recommended {
@@ -545,6 +556,7 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: Some("session.start".to_owned()),
                     lineage: None,
                     display_name: Some("Session Start Event".to_owned()),
@@ -612,6 +624,8 @@ mod tests {
                     kind: SpanKindSpec::Internal,
                     name: SpanName {
                         note: "test span".to_owned(),
+                        template: None,
+                        fallbacks: vec![],
                     },
                     attributes: vec![SpanAttribute {
                         base: V2Attribute {
@@ -623,6 +637,7 @@ mod tests {
                                 "value1".to_owned(),
                                 "value2".to_owned(),
                             ])),
+                            constraints: None,
                             common: CommonFields {
                                 brief: "Test attribute".to_owned(),
                                 note: String::new(),
@@ -638,6 +653,7 @@ mod tests {
                         sampling_relevant: None,
                     }],
                     entity_associations: vec![],
+                    events: vec![],
                     common: CommonFields {
                         brief: "Test span".to_owned(),
                         note: String::new(),
@@ -653,6 +669,7 @@ mod tests {
                         instrument: InstrumentSpec::UpDownCounter,
                         unit: "1".to_owned(),
                         requirement_level: Some(BasicRequirementLevelSpec::Required),
+                        advice: None,
                         attributes: vec![],
                         entity_associations: vec![],
                         common: CommonFields {
@@ -669,6 +686,7 @@ mod tests {
                         instrument: InstrumentSpec::Counter,
                         unit: "1".to_owned(),
                         requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                        advice: None,
                         attributes: vec![],
                         entity_associations: vec![],
                         common: CommonFields {
@@ -685,6 +703,7 @@ mod tests {
                         instrument: InstrumentSpec::Gauge,
                         unit: "1".to_owned(),
                         requirement_level: Some(BasicRequirementLevelSpec::OptIn),
+                        advice: None,
                         attributes: vec![],
                         entity_associations: vec![],
                         common: CommonFields {
@@ -701,6 +720,7 @@ mod tests {
                         instrument: InstrumentSpec::Histogram,
                         unit: "1".to_owned(),
                         requirement_level: Some(BasicRequirementLevelSpec::Required),
+                        advice: None,
                         attributes: vec![],
                         entity_associations: vec![],
                         common: CommonFields {
@@ -724,6 +744,7 @@ mod tests {
                             examples: Some(Examples::Strings(vec![
                                 "00112233-4455-6677-8899-aabbccddeeff".to_owned(),
                             ])),
+                            constraints: None,
                             common: CommonFields {
                                 brief: "A unique session identifier".to_owned(),
                                 note: String::new(),
@@ -5,5 +5,5 @@ pub mod ansi;
 pub mod case;
 pub mod code;
 pub mod otel;
-mod prom;
+pub mod prom;
 pub mod util;
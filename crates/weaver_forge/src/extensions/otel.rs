@@ -939,6 +939,7 @@ mod tests {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "A brief description".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             sampling_relevant: None,
@@ -972,6 +973,7 @@ mod tests {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "A brief description".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             sampling_relevant: None,
@@ -1160,6 +1162,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended),
                 sampling_relevant: None,
@@ -1177,6 +1180,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended),
                 sampling_relevant: None,
@@ -1194,6 +1198,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::ConditionallyRequired { text: "hi".into() },
                 sampling_relevant: None,
@@ -1211,6 +1216,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::ConditionallyRequired { text: "hi".into() },
                 sampling_relevant: None,
@@ -1228,6 +1234,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Recommended { text: "hi".into() },
                 sampling_relevant: None,
@@ -1245,6 +1252,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Recommended { text: "hi".into() },
                 sampling_relevant: None,
@@ -1262,6 +1270,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::OptIn),
                 sampling_relevant: None,
@@ -1279,6 +1288,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::OptIn),
                 sampling_relevant: None,
@@ -1296,6 +1306,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
                 sampling_relevant: None,
@@ -1313,6 +1324,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
                 sampling_relevant: None,
@@ -1427,6 +1439,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
                 sampling_relevant: None,
@@ -1444,6 +1457,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended),
                 sampling_relevant: None,
@@ -1461,6 +1475,7 @@ mod tests {
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
                 sampling_relevant: None,
@@ -1753,6 +1768,7 @@ mod tests {
             r#type: enum_type(vec!["value1".into(), "value2".into()]),
             brief: "A brief description".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             sampling_relevant: None,
@@ -1783,6 +1799,7 @@ mod tests {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "A brief description".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             sampling_relevant: None,
@@ -1,22 +1,33 @@
 // copied from https://github.com/open-telemetry/opentelemetry-rust/blob/main/opentelemetry-prometheus/src/utils.rs
 // with minor modifications
-// - making functions pub(crate)
+// - making functions pub(crate) or pub
 // - adding get_suffixes function that adds _total for counters
 // - add get_names function that generates all possible names
+// - add get_ucum_unit function that reverses get_prom_units
+
+//! Mapping of semantic convention metric names and units to Prometheus names.
 
 use itertools::Itertools;
 use std::borrow::Cow;
 
 const NON_APPLICABLE_ON_PER_UNIT: [&str; 8] = ["1", "d", "h", "min", "s", "ms", "us", "ns"];
 
-pub(crate) enum TranslationStrategy {
+/// The translation strategies of the OpenTelemetry Prometheus exporter.
+pub enum TranslationStrategy {
+    /// Names are kept as is.
     NoTranslation,
+    /// Invalid characters are replaced by underscores, no suffixes are added.
     UnderscoreEscapingWithoutSuffixes,
+    /// Names are kept as is, unit and `_total` suffixes are added.
     NoUTF8EscapingWithSuffixes,
+    /// Invalid characters are replaced by underscores, unit and `_total` suffixes are added.
     UnderscoreEscapingWithSuffixes,
 }
 
-pub(crate) fn get_names<'a>(
+/// Returns the Prometheus names a metric can be exported as. All the translation
+/// strategies are considered when `translation_strategy` is `None`.
+#[must_use]
+pub fn get_names<'a>(
     name: &Cow<'a, str>,
     unit: &str,
     instrument: &str,
@@ -118,7 +129,9 @@ pub(crate) fn get_suffixes(unit: &str, instrument: &str) -> Vec<Cow<'static, str
     suffixes
 }
 
-pub(crate) fn get_unit_suffixes(unit: &str) -> Option<Cow<'static, str>> {
+/// Returns the Prometheus unit suffix of a UCUM unit, if any.
+#[must_use]
+pub fn get_unit_suffixes(unit: &str) -> Option<Cow<'static, str>> {
     // no unit return early
     if unit.is_empty() {
         return None;
@@ -156,7 +169,9 @@ pub(crate) fn get_unit_suffixes(unit: &str) -> Option<Cow<'static, str>> {
     None
 }
 
-pub(crate) fn get_prom_units(unit: &str) -> Option<&'static str> {
+/// Returns the Prometheus unit name of a UCUM unit, if known.
+#[must_use]
+pub fn get_prom_units(unit: &str) -> Option<&'static str> {
     match unit {
         // Time
         "d" => Some("days"),
@@ -200,6 +215,38 @@ pub(crate) fn get_prom_units(unit: &str) -> Option<&'static str> {
     }
 }
 
+/// Returns the UCUM unit of a Prometheus unit name (e.g. `seconds` -> `s`), if known.
+/// This is the reverse of [`get_prom_units`] and [`get_unit_suffixes`].
+#[must_use]
+pub fn get_ucum_unit(prom_unit: &str) -> Option<Cow<'static, str>> {
+    const UCUM_UNITS: [&str; 25] = [
+        "d", "h", "min", "s", "ms", "us", "ns", "By", "KiBy", "MiBy", "GiBy", "TiBy", "KBy", "MBy",
+        "GBy", "TBy", "m", "V", "A", "J", "W", "g", "Cel", "Hz", "%",
+    ];
+    const UCUM_PER_UNITS: [&str; 7] = ["s", "m", "h", "d", "w", "mo", "y"];
+
+    let find = |name: &str| {
+        UCUM_UNITS
+            .iter()
+            .chain(std::iter::once(&"1"))
+            .find(|unit| get_prom_units(unit) == Some(name))
+            .copied()
+    };
+    if let Some(unit) = find(prom_unit) {
+        return Some(Cow::Borrowed(unit));
+    }
+    let (first, second) = prom_unit
+        .split_once("_per_")
+        .or_else(|| prom_unit.strip_prefix("per_").map(|second| ("", second)))?;
+    let per_unit = UCUM_PER_UNITS
+        .iter()
+        .find(|unit| get_prom_per_unit(unit) == Some(second))?;
+    if first.is_empty() {
+        return Some(Cow::Owned(format!("1/{per_unit}")));
+    }
+    find(first).map(|unit| Cow::Owned(format!("{unit}/{per_unit}")))
+}
+
 fn get_prom_per_unit(unit: &str) -> Option<&'static str> {
     match unit {
         "s" => Some("second"),
@@ -213,8 +260,10 @@ fn get_prom_per_unit(unit: &str) -> Option<&'static str> {
     }
 }
 
+/// Replaces the characters that are invalid in a Prometheus name by underscores.
+#[must_use]
 #[allow(clippy::ptr_arg)]
-pub(crate) fn sanitize_name<'a>(s: &Cow<'a, str>) -> Cow<'a, str> {
+pub fn sanitize_name<'a>(s: &Cow<'a, str>) -> Cow<'a, str> {
     // prefix chars to add in case name starts with number
     let mut prefix = "";
 
@@ -301,6 +350,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_ucum_unit() {
+        let test_cases = vec![
+            ("seconds", Some("s")),
+            ("bytes", Some("By")),
+            ("ratio", Some("1")),
+            ("per_second", Some("1/s")),
+            ("meters_per_second", Some("m/s")),
+            ("requests", None),
+            ("meters_per_fortnight", None),
+        ];
+        for (prom_unit, expected) in test_cases {
+            assert_eq!(get_ucum_unit(prom_unit).as_deref(), expected);
+        }
+    }
+
     #[test]
     fn test_get_names_with_specific_translation_strategy() {
         // Test with specific translation strategies
@@ -956,9 +956,12 @@ mod tests {
                     kind: SpanKindSpec::Client,
                     name: SpanName {
                         note: "A database client span.".to_owned(),
+                        template: None,
+                        fallbacks: vec![],
                     },
                     attributes: vec![],
                     entity_associations: vec![],
+                    events: vec![],
                     common: CommonFields::default(),
                     provenance: Default::default(),
                 }],
@@ -16,7 +16,7 @@ use weaver_resolved_schema::registry::{Group, Registry};
 use weaver_semconv::any_value::AnyValueSpec;
 use weaver_semconv::attribute::BasicRequirementLevelSpec;
 use weaver_semconv::deprecated::Deprecated;
-use weaver_semconv::group::{GroupType, InstrumentSpec, SpanKindSpec};
+use weaver_semconv::group::{GroupType, InstrumentSpec, MetricAdviceSpec, SpanKindSpec};
 use weaver_semconv::stability::Stability;
 use weaver_semconv::YamlValue;
 
@@ -106,6 +106,12 @@ pub struct ResolvedGroup {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metric_requirement_level: Option<BasicRequirementLevelSpec>,
+    /// Advisory parameters for the instrument recording the metric, such as
+    /// the recommended histogram bucket boundaries.
+    /// Note: This field is only valid if type is metric.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advice: Option<MetricAdviceSpec>,
     /// The name of the event. If not specified, the prefix is used.
     /// If prefix is empty (or unspecified), name is required.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -176,6 +182,7 @@ impl ResolvedGroup {
             instrument: group.instrument.clone(),
             unit: group.unit.clone(),
             metric_requirement_level: group.metric_requirement_level.clone(),
+            advice: group.advice.clone(),
             name: group.name.clone(),
             lineage,
             display_name: group.display_name.clone(),
@@ -238,6 +245,7 @@ impl ResolvedRegistry {
                     instrument: group.instrument.clone(),
                     unit: group.unit.clone(),
                     metric_requirement_level: group.metric_requirement_level.clone(),
+                    advice: group.advice.clone(),
                     name: group.name.clone(),
                     lineage,
                     display_name: group.display_name.clone(),
@@ -300,12 +308,14 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
                     body: None,
                     entity_associations: vec![],
                     annotations: None,
+                    span_name: None,
                     visibility: None,
                     is_v2: false,
                 },
@@ -325,12 +335,14 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
                     body: None,
                     entity_associations: vec![],
                     annotations: None,
+                    span_name: None,
                     visibility: None,
                     is_v2: false,
                 },
@@ -350,12 +362,14 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
                     body: None,
                     entity_associations: vec![],
                     annotations: None,
+                    span_name: None,
                     visibility: None,
                     is_v2: false,
                 },
@@ -6,7 +6,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use weaver_semconv::{
     attribute::{BasicRequirementLevelSpec, RequirementLevel},
-    group::InstrumentSpec,
+    group::{InstrumentSpec, MetricAdviceSpec},
     v2::{signal_id::SignalId, CommonFields},
 };
 
@@ -43,6 +43,11 @@ pub struct Metric {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub requirement_level: Option<BasicRequirementLevelSpec>,
 
+    /// Advisory parameters for the instrument recording the metric, such as
+    /// the recommended histogram bucket boundaries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advice: Option<MetricAdviceSpec>,
+
     /// Common fields (like brief, note, annotations).
     #[serde(flatten)]
     pub common: CommonFields,
@@ -113,6 +113,7 @@ impl ForgeResolvedRegistry {
                 key: a.key.clone(),
                 r#type: a.r#type.clone(),
                 examples: a.examples.clone(),
+                constraints: a.constraints.clone(),
                 common: a.common.clone(),
                 provenance: resolve_provenance(&a.provenance),
             })
@@ -129,6 +130,7 @@ impl ForgeResolvedRegistry {
                             key: a.key.clone(),
                             r#type: a.r#type.clone(),
                             examples: a.examples.clone(),
+                            constraints: a.constraints.clone(),
                             common: a.common.clone(),
                             provenance: resolve_provenance(&a.provenance),
                         },
@@ -150,6 +152,7 @@ impl ForgeResolvedRegistry {
                 attributes,
                 entity_associations: metric.entity_associations,
                 requirement_level: metric.requirement_level,
+                advice: metric.advice,
                 common: metric.common,
                 provenance: resolve_provenance(&metric.provenance),
             });
@@ -168,6 +171,7 @@ impl ForgeResolvedRegistry {
                             key: a.key.clone(),
                             r#type: a.r#type.clone(),
                             examples: a.examples.clone(),
+                            constraints: a.constraints.clone(),
                             common: a.common.clone(),
                             provenance: resolve_provenance(&a.provenance),
                         },
@@ -191,6 +195,7 @@ impl ForgeResolvedRegistry {
                     attributes,
                     entity_associations: metric.metric.entity_associations,
                     requirement_level: metric.metric.requirement_level,
+                    advice: metric.metric.advice,
                     common: metric.metric.common,
                     provenance: resolve_provenance(&metric.metric.provenance),
                 },
@@ -209,6 +214,7 @@ impl ForgeResolvedRegistry {
                             key: a.key.clone(),
                             r#type: a.r#type.clone(),
                             examples: a.examples.clone(),
+                            constraints: a.constraints.clone(),
                             common: a.common.clone(),
                             provenance: resolve_provenance(&a.provenance),
                         },
@@ -230,6 +236,7 @@ impl ForgeResolvedRegistry {
                 name: span.name,
                 attributes,
                 entity_associations: span.entity_associations,
+                events: span.events,
                 common: span.common,
                 provenance: resolve_provenance(&span.provenance),
             });
@@ -247,6 +254,7 @@ impl ForgeResolvedRegistry {
                             key: a.key.clone(),
                             r#type: a.r#type.clone(),
                             examples: a.examples.clone(),
+                            constraints: a.constraints.clone(),
                             common: a.common.clone(),
                             provenance: resolve_provenance(&a.provenance),
                         },
@@ -270,6 +278,7 @@ impl ForgeResolvedRegistry {
                     name: span.span.name,
                     attributes,
                     entity_associations: span.span.entity_associations,
+                    events: span.span.events,
                     common: span.span.common,
                     provenance: resolve_provenance(&span.span.provenance),
                 },
@@ -288,6 +297,7 @@ impl ForgeResolvedRegistry {
                             key: a.key.clone(),
                             r#type: a.r#type.clone(),
                             examples: a.examples.clone(),
+                            constraints: a.constraints.clone(),
                             common: a.common.clone(),
                             provenance: resolve_provenance(&a.provenance),
                         },
@@ -325,6 +335,7 @@ impl ForgeResolvedRegistry {
                             key: a.key.clone(),
                             r#type: a.r#type.clone(),
                             examples: a.examples.clone(),
+                            constraints: a.constraints.clone(),
                             common: a.common.clone(),
                             provenance: resolve_provenance(&a.provenance),
                         },
@@ -363,6 +374,7 @@ impl ForgeResolvedRegistry {
                             key: a.key.clone(),
                             r#type: a.r#type.clone(),
                             examples: a.examples.clone(),
+                            constraints: a.constraints.clone(),
                             common: a.common.clone(),
                             provenance: resolve_provenance(&a.provenance),
                         },
@@ -387,6 +399,7 @@ impl ForgeResolvedRegistry {
                             key: a.key.clone(),
                             r#type: a.r#type.clone(),
                             examples: a.examples.clone(),
+                            constraints: a.constraints.clone(),
                             common: a.common.clone(),
                             provenance: resolve_provenance(&a.provenance),
                         },
@@ -421,6 +434,7 @@ impl ForgeResolvedRegistry {
                         key: a.key.clone(),
                         r#type: a.r#type.clone(),
                         examples: a.examples.clone(),
+                        constraints: a.constraints.clone(),
                         common: a.common.clone(),
                         provenance: resolve_provenance(&a.provenance),
                     });
@@ -489,6 +503,7 @@ mod tests {
                 key: "test.attr".to_owned(),
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 examples: None,
+                constraints: None,
                 common: CommonFields::default(),
                 provenance: v2::provenance::Provenance {
                     source: Some(v2::provenance::DependencyRef(0)),
@@ -507,6 +522,8 @@ mod tests {
                     kind: SpanKindSpec::Internal,
                     name: SpanName {
                         note: "My Span".to_owned(),
+                        template: None,
+                        fallbacks: vec![],
                     },
                     attributes: vec![span::SpanAttributeRef {
                         base: attribute::AttributeRef(0),
@@ -516,6 +533,7 @@ mod tests {
                         sampling_relevant: Some(true),
                     }],
                     entity_associations: vec![],
+                    events: vec![],
                     common: CommonFields::default(),
                     provenance: Default::default(),
                 }],
@@ -533,6 +551,7 @@ mod tests {
                     requirement_level: Some(
                         weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
                     ),
+                    advice: None,
                     common: CommonFields::default(),
                     provenance: Default::default(),
                 }],
@@ -570,6 +589,8 @@ mod tests {
                         kind: SpanKindSpec::Client,
                         name: SpanName {
                             note: "My Refined Span".to_owned(),
+                            template: None,
+                            fallbacks: vec![],
                         },
                         attributes: vec![span::SpanAttributeRef {
                             base: attribute::AttributeRef(0),
@@ -579,6 +600,7 @@ mod tests {
                             sampling_relevant: Some(true),
                         }],
                         entity_associations: vec![],
+                        events: vec![],
                         common: CommonFields::default(),
                         provenance: Default::default(),
                     },
@@ -597,6 +619,7 @@ mod tests {
                         }],
                         entity_associations: vec![],
                         requirement_level: None,
+                        advice: None,
                         common: CommonFields::default(),
                         provenance: Default::default(),
                     },
@@ -683,6 +706,8 @@ mod tests {
                     kind: SpanKindSpec::Internal,
                     name: SpanName {
                         note: "My Span".to_owned(),
+                        template: None,
+                        fallbacks: vec![],
                     },
                     attributes: vec![span::SpanAttributeRef {
                         base: attribute::AttributeRef(0), // Refers to bad attribute.
@@ -692,6 +717,7 @@ mod tests {
                         sampling_relevant: Some(true),
                     }],
                     entity_associations: vec![],
+                    events: vec![],
                     common: CommonFields::default(),
                     provenance: Default::default(),
                 }],
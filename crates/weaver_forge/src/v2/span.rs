@@ -34,6 +34,11 @@ pub struct Span {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub entity_associations: Vec<String>,
 
+    /// The names of the events that may be recorded on this span.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<String>,
+
     /// Common fields (like brief, note, annotations).
     #[serde(flatten)]
     pub common: CommonFields,
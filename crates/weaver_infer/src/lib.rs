@@ -91,6 +91,11 @@ impl AccumulatedSamples {
         match sample {
             Sample::Resource(resource) => self.add_resource(resource),
             Sample::Span(span) => self.add_span(span),
+            Sample::Trace(trace) => {
+                for span in trace.spans {
+                    self.add_span(span);
+                }
+            }
             Sample::Metric(metric) => self.add_metric(metric),
             Sample::Log(log) => self.add_event(log.event_name, log.attributes),
             Sample::Attribute(attr) => accumulate_attribute(&mut self.resources, attr),
@@ -325,6 +330,7 @@ fn attribute_spec_from_sample(sample: &SampleAttribute) -> AttributeSpec {
         r#type: AttributeType::PrimitiveOrArray(attr_type),
         brief: Some(String::new()),
         examples,
+        constraints: None,
         tag: None,
         requirement_level: RequirementLevel::default(),
         sampling_relevant: None,
@@ -870,6 +876,7 @@ mod tests {
                 value: Some(json!("my-service")),
                 live_check_result: None,
             }],
+            schema_url: None,
             live_check_result: None,
         };
 
@@ -907,8 +914,13 @@ mod tests {
             }],
             span_events: vec![],
             span_links: vec![],
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
+            trace_id: None,
+            span_id: None,
+            parent_span_id: None,
         };
 
         acc.add_span(span);
@@ -941,8 +953,13 @@ mod tests {
                 live_check_result: None,
             }],
             span_links: vec![],
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
+            trace_id: None,
+            span_id: None,
+            parent_span_id: None,
         };
 
         acc.add_span(span);
@@ -1003,6 +1020,7 @@ mod tests {
                 value: Some(json!("checkout")),
                 live_check_result: None,
             }],
+            schema_url: None,
             live_check_result: None,
         }));
         acc.add_sample(Sample::Attribute(SampleAttribute {
@@ -1018,27 +1036,36 @@ mod tests {
             attributes: vec![],
             span_events: vec![],
             span_links: vec![],
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
+            trace_id: None,
+            span_id: None,
+            parent_span_id: None,
         }));
         acc.add_sample(Sample::Metric(SampleMetric {
             name: "requests.total".to_owned(),
             instrument: SampleInstrument::Supported(InstrumentSpec::Counter),
             unit: "{request}".to_owned(),
             data_points: None,
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         }));
         acc.add_sample(Sample::Log(SampleLog {
             event_name: "user.login".to_owned(),
             severity_number: Some(9),
             severity_text: Some("INFO".to_owned()),
-            body: Some("login success".to_owned()),
+            body: Some(Value::String("login success".to_owned())),
             attributes: vec![],
             trace_id: None,
             span_id: None,
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         }));
         acc.add_sample(Sample::SpanEvent(SampleSpanEvent {
             name: "ignored".to_owned(),
@@ -1067,8 +1094,10 @@ mod tests {
             instrument: SampleInstrument::Unsupported("Summary".to_owned()),
             unit: String::new(),
             data_points: None,
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
 
         assert!(acc.is_empty());
@@ -1095,8 +1124,10 @@ mod tests {
                 exemplars: vec![],
                 live_check_result: None,
             }])),
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
         acc.add_metric(SampleMetric {
             name: "http.server.duration".to_owned(),
@@ -1119,8 +1150,10 @@ mod tests {
                 exemplars: vec![],
                 live_check_result: None,
             }])),
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
         acc.add_metric(SampleMetric {
             name: "queue.latency".to_owned(),
@@ -1148,8 +1181,10 @@ mod tests {
                     live_check_result: None,
                 },
             ])),
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
 
         assert_eq!(acc.stats(), (0, 0, 3, 0));
@@ -1216,8 +1251,13 @@ mod tests {
             }],
             span_events: vec![],
             span_links: vec![],
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
+            trace_id: None,
+            span_id: None,
+            parent_span_id: None,
         });
 
         let registry = acc.to_semconv_spec();
@@ -1239,8 +1279,10 @@ mod tests {
             instrument: SampleInstrument::Supported(InstrumentSpec::Histogram),
             unit: "ms".to_owned(),
             data_points: None,
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         };
 
         acc.add_metric(metric);
@@ -1265,8 +1307,10 @@ mod tests {
             instrument: SampleInstrument::Supported(InstrumentSpec::Counter),
             unit: String::new(), // Empty unit
             data_points: None,
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         };
 
         acc.add_metric(metric);
@@ -1328,8 +1372,13 @@ mod tests {
                 live_check_result: None,
             }],
             span_links: vec![],
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
+            trace_id: None,
+            span_id: None,
+            parent_span_id: None,
         });
 
         let registry = acc.to_semconv_spec();
@@ -92,7 +92,7 @@ deny contains make_advice(advice_type, advice_level, advice_context, message) if
     input.sample.log
     input.registry_group.annotations.required_phrase
     phrase := input.registry_group.annotations.required_phrase
-    not contains(input.sample.log.body, phrase)
+    not contains(body_text(input.sample.log), phrase)
     advice_type := "required_phrase_missing"
     advice_level := "violation"
     advice_context := {
@@ -107,9 +107,27 @@ deny contains make_advice(advice_type, advice_level, advice_context, message) if
 deny contains make_advice(advice_type, advice_level, advice_context, message) if {
     input.sample.log
     input.sample.log.event_name == ""
-    input.sample.log.body == ""
+    empty_body(input.sample.log)
     advice_type := "empty_body"
     advice_level := "violation"
     advice_context := {}
     message := "Logs must not have an empty body."
-}
\ No newline at end of file
+}
+
+# The text of a log body: string bodies as they are, structured bodies (maps and
+# arrays) as JSON, and no text for an absent body.
+body_text(log) := body if {
+    body := object.get(log, "body", null)
+    is_string(body)
+}
+
+body_text(log) := json.marshal(body) if {
+    body := object.get(log, "body", null)
+    not is_string(body)
+    body != null
+}
+
+body_text(log) := "" if object.get(log, "body", null) == null
+
+# A log body is empty when it is absent, null or an empty string.
+empty_body(log) if object.get(log, "body", null) in {null, ""}
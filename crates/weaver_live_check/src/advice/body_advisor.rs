@@ -0,0 +1,337 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structured log/event body advisor
+
+use serde_json::{json, Map, Value};
+use std::rc::Rc;
+use weaver_checker::{FindingLevel, PolicyFinding};
+use weaver_semconv::any_value::AnyValueSpec;
+use weaver_semconv::attribute::{EnumEntriesSpec, ValueSpec};
+
+use super::{Advisor, FindingBuilder};
+use crate::{
+    otlp_logger::OtlpEmitter, Error, Sample, SampleRef, VersionedAttribute, VersionedSignal,
+    BODY_PATH_ADVICE_CONTEXT_KEY, BODY_TYPE_MISMATCH_ADVICE_TYPE, BODY_VALUE_ADVICE_CONTEXT_KEY,
+    EXPECTED_VALUE_ADVICE_CONTEXT_KEY, MISSING_BODY_FIELD_ADVICE_TYPE,
+    UNDEFINED_ENUM_VARIANT_ADVICE_TYPE,
+};
+
+/// An advisor that checks the body of a log/event against the body definition
+/// of the matched event: required fields, types and enum values
+pub struct BodyAdvisor;
+
+/// A difference between a body and its definition
+struct BodyIssue {
+    advice_type: &'static str,
+    level: FindingLevel,
+    path: String,
+    value: Option<Value>,
+    expected: String,
+    message: String,
+}
+
+/// Checks a (non-null) body value against its definition.
+fn check_value(spec: &AnyValueSpec, value: &Value, path: &str, issues: &mut Vec<BodyIssue>) {
+    match spec {
+        AnyValueSpec::Map { fields, .. } => match value.as_object() {
+            Some(map) => check_fields(fields, map, path, issues),
+            None => issues.push(type_mismatch(spec, value, path)),
+        },
+        AnyValueSpec::Maps { fields, .. } => match value.as_array() {
+            Some(values) if values.iter().all(Value::is_object) => {
+                for (index, map) in values.iter().filter_map(Value::as_object).enumerate() {
+                    check_fields(fields, map, &format!("{path}[{index}]"), issues);
+                }
+            }
+            _ => issues.push(type_mismatch(spec, value, path)),
+        },
+        AnyValueSpec::Enum { members, .. } => {
+            if !(value.is_string() || value.is_number() || value.is_boolean()) {
+                issues.push(type_mismatch(spec, value, path));
+            } else if !members.iter().any(|member| is_member(member, value)) {
+                issues.push(BodyIssue {
+                    advice_type: UNDEFINED_ENUM_VARIANT_ADVICE_TYPE,
+                    level: FindingLevel::Information,
+                    path: path.to_owned(),
+                    value: Some(value.clone()),
+                    expected: spec.to_string(),
+                    message: format!(
+                        "Enum body field '{path}' has value '{}' which is not documented.",
+                        display_value(value)
+                    ),
+                });
+            }
+        }
+        _ => {
+            if !is_type(spec, value) {
+                issues.push(type_mismatch(spec, value, path));
+            }
+        }
+    }
+}
+
+/// Checks the fields of a body map against their definitions.
+fn check_fields(
+    fields: &[AnyValueSpec],
+    map: &Map<String, Value>,
+    path: &str,
+    issues: &mut Vec<BodyIssue>,
+) {
+    for field in fields {
+        let field_id = field.id();
+        let field_path = format!("{path}.{field_id}");
+        match map.get(&field_id) {
+            None | Some(Value::Null) => {
+                if field.is_required() {
+                    issues.push(missing_field(spec_type(field), field_path));
+                }
+            }
+            Some(value) => check_value(field, value, &field_path, issues),
+        }
+    }
+}
+
+/// Returns true if the value has the type of a primitive, array or bytes definition.
+fn is_type(spec: &AnyValueSpec, value: &Value) -> bool {
+    // Int is compatible with double (OTLP serializers emit int_value for integral doubles)
+    let all = |predicate: fn(&Value) -> bool| {
+        value
+            .as_array()
+            .is_some_and(|values| values.iter().all(predicate))
+    };
+    match spec {
+        AnyValueSpec::Boolean { .. } => value.is_boolean(),
+        AnyValueSpec::Int { .. } => value.is_i64() || value.is_u64(),
+        AnyValueSpec::Double { .. } => value.is_number(),
+        AnyValueSpec::String { .. } | AnyValueSpec::Bytes { .. } => value.is_string(),
+        AnyValueSpec::Booleans { .. } => all(Value::is_boolean),
+        AnyValueSpec::Ints { .. } => all(|v| v.is_i64() || v.is_u64()),
+        AnyValueSpec::Doubles { .. } => all(Value::is_number),
+        AnyValueSpec::Strings { .. } => all(Value::is_string),
+        AnyValueSpec::Map { .. }
+        | AnyValueSpec::Maps { .. }
+        | AnyValueSpec::Enum { .. }
+        | AnyValueSpec::Undefined { .. } => true,
+    }
+}
+
+/// Returns true if the value is the value of the enum member.
+fn is_member(member: &EnumEntriesSpec, value: &Value) -> bool {
+    match &member.value {
+        ValueSpec::Int(int_value) => value.as_i64() == Some(*int_value),
+        ValueSpec::Double(double_value) => value.as_f64() == Some(double_value.0),
+        ValueSpec::String(string_value) => value.as_str() == Some(string_value.as_str()),
+        ValueSpec::Bool(bool_value) => value.as_bool() == Some(*bool_value),
+    }
+}
+
+/// The type of a definition without the enum members.
+fn spec_type(spec: &AnyValueSpec) -> String {
+    match spec {
+        AnyValueSpec::Enum { .. } => "enum".to_owned(),
+        _ => spec.to_string(),
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string(), str::to_owned)
+}
+
+fn type_mismatch(spec: &AnyValueSpec, value: &Value, path: &str) -> BodyIssue {
+    let expected = spec_type(spec);
+    BodyIssue {
+        advice_type: BODY_TYPE_MISMATCH_ADVICE_TYPE,
+        level: FindingLevel::Violation,
+        path: path.to_owned(),
+        value: Some(value.clone()),
+        message: format!(
+            "Body field '{path}' has value '{}' which is not of type '{expected}'.",
+            display_value(value)
+        ),
+        expected,
+    }
+}
+
+fn missing_field(expected: String, path: String) -> BodyIssue {
+    BodyIssue {
+        advice_type: MISSING_BODY_FIELD_ADVICE_TYPE,
+        level: FindingLevel::Violation,
+        message: format!("Required body field '{path}' is missing."),
+        path,
+        value: None,
+        expected,
+    }
+}
+
+/// Returns the differences between a body and its definition.
+fn check_body(spec: &AnyValueSpec, body: Option<&Value>) -> Vec<BodyIssue> {
+    let mut issues = Vec::new();
+    match body {
+        None | Some(Value::Null) => {
+            if spec.is_required() {
+                issues.push(missing_field(spec_type(spec), "body".to_owned()));
+            }
+        }
+        Some(value) => check_value(spec, value, "body", &mut issues),
+    }
+    issues
+}
+
+impl Advisor for BodyAdvisor {
+    fn advise(
+        &mut self,
+        sample: SampleRef<'_>,
+        signal: &Sample,
+        _registry_attribute: Option<Rc<VersionedAttribute>>,
+        registry_group: Option<Rc<VersionedSignal>>,
+        otlp_emitter: Option<Rc<OtlpEmitter>>,
+    ) -> Result<Vec<PolicyFinding>, Error> {
+        let SampleRef::Log(sample_log) = sample else {
+            return Ok(Vec::new());
+        };
+        let Some(semconv_event) = registry_group else {
+            return Ok(Vec::new());
+        };
+        let Some(body_spec) = semconv_event.body() else {
+            return Ok(Vec::new());
+        };
+
+        let findings = check_body(body_spec, sample_log.body.as_ref())
+            .into_iter()
+            .map(|issue| {
+                FindingBuilder::new(issue.advice_type)
+                    .context(json!({
+                        BODY_PATH_ADVICE_CONTEXT_KEY: issue.path,
+                        BODY_VALUE_ADVICE_CONTEXT_KEY: issue.value,
+                        EXPECTED_VALUE_ADVICE_CONTEXT_KEY: issue.expected,
+                    }))
+                    .message(issue.message)
+                    .level(issue.level)
+                    .signal(signal)
+                    .build_and_emit(&sample, otlp_emitter.as_deref(), signal)
+            })
+            .collect();
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exception_body() -> AnyValueSpec {
+        serde_yaml::from_str(
+            r#"
+type: map
+id: body
+requirement_level: required
+fields:
+  - id: exception
+    type: map
+    requirement_level: required
+    fields:
+      - id: type
+        type: string
+        requirement_level: required
+      - id: message
+        type: string
+        requirement_level: recommended
+      - id: severity
+        type: enum
+        requirement_level: recommended
+        members:
+          - id: low
+            value: low
+          - id: high
+            value: high
+  - id: frames
+    type: map[]
+    requirement_level: recommended
+    fields:
+      - id: line
+        type: int
+        requirement_level: required
+"#,
+        )
+        .unwrap()
+    }
+
+    fn issues(body: Option<Value>) -> Vec<(&'static str, String)> {
+        check_body(&exception_body(), body.as_ref())
+            .into_iter()
+            .map(|issue| (issue.advice_type, issue.path))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_body() {
+        assert!(issues(Some(json!({
+            "exception": {"type": "IOError", "message": "boom", "severity": "high"},
+            "frames": [{"line": 1}, {"line": 2}]
+        })))
+        .is_empty());
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        assert_eq!(
+            issues(None),
+            vec![(MISSING_BODY_FIELD_ADVICE_TYPE, "body".to_owned())]
+        );
+        assert_eq!(
+            issues(Some(json!({"exception": {"message": "boom"}}))),
+            vec![(
+                MISSING_BODY_FIELD_ADVICE_TYPE,
+                "body.exception.type".to_owned()
+            )]
+        );
+        assert_eq!(
+            issues(Some(
+                json!({"exception": {"type": "IOError"}, "frames": [{}]})
+            )),
+            vec![(
+                MISSING_BODY_FIELD_ADVICE_TYPE,
+                "body.frames[0].line".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_type_mismatches() {
+        assert_eq!(
+            issues(Some(json!("a plain string body"))),
+            vec![(BODY_TYPE_MISMATCH_ADVICE_TYPE, "body".to_owned())]
+        );
+        assert_eq!(
+            issues(Some(json!({
+                "exception": {"type": 42},
+                "frames": [{"line": "1"}]
+            }))),
+            vec![
+                (
+                    BODY_TYPE_MISMATCH_ADVICE_TYPE,
+                    "body.exception.type".to_owned()
+                ),
+                (
+                    BODY_TYPE_MISMATCH_ADVICE_TYPE,
+                    "body.frames[0].line".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undefined_enum_variant() {
+        assert_eq!(
+            issues(Some(
+                json!({"exception": {"type": "IOError", "severity": "medium"}})
+            )),
+            vec![(
+                UNDEFINED_ENUM_VARIANT_ADVICE_TYPE,
+                "body.exception.severity".to_owned()
+            )]
+        );
+    }
+}
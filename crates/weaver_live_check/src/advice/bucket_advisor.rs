@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Histogram bucket boundary advisor
+
+use serde_json::json;
+use std::rc::Rc;
+use weaver_checker::{FindingLevel, PolicyFinding};
+
+use super::{type_advisor::unit_scale_factor, Advisor, FindingBuilder};
+use crate::{
+    otlp_logger::OtlpEmitter, Error, Sample, SampleRef, VersionedAttribute, VersionedSignal,
+    BUCKET_BOUNDARIES_MISMATCH_ADVICE_TYPE, BUCKET_SCALE_MISMATCH_ADVICE_TYPE,
+    EXPECTED_VALUE_ADVICE_CONTEXT_KEY, EXPLICIT_BOUNDS_ADVICE_CONTEXT_KEY,
+    METRIC_NAME_ADVICE_CONTEXT_KEY, SCALE_FACTOR_ADVICE_CONTEXT_KEY, UNIT_ADVICE_CONTEXT_KEY,
+};
+
+/// The default explicit bucket boundaries of the OpenTelemetry SDKs, designed
+/// for durations in milliseconds
+const SDK_DEFAULT_BOUNDARIES: [f64; 15] = [
+    0.0, 5.0, 10.0, 25.0, 50.0, 75.0, 100.0, 250.0, 500.0, 750.0, 1000.0, 2500.0, 5000.0, 7500.0,
+    10000.0,
+];
+
+/// The scale factors checked when bounds differ from the advised boundaries
+const SCALE_FACTORS: [f64; 6] = [1e3, 1e6, 1e9, 1e-3, 1e-6, 1e-9];
+
+/// An advisor that checks the explicit bounds of histogram data points against
+/// the bucket boundaries advised by the metric definition.
+///
+/// Bounds which are the advised boundaries in another scale (e.g. milliseconds
+/// for a metric in seconds), or the SDK default boundaries for a metric in
+/// seconds, are reported as a scale mismatch.
+pub struct BucketAdvisor;
+
+/// Returns true if both lists of boundaries are equal, up to rounding errors.
+fn same_boundaries(bounds: &[f64], expected: &[f64]) -> bool {
+    bounds.len() == expected.len()
+        && bounds
+            .iter()
+            .zip(expected)
+            .all(|(a, b)| (a - b).abs() <= 1e-9 * a.abs().max(b.abs()))
+}
+
+/// Returns the factor the bounds are scaled by relative to the expected
+/// boundaries, if they are the expected boundaries in another scale.
+fn boundaries_scale(bounds: &[f64], expected: &[f64]) -> Option<f64> {
+    SCALE_FACTORS.into_iter().find(|factor| {
+        let scaled: Vec<f64> = expected.iter().map(|b| b * factor).collect();
+        same_boundaries(bounds, &scaled)
+    })
+}
+
+/// Checks the explicit bounds of a histogram data point.
+///
+/// `advised` are the advised boundaries expressed in `semconv_unit`, the
+/// histogram is recorded in `unit`.
+fn check_bounds(
+    bounds: &[f64],
+    advised: Option<&[f64]>,
+    unit: &str,
+    semconv_unit: Option<&str>,
+    metric_name: &str,
+) -> Option<FindingBuilder> {
+    if bounds.is_empty() {
+        return None;
+    }
+    let Some(advised) = advised else {
+        // The SDK defaults are designed for milliseconds, all values of a metric
+        // in seconds fall in the first buckets
+        if unit_scale_factor(unit, "s") == Some(1.0)
+            && same_boundaries(bounds, &SDK_DEFAULT_BOUNDARIES)
+        {
+            return Some(
+                FindingBuilder::new(BUCKET_SCALE_MISMATCH_ADVICE_TYPE)
+                    .context(json!({
+                        METRIC_NAME_ADVICE_CONTEXT_KEY: metric_name,
+                        UNIT_ADVICE_CONTEXT_KEY: unit,
+                        EXPLICIT_BOUNDS_ADVICE_CONTEXT_KEY: bounds,
+                    }))
+                    .message(format!(
+                        "Histogram '{metric_name}' in '{unit}' uses the default SDK bucket boundaries, which are designed for milliseconds."
+                    ))
+                    .level(FindingLevel::Violation),
+            );
+        }
+        return None;
+    };
+
+    // Express the advised boundaries in the unit of the histogram
+    let factor = semconv_unit
+        .and_then(|semconv_unit| unit_scale_factor(unit, semconv_unit))
+        .unwrap_or(1.0);
+    let expected: Vec<f64> = advised.iter().map(|b| b / factor).collect();
+    if same_boundaries(bounds, &expected) {
+        return None;
+    }
+    if let Some(scale_factor) = boundaries_scale(bounds, &expected) {
+        return Some(
+            FindingBuilder::new(BUCKET_SCALE_MISMATCH_ADVICE_TYPE)
+                .context(json!({
+                    METRIC_NAME_ADVICE_CONTEXT_KEY: metric_name,
+                    UNIT_ADVICE_CONTEXT_KEY: unit,
+                    EXPLICIT_BOUNDS_ADVICE_CONTEXT_KEY: bounds,
+                    EXPECTED_VALUE_ADVICE_CONTEXT_KEY: expected,
+                    SCALE_FACTOR_ADVICE_CONTEXT_KEY: scale_factor,
+                }))
+                .message(format!(
+                    "Explicit bounds of histogram '{metric_name}' are the advised bucket boundaries multiplied by {scale_factor}, they do not match the unit '{unit}'."
+                ))
+                .level(FindingLevel::Violation),
+        );
+    }
+    Some(
+        FindingBuilder::new(BUCKET_BOUNDARIES_MISMATCH_ADVICE_TYPE)
+            .context(json!({
+                METRIC_NAME_ADVICE_CONTEXT_KEY: metric_name,
+                UNIT_ADVICE_CONTEXT_KEY: unit,
+                EXPLICIT_BOUNDS_ADVICE_CONTEXT_KEY: bounds,
+                EXPECTED_VALUE_ADVICE_CONTEXT_KEY: expected,
+            }))
+            .message(format!(
+                "Explicit bounds of histogram '{metric_name}' differ from the advised bucket boundaries {expected:?}."
+            ))
+            .level(FindingLevel::Improvement),
+    )
+}
+
+impl Advisor for BucketAdvisor {
+    fn advise(
+        &mut self,
+        sample: SampleRef<'_>,
+        signal: &Sample,
+        _registry_attribute: Option<Rc<VersionedAttribute>>,
+        registry_group: Option<Rc<VersionedSignal>>,
+        otlp_emitter: Option<Rc<OtlpEmitter>>,
+    ) -> Result<Vec<PolicyFinding>, Error> {
+        let (SampleRef::HistogramDataPoint(data_point), Sample::Metric(sample_metric)) =
+            (&sample, signal)
+        else {
+            return Ok(Vec::new());
+        };
+        let finding = check_bounds(
+            &data_point.explicit_bounds,
+            registry_group
+                .as_deref()
+                .and_then(VersionedSignal::explicit_bucket_boundaries),
+            &sample_metric.unit,
+            registry_group
+                .as_deref()
+                .and_then(VersionedSignal::unit)
+                .map(String::as_str),
+            &sample_metric.name,
+        );
+        Ok(finding
+            .into_iter()
+            .map(|finding| {
+                finding
+                    .signal(signal)
+                    .build_and_emit(&sample, otlp_emitter.as_deref(), signal)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADVISED: [f64; 5] = [0.005, 0.01, 0.1, 1.0, 10.0];
+
+    fn id(finding: Option<FindingBuilder>) -> Option<String> {
+        finding.map(|finding| finding.build().id)
+    }
+
+    #[test]
+    fn test_advised_boundaries() {
+        let check = |bounds: &[f64], unit: &str| {
+            id(check_bounds(
+                bounds,
+                Some(&ADVISED),
+                unit,
+                Some("s"),
+                "http.server.request.duration",
+            ))
+        };
+        assert_eq!(check(&ADVISED, "s"), None);
+        // Empty bounds (a single bucket) are not checked
+        assert_eq!(check(&[], "s"), None);
+        // The advised boundaries converted to the unit of the histogram
+        assert_eq!(check(&[5.0, 10.0, 100.0, 1000.0, 10000.0], "ms"), None);
+
+        assert_eq!(
+            check(&[0.1, 1.0, 10.0], "s").as_deref(),
+            Some(BUCKET_BOUNDARIES_MISMATCH_ADVICE_TYPE)
+        );
+        // Milliseconds boundaries in a histogram in seconds
+        assert_eq!(
+            check(&[5.0, 10.0, 100.0, 1000.0, 10000.0], "s").as_deref(),
+            Some(BUCKET_SCALE_MISMATCH_ADVICE_TYPE)
+        );
+        // Seconds boundaries in a histogram in milliseconds
+        let finding = check_bounds(&ADVISED, Some(&ADVISED), "ms", Some("s"), "test")
+            .unwrap()
+            .build();
+        assert_eq!(finding.id, BUCKET_SCALE_MISMATCH_ADVICE_TYPE);
+        assert_eq!(
+            finding.context.unwrap()[SCALE_FACTOR_ADVICE_CONTEXT_KEY],
+            json!(0.001)
+        );
+    }
+
+    #[test]
+    fn test_sdk_default_boundaries() {
+        assert_eq!(
+            id(check_bounds(
+                &SDK_DEFAULT_BOUNDARIES,
+                None,
+                "s",
+                None,
+                "test"
+            ))
+            .as_deref(),
+            Some(BUCKET_SCALE_MISMATCH_ADVICE_TYPE)
+        );
+        assert_eq!(
+            id(check_bounds(
+                &SDK_DEFAULT_BOUNDARIES,
+                None,
+                "ms",
+                None,
+                "test"
+            )),
+            None
+        );
+        assert_eq!(id(check_bounds(&ADVISED, None, "s", None, "test")), None);
+    }
+}
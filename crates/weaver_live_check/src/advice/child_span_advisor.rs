@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Child span advisor
+
+use serde_json::json;
+use std::rc::Rc;
+use weaver_checker::FindingLevel;
+use weaver_semconv::group::SpanKindSpec;
+
+use super::{FindingBuilder, TraceAdvisor};
+use crate::{
+    sample_trace::SampleTrace, Error, VersionedSignal, EXPECTED_VALUE_ADVICE_CONTEXT_KEY,
+    MISSING_CHILD_SPAN_ADVICE_TYPE, SPAN_ID_ADVICE_CONTEXT_KEY, SPAN_KIND_ADVICE_CONTEXT_KEY,
+    SPAN_NAME_ADVICE_CONTEXT_KEY,
+};
+
+/// An advisor that checks the kinds of the children of the spans of a trace.
+///
+/// A CLIENT span is expected to be followed by a SERVER span in the downstream
+/// service, and a PRODUCER span by a CONSUMER span. A missing child usually means
+/// the downstream service is not instrumented, or does not propagate the context.
+/// As CLIENT spans calling services that are never instrumented (e.g. databases)
+/// have no child either, this advisor is only run when child spans are expected.
+pub struct ChildSpanAdvisor;
+
+/// Returns the kind of the child span expected for a span of the given kind.
+fn expected_child_kind(kind: &SpanKindSpec) -> Option<SpanKindSpec> {
+    match kind {
+        SpanKindSpec::Client => Some(SpanKindSpec::Server),
+        SpanKindSpec::Producer => Some(SpanKindSpec::Consumer),
+        _ => None,
+    }
+}
+
+impl TraceAdvisor for ChildSpanAdvisor {
+    fn advise(
+        &mut self,
+        trace: &SampleTrace,
+        _span_signals: &[Option<Rc<VersionedSignal>>],
+    ) -> Result<Vec<FindingBuilder>, Error> {
+        let mut findings = Vec::new();
+        for span in &trace.spans {
+            let (Some(expected_kind), Some(span_id)) =
+                (expected_child_kind(&span.kind), span.span_id.as_ref())
+            else {
+                continue;
+            };
+            let has_expected_child = trace.spans.iter().any(|child| {
+                child.parent_span_id.as_ref() == Some(span_id) && child.kind == expected_kind
+            });
+            if has_expected_child {
+                continue;
+            }
+            findings.push(
+                FindingBuilder::new(MISSING_CHILD_SPAN_ADVICE_TYPE)
+                    .context(json!({
+                        SPAN_NAME_ADVICE_CONTEXT_KEY: span.name,
+                        SPAN_ID_ADVICE_CONTEXT_KEY: span_id,
+                        SPAN_KIND_ADVICE_CONTEXT_KEY: span.kind,
+                        EXPECTED_VALUE_ADVICE_CONTEXT_KEY: expected_kind,
+                    }))
+                    .message(format!(
+                        "Span '{}' of kind '{:?}' has no child span of kind '{:?}'.",
+                        span.name, span.kind, expected_kind
+                    ))
+                    .level(FindingLevel::Information),
+            );
+        }
+        Ok(findings)
+    }
+}
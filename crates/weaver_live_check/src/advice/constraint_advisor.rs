@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Attribute value constraints advisor
+
+use regex::Regex;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+use weaver_checker::{FindingLevel, PolicyFinding};
+use weaver_semconv::attribute::ValueConstraints;
+
+use super::{Advisor, FindingBuilder};
+use crate::{
+    otlp_logger::OtlpEmitter, Error, Sample, SampleRef, VersionedAttribute, VersionedSignal,
+    ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY, ATTRIBUTE_VALUE_ADVICE_CONTEXT_KEY,
+    CONSTRAINT_ADVICE_CONTEXT_KEY, CONSTRAINT_VIOLATION_ADVICE_TYPE,
+    EXPECTED_VALUE_ADVICE_CONTEXT_KEY,
+};
+
+/// An advisor that reports attribute values violating the value constraints
+/// (pattern, range, length) of their definition
+#[derive(Default)]
+pub struct ConstraintAdvisor {
+    /// Compiled patterns by regular expression, `None` for invalid expressions
+    patterns: HashMap<String, Option<Regex>>,
+}
+
+/// A constraint violated by a value
+struct Violation {
+    constraint: &'static str,
+    expected: Value,
+    description: String,
+}
+
+impl ConstraintAdvisor {
+    /// Returns the constraints violated by at least one of the values.
+    fn violations(&mut self, constraints: &ValueConstraints, values: &[&Value]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        let strings: Vec<&str> = values.iter().filter_map(|v| v.as_str()).collect();
+        let numbers: Vec<f64> = values.iter().filter_map(|v| v.as_f64()).collect();
+
+        if let Some(pattern) = &constraints.pattern {
+            let regex = self
+                .patterns
+                .entry(pattern.clone())
+                .or_insert_with(|| Regex::new(pattern).ok());
+            if let Some(regex) = regex {
+                if strings.iter().any(|s| !regex.is_match(s)) {
+                    violations.push(Violation {
+                        constraint: "pattern",
+                        expected: json!(pattern),
+                        description: format!("does not match the pattern '{pattern}'"),
+                    });
+                }
+            }
+        }
+        if let Some(min_length) = constraints.min_length {
+            if strings
+                .iter()
+                .any(|s| (s.chars().count() as u64) < min_length)
+            {
+                violations.push(Violation {
+                    constraint: "min_length",
+                    expected: json!(min_length),
+                    description: format!("is shorter than {min_length} characters"),
+                });
+            }
+        }
+        if let Some(max_length) = constraints.max_length {
+            if strings
+                .iter()
+                .any(|s| (s.chars().count() as u64) > max_length)
+            {
+                violations.push(Violation {
+                    constraint: "max_length",
+                    expected: json!(max_length),
+                    description: format!("is longer than {max_length} characters"),
+                });
+            }
+        }
+        if let Some(min) = constraints.min {
+            if numbers.iter().any(|n| *n < min.as_f64()) {
+                violations.push(Violation {
+                    constraint: "min",
+                    expected: json!(min),
+                    description: format!("is less than the minimum {min}"),
+                });
+            }
+        }
+        if let Some(max) = constraints.max {
+            if numbers.iter().any(|n| *n > max.as_f64()) {
+                violations.push(Violation {
+                    constraint: "max",
+                    expected: json!(max),
+                    description: format!("is greater than the maximum {max}"),
+                });
+            }
+        }
+        violations
+    }
+}
+
+impl Advisor for ConstraintAdvisor {
+    fn advise(
+        &mut self,
+        sample: SampleRef<'_>,
+        signal: &Sample,
+        registry_attribute: Option<Rc<VersionedAttribute>>,
+        _registry_group: Option<Rc<VersionedSignal>>,
+        otlp_emitter: Option<Rc<OtlpEmitter>>,
+    ) -> Result<Vec<PolicyFinding>, Error> {
+        let SampleRef::Attribute(sample_attribute) = sample else {
+            return Ok(Vec::new());
+        };
+        let (Some(semconv_attribute), Some(attribute_value)) =
+            (registry_attribute, sample_attribute.value.as_ref())
+        else {
+            return Ok(Vec::new());
+        };
+        let Some(constraints) = semconv_attribute.constraints() else {
+            return Ok(Vec::new());
+        };
+        // The constraints of array types apply to each element
+        let values: Vec<&Value> = match attribute_value {
+            Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        };
+
+        let findings = self
+            .violations(constraints, &values)
+            .into_iter()
+            .map(|violation| {
+                FindingBuilder::new(CONSTRAINT_VIOLATION_ADVICE_TYPE)
+                    .context(json!({
+                        ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY: &sample_attribute.name,
+                        ATTRIBUTE_VALUE_ADVICE_CONTEXT_KEY: attribute_value,
+                        CONSTRAINT_ADVICE_CONTEXT_KEY: violation.constraint,
+                        EXPECTED_VALUE_ADVICE_CONTEXT_KEY: violation.expected,
+                    }))
+                    .message(format!(
+                        "Attribute '{}' has value '{}' which {}.",
+                        sample_attribute.name,
+                        attribute_value
+                            .as_str()
+                            .unwrap_or(&attribute_value.to_string()),
+                        violation.description
+                    ))
+                    .level(FindingLevel::Violation)
+                    .signal(signal)
+                    .build_and_emit(&sample, otlp_emitter.as_deref(), signal)
+            })
+            .collect();
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use weaver_semconv::attribute::ConstraintBound;
+
+    #[test]
+    fn test_violations() {
+        let mut advisor = ConstraintAdvisor::default();
+        let constraints = ValueConstraints {
+            pattern: Some("^[a-z0-9-]+$".to_owned()),
+            min: None,
+            max: None,
+            min_length: Some(2),
+            max_length: Some(8),
+        };
+        let ok = json!("my-host");
+        assert!(advisor.violations(&constraints, &[&ok]).is_empty());
+
+        let bad = json!("My_Host_Name");
+        let constraints_violated: Vec<&str> = advisor
+            .violations(&constraints, &[&bad])
+            .iter()
+            .map(|v| v.constraint)
+            .collect();
+        assert_eq!(constraints_violated, vec!["pattern", "max_length"]);
+
+        let range = ValueConstraints {
+            min: Some(ConstraintBound::Int(0)),
+            max: Some(ConstraintBound::Int(65535)),
+            ..Default::default()
+        };
+        let (port, negative, too_large) = (json!(8080), json!(-1), json!(70000));
+        assert!(advisor.violations(&range, &[&port]).is_empty());
+        assert_eq!(
+            advisor.violations(&range, &[&negative])[0].constraint,
+            "min"
+        );
+        // Each element of an array is checked
+        assert_eq!(
+            advisor.violations(&range, &[&port, &too_large])[0].constraint,
+            "max"
+        );
+    }
+}
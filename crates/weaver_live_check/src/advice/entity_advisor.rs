@@ -0,0 +1,367 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resource entity advisor
+
+use serde_json::json;
+use std::collections::HashSet;
+use std::rc::Rc;
+use weaver_checker::{FindingLevel, PolicyFinding};
+use weaver_semconv::attribute::{AttributeRole, BasicRequirementLevelSpec, RequirementLevel};
+use weaver_semconv::group::GroupType;
+
+use super::{Advisor, FindingBuilder};
+use crate::{
+    otlp_logger::OtlpEmitter, sample_resource::SampleResource, Error, Sample, SampleRef,
+    VersionedAttribute, VersionedRegistry, VersionedSignal, ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY,
+    ENTITY_ASSOCIATIONS_ADVICE_CONTEXT_KEY, ENTITY_TYPE_ADVICE_CONTEXT_KEY,
+    MISSING_ENTITY_ADVICE_TYPE, MISSING_ENTITY_IDENTITY_ADVICE_TYPE,
+    UNIDENTIFIED_ENTITY_ATTRIBUTE_ADVICE_TYPE,
+};
+
+/// An entity of the registry
+struct RegistryEntity {
+    /// The type of the entity
+    r#type: String,
+    /// The identifying attributes with their requirement level
+    identity: Vec<(String, RequirementLevel)>,
+    /// The descriptive attributes
+    description: Vec<String>,
+}
+
+/// An advisor that checks resources against the entities of the registry.
+///
+/// An entity is identified by a resource when at least one of its identifying
+/// attributes is present. The advisor reports the identifying attributes missing
+/// from identified entities, the attributes of entities the resource does not
+/// identify, and signals whose resource identifies none of the entities their
+/// definition is associated with.
+pub struct EntityAdvisor {
+    entities: Vec<RegistryEntity>,
+}
+
+impl EntityAdvisor {
+    /// Create a new EntityAdvisor with the entities of the registry
+    #[must_use]
+    pub fn new(registry: &VersionedRegistry) -> Self {
+        let entities = match registry {
+            VersionedRegistry::V1(registry) => registry
+                .groups
+                .iter()
+                .filter(|group| group.r#type == GroupType::Entity)
+                .filter_map(|group| {
+                    let (identity, description): (Vec<_>, Vec<_>) =
+                        group.attributes.iter().partition(|attribute| {
+                            attribute
+                                .role
+                                .as_ref()
+                                .map_or(true, AttributeRole::is_identifying)
+                        });
+                    Some(RegistryEntity {
+                        r#type: group.name.clone()?,
+                        identity: identity
+                            .into_iter()
+                            .map(|a| (a.name.clone(), a.requirement_level.clone()))
+                            .collect(),
+                        description: description.into_iter().map(|a| a.name.clone()).collect(),
+                    })
+                })
+                .collect(),
+            VersionedRegistry::V2(registry) => registry
+                .registry
+                .entities
+                .iter()
+                .map(|entity| RegistryEntity {
+                    r#type: entity.r#type.to_string(),
+                    identity: entity
+                        .identity
+                        .iter()
+                        .map(|a| (a.base.key.clone(), a.requirement_level.clone()))
+                        .collect(),
+                    description: entity
+                        .description
+                        .iter()
+                        .map(|a| a.base.key.clone())
+                        .collect(),
+                })
+                .collect(),
+        };
+        EntityAdvisor { entities }
+    }
+
+    /// Returns the entities identified by the attributes of a resource.
+    fn identified_entities(&self, attributes: &HashSet<&str>) -> Vec<&RegistryEntity> {
+        self.entities
+            .iter()
+            .filter(|entity| {
+                entity
+                    .identity
+                    .iter()
+                    .any(|(name, _)| attributes.contains(name.as_str()))
+            })
+            .collect()
+    }
+
+    /// Checks a resource against the entities it identifies.
+    fn check_resource(&self, resource: &SampleResource) -> Vec<FindingBuilder> {
+        let attributes = resource_attributes(resource);
+        let identified = self.identified_entities(&attributes);
+        let mut findings = Vec::new();
+
+        for entity in &identified {
+            for (name, requirement_level) in &entity.identity {
+                if attributes.contains(name.as_str()) {
+                    continue;
+                }
+                let (level, requirement) = match requirement_level {
+                    RequirementLevel::Basic(BasicRequirementLevelSpec::Required) => {
+                        (FindingLevel::Violation, "Required")
+                    }
+                    RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended)
+                    | RequirementLevel::Recommended { .. } => {
+                        (FindingLevel::Improvement, "Recommended")
+                    }
+                    RequirementLevel::Basic(BasicRequirementLevelSpec::OptIn)
+                    | RequirementLevel::OptIn { .. } => (FindingLevel::Information, "Opt-in"),
+                    RequirementLevel::ConditionallyRequired { .. } => {
+                        (FindingLevel::Information, "Conditionally required")
+                    }
+                };
+                findings.push(
+                    FindingBuilder::new(MISSING_ENTITY_IDENTITY_ADVICE_TYPE)
+                        .context(json!({
+                            ENTITY_TYPE_ADVICE_CONTEXT_KEY: entity.r#type,
+                            ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY: name,
+                        }))
+                        .message(format!(
+                            "{requirement} identifying attribute '{name}' of entity '{}' is not present.",
+                            entity.r#type
+                        ))
+                        .level(level),
+                );
+            }
+        }
+
+        // Attributes of entities the resource does not identify conflict with
+        // the entities it does identify
+        let identified_attributes: HashSet<&str> = identified
+            .iter()
+            .flat_map(|entity| {
+                entity
+                    .identity
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .chain(entity.description.iter().map(String::as_str))
+            })
+            .collect();
+        let mut reported = HashSet::new();
+        for entity in &self.entities {
+            for name in &entity.description {
+                if attributes.contains(name.as_str())
+                    && !identified_attributes.contains(name.as_str())
+                    && reported.insert(name.as_str())
+                {
+                    findings.push(
+                        FindingBuilder::new(UNIDENTIFIED_ENTITY_ATTRIBUTE_ADVICE_TYPE)
+                            .context(json!({
+                                ENTITY_TYPE_ADVICE_CONTEXT_KEY: entity.r#type,
+                                ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY: name,
+                            }))
+                            .message(format!(
+                                "Attribute '{name}' describes entity '{}' which the resource does not identify.",
+                                entity.r#type
+                            ))
+                            .level(FindingLevel::Improvement),
+                    );
+                }
+            }
+        }
+        findings
+    }
+
+    /// Checks that the resource of a signal identifies one of the entities the
+    /// signal definition is associated with.
+    fn check_associations(
+        &self,
+        entity_associations: &[String],
+        resource: &SampleResource,
+        signal_type: &str,
+        signal_name: &str,
+    ) -> Option<FindingBuilder> {
+        if entity_associations.is_empty() {
+            return None;
+        }
+        let attributes = resource_attributes(resource);
+        let identified = self.identified_entities(&attributes);
+        if identified
+            .iter()
+            .any(|entity| entity_associations.contains(&entity.r#type))
+        {
+            return None;
+        }
+        Some(
+            FindingBuilder::new(MISSING_ENTITY_ADVICE_TYPE)
+                .context(json!({
+                    ENTITY_ASSOCIATIONS_ADVICE_CONTEXT_KEY: entity_associations,
+                }))
+                .message(format!(
+                    "The resource of {signal_type} '{signal_name}' does not identify any of its associated entities: {}.",
+                    entity_associations.join(", ")
+                ))
+                .level(FindingLevel::Improvement),
+        )
+    }
+}
+
+/// The names of the attributes of a resource.
+fn resource_attributes(resource: &SampleResource) -> HashSet<&str> {
+    resource
+        .attributes
+        .iter()
+        .map(|attribute| attribute.registry_name())
+        .collect()
+}
+
+impl Advisor for EntityAdvisor {
+    fn advise(
+        &mut self,
+        sample: SampleRef<'_>,
+        signal: &Sample,
+        _registry_attribute: Option<Rc<VersionedAttribute>>,
+        registry_group: Option<Rc<VersionedSignal>>,
+        otlp_emitter: Option<Rc<OtlpEmitter>>,
+    ) -> Result<Vec<PolicyFinding>, Error> {
+        let findings = match sample {
+            SampleRef::Resource(resource) => self.check_resource(resource),
+            SampleRef::Metric(_) | SampleRef::Span(_) | SampleRef::Log(_) => {
+                match (registry_group, signal.resource()) {
+                    (Some(semconv_signal), Some(resource)) => self
+                        .check_associations(
+                            semconv_signal.entity_associations(),
+                            resource,
+                            &signal.signal_type().unwrap_or_default(),
+                            &signal.signal_name().unwrap_or_default(),
+                        )
+                        .into_iter()
+                        .collect(),
+                    _ => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        };
+        Ok(findings
+            .into_iter()
+            .map(|finding| {
+                finding
+                    .signal(signal)
+                    .build_and_emit(&sample, otlp_emitter.as_deref(), signal)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sample_attribute::SampleAttribute;
+
+    fn advisor() -> EntityAdvisor {
+        let required = RequirementLevel::Basic(BasicRequirementLevelSpec::Required);
+        let recommended = RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended);
+        EntityAdvisor {
+            entities: vec![
+                RegistryEntity {
+                    r#type: "service".to_owned(),
+                    identity: vec![
+                        ("service.name".to_owned(), required.clone()),
+                        ("service.namespace".to_owned(), recommended),
+                    ],
+                    description: vec!["service.version".to_owned()],
+                },
+                RegistryEntity {
+                    r#type: "host".to_owned(),
+                    identity: vec![("host.id".to_owned(), required)],
+                    description: vec!["host.arch".to_owned()],
+                },
+            ],
+        }
+    }
+
+    fn resource(attributes: &[&str]) -> SampleResource {
+        SampleResource {
+            attributes: attributes
+                .iter()
+                .map(|a| SampleAttribute::try_from(*a).unwrap())
+                .collect(),
+            schema_url: None,
+            live_check_result: None,
+        }
+    }
+
+    fn ids(findings: Vec<FindingBuilder>) -> Vec<(String, FindingLevel)> {
+        findings
+            .into_iter()
+            .map(|finding| {
+                let finding = finding.build();
+                (
+                    format!(
+                        "{}:{}",
+                        finding.id,
+                        finding.context.unwrap()[ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY]
+                            .as_str()
+                            .unwrap_or_default()
+                    ),
+                    finding.level,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_check_resource() {
+        let advisor = advisor();
+        assert!(ids(advisor.check_resource(&resource(&[
+            "service.name=checkout",
+            "service.namespace=shop",
+            "service.version=1.0"
+        ])))
+        .is_empty());
+
+        assert_eq!(
+            ids(advisor.check_resource(&resource(&["service.namespace=shop", "host.arch=amd64"]))),
+            vec![
+                (
+                    "missing_entity_identity:service.name".to_owned(),
+                    FindingLevel::Violation
+                ),
+                (
+                    "unidentified_entity_attribute:host.arch".to_owned(),
+                    FindingLevel::Improvement
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_associations() {
+        let advisor = advisor();
+        let host = resource(&["host.id=abc"]);
+        let service = resource(&["service.name=checkout"]);
+        let associations = vec!["host".to_owned()];
+
+        assert!(advisor
+            .check_associations(&associations, &host, "metric", "system.cpu.time")
+            .is_none());
+        assert!(advisor
+            .check_associations(&[], &service, "metric", "system.cpu.time")
+            .is_none());
+        let finding = advisor
+            .check_associations(&associations, &service, "metric", "system.cpu.time")
+            .unwrap()
+            .build();
+        assert_eq!(finding.id, MISSING_ENTITY_ADVICE_TYPE);
+        assert_eq!(
+            finding.message,
+            "The resource of metric 'system.cpu.time' does not identify any of its associated entities: host."
+        );
+    }
+}
@@ -7,20 +7,35 @@ use std::rc::Rc;
 use weaver_checker::{FindingLevel, PolicyFinding};
 
 use crate::{
-    otlp_logger::OtlpEmitter, Error, Sample, SampleRef, VersionedAttribute, VersionedSignal,
+    otlp_logger::OtlpEmitter, sample_trace::SampleTrace, Error, Sample, SampleRef,
+    VersionedAttribute, VersionedSignal,
 };
 
 // Internal modules
+mod body_advisor;
+mod bucket_advisor;
+mod child_span_advisor;
+mod constraint_advisor;
 mod deprecated_advisor;
+mod entity_advisor;
 mod enum_advisor;
+mod orphan_span_advisor;
 mod rego_advisor;
+mod span_event_advisor;
 mod stability_advisor;
 mod type_advisor;
 
 // Public re-exports
+pub use body_advisor::BodyAdvisor;
+pub use bucket_advisor::BucketAdvisor;
+pub use child_span_advisor::ChildSpanAdvisor;
+pub use constraint_advisor::ConstraintAdvisor;
 pub use deprecated_advisor::DeprecatedAdvisor;
+pub use entity_advisor::EntityAdvisor;
 pub use enum_advisor::EnumAdvisor;
-pub use rego_advisor::RegoAdvisor;
+pub use orphan_span_advisor::OrphanSpanAdvisor;
+pub use rego_advisor::{RegoAdvisor, RegoStatistics};
+pub use span_event_advisor::SpanEventAdvisor;
 pub use stability_advisor::StabilityAdvisor;
 pub use type_advisor::TypeAdvisor;
 
@@ -37,6 +52,17 @@ pub trait Advisor {
     ) -> Result<Vec<PolicyFinding>, Error>;
 }
 
+/// Provides advice on the structure of an assembled trace
+pub trait TraceAdvisor {
+    /// Provide advice on a trace. `span_signals` holds the registry span matched by
+    /// each span of the trace, in the same order as `trace.spans`.
+    fn advise(
+        &mut self,
+        trace: &SampleTrace,
+        span_signals: &[Option<Rc<VersionedSignal>>],
+    ) -> Result<Vec<FindingBuilder>, Error>;
+}
+
 /// Fluent builder for creating PolicyFinding instances with automatic emission
 pub struct FindingBuilder {
     id: String,
@@ -169,6 +195,7 @@ mod tests {
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "deprecated attribute".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             stability: None,
             deprecated: Some(Deprecated::Obsoleted {
@@ -206,6 +233,7 @@ mod tests {
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
             brief: "integer attribute".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             stability: None,
             deprecated: None,
@@ -244,6 +272,7 @@ mod tests {
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "development attribute".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             stability: Some(Stability::Development),
             deprecated: None,
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Orphan span advisor
+
+use serde_json::json;
+use std::collections::HashSet;
+use std::rc::Rc;
+use weaver_checker::FindingLevel;
+
+use super::{FindingBuilder, TraceAdvisor};
+use crate::{
+    sample_trace::SampleTrace, Error, VersionedSignal, ORPHANED_SPAN_ADVICE_TYPE,
+    PARENT_SPAN_ID_ADVICE_CONTEXT_KEY, SPAN_ID_ADVICE_CONTEXT_KEY, SPAN_NAME_ADVICE_CONTEXT_KEY,
+};
+
+/// An advisor that reports the spans whose parent is not part of the trace.
+///
+/// The parent may have been dropped, exported to another backend, or not yet
+/// received when the trace was assembled.
+pub struct OrphanSpanAdvisor;
+
+impl TraceAdvisor for OrphanSpanAdvisor {
+    fn advise(
+        &mut self,
+        trace: &SampleTrace,
+        _span_signals: &[Option<Rc<VersionedSignal>>],
+    ) -> Result<Vec<FindingBuilder>, Error> {
+        let span_ids: HashSet<&str> = trace
+            .spans
+            .iter()
+            .filter_map(|span| span.span_id.as_deref())
+            .collect();
+        let findings = trace
+            .spans
+            .iter()
+            .filter_map(|span| {
+                let parent_span_id = span.parent_span_id.as_deref()?;
+                if span_ids.contains(parent_span_id) {
+                    return None;
+                }
+                Some(
+                    FindingBuilder::new(ORPHANED_SPAN_ADVICE_TYPE)
+                        .context(json!({
+                            SPAN_NAME_ADVICE_CONTEXT_KEY: span.name,
+                            SPAN_ID_ADVICE_CONTEXT_KEY: span.span_id,
+                            PARENT_SPAN_ID_ADVICE_CONTEXT_KEY: parent_span_id,
+                        }))
+                        .message(format!(
+                            "Parent span '{}' of span '{}' is not part of the trace.",
+                            parent_span_id, span.name
+                        ))
+                        .level(FindingLevel::Improvement),
+                )
+            })
+            .collect();
+        Ok(findings)
+    }
+}
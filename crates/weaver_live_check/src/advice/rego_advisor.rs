@@ -3,20 +3,268 @@
 //! Rego policy-based advisor
 
 use serde::Serialize;
-use std::{collections::BTreeMap, path::PathBuf, rc::Rc};
-use weaver_checker::{Engine, PolicyFinding};
+use serde_json::{json, Value};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    path::PathBuf,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+use weaver_checker::{policy_files, Engine, PolicyFinding, PolicyStage};
 use weaver_forge::jq;
+use weaver_semconv::attribute::AttributeType;
 
 use super::{emit_findings, Advisor};
 use crate::{
-    live_checker::LiveChecker, otlp_logger::OtlpEmitter, Error, Sample, SampleRef,
+    live_checker::LiveChecker, otlp_logger::OtlpEmitter, sample_attribute::SampleAttribute,
+    sample_metric::DataPoints, sample_scope::SampleScope, Error, Sample, SampleRef,
     VersionedAttribute, VersionedSignal, DEFAULT_LIVE_CHECK_JQ, DEFAULT_LIVE_CHECK_REGO,
     DEFAULT_LIVE_CHECK_REGO_POLICY_PATH,
 };
 
+/// The sample types, as referenced by the policies with `input.sample.<type>`
+const SAMPLE_TYPES: [&str; 12] = [
+    "attribute",
+    "span",
+    "span_event",
+    "span_link",
+    "resource",
+    "metric",
+    "number_data_point",
+    "histogram_data_point",
+    "exponential_histogram_data_point",
+    "exemplar",
+    "log",
+    "trace",
+];
+
+/// The maximum number of inputs whose findings are cached. When the cache is full,
+/// the oldest entry is evicted.
+const CACHE_CAPACITY: usize = 10_000;
+
 /// An advisor which runs a rego policy on the attribute
+///
+/// The policies are partitioned by sample type: a policy file defining `deny`
+/// rules is only evaluated for the sample types it references as
+/// `input.sample.<type>`, and other policy files (e.g. helper functions) are
+/// loaded in every partition. Sample types no policy references are not evaluated.
+/// The references are read from the AST of the compiled policies.
+///
+/// The findings are cached by the shape of the samples, so samples with the same
+/// shape, matched to the same registry definitions, in the same scope, are only
+/// evaluated once. The shape of a sample is its name and type and the names and
+/// types of its attributes (see `sample_shape`). The measurements and IDs, which
+/// differ on every export, are left out: e.g. a `metric` sample is evaluated once
+/// whatever the values of its data points, and policies checking values should
+/// match the `number_data_point` or `exemplar` samples, whose value is part of the
+/// shape. The clones of an advisor share the cache and the counts of evaluations.
+#[derive(Clone)]
 pub struct RegoAdvisor {
-    engine: Engine,
+    engines: Vec<Engine>,
+    partitions: HashMap<&'static str, usize>,
+    cache: Arc<Mutex<RegoCache>>,
+    counters: Arc<RegoCounters>,
+}
+
+/// The findings of the inputs evaluated so far, evicted in insertion order
+#[derive(Default)]
+struct RegoCache {
+    findings: HashMap<String, Vec<PolicyFinding>>,
+    keys: VecDeque<String>,
+}
+
+impl RegoCache {
+    fn get(&self, key: &str) -> Option<Vec<PolicyFinding>> {
+        self.findings.get(key).cloned()
+    }
+
+    /// Adds the findings of an input, evicting the oldest entry if the cache is full.
+    fn insert(&mut self, key: String, findings: Vec<PolicyFinding>) {
+        if self.findings.contains_key(&key) {
+            return;
+        }
+        if self.keys.len() >= CACHE_CAPACITY {
+            if let Some(oldest) = self.keys.pop_front() {
+                let _ = self.findings.remove(&oldest);
+            }
+        }
+        self.keys.push_back(key.clone());
+        let _ = self.findings.insert(key, findings);
+    }
+}
+
+/// Counts of the rego evaluations of a RegoAdvisor and its clones
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct RegoStatistics {
+    /// The number of sample entities the policies were evaluated for
+    pub evaluations: usize,
+    /// The number of sample entities whose findings were found in the cache
+    pub cache_hits: usize,
+    /// The number of sample entities of a type no policy applies to
+    pub skipped: usize,
+}
+
+/// The counters behind the statistics, shared by the clones of an advisor
+#[derive(Default)]
+struct RegoCounters {
+    evaluations: AtomicUsize,
+    cache_hits: AtomicUsize,
+    skipped: AtomicUsize,
+}
+
+/// Returns whether a policy defines `deny` rules, and the sample types it is
+/// evaluated for, or None if it is evaluated for all of them.
+fn policy_sample_types(
+    path: &str,
+    rego: &str,
+) -> Result<(bool, Option<HashSet<&'static str>>), weaver_checker::Error> {
+    let mut engine = Engine::new();
+    let _ = engine.add_policy(path, rego)?;
+    let deny_rule = format!("data.{}.deny", PolicyStage::LiveCheckAdvice);
+    if !engine.rule_names()?.contains(&deny_rule) {
+        return Ok((false, None));
+    }
+    let Some(fields) = engine.input_fields(&["sample"])? else {
+        return Ok((true, None));
+    };
+    let sample_types: HashSet<_> = SAMPLE_TYPES
+        .iter()
+        .filter(|sample_type| fields.contains(**sample_type))
+        .copied()
+        .collect();
+    Ok((true, (!sample_types.is_empty()).then_some(sample_types)))
+}
+
+/// Returns the key identifying a registry signal.
+fn signal_key(signal: &VersionedSignal) -> String {
+    match signal {
+        VersionedSignal::Group(group) => group.id.clone(),
+        VersionedSignal::Metric(metric) => format!("metric:{}", metric.name),
+        VersionedSignal::Span(span) => format!("span:{}", span.r#type),
+        VersionedSignal::Event(event) => format!("event:{}", event.name),
+    }
+}
+
+/// Returns the shape of a list of attributes: their names and types.
+fn attributes_shape(attributes: &[SampleAttribute]) -> Value {
+    attributes
+        .iter()
+        .map(|attribute| json!([attribute.name, attribute.r#type]))
+        .collect()
+}
+
+/// Returns the shape of a sample: its name, type and the shape of its attributes,
+/// the value of an enum attribute, and the value of the samples carrying one (data
+/// points, exemplars, log bodies and span statuses). Counts, sums, bucket counts,
+/// timestamps and IDs are left out.
+fn sample_shape(sample: &SampleRef<'_>, registry_attribute: Option<&VersionedAttribute>) -> Value {
+    match sample {
+        SampleRef::Attribute(attribute) => {
+            let is_enum = registry_attribute
+                .is_some_and(|attribute| matches!(attribute.r#type(), AttributeType::Enum { .. }));
+            json!([
+                attribute.name,
+                attribute.r#type,
+                if is_enum {
+                    attribute.value.as_ref()
+                } else {
+                    None
+                },
+            ])
+        }
+        SampleRef::Span(span) => json!([
+            span.name,
+            span.kind,
+            span.status.as_ref().map(|status| &status.code),
+            attributes_shape(&span.attributes),
+            span.span_events
+                .iter()
+                .map(|event| &event.name)
+                .collect::<Vec<_>>(),
+        ]),
+        SampleRef::SpanEvent(event) => json!([event.name, attributes_shape(&event.attributes)]),
+        SampleRef::SpanLink(link) => attributes_shape(&link.attributes),
+        SampleRef::Resource(resource) => attributes_shape(&resource.attributes),
+        SampleRef::Metric(metric) => {
+            // The distinct attribute shapes of the data points
+            let data_points: BTreeSet<String> = match &metric.data_points {
+                Some(DataPoints::Number(points)) => points
+                    .iter()
+                    .map(|point| attributes_shape(&point.attributes).to_string())
+                    .collect(),
+                Some(DataPoints::Histogram(points)) => points
+                    .iter()
+                    .map(|point| attributes_shape(&point.attributes).to_string())
+                    .collect(),
+                Some(DataPoints::ExponentialHistogram(points)) => points
+                    .iter()
+                    .map(|point| attributes_shape(&point.attributes).to_string())
+                    .collect(),
+                None => BTreeSet::new(),
+            };
+            json!([metric.name, metric.instrument, metric.unit, data_points])
+        }
+        SampleRef::NumberDataPoint(point) => {
+            json!([attributes_shape(&point.attributes), point.value])
+        }
+        SampleRef::HistogramDataPoint(point) => {
+            json!([attributes_shape(&point.attributes), point.explicit_bounds])
+        }
+        SampleRef::ExponentialHistogramDataPoint(point) => {
+            json!([attributes_shape(&point.attributes), point.scale])
+        }
+        SampleRef::Exemplar(exemplar) => {
+            json!([
+                attributes_shape(&exemplar.filtered_attributes),
+                exemplar.value
+            ])
+        }
+        SampleRef::Log(log) => json!([
+            log.event_name,
+            log.severity_number,
+            log.severity_text,
+            log.body,
+            attributes_shape(&log.attributes),
+        ]),
+        SampleRef::Trace(trace) => {
+            // The spans and the index of their parent in the trace
+            let spans: Vec<_> = trace
+                .spans
+                .iter()
+                .map(|span| {
+                    let parent = span.parent_span_id.as_ref().map(|parent_id| {
+                        trace
+                            .spans
+                            .iter()
+                            .position(|parent| parent.span_id.as_ref() == Some(parent_id))
+                    });
+                    json!([span.name, span.kind, span.span_events.len(), parent])
+                })
+                .collect();
+            json!(spans)
+        }
+    }
+}
+
+/// Builds the cache key of an input: the type and shape of the sample, the names
+/// of the registry definitions it is matched to, and its scope.
+fn cache_key(
+    sample: &SampleRef<'_>,
+    registry_attribute: Option<&VersionedAttribute>,
+    registry_group: Option<&VersionedSignal>,
+    scope: Option<&SampleScope>,
+) -> String {
+    json!([
+        sample.sample_type(),
+        sample_shape(sample, registry_attribute),
+        registry_attribute.map(VersionedAttribute::name),
+        registry_group.map(signal_key),
+        scope,
+    ])
+    .to_string()
 }
 
 impl RegoAdvisor {
@@ -26,20 +274,25 @@ impl RegoAdvisor {
         policy_dir: &Option<PathBuf>,
         jq_preprocessor: &Option<PathBuf>,
     ) -> Result<Self, Error> {
-        let mut engine = Engine::new();
-        if let Some(path) = policy_dir {
-            let _ = engine
-                .add_policies(path, "*.rego")
+        let policies = if let Some(path) = policy_dir {
+            policy_files(path, "*.rego")
                 .map_err(|e| Error::AdviceError {
                     error: e.to_string(),
-                })?;
+                })?
+                .into_iter()
+                .map(|path| {
+                    let rego = std::fs::read_to_string(&path).map_err(|e| Error::AdviceError {
+                        error: format!("{}: {e}", path.display()),
+                    })?;
+                    Ok((path.to_string_lossy().to_string(), rego))
+                })
+                .collect::<Result<Vec<_>, Error>>()?
         } else {
-            let _ = engine
-                .add_policy(DEFAULT_LIVE_CHECK_REGO_POLICY_PATH, DEFAULT_LIVE_CHECK_REGO)
-                .map_err(|e| Error::AdviceError {
-                    error: e.to_string(),
-                })?;
-        }
+            vec![(
+                DEFAULT_LIVE_CHECK_REGO_POLICY_PATH.to_owned(),
+                DEFAULT_LIVE_CHECK_REGO.to_owned(),
+            )]
+        };
 
         // If there is a jq preprocessor then pass the live_checker data through it before adding it to the engine
         // Otherwise use the default jq preprocessor
@@ -62,30 +315,85 @@ impl RegoAdvisor {
             error: e.to_string(),
         })?;
 
-        engine
-            .add_data(&jq_result)
+        // Group the sample types evaluated with the same policies in one partition
+        let policy_types = policies
+            .iter()
+            .map(|(path, rego)| policy_sample_types(path, rego))
+            .collect::<Result<Vec<_>, _>>()
             .map_err(|e| Error::AdviceError {
                 error: e.to_string(),
             })?;
+        let mut engines = Vec::new();
+        let mut partitions = HashMap::new();
+        let mut partition_by_policies: HashMap<BTreeSet<usize>, usize> = HashMap::new();
+        for sample_type in SAMPLE_TYPES {
+            let policy_indexes: BTreeSet<usize> = policy_types
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, types))| {
+                    types
+                        .as_ref()
+                        .map_or(true, |types| types.contains(sample_type))
+                })
+                .map(|(index, _)| index)
+                .collect();
+            // Without any `deny` rule, there is nothing to evaluate
+            if !policy_indexes.iter().any(|index| policy_types[*index].0) {
+                continue;
+            }
+            let partition = if let Some(partition) = partition_by_policies.get(&policy_indexes) {
+                *partition
+            } else {
+                let mut engine = Engine::new();
+                for index in &policy_indexes {
+                    let (path, rego) = &policies[*index];
+                    let _ = engine
+                        .add_policy(path, rego)
+                        .map_err(|e| Error::AdviceError {
+                            error: e.to_string(),
+                        })?;
+                }
+                engine
+                    .add_data(&jq_result)
+                    .map_err(|e| Error::AdviceError {
+                        error: e.to_string(),
+                    })?;
+                engines.push(engine);
+                let _ = partition_by_policies.insert(policy_indexes, engines.len() - 1);
+                engines.len() - 1
+            };
+            let _ = partitions.insert(sample_type, partition);
+        }
 
-        Ok(RegoAdvisor { engine })
+        Ok(RegoAdvisor {
+            engines,
+            partitions,
+            cache: Arc::new(Mutex::new(RegoCache::default())),
+            counters: Arc::new(RegoCounters::default()),
+        })
     }
 
-    fn check<T>(&mut self, input: T) -> Result<Vec<PolicyFinding>, Error>
-    where
-        T: Serialize,
-    {
-        self.engine
-            .set_input(&input)
-            .map_err(|e| Error::AdviceError {
-                error: e.to_string(),
-            })?;
-        let violations = self
-            .engine
-            .check(weaver_checker::PolicyStage::LiveCheckAdvice)
-            .map_err(|e| Error::AdviceError {
-                error: e.to_string(),
-            })?;
+    /// Returns the counts of rego evaluations of this advisor and its clones
+    #[must_use]
+    pub fn statistics(&self) -> RegoStatistics {
+        RegoStatistics {
+            evaluations: self.counters.evaluations.load(Ordering::Relaxed),
+            cache_hits: self.counters.cache_hits.load(Ordering::Relaxed),
+            skipped: self.counters.skipped.load(Ordering::Relaxed),
+        }
+    }
+
+    fn check(&mut self, partition: usize, input: &Value) -> Result<Vec<PolicyFinding>, Error> {
+        let engine = &mut self.engines[partition];
+        engine.set_input(input).map_err(|e| Error::AdviceError {
+            error: e.to_string(),
+        })?;
+        let violations =
+            engine
+                .check(PolicyStage::LiveCheckAdvice)
+                .map_err(|e| Error::AdviceError {
+                    error: e.to_string(),
+                })?;
         // Extract advice from violations
         Ok(violations)
     }
@@ -97,6 +405,8 @@ struct RegoInput<'a> {
     sample: SampleRef<'a>,
     registry_attribute: Option<Rc<VersionedAttribute>>,
     registry_group: Option<Rc<VersionedSignal>>,
+    /// The instrumentation scope of the signal the sample belongs to
+    scope: Option<&'a SampleScope>,
 }
 
 impl Advisor for RegoAdvisor {
@@ -108,11 +418,44 @@ impl Advisor for RegoAdvisor {
         registry_group: Option<Rc<VersionedSignal>>,
         otlp_emitter: Option<Rc<OtlpEmitter>>,
     ) -> Result<Vec<PolicyFinding>, Error> {
-        let mut findings = self.check(RegoInput {
-            sample: sample.clone(),
-            registry_attribute,
-            registry_group,
-        })?;
+        let Some(&partition) = self.partitions.get(sample.sample_type()) else {
+            let _ = self.counters.skipped.fetch_add(1, Ordering::Relaxed);
+            return Ok(vec![]);
+        };
+        let scope = signal.scope();
+        let key = cache_key(
+            &sample,
+            registry_attribute.as_deref(),
+            registry_group.as_deref(),
+            scope,
+        );
+
+        let cached = self
+            .cache
+            .lock()
+            .expect("Failed to acquire lock on rego cache")
+            .get(&key);
+        let mut findings = if let Some(findings) = cached {
+            let _ = self.counters.cache_hits.fetch_add(1, Ordering::Relaxed);
+            findings
+        } else {
+            let _ = self.counters.evaluations.fetch_add(1, Ordering::Relaxed);
+            let input = serde_json::to_value(RegoInput {
+                sample: sample.clone(),
+                registry_attribute,
+                registry_group,
+                scope,
+            })
+            .map_err(|e| Error::AdviceError {
+                error: e.to_string(),
+            })?;
+            let findings = self.check(partition, &input)?;
+            self.cache
+                .lock()
+                .expect("Failed to acquire lock on rego cache")
+                .insert(key, findings.clone());
+            findings
+        };
 
         // Populate signal_type and signal_name from the parent signal if not already set
         for finding in &mut findings {
@@ -130,3 +473,49 @@ impl Advisor for RegoAdvisor {
         Ok(findings)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::sample_span::SampleSpan;
+
+    #[test]
+    fn test_cache_key() {
+        let span = |span_id: &str, name: &str| -> SampleSpan {
+            serde_json::from_value(json!({
+                "name": name,
+                "kind": "client",
+                "span_id": span_id,
+                "trace_id": "0af7651916cd43dd8448eb211c80319c",
+                "live_check_result": {"all_advice": [], "highest_advice_level": null}
+            }))
+            .expect("Valid span")
+        };
+        let key = |span: &SampleSpan| cache_key(&SampleRef::Span(span), None, None, None);
+        // The IDs and results are left out
+        assert_eq!(
+            key(&span("00f067aa0ba902b7", "GET")),
+            key(&span("b7ad6b7169203331", "GET"))
+        );
+        assert!(!key(&span("00f067aa0ba902b7", "GET")).contains("0af7651916cd43dd8448eb211c80319c"));
+        assert_ne!(
+            key(&span("00f067aa0ba902b7", "GET")),
+            key(&span("00f067aa0ba902b7", "POST"))
+        );
+    }
+
+    #[test]
+    fn test_cache_eviction() {
+        let mut cache = RegoCache::default();
+        for i in 0..=CACHE_CAPACITY {
+            cache.insert(i.to_string(), vec![]);
+        }
+        // Only the oldest entry is evicted
+        assert_eq!(cache.findings.len(), CACHE_CAPACITY);
+        assert!(cache.get("0").is_none());
+        assert!(cache.get("1").is_some());
+        assert!(cache.get(&CACHE_CAPACITY.to_string()).is_some());
+    }
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Span event advisor
+
+use serde_json::json;
+use std::collections::HashSet;
+use std::rc::Rc;
+use weaver_checker::FindingLevel;
+
+use super::{FindingBuilder, TraceAdvisor};
+use crate::{
+    sample_trace::SampleTrace, Error, VersionedRegistry, VersionedSignal,
+    EVENT_NAME_ADVICE_CONTEXT_KEY, SPAN_ID_ADVICE_CONTEXT_KEY, SPAN_NAME_ADVICE_CONTEXT_KEY,
+    SPAN_TYPE_ADVICE_CONTEXT_KEY, UNEXPECTED_SPAN_EVENT_ADVICE_TYPE,
+};
+
+/// Events that may be recorded on any span, whatever the span types listing them
+/// (e.g. `exception`, recorded by `Span.recordException`).
+const GENERIC_SPAN_EVENTS: &[&str] = &["exception"];
+
+/// An advisor that checks the events recorded on the spans of a trace.
+///
+/// An event defined in the registry is only expected on spans whose type lists
+/// it in its `events`. Events unknown to the registry, generic events such as
+/// `exception` and spans not matched to a span type of the registry are not
+/// checked.
+pub struct SpanEventAdvisor {
+    event_names: HashSet<String>,
+}
+
+impl SpanEventAdvisor {
+    /// Create a new SpanEventAdvisor with the events of the registry.
+    ///
+    /// Returns None for a v1 registry, whose spans are not matched to the span
+    /// types of the registry.
+    #[must_use]
+    pub fn new(registry: &VersionedRegistry) -> Option<Self> {
+        let VersionedRegistry::V2(registry) = registry else {
+            return None;
+        };
+        let event_names = registry
+            .registry
+            .events
+            .iter()
+            .map(|event| event.name.to_string())
+            .filter(|name| !GENERIC_SPAN_EVENTS.contains(&name.as_str()))
+            .collect();
+        Some(SpanEventAdvisor { event_names })
+    }
+}
+
+impl TraceAdvisor for SpanEventAdvisor {
+    fn advise(
+        &mut self,
+        trace: &SampleTrace,
+        span_signals: &[Option<Rc<VersionedSignal>>],
+    ) -> Result<Vec<FindingBuilder>, Error> {
+        let mut findings = Vec::new();
+        for (span, signal) in trace.spans.iter().zip(span_signals) {
+            let Some(signal) = signal else {
+                continue;
+            };
+            let Some(span_type) = signal.span_type() else {
+                continue;
+            };
+            for event in &span.span_events {
+                if !self.event_names.contains(&event.name) || signal.events().contains(&event.name)
+                {
+                    continue;
+                }
+                findings.push(
+                    FindingBuilder::new(UNEXPECTED_SPAN_EVENT_ADVICE_TYPE)
+                        .context(json!({
+                            SPAN_NAME_ADVICE_CONTEXT_KEY: span.name,
+                            SPAN_ID_ADVICE_CONTEXT_KEY: span.span_id,
+                            SPAN_TYPE_ADVICE_CONTEXT_KEY: span_type,
+                            EVENT_NAME_ADVICE_CONTEXT_KEY: event.name,
+                        }))
+                        .message(format!(
+                            "Event '{}' recorded on span '{}' is not declared on span type '{}'.",
+                            event.name, span.name, span_type
+                        ))
+                        .level(FindingLevel::Improvement),
+                );
+            }
+        }
+        Ok(findings)
+    }
+}
@@ -11,19 +11,29 @@ use weaver_semconv::attribute::{
     AttributeType, BasicRequirementLevelSpec, PrimitiveOrArrayTypeSpec, RequirementLevel,
     TemplateTypeSpec,
 };
+use weaver_semconv::unit::Unit;
 
 use super::{emit_findings, Advisor, FindingBuilder};
 use crate::{
     otlp_logger::OtlpEmitter, sample_attribute::SampleAttribute, sample_metric::SampleInstrument,
     Error, Sample, SampleRef, VersionedAttribute, VersionedSignal,
     ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY, ATTRIBUTE_TYPE_ADVICE_CONTEXT_KEY,
-    EXPECTED_VALUE_ADVICE_CONTEXT_KEY, INSTRUMENT_ADVICE_CONTEXT_KEY, TYPE_MISMATCH_ADVICE_TYPE,
-    UNEXPECTED_INSTRUMENT_ADVICE_TYPE, UNIT_ADVICE_CONTEXT_KEY, UNIT_MISMATCH_ADVICE_TYPE,
+    EXPECTED_VALUE_ADVICE_CONTEXT_KEY, INSTRUMENT_ADVICE_CONTEXT_KEY,
+    SCALE_FACTOR_ADVICE_CONTEXT_KEY, TYPE_MISMATCH_ADVICE_TYPE, UNEXPECTED_INSTRUMENT_ADVICE_TYPE,
+    UNIT_ADVICE_CONTEXT_KEY, UNIT_CONVERTIBLE_ADVICE_TYPE, UNIT_EQUIVALENT_ADVICE_TYPE,
+    UNIT_MISMATCH_ADVICE_TYPE,
 };
 
 /// An advisor that checks if a sample has the correct type
 pub struct TypeAdvisor;
 
+/// Returns the factor to multiply values in `unit` by to express them in `expected_unit`,
+/// when both are valid UCUM units measuring the same thing.
+pub(super) fn unit_scale_factor(unit: &str, expected_unit: &str) -> Option<f64> {
+    let unit = Unit::parse(unit).ok()?;
+    unit.conversion_factor(&Unit::parse(expected_unit).ok()?)
+}
+
 /// Trait to abstract over different attribute types for checking
 trait CheckableAttribute {
     fn key(&self) -> &str;
@@ -93,7 +103,7 @@ fn check_attributes<T: CheckableAttribute>(
     // Create a HashSet of attribute names for O(1) lookups
     let attribute_set: HashSet<_> = sample_attributes
         .iter()
-        .map(|attr| attr.name.as_str())
+        .map(|attr| attr.registry_name())
         .collect();
 
     let mut advice_list = Vec::new();
@@ -110,7 +120,7 @@ fn check_attributes<T: CheckableAttribute>(
         let is_present = if is_template {
             sample_attributes
                 .iter()
-                .any(|attr| attr.name.starts_with(key))
+                .any(|attr| attr.registry_name().starts_with(key))
         } else {
             attribute_set.contains(key)
         };
@@ -280,15 +290,36 @@ impl Advisor for TypeAdvisor {
                     if let Some(semconv_unit) = semconv_metric.unit() {
                         if semconv_unit != &sample_metric.unit {
                             let unit = &sample_metric.unit;
-                            let finding = FindingBuilder::new(UNIT_MISMATCH_ADVICE_TYPE)
-                                .context(json!({
-                                    UNIT_ADVICE_CONTEXT_KEY: unit,
-                                    EXPECTED_VALUE_ADVICE_CONTEXT_KEY: semconv_unit,
-                                }))
-                                .message(format!(
-                                    "Unit should be '{semconv_unit}', but found '{unit}'."
-                                ))
-                                .level(FindingLevel::Violation)
+                            let scale_factor = unit_scale_factor(unit, semconv_unit);
+                            let (advice_type, message, level) = match scale_factor {
+                                // Same unit, spelled differently (e.g. `Hz` and `1/s`).
+                                Some(1.0) => (
+                                    UNIT_EQUIVALENT_ADVICE_TYPE,
+                                    format!("Unit '{unit}' is equivalent to '{semconv_unit}', but should be written '{semconv_unit}'."),
+                                    FindingLevel::Improvement,
+                                ),
+                                Some(scale_factor) => (
+                                    UNIT_CONVERTIBLE_ADVICE_TYPE,
+                                    format!("Unit should be '{semconv_unit}', but found '{unit}'. Values can be converted by multiplying them by {scale_factor}."),
+                                    FindingLevel::Violation,
+                                ),
+                                None => (
+                                    UNIT_MISMATCH_ADVICE_TYPE,
+                                    format!("Unit should be '{semconv_unit}', but found '{unit}'."),
+                                    FindingLevel::Violation,
+                                ),
+                            };
+                            let mut context = json!({
+                                UNIT_ADVICE_CONTEXT_KEY: unit,
+                                EXPECTED_VALUE_ADVICE_CONTEXT_KEY: semconv_unit,
+                            });
+                            if let Some(scale_factor) = scale_factor {
+                                context[SCALE_FACTOR_ADVICE_CONTEXT_KEY] = json!(scale_factor);
+                            }
+                            let finding = FindingBuilder::new(advice_type)
+                                .context(context)
+                                .message(message)
+                                .level(level)
                                 .signal(parent_signal)
                                 .build_and_emit(&sample, otlp_emitter.as_deref(), parent_signal);
 
@@ -414,6 +445,7 @@ mod tests {
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "test attribute".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             stability: None,
             deprecated: None,
@@ -480,8 +512,10 @@ mod tests {
             unit: "".to_owned(),
             data_points: None,
             instrument: SampleInstrument::Supported(weaver_semconv::group::InstrumentSpec::Counter),
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
 
         let advice = check_attributes(&semconv_attributes, &sample_attributes, &sample);
@@ -549,8 +583,10 @@ mod tests {
             unit: "".to_owned(),
             data_points: None,
             instrument: SampleInstrument::Supported(weaver_semconv::group::InstrumentSpec::Counter),
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
         let advice = check_attributes(&semconv_attributes, &sample_attributes, &sample);
         assert!(advice.is_empty());
@@ -567,6 +603,7 @@ mod tests {
             r#type: AttributeType::Template(TemplateTypeSpec::Any),
             brief: "Template attribute for context".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             stability: None,
             deprecated: None,
@@ -592,8 +629,10 @@ mod tests {
             unit: "".to_owned(),
             data_points: None,
             instrument: SampleInstrument::Supported(weaver_semconv::group::InstrumentSpec::Counter),
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
 
         let advice = check_attributes(&semconv_attributes, &sample_attributes_with_match, &sample);
@@ -633,6 +672,7 @@ mod tests {
                 r#type: AttributeType::Template(TemplateTypeSpec::String),
                 brief: "Template attribute".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 stability: None,
                 deprecated: None,
@@ -658,4 +698,15 @@ mod tests {
             "Expected no advice when both template and regular attributes are present"
         );
     }
+
+    #[test]
+    fn test_unit_scale_factor() {
+        assert_eq!(unit_scale_factor("ms", "s"), Some(0.001));
+        assert_eq!(unit_scale_factor("KiBy", "By"), Some(1024.0));
+        assert_eq!(unit_scale_factor("1/s", "Hz"), Some(1.0));
+        // Incompatible dimensions
+        assert_eq!(unit_scale_factor("By", "s"), None);
+        // Not a valid UCUM unit
+        assert_eq!(unit_scale_factor("seconds", "s"), None);
+    }
 }
@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tracking of the number of distinct attribute values (cardinality) of metrics.
+//!
+//! The distinct values are counted with HyperLogLog sketches, so the memory used
+//! per attribute is bounded whatever the number of values seen in a session.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use serde_json::{json, Value};
+use weaver_checker::FindingLevel;
+
+use crate::{
+    advice::FindingBuilder, sample_attribute::SampleAttribute, LiveCheckStatistics,
+    ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY, CARDINALITY_ADVICE_CONTEXT_KEY,
+    HIGH_CARDINALITY_ADVICE_TYPE, METRIC_NAME_ADVICE_CONTEXT_KEY, THRESHOLD_ADVICE_CONTEXT_KEY,
+};
+
+/// The default number of distinct values above which a metric attribute is
+/// reported as high cardinality
+pub const DEFAULT_CARDINALITY_THRESHOLD: u64 = 100;
+
+/// Number of bits of the hash used to select a register
+const PRECISION: u32 = 12;
+/// Number of registers (4 KiB per sketch, ~1.6% standard error)
+const REGISTERS: usize = 1 << PRECISION;
+
+/// A HyperLogLog sketch estimating the number of distinct values added to it
+///
+/// The harmonic sum of the registers and the number of empty registers are kept up
+/// to date on insertion, so an estimate does not scan the registers.
+#[derive(Debug, Clone)]
+pub(crate) struct HyperLogLog {
+    registers: Box<[u8]>,
+    /// Sum of 2^-rank over the registers
+    sum: f64,
+    /// Number of registers with a rank of 0
+    zeros: usize,
+}
+
+impl HyperLogLog {
+    pub(crate) fn new() -> Self {
+        Self {
+            registers: vec![0; REGISTERS].into_boxed_slice(),
+            sum: REGISTERS as f64,
+            zeros: REGISTERS,
+        }
+    }
+
+    /// Add a value to the sketch.
+    pub(crate) fn insert(&mut self, value: &impl Hash) {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // Position of the first set bit in the remaining bits, capped when they are all 0
+        let rank = ((hash << PRECISION).leading_zeros() + 1).min(64 - PRECISION + 1) as u8;
+        let previous = self.registers[index];
+        if rank > previous {
+            self.registers[index] = rank;
+            self.sum += 2f64.powi(-i32::from(rank)) - 2f64.powi(-i32::from(previous));
+            if previous == 0 {
+                self.zeros -= 1;
+            }
+        }
+    }
+
+    /// Estimated number of distinct values added to the sketch.
+    pub(crate) fn estimate(&self) -> u64 {
+        let m = REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let estimate = alpha * m * m / self.sum;
+        if estimate <= 2.5 * m && self.zeros > 0 {
+            // Linear counting is more accurate for small cardinalities
+            (m * (m / self.zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+/// Tracks the distinct values of the attributes of metrics, per metric and per
+/// attribute across all metrics, over a live-check session
+///
+/// The clones of a tracker share the values seen, so the cardinality is tracked
+/// for the whole session when samples are checked in parallel.
+#[derive(Debug, Clone)]
+pub struct CardinalityTracker {
+    threshold: u64,
+    state: Arc<Mutex<TrackerState>>,
+}
+
+/// The sketches of a tracker, and the metric attributes already reported
+#[derive(Debug, Default)]
+struct TrackerState {
+    by_metric: HashMap<(String, String), HyperLogLog>,
+    by_attribute: HashMap<String, HyperLogLog>,
+    reported: HashSet<(String, String)>,
+}
+
+impl Default for CardinalityTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_CARDINALITY_THRESHOLD)
+    }
+}
+
+impl CardinalityTracker {
+    /// Create a new tracker reporting the metric attributes with more than
+    /// `threshold` distinct values
+    #[must_use]
+    pub fn new(threshold: u64) -> Self {
+        Self {
+            threshold,
+            state: Arc::new(Mutex::new(TrackerState::default())),
+        }
+    }
+
+    /// Add the attribute values of a metric data point, update the statistics, and
+    /// return a finding for each attribute crossing the threshold. An attribute is
+    /// only reported once per metric.
+    pub(crate) fn track(
+        &mut self,
+        metric_name: &str,
+        attributes: &[SampleAttribute],
+        stats: &mut LiveCheckStatistics,
+    ) -> Vec<FindingBuilder> {
+        let mut findings = Vec::new();
+        let mut state = self
+            .state
+            .lock()
+            .expect("Failed to acquire lock on cardinality tracker");
+        for attribute in attributes {
+            let Some(value) = attribute.value.as_ref() else {
+                continue;
+            };
+            let attribute_name = attribute.registry_name();
+            let value = hashable_value(value);
+            let key = (metric_name.to_owned(), attribute_name.to_owned());
+
+            let metric_sketch = state
+                .by_metric
+                .entry(key.clone())
+                .or_insert_with(HyperLogLog::new);
+            metric_sketch.insert(&value);
+            let cardinality = metric_sketch.estimate();
+            let attribute_sketch = state
+                .by_attribute
+                .entry(attribute_name.to_owned())
+                .or_insert_with(HyperLogLog::new);
+            attribute_sketch.insert(&value);
+            stats.set_cardinality(
+                metric_name,
+                attribute_name,
+                cardinality,
+                attribute_sketch.estimate(),
+            );
+
+            if cardinality > self.threshold && state.reported.insert(key) {
+                findings.push(
+                    FindingBuilder::new(HIGH_CARDINALITY_ADVICE_TYPE)
+                        .context(json!({
+                            METRIC_NAME_ADVICE_CONTEXT_KEY: metric_name,
+                            ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY: attribute_name,
+                            CARDINALITY_ADVICE_CONTEXT_KEY: cardinality,
+                            THRESHOLD_ADVICE_CONTEXT_KEY: self.threshold,
+                        }))
+                        .message(format!(
+                            "Attribute '{attribute_name}' of metric '{metric_name}' has more than {} distinct values (about {cardinality}).",
+                            self.threshold
+                        ))
+                        .level(FindingLevel::Improvement),
+                );
+            }
+        }
+        findings
+    }
+}
+
+/// The string form of a value, used to hash it
+fn hashable_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CumulativeStatistics, VersionedRegistry};
+    use weaver_forge::registry::ResolvedRegistry;
+
+    #[test]
+    fn test_hyperloglog_estimate() {
+        let mut sketch = HyperLogLog::new();
+        assert_eq!(sketch.estimate(), 0);
+        for i in 0..10 {
+            // Duplicates are not counted
+            sketch.insert(&i);
+            sketch.insert(&i);
+        }
+        assert_eq!(sketch.estimate(), 10);
+
+        for i in 0..100_000 {
+            sketch.insert(&format!("user-{i}"));
+        }
+        let estimate = sketch.estimate() as f64;
+        assert!(
+            (estimate - 100_010.0).abs() / 100_010.0 < 0.05,
+            "{estimate}"
+        );
+
+        // The incremental sum and zero count match the registers
+        let sum: f64 = sketch
+            .registers
+            .iter()
+            .map(|&rank| 2f64.powi(-i32::from(rank)))
+            .sum();
+        assert!((sketch.sum - sum).abs() < 1e-9);
+        assert_eq!(
+            sketch.zeros,
+            sketch.registers.iter().filter(|&&rank| rank == 0).count()
+        );
+    }
+
+    #[test]
+    fn test_track() {
+        let registry = VersionedRegistry::V1(Box::new(ResolvedRegistry {
+            groups: vec![],
+            registry_url: String::new(),
+        }));
+        let mut stats = LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&registry));
+        let mut tracker = CardinalityTracker::new(3);
+        let attributes = |i: usize| {
+            vec![
+                SampleAttribute::try_from(format!("user.id={i}").as_str()).unwrap(),
+                SampleAttribute::try_from("http.request.method=GET").unwrap(),
+            ]
+        };
+
+        let mut findings = Vec::new();
+        for i in 0..10 {
+            findings.extend(tracker.track("requests", &attributes(i), &mut stats));
+        }
+        // Reported once, when the threshold is crossed
+        assert_eq!(findings.len(), 1);
+        let finding = findings.pop().unwrap().build();
+        assert_eq!(finding.id, HIGH_CARDINALITY_ADVICE_TYPE);
+        assert_eq!(
+            finding.context.unwrap()[ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY],
+            "user.id"
+        );
+
+        // Clones share the values seen and the attributes already reported
+        assert!(tracker
+            .clone()
+            .track("requests", &attributes(10), &mut stats)
+            .is_empty());
+
+        // The same attribute on another metric is tracked separately
+        assert!(tracker
+            .track("errors", &attributes(0), &mut stats)
+            .is_empty());
+
+        let LiveCheckStatistics::Cumulative(stats) = stats else {
+            panic!("Expected Cumulative statistics");
+        };
+        assert_eq!(
+            stats.metric_attribute_cardinality["requests"]["user.id"],
+            11
+        );
+        assert_eq!(stats.metric_attribute_cardinality["errors"]["user.id"], 1);
+        assert_eq!(
+            stats.metric_attribute_cardinality["requests"]["http.request.method"],
+            1
+        );
+        assert_eq!(stats.attribute_cardinality["user.id"], 11);
+    }
+}
@@ -79,13 +79,26 @@ impl FindingModifier {
     /// `exclude_samples` filters to suppress findings by sample name (e.g.
     /// attribute key for attribute samples).
     ///
+    /// `scope_name` is the name of the instrumentation scope of the signal the
+    /// sample belongs to, if known.
+    ///
     /// A global filter (no `signal_type`) applies to all findings; a scoped
     /// filter applies only when its `signal_type` matches the finding's
-    /// `signal_type`.
+    /// `signal_type`. Likewise, a filter with a `scope_name` applies only to
+    /// findings from that instrumentation scope.
     #[must_use]
-    pub fn apply(&self, finding: PolicyFinding, sample: &SampleRef<'_>) -> Option<PolicyFinding> {
+    pub fn apply(
+        &self,
+        finding: PolicyFinding,
+        sample: &SampleRef<'_>,
+        scope_name: Option<&str>,
+    ) -> Option<PolicyFinding> {
         for filter in &self.filters {
             if scope_matches(filter.signal_type.as_ref(), finding.signal_type.as_ref())
+                && filter
+                    .scope_name
+                    .as_deref()
+                    .map_or(true, |name| scope_name == Some(name))
                 && is_excluded_by(&finding, filter, sample)
             {
                 return None;
@@ -136,6 +149,7 @@ mod tests {
                 exclude: Some(vec!["deprecated".to_owned()]),
                 min_level: None,
                 signal_type: None,
+                scope_name: None,
                 exclude_samples: vec![],
             }],
             ..Default::default()
@@ -145,10 +159,10 @@ mod tests {
         let sample = SampleRef::Attribute(&attr);
 
         let finding = make_finding("deprecated", FindingLevel::Violation, None);
-        assert!(modifier.apply(finding, &sample).is_none());
+        assert!(modifier.apply(finding, &sample, None).is_none());
 
         let finding = make_finding("not_stable", FindingLevel::Violation, None);
-        assert!(modifier.apply(finding, &sample).is_some());
+        assert!(modifier.apply(finding, &sample, None).is_some());
     }
 
     #[test]
@@ -158,6 +172,7 @@ mod tests {
                 exclude: None,
                 min_level: Some(FindingLevel::Improvement),
                 signal_type: None,
+                scope_name: None,
                 exclude_samples: vec![],
             }],
             ..Default::default()
@@ -167,13 +182,13 @@ mod tests {
         let sample = SampleRef::Attribute(&attr);
 
         let finding = make_finding("foo", FindingLevel::Information, None);
-        assert!(modifier.apply(finding, &sample).is_none());
+        assert!(modifier.apply(finding, &sample, None).is_none());
 
         let finding = make_finding("foo", FindingLevel::Improvement, None);
-        assert!(modifier.apply(finding, &sample).is_some());
+        assert!(modifier.apply(finding, &sample, None).is_some());
 
         let finding = make_finding("foo", FindingLevel::Violation, None);
-        assert!(modifier.apply(finding, &sample).is_some());
+        assert!(modifier.apply(finding, &sample, None).is_some());
     }
 
     #[test]
@@ -183,6 +198,7 @@ mod tests {
                 exclude: Some(vec!["not_stable".to_owned()]),
                 min_level: None,
                 signal_type: Some("span".to_owned()),
+                scope_name: None,
                 exclude_samples: vec![],
             }],
             ..Default::default()
@@ -192,10 +208,10 @@ mod tests {
         let sample = SampleRef::Attribute(&attr);
 
         let finding = make_finding("not_stable", FindingLevel::Information, Some("span"));
-        assert!(modifier.apply(finding, &sample).is_none());
+        assert!(modifier.apply(finding, &sample, None).is_none());
 
         let finding = make_finding("not_stable", FindingLevel::Information, Some("metric"));
-        assert!(modifier.apply(finding, &sample).is_some());
+        assert!(modifier.apply(finding, &sample, None).is_some());
     }
 
     #[test]
@@ -205,6 +221,7 @@ mod tests {
                 exclude: None,
                 min_level: None,
                 signal_type: None,
+                scope_name: None,
                 exclude_samples: vec!["trace.parent_id".to_owned(), "trace.span_id".to_owned()],
             }],
             ..Default::default()
@@ -215,13 +232,13 @@ mod tests {
         let attr = make_attribute("trace.parent_id");
         let sample = SampleRef::Attribute(&attr);
         let finding = make_finding("missing_attribute", FindingLevel::Violation, Some("span"));
-        assert!(modifier.apply(finding, &sample).is_none());
+        assert!(modifier.apply(finding, &sample, None).is_none());
 
         // Non-matching attribute name — kept
         let attr = make_attribute("http.method");
         let sample = SampleRef::Attribute(&attr);
         let finding = make_finding("missing_attribute", FindingLevel::Violation, Some("span"));
-        assert!(modifier.apply(finding, &sample).is_some());
+        assert!(modifier.apply(finding, &sample, None).is_some());
     }
 
     #[test]
@@ -231,6 +248,7 @@ mod tests {
                 exclude: None,
                 min_level: None,
                 signal_type: Some("span".to_owned()),
+                scope_name: None,
                 exclude_samples: vec!["trace.parent_id".to_owned()],
             }],
             ..Default::default()
@@ -241,10 +259,41 @@ mod tests {
 
         // Matching signal_type + attribute — excluded
         let finding = make_finding("missing_attribute", FindingLevel::Violation, Some("span"));
-        assert!(modifier.apply(finding, &sample).is_none());
+        assert!(modifier.apply(finding, &sample, None).is_none());
 
         // Non-matching signal_type — kept even though attribute matches
         let finding = make_finding("missing_attribute", FindingLevel::Violation, Some("metric"));
-        assert!(modifier.apply(finding, &sample).is_some());
+        assert!(modifier.apply(finding, &sample, None).is_some());
+    }
+
+    #[test]
+    fn test_scope_name_filter() {
+        let config = LiveCheckConfig {
+            finding_filters: vec![FindingFilter {
+                exclude: None,
+                min_level: Some(FindingLevel::Violation),
+                signal_type: None,
+                scope_name: Some("io.opentelemetry.jdbc".to_owned()),
+                exclude_samples: vec![],
+            }],
+            ..Default::default()
+        };
+        let modifier = FindingModifier::from_config(&config).expect("modifier");
+        let attr = make_attribute("db.statement");
+        let sample = SampleRef::Attribute(&attr);
+
+        // Matching scope — excluded
+        let finding = make_finding("deprecated", FindingLevel::Improvement, Some("span"));
+        assert!(modifier
+            .apply(finding, &sample, Some("io.opentelemetry.jdbc"))
+            .is_none());
+
+        // Other or unknown scope — kept
+        let finding = make_finding("deprecated", FindingLevel::Improvement, Some("span"));
+        assert!(modifier
+            .apply(finding, &sample, Some("io.opentelemetry.netty"))
+            .is_some());
+        let finding = make_finding("deprecated", FindingLevel::Improvement, Some("span"));
+        assert!(modifier.apply(finding, &sample, None).is_some());
     }
 }
@@ -14,7 +14,9 @@ use sample_metric::{
     SampleNumberDataPoint,
 };
 use sample_resource::SampleResource;
+use sample_scope::SampleScope;
 use sample_span::{SampleSpan, SampleSpanEvent, SampleSpanLink};
+use sample_trace::SampleTrace;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use weaver_checker::{FindingLevel, PolicyFinding};
@@ -24,11 +26,17 @@ use weaver_forge::{
     v2::registry::ForgeResolvedRegistry,
 };
 use weaver_semconv::{
-    attribute::AttributeType, deprecated::Deprecated, group::InstrumentSpec, stability::Stability,
+    any_value::AnyValueSpec,
+    attribute::{AttributeType, ValueConstraints},
+    deprecated::Deprecated,
+    group::{GroupType, InstrumentSpec},
+    stability::Stability,
 };
 
 /// Advisors for live checks
 pub mod advice;
+/// Cardinality tracking of metric attributes
+pub mod cardinality;
 /// Finding modifier engine (overrides and filters).
 pub mod finding_modifier;
 /// An ingester that reads samples from a JSON file.
@@ -39,6 +47,8 @@ pub mod json_stdin_ingester;
 pub mod live_checker;
 /// OTLP logger for emitting policy findings as log records
 pub mod otlp_logger;
+/// An ingester that reads metrics from a Prometheus or OpenMetrics exposition.
+pub mod prometheus_ingester;
 /// The intermediary format for attributes
 pub mod sample_attribute;
 /// The intermediary format for logs
@@ -47,17 +57,26 @@ pub mod sample_log;
 pub mod sample_metric;
 /// An intermediary format for resources
 pub mod sample_resource;
+pub mod sample_scope;
 /// The intermediary format for spans
 pub mod sample_span;
+/// The intermediary format for traces assembled from spans
+pub mod sample_trace;
+/// Renamings of telemetry between semantic convention versions
+pub mod schema_history;
 /// Statistics tracking for live check reports
 mod stats;
 /// An ingester that reads attribute names from a text file.
 pub mod text_file_ingester;
 /// An ingester that reads attribute names from standard input.
 pub mod text_stdin_ingester;
+/// Upgrade of samples to a later semantic conventions version
+pub mod upgrade;
 
 // Re-export statistics types from stats module
-pub use stats::{CumulativeStatistics, DisabledStatistics, LiveCheckStatistics};
+pub use stats::{
+    CumulativeStatistics, DisabledStatistics, LiveCheckStatistics, ThroughputStatistics,
+};
 
 /// Missing Attribute advice type
 pub const MISSING_ATTRIBUTE_ADVICE_TYPE: &str = "missing_attribute";
@@ -75,10 +94,42 @@ pub const TYPE_MISMATCH_ADVICE_TYPE: &str = "type_mismatch";
 pub const NOT_STABLE_ADVICE_TYPE: &str = "not_stable";
 /// Unit mismatch advice type
 pub const UNIT_MISMATCH_ADVICE_TYPE: &str = "unit_mismatch";
+/// Convertible unit advice type: the unit measures the same quantity in another scale
+pub const UNIT_CONVERTIBLE_ADVICE_TYPE: &str = "unit_convertible";
+/// Equivalent unit advice type: the unit is the same, spelled differently
+pub const UNIT_EQUIVALENT_ADVICE_TYPE: &str = "unit_equivalent";
 /// Instrument mismatch advice type
 pub const UNEXPECTED_INSTRUMENT_ADVICE_TYPE: &str = "unexpected_instrument";
 /// Undefined enum variant advice type
 pub const UNDEFINED_ENUM_VARIANT_ADVICE_TYPE: &str = "undefined_enum_variant";
+/// Outdated semconv version advice type
+pub const OUTDATED_SEMCONV_VERSION_ADVICE_TYPE: &str = "outdated_semconv_version";
+/// Span name mismatch advice type
+pub const SPAN_NAME_MISMATCH_ADVICE_TYPE: &str = "span_name_mismatch";
+/// Constraint violation advice type
+pub const CONSTRAINT_VIOLATION_ADVICE_TYPE: &str = "constraint_violation";
+/// Missing body field advice type
+pub const MISSING_BODY_FIELD_ADVICE_TYPE: &str = "missing_body_field";
+/// Body type mismatch advice type
+pub const BODY_TYPE_MISMATCH_ADVICE_TYPE: &str = "body_type_mismatch";
+/// Missing entity identity advice type
+pub const MISSING_ENTITY_IDENTITY_ADVICE_TYPE: &str = "missing_entity_identity";
+/// Unidentified entity attribute advice type
+pub const UNIDENTIFIED_ENTITY_ATTRIBUTE_ADVICE_TYPE: &str = "unidentified_entity_attribute";
+/// Missing entity advice type
+pub const MISSING_ENTITY_ADVICE_TYPE: &str = "missing_entity";
+/// High cardinality advice type
+pub const HIGH_CARDINALITY_ADVICE_TYPE: &str = "high_cardinality";
+/// Histogram bucket boundaries mismatch advice type
+pub const BUCKET_BOUNDARIES_MISMATCH_ADVICE_TYPE: &str = "bucket_boundaries_mismatch";
+/// Histogram bucket scale mismatch advice type
+pub const BUCKET_SCALE_MISMATCH_ADVICE_TYPE: &str = "bucket_scale_mismatch";
+/// Unexpected span event advice type
+pub const UNEXPECTED_SPAN_EVENT_ADVICE_TYPE: &str = "unexpected_span_event";
+/// Missing child span advice type
+pub const MISSING_CHILD_SPAN_ADVICE_TYPE: &str = "missing_child_span";
+/// Orphaned span advice type
+pub const ORPHANED_SPAN_ADVICE_TYPE: &str = "orphaned_span";
 
 /// Attribute name key in advice context
 pub const ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY: &str = "attribute_name";
@@ -96,12 +147,44 @@ pub const STABILITY_ADVICE_CONTEXT_KEY: &str = "stability";
 pub const UNIT_ADVICE_CONTEXT_KEY: &str = "unit";
 /// Instrument key in advice context
 pub const INSTRUMENT_ADVICE_CONTEXT_KEY: &str = "instrument";
+/// Scale factor key in advice context (converts a sample unit to the expected unit)
+pub const SCALE_FACTOR_ADVICE_CONTEXT_KEY: &str = "scale_factor";
 /// Expected value key in advice context
 pub const EXPECTED_VALUE_ADVICE_CONTEXT_KEY: &str = "expected";
 /// Event name key in advice context
 pub const EVENT_NAME_ADVICE_CONTEXT_KEY: &str = "event_name";
 /// Metric name key in advice context
 pub const METRIC_NAME_ADVICE_CONTEXT_KEY: &str = "metric_name";
+/// Renamed to key in advice context
+pub const RENAMED_TO_ADVICE_CONTEXT_KEY: &str = "renamed_to";
+/// Schema URL key in advice context
+pub const SCHEMA_URL_ADVICE_CONTEXT_KEY: &str = "schema_url";
+/// Span name key in advice context
+pub const SPAN_NAME_ADVICE_CONTEXT_KEY: &str = "span_name";
+/// Span type key in advice context
+pub const SPAN_TYPE_ADVICE_CONTEXT_KEY: &str = "span_type";
+/// Constraint key in advice context
+pub const CONSTRAINT_ADVICE_CONTEXT_KEY: &str = "constraint";
+/// Body path advice context key
+pub const BODY_PATH_ADVICE_CONTEXT_KEY: &str = "body_path";
+/// Body value advice context key
+pub const BODY_VALUE_ADVICE_CONTEXT_KEY: &str = "body_value";
+/// Entity type advice context key
+pub const ENTITY_TYPE_ADVICE_CONTEXT_KEY: &str = "entity_type";
+/// Entity associations advice context key
+pub const ENTITY_ASSOCIATIONS_ADVICE_CONTEXT_KEY: &str = "entity_associations";
+/// Cardinality advice context key
+pub const CARDINALITY_ADVICE_CONTEXT_KEY: &str = "cardinality";
+/// Threshold advice context key
+pub const THRESHOLD_ADVICE_CONTEXT_KEY: &str = "threshold";
+/// Explicit bounds advice context key
+pub const EXPLICIT_BOUNDS_ADVICE_CONTEXT_KEY: &str = "explicit_bounds";
+/// Span ID advice context key
+pub const SPAN_ID_ADVICE_CONTEXT_KEY: &str = "span_id";
+/// Parent span ID advice context key
+pub const PARENT_SPAN_ID_ADVICE_CONTEXT_KEY: &str = "parent_span_id";
+/// Span kind advice context key
+pub const SPAN_KIND_ADVICE_CONTEXT_KEY: &str = "span_kind";
 
 /// Embedded default live check rego policies
 pub const DEFAULT_LIVE_CHECK_REGO: &str =
@@ -153,6 +236,15 @@ impl VersionedAttribute {
         }
     }
 
+    /// Get the value constraints of the attribute
+    #[must_use]
+    pub fn constraints(&self) -> Option<&ValueConstraints> {
+        match self {
+            VersionedAttribute::V1(attr) => attr.constraints.as_ref(),
+            VersionedAttribute::V2(attr) => attr.constraints.as_ref(),
+        }
+    }
+
     /// Get the deprecated field of the attribute
     #[must_use]
     pub fn deprecated(&self) -> &Option<Deprecated> {
@@ -230,6 +322,61 @@ impl VersionedSignal {
             VersionedSignal::Event(_) => None,
         }
     }
+
+    /// Get the types of the entities the signal is associated with
+    #[must_use]
+    pub fn entity_associations(&self) -> &[String] {
+        match self {
+            VersionedSignal::Group(group) => &group.as_ref().entity_associations,
+            VersionedSignal::Metric(metric) => &metric.entity_associations,
+            VersionedSignal::Span(span) => &span.entity_associations,
+            VersionedSignal::Event(event) => &event.entity_associations,
+        }
+    }
+
+    /// Get the advised explicit bucket boundaries of the signal, if applicable
+    #[must_use]
+    pub fn explicit_bucket_boundaries(&self) -> Option<&[f64]> {
+        let advice = match self {
+            VersionedSignal::Group(group) => group.as_ref().advice.as_ref(),
+            VersionedSignal::Metric(metric) => metric.advice.as_ref(),
+            VersionedSignal::Span(_) => None,
+            VersionedSignal::Event(_) => None,
+        };
+        advice?.explicit_bucket_boundaries.as_deref()
+    }
+
+    /// Get the names of the events that may be recorded on the signal, if it is a span
+    #[must_use]
+    pub fn events(&self) -> &[String] {
+        match self {
+            VersionedSignal::Group(group) => &group.as_ref().events,
+            VersionedSignal::Span(span) => &span.events,
+            VersionedSignal::Metric(_) => &[],
+            VersionedSignal::Event(_) => &[],
+        }
+    }
+
+    /// Get the type of the signal, if it is a span
+    #[must_use]
+    pub fn span_type(&self) -> Option<&str> {
+        match self {
+            VersionedSignal::Group(group) if group.r#type == GroupType::Span => Some(&group.id),
+            VersionedSignal::Span(span) => Some(&*span.r#type),
+            _ => None,
+        }
+    }
+
+    /// Get the body field of the signal, if applicable
+    #[must_use]
+    pub fn body(&self) -> Option<&AnyValueSpec> {
+        match self {
+            VersionedSignal::Group(group) => group.as_ref().body.as_ref(),
+            VersionedSignal::Metric(_) => None,
+            VersionedSignal::Span(_) => None,
+            VersionedSignal::Event(_) => None,
+        }
+    }
 }
 
 /// Weaver live check errors
@@ -299,6 +446,8 @@ pub enum Sample {
     Metric(SampleMetric),
     /// A sample log
     Log(SampleLog),
+    /// A sample trace, assembled from spans sharing a trace ID
+    Trace(SampleTrace),
 }
 
 /// Represents a sample entity with a reference to the inner type.
@@ -328,6 +477,8 @@ pub enum SampleRef<'a> {
     Exemplar(&'a SampleExemplar),
     /// A sample log
     Log(&'a SampleLog),
+    /// A sample trace
+    Trace(&'a SampleTrace),
 }
 
 impl SampleRef<'_> {
@@ -363,6 +514,7 @@ impl SampleRef<'_> {
             SampleRef::ExponentialHistogramDataPoint(_) => "exponential_histogram_data_point",
             SampleRef::Exemplar(_) => "exemplar",
             SampleRef::Log(_) => "log",
+            SampleRef::Trace(_) => "trace",
         }
     }
 }
@@ -380,6 +532,7 @@ impl Sample {
             Sample::Resource(_) => Some("resource".to_owned()),
             Sample::Metric(_) => Some("metric".to_owned()),
             Sample::Log(_) => Some("log".to_owned()),
+            Sample::Trace(_) => Some("trace".to_owned()),
         }
     }
 
@@ -394,6 +547,33 @@ impl Sample {
         }
     }
 
+    /// Returns a reference to the instrumentation scope that produced the signal,
+    /// if available.
+    #[must_use]
+    pub fn scope(&self) -> Option<&SampleScope> {
+        match self {
+            Sample::Span(s) => s.scope.as_deref(),
+            Sample::Metric(m) => m.scope.as_deref(),
+            Sample::Log(l) => l.scope.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the schema URL of the semantic conventions the sample was produced
+    /// with, if known. Signals without their own schema URL use the one of their
+    /// resource.
+    #[must_use]
+    pub fn schema_url(&self) -> Option<&str> {
+        let schema_url = match self {
+            Sample::Span(s) => s.schema_url.as_deref(),
+            Sample::Metric(m) => m.schema_url.as_deref(),
+            Sample::Log(l) => l.schema_url.as_deref(),
+            Sample::Resource(r) => r.schema_url.as_deref(),
+            _ => None,
+        };
+        schema_url.or_else(|| self.resource().and_then(|r| r.schema_url.as_deref()))
+    }
+
     /// Returns the signal name as a string or None if sample
     /// does not capture a whole signal.
     #[must_use]
@@ -406,6 +586,57 @@ impl Sample {
             Sample::Resource(_) => None,
             Sample::Metric(metric) => Some(metric.name.clone()),
             Sample::Log(log) => Some(log.event_name.clone()),
+            Sample::Trace(_) => None,
+        }
+    }
+
+    /// Returns a copy of the sample without its nested samples (attributes, events,
+    /// links, data points, body and spans), to be passed as the parent signal of its
+    /// own checks. The advisors only use the identity, resource, scope and schema URL
+    /// of the parent signal, so the nested samples are not copied.
+    pub fn parent_signal(&mut self) -> Sample {
+        use std::mem::take;
+        match self {
+            Sample::Attribute(attribute) => Sample::Attribute(attribute.clone()),
+            Sample::Span(span) => span.parent_signal(),
+            Sample::SpanEvent(span_event) => {
+                let attributes = take(&mut span_event.attributes);
+                let parent_signal = Sample::SpanEvent(span_event.clone());
+                span_event.attributes = attributes;
+                parent_signal
+            }
+            Sample::SpanLink(span_link) => {
+                let attributes = take(&mut span_link.attributes);
+                let parent_signal = Sample::SpanLink(span_link.clone());
+                span_link.attributes = attributes;
+                parent_signal
+            }
+            Sample::Resource(resource) => {
+                let attributes = take(&mut resource.attributes);
+                let parent_signal = Sample::Resource(resource.clone());
+                resource.attributes = attributes;
+                parent_signal
+            }
+            Sample::Metric(metric) => {
+                let data_points = take(&mut metric.data_points);
+                let parent_signal = Sample::Metric(metric.clone());
+                metric.data_points = data_points;
+                parent_signal
+            }
+            Sample::Log(log) => {
+                let attributes = take(&mut log.attributes);
+                let body = take(&mut log.body);
+                let parent_signal = Sample::Log(log.clone());
+                log.attributes = attributes;
+                log.body = body;
+                parent_signal
+            }
+            Sample::Trace(trace) => {
+                let spans = take(&mut trace.spans);
+                let parent_signal = Sample::Trace(trace.clone());
+                trace.spans = spans;
+                parent_signal
+            }
         }
     }
 }
@@ -441,6 +672,9 @@ impl LiveCheckRunner for Sample {
             Sample::Log(log) => {
                 log.run_live_check(live_checker, stats, parent_group, parent_signal)
             }
+            Sample::Trace(trace) => {
+                trace.run_live_check(live_checker, stats, parent_group, parent_signal)
+            }
         }
     }
 }
@@ -452,6 +686,11 @@ pub struct LiveCheckResult {
     pub all_advice: Vec<PolicyFinding>,
     /// The highest advice level
     pub highest_advice_level: Option<FindingLevel>,
+    /// The name the checked sample has been renamed to since the semconv version of
+    /// its signal, when it has been translated
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub renamed_to: Option<String>,
 }
 
 impl LiveCheckResult {
@@ -461,6 +700,7 @@ impl LiveCheckResult {
         LiveCheckResult {
             all_advice: Vec::new(),
             highest_advice_level: None,
+            renamed_to: None,
         }
     }
 
@@ -470,15 +710,18 @@ impl LiveCheckResult {
     /// (filter exclusion) before being stored.
     ///
     /// `sample` is the sample that produced this finding, used by
-    /// `exclude_samples` filters to inspect and match on it.
+    /// `exclude_samples` filters to inspect and match on it. `parent_signal`
+    /// is the signal it belongs to, used by `scope_name` filters.
     pub fn add_advice(
         &mut self,
         advice: PolicyFinding,
         modifier: Option<&FindingModifier>,
         sample: &SampleRef<'_>,
+        parent_signal: &Sample,
     ) {
         let advice = if let Some(modifier) = modifier {
-            match modifier.apply(advice, sample) {
+            let scope_name = parent_signal.scope().map(|scope| scope.name.as_str());
+            match modifier.apply(advice, sample, scope_name) {
                 Some(kept) => kept,
                 None => return, // Excluded by filter
             }
@@ -502,9 +745,10 @@ impl LiveCheckResult {
         advice: Vec<PolicyFinding>,
         modifier: Option<&FindingModifier>,
         sample: &SampleRef<'_>,
+        parent_signal: &Sample,
     ) {
         for advice in advice {
-            self.add_advice(advice, modifier, sample);
+            self.add_advice(advice, modifier, sample, parent_signal);
         }
     }
 }
@@ -560,6 +804,11 @@ pub trait Advisable {
     /// Get entity type for statistics
     fn entity_type(&self) -> &str;
 
+    /// Get the attributes whose distinct values are tracked for cardinality
+    fn cardinality_attributes(&self) -> &[SampleAttribute] {
+        &[]
+    }
+
     /// Run advisors on this entity
     fn run_advisors(
         &mut self,
@@ -568,7 +817,44 @@ pub trait Advisable {
         parent_group: Option<Rc<VersionedSignal>>,
         parent_signal: &Sample,
     ) -> Result<LiveCheckResult, Error> {
-        let mut result = LiveCheckResult::new();
+        self.run_advisors_with(
+            LiveCheckResult::new(),
+            live_checker,
+            stats,
+            parent_group,
+            parent_signal,
+        )
+    }
+
+    /// Run advisors on this entity, adding their advice to the result of the
+    /// checks the entity runs on its own
+    fn run_advisors_with(
+        &mut self,
+        mut result: LiveCheckResult,
+        live_checker: &mut LiveChecker,
+        stats: &mut LiveCheckStatistics,
+        parent_group: Option<Rc<VersionedSignal>>,
+        parent_signal: &Sample,
+    ) -> Result<LiveCheckResult, Error> {
+        if let Sample::Metric(metric) = parent_signal {
+            let findings =
+                live_checker
+                    .cardinality
+                    .track(&metric.name, self.cardinality_attributes(), stats);
+            for finding in findings {
+                let finding = finding.signal(parent_signal).build_and_emit(
+                    &self.as_sample_ref(),
+                    live_checker.otlp_emitter.as_deref(),
+                    parent_signal,
+                );
+                result.add_advice(
+                    finding,
+                    live_checker.finding_modifier.as_ref(),
+                    &self.as_sample_ref(),
+                    parent_signal,
+                );
+            }
+        }
 
         for advisor in live_checker.advisors.iter_mut() {
             let advice_list = advisor.advise(
@@ -582,11 +868,12 @@ pub trait Advisable {
                 advice_list,
                 live_checker.finding_modifier.as_ref(),
                 &self.as_sample_ref(),
+                parent_signal,
             );
         }
 
         stats.inc_entity_count(self.entity_type());
-        stats.maybe_add_live_check_result(Some(&result));
+        stats.maybe_add_live_check_result(Some(&result), parent_signal.scope());
 
         Ok(result)
     }
@@ -2,15 +2,22 @@
 
 //! Holds the registry, helper structs, and the advisors for the live check
 
+use regex::Regex;
 use serde::Serialize;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::Arc;
 use weaver_semconv::{attribute::AttributeType, group::GroupType};
 
 use crate::{
-    advice::Advisor, finding_modifier::FindingModifier, otlp_logger::OtlpEmitter,
-    VersionedAttribute, VersionedRegistry, VersionedSignal,
+    advice::{Advisor, TraceAdvisor},
+    cardinality::CardinalityTracker,
+    finding_modifier::FindingModifier,
+    otlp_logger::OtlpEmitter,
+    sample_span::SampleSpan,
+    schema_history::SchemaHistory,
+    Sample, VersionedAttribute, VersionedRegistry, VersionedSignal,
 };
 
 #[cfg(test)]
@@ -25,9 +32,16 @@ pub struct LiveChecker {
     semconv_templates: HashMap<String, Rc<VersionedAttribute>>,
     semconv_metrics: HashMap<String, Rc<VersionedSignal>>,
     semconv_events: HashMap<String, Rc<VersionedSignal>>,
+    semconv_spans: Vec<Rc<VersionedSignal>>,
+    /// The name templates of `semconv_spans` compiled into regexes, in the same order
+    #[serde(skip)]
+    span_name_regexes: Vec<Vec<Regex>>,
     /// The advisors to run
     #[serde(skip)]
     pub advisors: Vec<Box<dyn Advisor>>,
+    /// The advisors to run on assembled traces
+    #[serde(skip)]
+    pub trace_advisors: Vec<Box<dyn TraceAdvisor>>,
     #[serde(skip)]
     templates_by_length: Vec<(String, Rc<VersionedAttribute>)>,
     /// Optional OTLP emitter for emitting findings as log records
@@ -36,6 +50,13 @@ pub struct LiveChecker {
     /// Optional finding modifier for overriding/filtering findings
     #[serde(skip)]
     pub finding_modifier: Option<FindingModifier>,
+    /// Optional renamings between semconv versions, used to translate samples
+    /// produced with an older version
+    #[serde(skip)]
+    pub schema_history: Option<SchemaHistory>,
+    /// Distinct values of the metric attributes seen in the session
+    #[serde(skip)]
+    pub cardinality: CardinalityTracker,
 }
 
 impl LiveChecker {
@@ -50,6 +71,9 @@ impl LiveChecker {
         let mut semconv_metrics = HashMap::new();
         // Hashmap of events by name
         let mut semconv_events = HashMap::new();
+        // Spans with a name template, matched by kind and name
+        let mut semconv_spans = Vec::new();
+        let mut span_name_regexes = Vec::new();
 
         match registry.as_ref() {
             VersionedRegistry::V1(registry) => {
@@ -94,6 +118,17 @@ impl LiveChecker {
                     let event_rc = Rc::new(VersionedSignal::Event(event.clone()));
                     let _ = semconv_events.insert(event_name, event_rc);
                 }
+                for span in &registry.registry.spans {
+                    if span.name.templates().next().is_some() {
+                        semconv_spans.push(Rc::new(VersionedSignal::Span(span.clone())));
+                        span_name_regexes.push(
+                            span.name
+                                .templates()
+                                .filter_map(|template| template.to_regex())
+                                .collect(),
+                        );
+                    }
+                }
                 for attribute in &registry.registry.attributes {
                     let attribute_rc = Rc::new(VersionedAttribute::V2(attribute.clone()));
                     match &attribute.r#type {
@@ -118,10 +153,15 @@ impl LiveChecker {
             semconv_templates,
             semconv_metrics,
             semconv_events,
+            semconv_spans,
+            span_name_regexes,
             advisors,
+            trace_advisors: Vec::new(),
             templates_by_length,
             otlp_emitter: None,
             finding_modifier: None,
+            schema_history: None,
+            cardinality: CardinalityTracker::default(),
         }
     }
 
@@ -130,6 +170,11 @@ impl LiveChecker {
         self.advisors.push(advisor);
     }
 
+    /// Add a trace advisor
+    pub fn add_trace_advisor(&mut self, advisor: Box<dyn TraceAdvisor>) {
+        self.trace_advisors.push(advisor);
+    }
+
     /// Find an attribute in the registry
     #[must_use]
     pub fn find_attribute(&self, name: &str) -> Option<Rc<VersionedAttribute>> {
@@ -148,6 +193,88 @@ impl LiveChecker {
         self.semconv_events.get(name).map(Rc::clone)
     }
 
+    /// Find the span a sample span is an instance of, by matching its kind and name
+    /// against the span name templates in the registry.
+    ///
+    /// A span whose applicable template, rendered with the sample's attribute values,
+    /// equals the sample name is preferred. Otherwise the first span with a template
+    /// of the same shape as the sample name is returned along with the name the
+    /// sample was expected to have.
+    #[must_use]
+    pub fn find_span(&self, sample: &SampleSpan) -> Option<(Rc<VersionedSignal>, Option<String>)> {
+        let attribute_value = |key: &str| {
+            sample
+                .attributes
+                .iter()
+                .find(|attribute| attribute.name == key)
+                .and_then(|attribute| attribute.value.as_ref())
+                .map(|value| match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+        };
+        let candidates = self
+            .semconv_spans
+            .iter()
+            .zip(&self.span_name_regexes)
+            .filter_map(|(signal, name_regexes)| match signal.as_ref() {
+                VersionedSignal::Span(span) if span.kind == sample.kind => {
+                    Some((signal, span, name_regexes))
+                }
+                _ => None,
+            });
+
+        let mut shape_match = None;
+        for (signal, span, name_regexes) in candidates {
+            let expected = span
+                .name
+                .applicable_template(|key| attribute_value(key).is_some())
+                .and_then(|template| template.render(attribute_value));
+            if expected.as_deref() == Some(sample.name.as_str()) {
+                return Some((Rc::clone(signal), None));
+            }
+            if shape_match.is_none() && name_regexes.iter().any(|re| re.is_match(&sample.name)) {
+                let expected =
+                    expected.or_else(|| span.name.template.as_ref().map(|t| t.to_string()));
+                shape_match = Some((Rc::clone(signal), expected));
+            }
+        }
+        shape_match
+    }
+
+    /// Find the name an attribute has in the registry when the semconv version of the
+    /// parent signal (given by its schema URL) uses a former name of the attribute
+    #[must_use]
+    pub fn find_renamed_attribute(&self, name: &str, parent_signal: &Sample) -> Option<String> {
+        let upgrade = self
+            .schema_history
+            .as_ref()?
+            .upgrade(parent_signal.schema_url()?)?;
+        let new_name = match parent_signal {
+            Sample::Resource(_) => upgrade.resource_attribute(name),
+            Sample::Metric(metric) => upgrade.metric_attribute(&metric.name, name),
+            Sample::Log(_) => upgrade.log_attribute(name),
+            Sample::Span(span) => upgrade.span_attribute(&span.name, name),
+            _ => upgrade.attribute(name),
+        };
+        (new_name != name
+            && (self.find_attribute(&new_name).is_some()
+                || self.find_template(&new_name).is_some()))
+        .then_some(new_name)
+    }
+
+    /// Find the name a metric has in the registry when the semconv version of the
+    /// sample (given by its schema URL) uses a former name of the metric
+    #[must_use]
+    pub fn find_renamed_metric(&self, name: &str, sample: &Sample) -> Option<String> {
+        let upgrade = self
+            .schema_history
+            .as_ref()?
+            .upgrade(sample.schema_url()?)?;
+        let new_name = upgrade.metric_name(name);
+        (new_name != name && self.find_metric(&new_name).is_some()).then_some(new_name)
+    }
+
     /// Find a template in the registry
     #[must_use]
     pub fn find_template(&self, attribute_name: &str) -> Option<Rc<VersionedAttribute>> {
@@ -166,13 +293,20 @@ mod tests {
     use std::fs::File;
 
     use crate::{
-        advice::{DeprecatedAdvisor, EnumAdvisor, RegoAdvisor, StabilityAdvisor, TypeAdvisor},
+        advice::{
+            ChildSpanAdvisor, DeprecatedAdvisor, EnumAdvisor, OrphanSpanAdvisor, RegoAdvisor,
+            RegoStatistics, SpanEventAdvisor, StabilityAdvisor, TypeAdvisor,
+        },
         sample_attribute::SampleAttribute,
         sample_metric::{
             DataPoints, SampleExemplar, SampleExponentialHistogramDataPoint, SampleInstrument,
             SampleMetric, SampleNumberDataPoint,
         },
-        LiveCheckRunner, LiveCheckStatistics, Sample,
+        sample_resource::SampleResource,
+        LiveCheckRunner, LiveCheckStatistics, Sample, MISSING_CHILD_SPAN_ADVICE_TYPE,
+        ORPHANED_SPAN_ADVICE_TYPE, SCALE_FACTOR_ADVICE_CONTEXT_KEY, SPAN_NAME_MISMATCH_ADVICE_TYPE,
+        UNEXPECTED_SPAN_EVENT_ADVICE_TYPE, UNIT_CONVERTIBLE_ADVICE_TYPE,
+        UNIT_EQUIVALENT_ADVICE_TYPE, UNIT_MISMATCH_ADVICE_TYPE,
     };
 
     use super::*;
@@ -189,7 +323,10 @@ mod tests {
         span::{Span as V2Span, SpanAttribute},
     };
     use weaver_resolved_schema::attribute::Attribute;
-    use weaver_semconv::v2::{span::SpanName, CommonFields};
+    use weaver_semconv::v2::{
+        span::{SpanName, SpanNameTemplate},
+        CommonFields,
+    };
     use weaver_semconv::{
         attribute::{
             AttributeType, BasicRequirementLevelSpec, EnumEntriesSpec, Examples,
@@ -534,6 +671,7 @@ mod tests {
                                 "value1".to_owned(),
                                 "value2".to_owned(),
                             ])),
+                            constraints: None,
                             common: CommonFields {
                                 brief: "".to_owned(),
                                 note: "".to_owned(),
@@ -568,6 +706,7 @@ mod tests {
                                 ],
                             },
                             examples: None,
+                            constraints: None,
                             common: CommonFields {
                                 brief: "".to_owned(),
                                 note: "".to_owned(),
@@ -586,6 +725,7 @@ mod tests {
                                 "value1".to_owned(),
                                 "value2".to_owned(),
                             ])),
+                            constraints: None,
                             common: CommonFields {
                                 brief: "".to_owned(),
                                 note: "".to_owned(),
@@ -606,6 +746,7 @@ mod tests {
                                 "value1".to_owned(),
                                 "value2".to_owned(),
                             ])),
+                            constraints: None,
                             common: CommonFields {
                                 brief: "".to_owned(),
                                 note: "".to_owned(),
@@ -651,6 +792,7 @@ mod tests {
                                 "value1".to_owned(),
                                 "value2".to_owned(),
                             ])),
+                            constraints: None,
                             brief: "".to_owned(),
                             tag: None,
                             requirement_level: RequirementLevel::Recommended {
@@ -691,6 +833,7 @@ mod tests {
                                 ],
                             },
                             examples: None,
+                            constraints: None,
                             brief: "".to_owned(),
                             tag: None,
                             requirement_level: RequirementLevel::Recommended {
@@ -715,6 +858,7 @@ mod tests {
                                 "value1".to_owned(),
                                 "value2".to_owned(),
                             ])),
+                            constraints: None,
                             brief: "".to_owned(),
                             tag: None,
                             requirement_level: RequirementLevel::Recommended {
@@ -741,6 +885,7 @@ mod tests {
                                 "value1".to_owned(),
                                 "value2".to_owned(),
                             ])),
+                            constraints: None,
                             brief: "".to_owned(),
                             tag: None,
                             requirement_level: RequirementLevel::Recommended {
@@ -763,6 +908,7 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -803,6 +949,7 @@ mod tests {
                     "free".to_owned(),
                     "cached".to_owned(),
                 ])),
+                constraints: None,
                 common: CommonFields {
                     brief: "The memory state".to_owned(),
                     note: "".to_owned(),
@@ -826,6 +973,7 @@ mod tests {
                             instrument: InstrumentSpec::Gauge,
                             unit: "s".to_owned(),
                             requirement_level: Some(BasicRequirementLevelSpec::Required),
+                            advice: None,
                             attributes: vec![],
                             entity_associations: vec![],
                             common: CommonFields {
@@ -842,6 +990,7 @@ mod tests {
                             instrument: InstrumentSpec::UpDownCounter,
                             unit: "By".to_owned(),
                             requirement_level: Some(BasicRequirementLevelSpec::Required),
+                            advice: None,
                             attributes: vec![MetricAttribute {
                                 base: memory_state_attr.clone(),
                                 requirement_level: RequirementLevel::Recommended {
@@ -912,6 +1061,7 @@ mod tests {
                                 "free".to_owned(),
                                 "cached".to_owned(),
                             ])),
+                            constraints: None,
                             brief: "The memory state".to_owned(),
                             tag: None,
                             requirement_level: RequirementLevel::Recommended {
@@ -933,6 +1083,7 @@ mod tests {
                         instrument: None,
                         unit: None,
                         metric_requirement_level: None,
+                        advice: None,
                         name: None,
                         lineage: None,
                         display_name: Some("System Memory Attributes".to_owned()),
@@ -957,6 +1108,7 @@ mod tests {
                         instrument: Some(InstrumentSpec::Gauge),
                         unit: Some("s".to_owned()),
                         metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                        advice: None,
                         name: None,
                         lineage: None,
                         display_name: None,
@@ -980,6 +1132,7 @@ mod tests {
                                 PrimitiveOrArrayTypeSpec::String,
                             ),
                             examples: None,
+                            constraints: None,
                             brief: "The memory state".to_owned(),
                             tag: None,
                             requirement_level: RequirementLevel::Recommended {
@@ -1001,6 +1154,7 @@ mod tests {
                         instrument: Some(InstrumentSpec::UpDownCounter),
                         unit: Some("By".to_owned()),
                         metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+                        advice: None,
                         name: None,
                         lineage: None,
                         display_name: None,
@@ -1021,6 +1175,7 @@ mod tests {
                     "value1".to_owned(),
                     "value2".to_owned(),
                 ])),
+                constraints: None,
                 common: CommonFields {
                     brief: "".to_owned(),
                     note: "".to_owned(),
@@ -1044,6 +1199,8 @@ mod tests {
                         kind: SpanKindSpec::Internal,
                         name: SpanName {
                             note: "custom.comprehensive.internal".to_owned(),
+                            template: None,
+                            fallbacks: vec![],
                         },
                         attributes: vec![SpanAttribute {
                             base: custom_string_attr.clone(),
@@ -1053,6 +1210,7 @@ mod tests {
                             sampling_relevant: None,
                         }],
                         entity_associations: vec![],
+                        events: vec![],
                         common: CommonFields {
                             brief: "".to_owned(),
                             note: "".to_owned(),
@@ -1091,6 +1249,7 @@ mod tests {
                             "value1".to_owned(),
                             "value2".to_owned(),
                         ])),
+                        constraints: None,
                         brief: "".to_owned(),
                         tag: None,
                         requirement_level: RequirementLevel::Recommended {
@@ -1112,6 +1271,7 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
@@ -1480,6 +1640,14 @@ mod tests {
         let mut samples: Vec<Sample> =
             serde_json::from_reader(File::open(path).expect("Unable to open file"))
                 .expect("Unable to parse JSON");
+        // A structured body containing the required phrase, and an absent body
+        samples.extend(
+            serde_json::from_value::<Vec<Sample>>(json!([
+                {"log": {"event_name": "session.start", "body": {"greeting": "hello world"}}},
+                {"log": {"event_name": ""}}
+            ]))
+            .expect("Unable to parse JSON"),
+        );
 
         let mut live_checker = LiveChecker::new(Arc::new(registry), vec![]);
         let rego_advisor = RegoAdvisor::new(
@@ -1503,8 +1671,8 @@ mod tests {
         if let LiveCheckStatistics::Cumulative(cumulative_stats) = &stats {
             assert_eq!(
                 cumulative_stats.advice_type_counts.get("empty_body"),
-                Some(&1),
-                "Expected 1 empty_body advice for event with empty name and empty body"
+                Some(&2),
+                "Expected 2 empty_body advice for events with empty name and empty or absent body"
             );
 
             assert_eq!(
@@ -1525,6 +1693,7 @@ mod tests {
                 examples: Some(Examples::Strings(vec![
                     "00112233-4455-6677-8899-aabbccddeeff".to_owned(),
                 ])),
+                constraints: None,
                 common: CommonFields {
                     brief: "A unique session identifier".to_owned(),
                     note: "".to_owned(),
@@ -1541,6 +1710,7 @@ mod tests {
                 examples: Some(Examples::Strings(vec![
                     "00112233-4455-6677-8899-aabbccddeeff".to_owned(),
                 ])),
+                constraints: None,
                 common: CommonFields {
                     brief: "The previous session identifier".to_owned(),
                     note: "".to_owned(),
@@ -1656,6 +1826,7 @@ mod tests {
                                 examples: Some(Examples::Strings(vec![
                                     "00112233-4455-6677-8899-aabbccddeeff".to_owned(),
                                 ])),
+                                constraints: None,
                                 brief: "A unique session identifier".to_owned(),
                                 tag: None,
                                 requirement_level: RequirementLevel::Basic(
@@ -1679,6 +1850,7 @@ mod tests {
                                 examples: Some(Examples::Strings(vec![
                                     "00112233-4455-6677-8899-aabbccddeeff".to_owned(),
                                 ])),
+                                constraints: None,
                                 brief: "The previous session identifier".to_owned(),
                                 tag: None,
                                 requirement_level: RequirementLevel::Recommended {
@@ -1701,6 +1873,7 @@ mod tests {
                         instrument: None,
                         unit: None,
                         metric_requirement_level: None,
+                        advice: None,
                         name: Some("session.start".to_owned()),
                         lineage: None,
                         display_name: Some("Session Start Event".to_owned()),
@@ -1731,6 +1904,7 @@ mod tests {
                         instrument: None,
                         unit: None,
                         metric_requirement_level: None,
+                        advice: None,
                         name: Some("example.event".to_owned()),
                         lineage: None,
                         display_name: Some("Example Event".to_owned()),
@@ -1880,6 +2054,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rego_partitions_and_cache() {
+        let registry = make_custom_rego_registry(false);
+
+        let mut samples = vec![
+            Sample::Attribute(SampleAttribute::try_from("custom.string=hello").unwrap()),
+            Sample::Attribute(SampleAttribute::try_from("custom.string=hello").unwrap()),
+            Sample::Resource(SampleResource {
+                attributes: vec![],
+                schema_url: None,
+                live_check_result: None,
+            }),
+        ];
+
+        let mut live_checker = LiveChecker::new(Arc::new(registry), vec![]);
+        let rego_advisor =
+            RegoAdvisor::new(&live_checker, &None, &None).expect("Failed to create Rego advisor");
+        live_checker.add_advisor(Box::new(rego_advisor.clone()));
+
+        let mut stats =
+            LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry));
+        for sample in &mut samples {
+            let result =
+                sample.run_live_check(&mut live_checker, &mut stats, None, &sample.clone());
+            assert!(result.is_ok());
+        }
+
+        // The second attribute is found in the cache, and the default policies
+        // do not apply to resources
+        assert_eq!(
+            rego_advisor.statistics(),
+            RegoStatistics {
+                evaluations: 1,
+                cache_hits: 1,
+                skipped: 1,
+            }
+        );
+        assert_eq!(samples[0], samples[1]);
+    }
+
+    #[test]
+    fn test_rego_cache_metric_values() {
+        let registry = make_metrics_registry(false);
+
+        // Two exports of the same metric with different values
+        let metric = |value: f64| -> Sample {
+            Sample::Metric(
+                serde_json::from_value(json!({
+                    "name": "system.memory.usage",
+                    "instrument": "updowncounter",
+                    "unit": "By",
+                    "data_points": [{
+                        "attributes": [{"name": "system.memory.state", "value": "used"}],
+                        "value": value
+                    }]
+                }))
+                .expect("Valid metric"),
+            )
+        };
+        let mut samples = vec![metric(26963050496.0), metric(26963066880.0)];
+
+        let mut live_checker = LiveChecker::new(Arc::new(registry), vec![]);
+        let rego_advisor =
+            RegoAdvisor::new(&live_checker, &None, &None).expect("Failed to create Rego advisor");
+        live_checker.add_advisor(Box::new(rego_advisor.clone()));
+
+        let mut stats =
+            LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry));
+        for sample in &mut samples {
+            let parent_signal = sample.parent_signal();
+            let result = sample.run_live_check(&mut live_checker, &mut stats, None, &parent_signal);
+            assert!(result.is_ok());
+        }
+
+        // The metric and its data point attribute of the second export are found
+        // in the cache, and the default policies do not apply to data points
+        assert_eq!(
+            rego_advisor.statistics(),
+            RegoStatistics {
+                evaluations: 2,
+                cache_hits: 2,
+                skipped: 2,
+            }
+        );
+    }
+
     #[test]
     fn test_exponential_histogram() {
         run_exponential_histogram_test(false);
@@ -1915,8 +2175,10 @@ mod tests {
                     exemplars: vec![],
                 },
             ])),
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
         let mut samples = vec![sample];
         let advisors: Vec<Box<dyn Advisor>> = vec![Box::new(TypeAdvisor)];
@@ -1992,8 +2254,10 @@ mod tests {
                     live_check_result: None,
                 }],
             }])),
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         });
         let advisors: Vec<Box<dyn Advisor>> = vec![Box::new(TypeAdvisor)];
         let mut live_checker = LiveChecker::new(Arc::new(registry), advisors);
@@ -2041,16 +2305,20 @@ mod tests {
                 instrument: SampleInstrument::Unsupported("Summary".to_owned()),
                 unit: "By".to_owned(),
                 data_points: None,
+                schema_url: None,
                 live_check_result: None,
                 resource: None,
+                scope: None,
             }),
             Sample::Metric(SampleMetric {
                 name: "system.memory.usage".to_owned(),
                 instrument: SampleInstrument::Unsupported("Unspecified".to_owned()),
                 unit: "By".to_owned(),
                 data_points: None,
+                schema_url: None,
                 live_check_result: None,
                 resource: None,
+                scope: None,
             }),
         ];
         let advisors: Vec<Box<dyn Advisor>> = vec![Box::new(TypeAdvisor)];
@@ -2075,4 +2343,518 @@ mod tests {
             panic!("Expected Cumulative statistics");
         }
     }
+
+    #[test]
+    fn test_unit_mismatch() {
+        run_unit_mismatch_test(false);
+    }
+
+    #[test]
+    fn test_unit_mismatch_v2() {
+        run_unit_mismatch_test(true);
+    }
+
+    fn run_unit_mismatch_test(use_v2: bool) {
+        let registry = make_metrics_registry(use_v2);
+        let advisors: Vec<Box<dyn Advisor>> = vec![Box::new(TypeAdvisor)];
+        let mut live_checker = LiveChecker::new(Arc::new(registry), advisors);
+        let mut stats =
+            LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry));
+
+        // `system.uptime` is in seconds
+        for (unit, expected_id, expected_level) in [
+            ("ms", UNIT_CONVERTIBLE_ADVICE_TYPE, FindingLevel::Violation),
+            (
+                "1/Hz",
+                UNIT_EQUIVALENT_ADVICE_TYPE,
+                FindingLevel::Improvement,
+            ),
+            ("By", UNIT_MISMATCH_ADVICE_TYPE, FindingLevel::Violation),
+        ] {
+            let mut sample = Sample::Metric(SampleMetric {
+                name: "system.uptime".to_owned(),
+                instrument: SampleInstrument::Supported(InstrumentSpec::Gauge),
+                unit: unit.to_owned(),
+                data_points: None,
+                schema_url: None,
+                live_check_result: None,
+                resource: None,
+                scope: None,
+            });
+            let parent_signal = sample.parent_signal();
+            sample
+                .run_live_check(&mut live_checker, &mut stats, None, &parent_signal)
+                .expect("Live check should run");
+            let Sample::Metric(metric) = sample else {
+                panic!("Expected a metric");
+            };
+            let result = metric.live_check_result.expect("Expected a result");
+            let [finding] = result.all_advice.as_slice() else {
+                panic!("Expected a single finding for `{unit}`: {result:?}");
+            };
+            assert_eq!(finding.id, expected_id);
+            assert_eq!(finding.level, expected_level);
+            assert_eq!(
+                finding
+                    .context
+                    .as_ref()
+                    .and_then(|context| context.get(SCALE_FACTOR_ADVICE_CONTEXT_KEY))
+                    .is_some(),
+                expected_id != UNIT_MISMATCH_ADVICE_TYPE
+            );
+        }
+    }
+
+    #[test]
+    fn test_outdated_semconv_version() {
+        run_outdated_semconv_version_test(false);
+    }
+
+    #[test]
+    fn test_outdated_semconv_version_v2() {
+        run_outdated_semconv_version_test(true);
+    }
+
+    fn run_outdated_semconv_version_test(use_v2: bool) {
+        let registry = make_metrics_registry(use_v2);
+
+        let make_sample = |schema_url: &str| {
+            Sample::Metric(SampleMetric {
+                name: "system.memory.used".to_owned(),
+                instrument: SampleInstrument::Supported(InstrumentSpec::UpDownCounter),
+                unit: "By".to_owned(),
+                data_points: Some(DataPoints::Number(vec![SampleNumberDataPoint {
+                    attributes: vec![SampleAttribute::try_from("system.memory.type=used").unwrap()],
+                    value: json!(42),
+                    flags: 0,
+                    live_check_result: None,
+                    exemplars: vec![],
+                }])),
+                schema_url: Some(schema_url.to_owned()),
+                live_check_result: None,
+                resource: None,
+                scope: None,
+            })
+        };
+        // Produced before the renames, and after them
+        let mut samples = vec![
+            make_sample("https://opentelemetry.io/schemas/1.25.0"),
+            make_sample("https://opentelemetry.io/schemas/1.26.0"),
+        ];
+
+        let advisors: Vec<Box<dyn Advisor>> = vec![Box::new(TypeAdvisor)];
+        let mut live_checker = LiveChecker::new(Arc::new(registry), advisors);
+        let mut schema_history = SchemaHistory::new();
+        schema_history
+            .add_schema_file("data/schema-history.yaml")
+            .expect("Failed to load the schema file");
+        live_checker.schema_history = Some(schema_history);
+
+        let mut stats =
+            LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry));
+        for sample in &mut samples {
+            let result =
+                sample.run_live_check(&mut live_checker, &mut stats, None, &sample.clone());
+            assert!(result.is_ok());
+        }
+        stats.finalize();
+
+        let Sample::Metric(metric) = &samples[0] else {
+            panic!("Expected a metric");
+        };
+        let advice = &metric.live_check_result.as_ref().unwrap().all_advice;
+        assert_eq!(advice.len(), 1);
+        assert_eq!(advice[0].id, "outdated_semconv_version");
+        assert_eq!(
+            advice[0].context,
+            Some(json!({
+                "metric_name": "system.memory.used",
+                "renamed_to": "system.memory.usage",
+                "schema_url": "https://opentelemetry.io/schemas/1.25.0"
+            }))
+        );
+        let Some(DataPoints::Number(points)) = &metric.data_points else {
+            panic!("Expected number data points");
+        };
+        let advice = &points[0].attributes[0]
+            .live_check_result
+            .as_ref()
+            .unwrap()
+            .all_advice;
+        assert_eq!(advice.len(), 1);
+        assert_eq!(advice[0].id, "outdated_semconv_version");
+        assert_eq!(
+            advice[0].message,
+            "Attribute 'system.memory.type' has been renamed to 'system.memory.state' since the semantic conventions version of the sample (https://opentelemetry.io/schemas/1.25.0)."
+        );
+
+        if let LiveCheckStatistics::Cumulative(cumulative_stats) = &stats {
+            assert_eq!(
+                cumulative_stats
+                    .advice_type_counts
+                    .get("outdated_semconv_version"),
+                Some(&2)
+            );
+            // The sample of the later version is not translated
+            assert_eq!(
+                cumulative_stats.advice_type_counts.get("missing_metric"),
+                Some(&1)
+            );
+            assert_eq!(
+                cumulative_stats.advice_type_counts.get("missing_attribute"),
+                Some(&1)
+            );
+            assert_eq!(
+                cumulative_stats
+                    .seen_registry_metrics
+                    .get("system.memory.usage"),
+                Some(&1)
+            );
+        } else {
+            panic!("Expected Cumulative statistics");
+        }
+    }
+
+    #[test]
+    fn test_span_name_template() {
+        let string_attr = |key: &str| V2Attribute {
+            key: key.to_owned(),
+            r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
+            examples: None,
+            constraints: None,
+            common: CommonFields {
+                brief: "".to_owned(),
+                note: "".to_owned(),
+                stability: Stability::Stable,
+                deprecated: None,
+                annotations: BTreeMap::new(),
+            },
+            provenance: Default::default(),
+        };
+        let method = string_attr("http.request.method");
+        let route = string_attr("http.route");
+        let registry = VersionedRegistry::V2(Box::new(ForgeResolvedRegistry {
+            schema_url: "https://example.com/schemas/1.2.3"
+                .try_into()
+                .expect("Should be valid schema url"),
+            registry: Registry {
+                attributes: vec![method.clone(), route.clone()],
+                attribute_groups: vec![],
+                metrics: vec![],
+                spans: vec![V2Span {
+                    r#type: "http.server".to_owned().into(),
+                    kind: SpanKindSpec::Server,
+                    name: SpanName {
+                        note: "{http.request.method} {http.route}".to_owned(),
+                        template: Some(SpanNameTemplate(
+                            "{http.request.method} {http.route}".to_owned(),
+                        )),
+                        fallbacks: vec![SpanNameTemplate("{http.request.method}".to_owned())],
+                    },
+                    attributes: [method, route]
+                        .into_iter()
+                        .map(|base| SpanAttribute {
+                            base,
+                            requirement_level: RequirementLevel::Recommended {
+                                text: "".to_owned(),
+                            },
+                            sampling_relevant: None,
+                        })
+                        .collect(),
+                    entity_associations: vec![],
+                    events: vec![],
+                    common: CommonFields {
+                        brief: "".to_owned(),
+                        note: "".to_owned(),
+                        stability: Stability::Stable,
+                        deprecated: None,
+                        annotations: BTreeMap::new(),
+                    },
+                    provenance: Default::default(),
+                }],
+                events: vec![],
+                entities: vec![],
+            },
+            refinements: Refinements {
+                metrics: vec![],
+                spans: vec![],
+                events: vec![],
+            },
+        }));
+
+        let make_span = |name: &str, kind: SpanKindSpec, attributes: &[&str]| SampleSpan {
+            name: name.to_owned(),
+            kind,
+            status: None,
+            attributes: attributes
+                .iter()
+                .map(|a| SampleAttribute::try_from(*a).unwrap())
+                .collect(),
+            span_events: vec![],
+            span_links: vec![],
+            schema_url: None,
+            live_check_result: None,
+            resource: None,
+            scope: None,
+            trace_id: None,
+            span_id: None,
+            parent_span_id: None,
+        };
+        let live_checker = LiveChecker::new(Arc::new(registry), vec![]);
+
+        // The name follows the template
+        let span = make_span(
+            "GET /users/{id}",
+            SpanKindSpec::Server,
+            &["http.request.method=GET", "http.route=/users/{id}"],
+        );
+        let (signal, expected) = live_checker.find_span(&span).expect("Should match");
+        assert!(matches!(signal.as_ref(), VersionedSignal::Span(s) if *s.r#type == *"http.server"));
+        assert_eq!(expected, None);
+
+        // The fallback applies when the route is missing
+        let span = make_span("POST", SpanKindSpec::Server, &["http.request.method=POST"]);
+        let (_, expected) = live_checker.find_span(&span).expect("Should match");
+        assert_eq!(expected, None);
+
+        // The name has the shape of the template but does not use the route
+        let span = make_span(
+            "GET /users/123",
+            SpanKindSpec::Server,
+            &["http.request.method=GET", "http.route=/users/{id}"],
+        );
+        let (_, expected) = live_checker.find_span(&span).expect("Should match");
+        assert_eq!(expected.as_deref(), Some("GET /users/{id}"));
+
+        // Spans of another kind are not matched
+        let span = make_span("GET /users/{id}", SpanKindSpec::Client, &[]);
+        assert!(live_checker.find_span(&span).is_none());
+
+        // The mismatch is reported by the live check
+        let mut live_checker = live_checker;
+        let mut sample = Sample::Span(make_span(
+            "GET /users/123",
+            SpanKindSpec::Server,
+            &["http.request.method=GET", "http.route=/users/{id}"],
+        ));
+        let mut stats =
+            LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry));
+        let result = sample.run_live_check(&mut live_checker, &mut stats, None, &sample.clone());
+        assert!(result.is_ok());
+        let Sample::Span(span) = &sample else {
+            panic!("Expected a span");
+        };
+        let advice = &span.live_check_result.as_ref().unwrap().all_advice;
+        assert_eq!(advice.len(), 1);
+        assert_eq!(advice[0].id, SPAN_NAME_MISMATCH_ADVICE_TYPE);
+        assert_eq!(
+            advice[0].context,
+            Some(json!({
+                "span_name": "GET /users/123",
+                "span_type": "http.server",
+                "expected": "GET /users/{id}"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_trace_structure() {
+        let method = V2Attribute {
+            key: "http.request.method".to_owned(),
+            r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
+            examples: None,
+            constraints: None,
+            common: CommonFields::default(),
+            provenance: Default::default(),
+        };
+        let event = |name: &str| V2Event {
+            name: name.to_owned().into(),
+            attributes: vec![],
+            entity_associations: vec![],
+            common: CommonFields::default(),
+            provenance: Default::default(),
+        };
+        let registry = VersionedRegistry::V2(Box::new(ForgeResolvedRegistry {
+            schema_url: "https://example.com/schemas/1.2.3"
+                .try_into()
+                .expect("Should be valid schema url"),
+            registry: Registry {
+                attributes: vec![method.clone()],
+                attribute_groups: vec![],
+                metrics: vec![],
+                spans: vec![V2Span {
+                    r#type: "http.client".to_owned().into(),
+                    kind: SpanKindSpec::Client,
+                    name: SpanName {
+                        note: "{http.request.method}".to_owned(),
+                        template: Some(SpanNameTemplate("{http.request.method}".to_owned())),
+                        fallbacks: vec![],
+                    },
+                    attributes: vec![SpanAttribute {
+                        base: method,
+                        requirement_level: RequirementLevel::Basic(
+                            BasicRequirementLevelSpec::Required,
+                        ),
+                        sampling_relevant: None,
+                    }],
+                    entity_associations: vec![],
+                    events: vec!["http.request.retry".to_owned()],
+                    common: CommonFields::default(),
+                    provenance: Default::default(),
+                }],
+                events: vec![
+                    event("exception"),
+                    event("http.request.retry"),
+                    event("session.start"),
+                ],
+                entities: vec![],
+            },
+            refinements: Refinements {
+                metrics: vec![],
+                spans: vec![],
+                events: vec![],
+            },
+        }));
+        let mut live_checker = LiveChecker::new(Arc::new(registry), vec![]);
+        let span_event_advisor =
+            SpanEventAdvisor::new(&live_checker.registry).expect("Should be a v2 registry");
+        live_checker.add_trace_advisor(Box::new(span_event_advisor));
+        live_checker.add_trace_advisor(Box::new(ChildSpanAdvisor));
+        live_checker.add_trace_advisor(Box::new(OrphanSpanAdvisor));
+
+        let mut sample: Sample = serde_json::from_value(json!({
+            "trace": {
+                "trace_id": "0af7651916cd43dd8448eb211c80319c",
+                "spans": [
+                    {
+                        "name": "GET",
+                        "kind": "client",
+                        "attributes": [{"name": "http.request.method", "value": "GET"}],
+                        "span_events": [
+                            {"name": "exception"},
+                            {"name": "http.request.retry"},
+                            {"name": "session.start"},
+                            {"name": "custom.event"}
+                        ],
+                        "span_id": "00f067aa0ba902b7"
+                    },
+                    {
+                        "name": "POST",
+                        "kind": "client",
+                        "attributes": [{"name": "http.request.method", "value": "POST"}],
+                        "span_id": "b7ad6b7169203331"
+                    },
+                    {
+                        "name": "POST /users",
+                        "kind": "server",
+                        "span_id": "53995c3f42cd8ad8",
+                        "parent_span_id": "b7ad6b7169203331"
+                    },
+                    {
+                        "name": "work",
+                        "kind": "internal",
+                        "span_id": "a2fb4a1d1a96d312",
+                        "parent_span_id": "ffffffffffffffff"
+                    }
+                ]
+            }
+        }))
+        .expect("Should be a valid trace");
+        let mut stats =
+            LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry));
+        let result = sample.run_live_check(&mut live_checker, &mut stats, None, &sample.clone());
+        assert!(result.is_ok());
+        let Sample::Trace(trace) = &sample else {
+            panic!("Expected a trace");
+        };
+
+        // The spans are checked individually
+        assert!(trace
+            .spans
+            .iter()
+            .all(|span| span.live_check_result.is_some()));
+
+        let advice = &trace.live_check_result.as_ref().unwrap().all_advice;
+        assert_eq!(advice.len(), 3);
+        assert_eq!(advice[0].id, UNEXPECTED_SPAN_EVENT_ADVICE_TYPE);
+        assert_eq!(
+            advice[0].context,
+            Some(json!({
+                "span_name": "GET",
+                "span_id": "00f067aa0ba902b7",
+                "span_type": "http.client",
+                "event_name": "session.start"
+            }))
+        );
+        assert_eq!(advice[0].signal_type.as_deref(), Some("trace"));
+        assert_eq!(advice[1].id, MISSING_CHILD_SPAN_ADVICE_TYPE);
+        assert_eq!(advice[1].level, FindingLevel::Information);
+        assert_eq!(
+            advice[1].context,
+            Some(json!({
+                "span_name": "GET",
+                "span_id": "00f067aa0ba902b7",
+                "span_kind": "client",
+                "expected": "server"
+            }))
+        );
+        assert_eq!(advice[2].id, ORPHANED_SPAN_ADVICE_TYPE);
+        assert_eq!(
+            advice[2].context,
+            Some(json!({
+                "span_name": "work",
+                "span_id": "a2fb4a1d1a96d312",
+                "parent_span_id": "ffffffffffffffff"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_trace_structure_v1() {
+        // The spans of a v1 registry are not matched to span types, so the events
+        // recorded on them are not checked
+        let registry = make_registry(false);
+        assert!(SpanEventAdvisor::new(&registry).is_none());
+        let mut live_checker = LiveChecker::new(Arc::new(registry), vec![]);
+        live_checker.add_trace_advisor(Box::new(OrphanSpanAdvisor));
+
+        let mut sample: Sample = serde_json::from_value(json!({
+            "trace": {
+                "trace_id": "0af7651916cd43dd8448eb211c80319c",
+                "spans": [
+                    {
+                        "name": "internal",
+                        "kind": "internal",
+                        "attributes": [{"name": "test.string", "value": "value1"}],
+                        "span_events": [{"name": "custom.event"}],
+                        "span_id": "00f067aa0ba902b7"
+                    },
+                    {
+                        "name": "work",
+                        "kind": "internal",
+                        "span_id": "a2fb4a1d1a96d312",
+                        "parent_span_id": "ffffffffffffffff"
+                    }
+                ]
+            }
+        }))
+        .expect("Should be a valid trace");
+        let mut stats =
+            LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry));
+        let parent_signal = sample.parent_signal();
+        let result = sample.run_live_check(&mut live_checker, &mut stats, None, &parent_signal);
+        assert!(result.is_ok());
+        let Sample::Trace(trace) = &sample else {
+            panic!("Expected a trace");
+        };
+
+        // The spans are checked individually, and the trace structure is checked
+        assert!(trace
+            .spans
+            .iter()
+            .all(|span| span.live_check_result.is_some()));
+        let advice = &trace.live_check_result.as_ref().unwrap().all_advice;
+        assert_eq!(advice.len(), 1);
+        assert_eq!(advice[0].id, ORPHANED_SPAN_ADVICE_TYPE);
+    }
 }
@@ -184,6 +184,20 @@ fn build_finding_attributes(
         }
     }
 
+    // Add the instrumentation scope of the parent signal, to group findings by library
+    if let Some(scope) = parent_signal.scope() {
+        attributes.push((
+            Key::from("weaver.finding.scope.name"),
+            AnyValue::from(scope.name.clone()),
+        ));
+        if let Some(version) = &scope.version {
+            attributes.push((
+                Key::from("weaver.finding.scope.version"),
+                AnyValue::from(version.clone()),
+            ));
+        }
+    }
+
     attributes
 }
 
@@ -253,12 +267,13 @@ fn flatten_json_recursive(value: &JsonValue, prefix: &str, attributes: &mut Vec<
 
 #[cfg(test)]
 mod tests {
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     use super::*;
     use crate::sample_attribute::SampleAttribute;
     use crate::sample_metric::{SampleInstrument, SampleMetric};
     use crate::sample_resource::SampleResource;
+    use crate::sample_scope::SampleScope;
     use crate::sample_span::{SampleSpan, SampleSpanEvent, SampleSpanLink, Status, StatusCode};
     use serde_json::json;
     use weaver_checker::{FindingLevel, PolicyFinding};
@@ -286,8 +301,13 @@ mod tests {
             attributes: vec![],
             span_events: vec![],
             span_links: vec![],
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
+            trace_id: None,
+            span_id: None,
+            parent_span_id: None,
         }
     }
 
@@ -298,8 +318,10 @@ mod tests {
             instrument: SampleInstrument::Supported(InstrumentSpec::Gauge),
             unit: "ms".to_owned(),
             data_points: None,
+            schema_url: None,
             live_check_result: None,
             resource: None,
+            scope: None,
         }
     }
 
@@ -583,7 +605,7 @@ mod tests {
     #[test]
     fn test_build_finding_attributes_includes_resource_attributes() {
         let mut span = create_test_span("test.span");
-        span.resource = Some(Rc::new(SampleResource {
+        span.resource = Some(Arc::new(SampleResource {
             attributes: vec![
                 SampleAttribute {
                     name: "service.name".to_owned(),
@@ -616,6 +638,7 @@ mod tests {
                     live_check_result: None,
                 },
             ],
+            schema_url: None,
             live_check_result: None,
         }));
         let parent_signal = Sample::Span(span.clone());
@@ -679,6 +702,38 @@ mod tests {
             .any(|(k, _)| k.as_str().starts_with("weaver.finding.resource_attribute.")));
     }
 
+    #[test]
+    fn test_build_finding_attributes_includes_scope() {
+        let mut metric = create_test_metric("db.client.operation.duration");
+        metric.scope = Some(Arc::new(SampleScope {
+            name: "io.opentelemetry.jdbc".to_owned(),
+            version: Some("2.10.0".to_owned()),
+            schema_url: None,
+            attributes: vec![],
+        }));
+        let parent_signal = Sample::Metric(metric.clone());
+        let sample_ref = SampleRef::Metric(&metric);
+        let finding = create_test_finding(
+            "test_id",
+            "test message",
+            FindingLevel::Violation,
+            Some("metric"),
+            Some("db.client.operation.duration"),
+            None,
+        );
+
+        let attrs = build_finding_attributes(&finding, &sample_ref, &parent_signal);
+
+        assert_eq!(
+            find_attr(&attrs, "weaver.finding.scope.name"),
+            Some(&AnyValue::from("io.opentelemetry.jdbc"))
+        );
+        assert_eq!(
+            find_attr(&attrs, "weaver.finding.scope.version"),
+            Some(&AnyValue::from("2.10.0"))
+        );
+    }
+
     #[test]
     fn test_sample_ref_types() {
         let attr_sample = create_test_attribute("test");
@@ -708,6 +763,7 @@ mod tests {
 
         let resource_sample = SampleResource {
             attributes: vec![],
+            schema_url: None,
             live_check_result: None,
         };
         assert_eq!(
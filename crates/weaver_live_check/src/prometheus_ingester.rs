@@ -0,0 +1,878 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! An ingester that reads metrics from a Prometheus or OpenMetrics text exposition.
+//!
+//! Prometheus names are mapped back to the registry: metric family names are matched
+//! against every name a registry metric can be exported as (with or without unit and
+//! `_total` suffixes, see `weaver_forge::extensions::prom`), and label names are matched
+//! against the attribute keys with invalid characters replaced by underscores. Families
+//! and labels that can't be mapped are kept under their Prometheus name, so the live
+//! checker reports them as missing metrics and missing attributes.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::{borrow::Cow, fs};
+
+use serde_json::{json, Value};
+use weaver_forge::extensions::prom::{get_names, get_ucum_unit, get_unit_suffixes, sanitize_name};
+use weaver_semconv::attribute::{AttributeType, PrimitiveOrArrayTypeSpec, ValueSpec};
+use weaver_semconv::group::{GroupType, InstrumentSpec};
+
+use crate::sample_attribute::SampleAttribute;
+use crate::sample_metric::{
+    DataPoints, SampleHistogramDataPoint, SampleInstrument, SampleMetric, SampleNumberDataPoint,
+};
+use crate::sample_resource::SampleResource;
+use crate::{Error, Ingester, Sample, VersionedRegistry};
+
+/// Accept header sent when scraping an endpoint.
+const SCRAPE_ACCEPT_HEADER: &str =
+    "application/openmetrics-text;version=1.0.0,text/plain;version=0.0.4;q=0.5";
+
+/// Sample name suffixes of the series belonging to a metric family.
+const FAMILY_SUFFIXES: [&str; 8] = [
+    "_total", "_created", "_bucket", "_count", "_sum", "_gcount", "_gsum", "_info",
+];
+
+/// The family holding the resource attributes of the target.
+const TARGET_INFO_FAMILY: &str = "target_info";
+
+/// Prefix of the labels added by the OpenTelemetry exporter for the instrumentation scope.
+const SCOPE_LABEL_PREFIX: &str = "otel_scope_";
+
+/// Where to read the exposition from.
+#[derive(Debug, Clone)]
+pub enum PrometheusSource {
+    /// A file containing an exposition.
+    File(PathBuf),
+    /// An exposition read from standard input.
+    Stdin,
+    /// An HTTP(S) endpoint to scrape.
+    Url(String),
+}
+
+/// An ingester that reads a Prometheus or OpenMetrics text exposition and maps each
+/// metric family to a sample metric.
+pub struct PrometheusIngester {
+    source: PrometheusSource,
+    name_map: PrometheusNameMap,
+}
+
+impl PrometheusIngester {
+    /// Create a new PrometheusIngester mapping names to the given registry
+    #[must_use]
+    pub fn new(source: PrometheusSource, registry: &VersionedRegistry) -> Self {
+        PrometheusIngester {
+            source,
+            name_map: PrometheusNameMap::new(registry),
+        }
+    }
+
+    /// Create a new PrometheusIngester reading from a file
+    #[must_use]
+    pub fn from_file(path: &Path, registry: &VersionedRegistry) -> Self {
+        Self::new(PrometheusSource::File(path.to_path_buf()), registry)
+    }
+
+    fn read(&self) -> Result<String, Error> {
+        match &self.source {
+            PrometheusSource::File(path) => {
+                fs::read_to_string(path).map_err(|e| Error::IngestError {
+                    error: format!("Failed to read file {}: {}", path.display(), e),
+                })
+            }
+            PrometheusSource::Stdin => {
+                let mut content = String::new();
+                _ = std::io::stdin().read_to_string(&mut content).map_err(|e| {
+                    Error::IngestError {
+                        error: format!("Failed to read from stdin: {e}"),
+                    }
+                })?;
+                Ok(content)
+            }
+            PrometheusSource::Url(url) => ureq::get(url)
+                .header("Accept", SCRAPE_ACCEPT_HEADER)
+                .call()
+                .and_then(|mut response| response.body_mut().read_to_string())
+                .map_err(|e| Error::IngestError {
+                    error: format!("Failed to scrape {url}: {e}"),
+                }),
+        }
+    }
+}
+
+impl Ingester for PrometheusIngester {
+    fn ingest(&self) -> Result<Box<dyn Iterator<Item = Sample>>, Error> {
+        let content = self.read()?;
+        let samples = parse_exposition(&content)?
+            .into_iter()
+            .map(|family| self.name_map.to_sample(family))
+            .collect::<Vec<_>>();
+        Ok(Box::new(samples.into_iter()))
+    }
+}
+
+/// A registry attribute a label can be mapped to.
+#[derive(Debug, Clone)]
+struct MappedAttribute {
+    key: String,
+    r#type: AttributeType,
+}
+
+/// A registry metric a family can be mapped to.
+#[derive(Debug)]
+struct MappedMetric {
+    name: String,
+    unit: String,
+    instrument: InstrumentSpec,
+    /// Attributes of the metric by key and by Prometheus label name.
+    attributes: HashMap<String, MappedAttribute>,
+}
+
+/// Reverse mapping of Prometheus names to the registry.
+#[derive(Debug, Default)]
+struct PrometheusNameMap {
+    metrics: Vec<MappedMetric>,
+    /// Index in `metrics` by Prometheus family name.
+    metrics_by_name: HashMap<String, usize>,
+    /// All the attributes of the registry by key and by Prometheus label name.
+    attributes: HashMap<String, MappedAttribute>,
+}
+
+impl PrometheusNameMap {
+    fn new(registry: &VersionedRegistry) -> Self {
+        let mut map = PrometheusNameMap::default();
+        match registry {
+            VersionedRegistry::V1(registry) => {
+                for group in &registry.groups {
+                    let attributes = group.attributes.iter().map(|attribute| MappedAttribute {
+                        key: attribute.name.clone(),
+                        r#type: attribute.r#type.clone(),
+                    });
+                    if group.r#type != GroupType::Metric {
+                        map.add_attributes(attributes);
+                        continue;
+                    }
+                    let attributes = attributes.collect::<Vec<_>>();
+                    map.add_attributes(attributes.iter().cloned());
+                    if let (Some(name), Some(instrument)) = (&group.metric_name, &group.instrument)
+                    {
+                        map.add_metric(
+                            name,
+                            group.unit.as_deref().unwrap_or_default(),
+                            instrument,
+                            attributes,
+                        );
+                    }
+                }
+            }
+            VersionedRegistry::V2(registry) => {
+                map.add_attributes(registry.registry.attributes.iter().map(|attribute| {
+                    MappedAttribute {
+                        key: attribute.key.clone(),
+                        r#type: attribute.r#type.clone(),
+                    }
+                }));
+                for metric in &registry.registry.metrics {
+                    let attributes = metric
+                        .attributes
+                        .iter()
+                        .map(|attribute| MappedAttribute {
+                            key: attribute.base.key.clone(),
+                            r#type: attribute.base.r#type.clone(),
+                        })
+                        .collect();
+                    map.add_metric(&metric.name, &metric.unit, &metric.instrument, attributes);
+                }
+            }
+        }
+        map
+    }
+
+    fn add_attributes(&mut self, attributes: impl Iterator<Item = MappedAttribute>) {
+        for attribute in attributes {
+            insert_attribute(&mut self.attributes, attribute);
+        }
+    }
+
+    fn add_metric(
+        &mut self,
+        name: &str,
+        unit: &str,
+        instrument: &InstrumentSpec,
+        attributes: Vec<MappedAttribute>,
+    ) {
+        let index = self.metrics.len();
+        let mut mapped_attributes = HashMap::new();
+        for attribute in attributes {
+            insert_attribute(&mut mapped_attributes, attribute);
+        }
+        for prom_name in get_names(
+            &Cow::Borrowed(name),
+            unit,
+            &instrument.to_string(),
+            None,
+            false,
+        ) {
+            _ = self
+                .metrics_by_name
+                .entry(prom_name.into_owned())
+                .or_insert(index);
+        }
+        self.metrics.push(MappedMetric {
+            name: name.to_owned(),
+            unit: unit.to_owned(),
+            instrument: instrument.clone(),
+            attributes: mapped_attributes,
+        });
+    }
+
+    fn find_metric(&self, family: &MetricFamily) -> Option<&MappedMetric> {
+        let mut candidates = vec![Cow::Borrowed(family.name.as_str())];
+        if family.r#type == "counter" {
+            // Prometheus declares counters with their `_total` suffix, OpenMetrics without.
+            match family.name.strip_suffix("_total") {
+                Some(stripped) => candidates.push(Cow::Borrowed(stripped)),
+                None => candidates.push(Cow::Owned(format!("{}_total", family.name))),
+            }
+        }
+        candidates
+            .iter()
+            .find_map(|candidate| self.metrics_by_name.get(candidate.as_ref()))
+            .map(|index| &self.metrics[*index])
+    }
+
+    /// Maps a label to a sample attribute, preferring the attributes of the metric.
+    fn to_attribute(
+        &self,
+        metric: Option<&MappedMetric>,
+        label: &str,
+        value: &str,
+    ) -> SampleAttribute {
+        let mapped = metric
+            .and_then(|metric| metric.attributes.get(label))
+            .or_else(|| self.attributes.get(label));
+        let (name, value) = match mapped {
+            Some(attribute) => (attribute.key.clone(), typed_value(value, &attribute.r#type)),
+            None => (label.to_owned(), Value::String(value.to_owned())),
+        };
+        SampleAttribute {
+            name,
+            r#type: SampleAttribute::infer_type(&value),
+            value: Some(value),
+            live_check_result: None,
+        }
+    }
+
+    fn to_attributes(
+        &self,
+        metric: Option<&MappedMetric>,
+        labels: &[Label],
+    ) -> Vec<SampleAttribute> {
+        labels
+            .iter()
+            .filter(|(label, _)| !label.starts_with(SCOPE_LABEL_PREFIX))
+            .map(|(label, value)| self.to_attribute(metric, label, value))
+            .collect()
+    }
+
+    fn to_sample(&self, family: MetricFamily) -> Sample {
+        if family.name == TARGET_INFO_FAMILY {
+            let attributes = family
+                .samples
+                .first()
+                .map(|sample| self.to_attributes(None, &sample.labels))
+                .unwrap_or_default();
+            return Sample::Resource(SampleResource {
+                attributes,
+                schema_url: None,
+                live_check_result: None,
+            });
+        }
+
+        let metric = self.find_metric(&family);
+        let instrument = match (family.r#type.as_str(), metric.map(|m| &m.instrument)) {
+            // Up-down counters are exported as gauges.
+            ("gauge", Some(InstrumentSpec::UpDownCounter)) => {
+                SampleInstrument::Supported(InstrumentSpec::UpDownCounter)
+            }
+            ("gauge", _) => SampleInstrument::Supported(InstrumentSpec::Gauge),
+            ("counter", _) => SampleInstrument::Supported(InstrumentSpec::Counter),
+            ("histogram", _) => SampleInstrument::Supported(InstrumentSpec::Histogram),
+            (other, _) => SampleInstrument::Unsupported(other.to_owned()),
+        };
+        let unit = match (metric, &family.unit) {
+            (Some(metric), Some(unit))
+                if get_unit_suffixes(&metric.unit).as_deref() != Some(unit.as_str()) =>
+            {
+                prom_unit_to_ucum(unit)
+            }
+            (Some(metric), _) => metric.unit.clone(),
+            (None, Some(unit)) => prom_unit_to_ucum(unit),
+            (None, None) => String::new(),
+        };
+        let data_points = match family.r#type.as_str() {
+            "counter" | "gauge" | "unknown" | "untyped" => Some(DataPoints::Number(
+                family
+                    .samples
+                    .iter()
+                    .filter(|sample| !sample.name.ends_with("_created"))
+                    .map(|sample| SampleNumberDataPoint {
+                        attributes: self.to_attributes(metric, &sample.labels),
+                        value: number_value(sample.value),
+                        flags: 0,
+                        exemplars: Vec::new(),
+                        live_check_result: None,
+                    })
+                    .collect(),
+            )),
+            "histogram" => Some(DataPoints::Histogram(
+                histogram_points(&family)
+                    .into_iter()
+                    .map(|mut point| {
+                        point.attributes = self.to_attributes(metric, &point.labels);
+                        point.into_data_point()
+                    })
+                    .collect(),
+            )),
+            _ => None,
+        };
+
+        Sample::Metric(SampleMetric {
+            name: metric.map_or(family.name, |metric| metric.name.clone()),
+            instrument,
+            unit,
+            data_points,
+            schema_url: None,
+            live_check_result: None,
+            resource: None,
+            scope: None,
+        })
+    }
+}
+
+/// Indexes an attribute by key and by Prometheus label name, keeping the first one
+/// when several keys sanitize to the same label name.
+fn insert_attribute(map: &mut HashMap<String, MappedAttribute>, attribute: MappedAttribute) {
+    let label = sanitize_name(&Cow::Borrowed(attribute.key.as_str())).into_owned();
+    if label != attribute.key {
+        _ = map.entry(label).or_insert_with(|| attribute.clone());
+    }
+    _ = map.entry(attribute.key.clone()).or_insert(attribute);
+}
+
+/// Converts a label value (always a string in Prometheus) to the type of the attribute.
+fn typed_value(value: &str, r#type: &AttributeType) -> Value {
+    let spec = match r#type {
+        AttributeType::PrimitiveOrArray(spec) => Some(spec.clone()),
+        AttributeType::Enum { members } => match members.first().map(|member| &member.value) {
+            Some(ValueSpec::Int(_)) => Some(PrimitiveOrArrayTypeSpec::Int),
+            Some(ValueSpec::Double(_)) => Some(PrimitiveOrArrayTypeSpec::Double),
+            Some(ValueSpec::Bool(_)) => Some(PrimitiveOrArrayTypeSpec::Boolean),
+            _ => None,
+        },
+        AttributeType::Template(_) => None,
+    };
+    let typed = match spec {
+        Some(PrimitiveOrArrayTypeSpec::Int) => value.parse::<i64>().ok().map(Value::from),
+        Some(PrimitiveOrArrayTypeSpec::Double) => value.parse::<f64>().ok().map(Value::from),
+        Some(PrimitiveOrArrayTypeSpec::Boolean) => value.parse::<bool>().ok().map(Value::from),
+        _ => None,
+    };
+    typed.unwrap_or_else(|| Value::String(value.to_owned()))
+}
+
+fn prom_unit_to_ucum(unit: &str) -> String {
+    get_ucum_unit(unit).map_or_else(|| unit.to_owned(), Cow::into_owned)
+}
+
+/// Values are kept as integers when they have no fractional part.
+fn number_value(value: f64) -> Value {
+    if value.is_finite() && value.fract() == 0.0 && value.abs() < 9_007_199_254_740_992.0 {
+        json!(value as i64)
+    } else {
+        serde_json::Number::from_f64(value).map_or(Value::Null, Value::Number)
+    }
+}
+
+type Label = (String, String);
+
+/// A sample line of the exposition.
+#[derive(Debug)]
+struct RawSample {
+    name: String,
+    labels: Vec<Label>,
+    value: f64,
+}
+
+/// The samples of a metric family with their metadata.
+#[derive(Debug)]
+struct MetricFamily {
+    name: String,
+    r#type: String,
+    unit: Option<String>,
+    samples: Vec<RawSample>,
+}
+
+/// A histogram data point being assembled from the `_bucket`, `_count` and `_sum` series.
+#[derive(Debug)]
+struct HistogramPoint {
+    labels: Vec<Label>,
+    attributes: Vec<SampleAttribute>,
+    /// Upper bounds and cumulative counts.
+    buckets: Vec<(f64, u64)>,
+    count: Option<u64>,
+    sum: Option<f64>,
+}
+
+impl HistogramPoint {
+    fn into_data_point(mut self) -> SampleHistogramDataPoint {
+        self.buckets.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut bucket_counts = Vec::with_capacity(self.buckets.len());
+        let mut previous = 0;
+        for (_, cumulative) in &self.buckets {
+            bucket_counts.push(cumulative.saturating_sub(previous));
+            previous = *cumulative;
+        }
+        SampleHistogramDataPoint {
+            attributes: self.attributes,
+            count: self.count.unwrap_or(previous),
+            sum: self.sum,
+            bucket_counts,
+            explicit_bounds: self
+                .buckets
+                .iter()
+                .map(|(bound, _)| *bound)
+                .filter(|bound| bound.is_finite())
+                .collect(),
+            min: None,
+            max: None,
+            flags: 0,
+            exemplars: Vec::new(),
+            live_check_result: None,
+        }
+    }
+}
+
+/// Groups the series of a histogram family by label set (without `le`).
+fn histogram_points(family: &MetricFamily) -> Vec<HistogramPoint> {
+    let mut points: Vec<HistogramPoint> = Vec::new();
+    for sample in &family.samples {
+        let (labels, le): (Vec<_>, Vec<_>) = sample
+            .labels
+            .iter()
+            .cloned()
+            .partition(|(label, _)| label != "le");
+        let index = if let Some(index) = points.iter().position(|point| point.labels == labels) {
+            index
+        } else {
+            points.push(HistogramPoint {
+                labels,
+                attributes: Vec::new(),
+                buckets: Vec::new(),
+                count: None,
+                sum: None,
+            });
+            points.len() - 1
+        };
+        let point = &mut points[index];
+        if sample.name.ends_with("_bucket") {
+            if let Some(bound) = le.first().and_then(|(_, le)| le.parse::<f64>().ok()) {
+                point.buckets.push((bound, sample.value as u64));
+            }
+        } else if sample.name.ends_with("_count") {
+            point.count = Some(sample.value as u64);
+        } else if sample.name.ends_with("_sum") {
+            point.sum = Some(sample.value);
+        }
+    }
+    points
+}
+
+/// Parses a Prometheus or OpenMetrics text exposition into metric families, in order
+/// of appearance.
+fn parse_exposition(content: &str) -> Result<Vec<MetricFamily>, Error> {
+    let mut families: Vec<MetricFamily> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for (line_number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            let mut parts = comment.trim_start().splitn(3, ' ');
+            let (Some(keyword), Some(name)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            let name = unquote(name);
+            let text = parts.next().unwrap_or_default().trim();
+            match keyword {
+                "TYPE" => {
+                    let i = family_index(&mut families, &mut index, &name);
+                    families[i].r#type = text.to_owned();
+                }
+                "UNIT" if !text.is_empty() => {
+                    let i = family_index(&mut families, &mut index, &name);
+                    families[i].unit = Some(text.to_owned());
+                }
+                "HELP" => _ = family_index(&mut families, &mut index, &name),
+                _ => {}
+            }
+            continue;
+        }
+
+        let sample = parse_sample(line).map_err(|error| Error::IngestError {
+            error: format!("Invalid exposition line {}: {error}", line_number + 1),
+        })?;
+        let family_name = if index.contains_key(&sample.name) {
+            sample.name.clone()
+        } else {
+            FAMILY_SUFFIXES
+                .iter()
+                .filter_map(|suffix| sample.name.strip_suffix(suffix))
+                .find(|stripped| index.contains_key(*stripped))
+                .unwrap_or(&sample.name)
+                .to_owned()
+        };
+        let i = family_index(&mut families, &mut index, &family_name);
+        families[i].samples.push(sample);
+    }
+    Ok(families)
+}
+
+/// Returns the index of the family with the given name, creating it if needed.
+fn family_index(
+    families: &mut Vec<MetricFamily>,
+    index: &mut HashMap<String, usize>,
+    name: &str,
+) -> usize {
+    *index.entry(name.to_owned()).or_insert_with(|| {
+        families.push(MetricFamily {
+            name: name.to_owned(),
+            r#type: "unknown".to_owned(),
+            unit: None,
+            samples: Vec::new(),
+        });
+        families.len() - 1
+    })
+}
+
+fn unquote(name: &str) -> String {
+    name.strip_prefix('"')
+        .and_then(|name| name.strip_suffix('"'))
+        .unwrap_or(name)
+        .to_owned()
+}
+
+/// Parses `name{label="value",...} value [timestamp] [# exemplar]`. Quoted UTF-8 names
+/// (`{"name","label.name"="value"}`) are supported.
+fn parse_sample(line: &str) -> Result<RawSample, String> {
+    let (mut name, rest) = match line.find(|c: char| c == '{' || c.is_whitespace()) {
+        Some(i) => (line[..i].to_owned(), &line[i..]),
+        None => return Err("missing value".to_owned()),
+    };
+    let mut labels = Vec::new();
+    let rest = if let Some(body) = rest.strip_prefix('{') {
+        let mut chars = body.char_indices().peekable();
+        let end = loop {
+            match chars.peek().copied() {
+                None => return Err("unterminated label set".to_owned()),
+                Some((i, '}')) => break i,
+                Some((_, ',' | ' ')) => _ = chars.next(),
+                Some((_, '"')) => {
+                    let quoted = parse_quoted(&mut chars)?;
+                    if chars.peek().is_some_and(|(_, c)| *c == '=') {
+                        _ = chars.next();
+                        labels.push((quoted, parse_quoted(&mut chars)?));
+                    } else {
+                        name = quoted;
+                    }
+                }
+                Some((start, _)) => {
+                    let mut end = start;
+                    while let Some((i, c)) = chars.peek().copied() {
+                        if c == '=' {
+                            break;
+                        }
+                        end = i + c.len_utf8();
+                        _ = chars.next();
+                    }
+                    if chars.next().is_none() {
+                        return Err("label without value".to_owned());
+                    }
+                    labels.push((
+                        body[start..end].trim().to_owned(),
+                        parse_quoted(&mut chars)?,
+                    ));
+                }
+            }
+        };
+        &body[end + 1..]
+    } else {
+        rest
+    };
+    if name.is_empty() {
+        return Err("missing metric name".to_owned());
+    }
+    let value = rest
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| "missing value".to_owned())?;
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| format!("invalid value `{value}`"))?;
+    Ok(RawSample {
+        name,
+        labels,
+        value,
+    })
+}
+
+/// Parses a double-quoted string with `\\`, `\"` and `\n` escapes.
+fn parse_quoted(
+    chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>,
+) -> Result<String, String> {
+    if chars.next().map(|(_, c)| c) != Some('"') {
+        return Err("expected a quoted string".to_owned());
+    }
+    let mut value = String::new();
+    loop {
+        match chars.next().map(|(_, c)| c) {
+            None => return Err("unterminated quoted string".to_owned()),
+            Some('"') => return Ok(value),
+            Some('\\') => match chars.next().map(|(_, c)| c) {
+                Some('n') => value.push('\n'),
+                Some(c) => value.push(c),
+                None => return Err("unterminated quoted string".to_owned()),
+            },
+            Some(c) => value.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::Write;
+    use tempfile::tempdir;
+    use weaver_forge::registry::ResolvedRegistry;
+
+    const EXPOSITION: &str = r#"# HELP target_info Target metadata
+# TYPE target_info gauge
+target_info{service_name="checkout",custom_label="x"} 1
+# HELP http_server_request_duration_seconds Duration of HTTP server requests.
+# TYPE http_server_request_duration_seconds histogram
+http_server_request_duration_seconds_bucket{http_request_method="GET",http_response_status_code="200",otel_scope_name="app",le="0.1"} 3
+http_server_request_duration_seconds_bucket{http_request_method="GET",http_response_status_code="200",otel_scope_name="app",le="1"} 5
+http_server_request_duration_seconds_bucket{http_request_method="GET",http_response_status_code="200",otel_scope_name="app",le="+Inf"} 6
+http_server_request_duration_seconds_sum{http_request_method="GET",http_response_status_code="200",otel_scope_name="app"} 4.5
+http_server_request_duration_seconds_count{http_request_method="GET",http_response_status_code="200",otel_scope_name="app"} 6
+# TYPE http_server_active_requests gauge
+http_server_active_requests{http_request_method="GET",tenant="a"} 2
+# TYPE queue_depth gauge
+# UNIT queue_depth bytes
+queue_depth 12.5
+"#;
+
+    const REGISTRY: &str = r#"
+registry_url: https://example.com
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes
+    attributes:
+      - name: http.request.method
+        type: string
+        brief: method
+        requirement_level: recommended
+      - name: http.response.status_code
+        type: int
+        brief: status
+        requirement_level: recommended
+      - name: service.name
+        type: string
+        brief: service
+        requirement_level: recommended
+  - id: metric.http.server.request.duration
+    type: metric
+    metric_name: http.server.request.duration
+    brief: duration
+    instrument: histogram
+    unit: s
+    attributes:
+      - name: http.request.method
+        type: string
+        brief: method
+        requirement_level: recommended
+      - name: http.response.status_code
+        type: int
+        brief: status
+        requirement_level: recommended
+  - id: metric.http.server.active_requests
+    type: metric
+    metric_name: http.server.active_requests
+    brief: active requests
+    instrument: updowncounter
+    unit: "{request}"
+    attributes:
+      - name: http.request.method
+        type: string
+        brief: method
+        requirement_level: recommended
+"#;
+
+    fn ingest(exposition: &str) -> Vec<Sample> {
+        let registry: ResolvedRegistry = serde_yaml::from_str(REGISTRY).unwrap();
+        let registry = VersionedRegistry::V1(Box::new(registry));
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("metrics.prom");
+        let mut file = File::create(&file_path).unwrap();
+        file.write_all(exposition.as_bytes()).unwrap();
+        PrometheusIngester::from_file(&file_path, &registry)
+            .ingest()
+            .unwrap()
+            .collect()
+    }
+
+    fn attribute_names(attributes: &[SampleAttribute]) -> Vec<&str> {
+        attributes.iter().map(|a| a.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_prometheus_ingestion() {
+        let samples = ingest(EXPOSITION);
+        assert_eq!(samples.len(), 4);
+
+        let Sample::Resource(resource) = &samples[0] else {
+            panic!("Expected a resource");
+        };
+        assert_eq!(
+            attribute_names(&resource.attributes),
+            vec!["service.name", "custom_label"]
+        );
+
+        let Sample::Metric(histogram) = &samples[1] else {
+            panic!("Expected a metric");
+        };
+        assert_eq!(histogram.name, "http.server.request.duration");
+        assert_eq!(histogram.unit, "s");
+        assert_eq!(
+            histogram.instrument,
+            SampleInstrument::Supported(InstrumentSpec::Histogram)
+        );
+        let Some(DataPoints::Histogram(points)) = &histogram.data_points else {
+            panic!("Expected histogram data points");
+        };
+        assert_eq!(points.len(), 1);
+        assert_eq!(
+            attribute_names(&points[0].attributes),
+            vec!["http.request.method", "http.response.status_code"]
+        );
+        assert_eq!(points[0].attributes[1].value, Some(json!(200)));
+        assert_eq!(points[0].count, 6);
+        assert_eq!(points[0].sum, Some(4.5));
+        assert_eq!(points[0].bucket_counts, vec![3, 2, 1]);
+        assert_eq!(points[0].explicit_bounds, vec![0.1, 1.0]);
+
+        let Sample::Metric(gauge) = &samples[2] else {
+            panic!("Expected a metric");
+        };
+        assert_eq!(gauge.name, "http.server.active_requests");
+        assert_eq!(gauge.unit, "{request}");
+        assert_eq!(
+            gauge.instrument,
+            SampleInstrument::Supported(InstrumentSpec::UpDownCounter)
+        );
+        let Some(DataPoints::Number(points)) = &gauge.data_points else {
+            panic!("Expected number data points");
+        };
+        assert_eq!(
+            attribute_names(&points[0].attributes),
+            vec!["http.request.method", "tenant"]
+        );
+        assert_eq!(points[0].value, json!(2));
+
+        let Sample::Metric(unknown) = &samples[3] else {
+            panic!("Expected a metric");
+        };
+        assert_eq!(unknown.name, "queue_depth");
+        assert_eq!(unknown.unit, "By");
+        let Some(DataPoints::Number(points)) = &unknown.data_points else {
+            panic!("Expected number data points");
+        };
+        assert_eq!(points[0].value, json!(12.5));
+    }
+
+    #[test]
+    fn test_counter_families() {
+        let exposition = r#"# TYPE requests counter
+requests_total{method="GET"} 10
+requests_created{method="GET"} 1700000000
+# TYPE errors_total counter
+errors_total 3
+"#;
+        let samples = ingest(exposition);
+        assert_eq!(samples.len(), 2);
+        let Sample::Metric(requests) = &samples[0] else {
+            panic!("Expected a metric");
+        };
+        assert_eq!(requests.name, "requests");
+        let Some(DataPoints::Number(points)) = &requests.data_points else {
+            panic!("Expected number data points");
+        };
+        assert_eq!(points.len(), 1);
+        let Sample::Metric(errors) = &samples[1] else {
+            panic!("Expected a metric");
+        };
+        assert_eq!(errors.name, "errors_total");
+        assert_eq!(
+            errors.instrument,
+            SampleInstrument::Supported(InstrumentSpec::Counter)
+        );
+    }
+
+    #[test]
+    fn test_parse_sample() {
+        let sample =
+            parse_sample(r#"{"http.server.duration","a.b"="x\"y",c="1,2}"} 1.5 1700000000"#)
+                .unwrap();
+        assert_eq!(sample.name, "http.server.duration");
+        assert_eq!(
+            sample.labels,
+            vec![
+                ("a.b".to_owned(), "x\"y".to_owned()),
+                ("c".to_owned(), "1,2}".to_owned())
+            ]
+        );
+        assert_eq!(sample.value, 1.5);
+
+        let sample = parse_sample("up +Inf").unwrap();
+        assert_eq!(sample.name, "up");
+        assert!(sample.value.is_infinite());
+
+        assert!(parse_sample("up").is_err());
+        assert!(parse_sample(r#"up{a="b" 1"#).is_err());
+        assert!(parse_sample("up abc").is_err());
+    }
+
+    #[test]
+    fn test_invalid_exposition() {
+        let temp_dir = tempdir().unwrap();
+        let file_path = temp_dir.path().join("metrics.prom");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "# TYPE up gauge").unwrap();
+        writeln!(file, "up not_a_number").unwrap();
+        let registry = VersionedRegistry::V1(Box::new(
+            serde_yaml::from_str::<ResolvedRegistry>(REGISTRY).unwrap(),
+        ));
+        let result = PrometheusIngester::from_file(&file_path, &registry).ingest();
+        if let Err(Error::IngestError { error }) = result {
+            assert!(error.contains("line 2"));
+        } else {
+            panic!("Expected IngestError");
+        }
+    }
+}
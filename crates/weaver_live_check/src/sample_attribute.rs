@@ -11,9 +11,11 @@ use weaver_checker::FindingLevel;
 use weaver_semconv::attribute::{AttributeType, PrimitiveOrArrayTypeSpec};
 
 use crate::{
-    advice::FindingBuilder, live_checker::LiveChecker, Error, LiveCheckResult, LiveCheckRunner,
-    LiveCheckStatistics, Sample, SampleRef, VersionedSignal, ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY,
-    MISSING_ATTRIBUTE_ADVICE_TYPE, TEMPLATE_ATTRIBUTE_ADVICE_TYPE,
+    advice::FindingBuilder, live_checker::LiveChecker, sample_scope::SampleScope, Error,
+    LiveCheckResult, LiveCheckRunner, LiveCheckStatistics, Sample, SampleRef, VersionedSignal,
+    ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY, MISSING_ATTRIBUTE_ADVICE_TYPE,
+    OUTDATED_SEMCONV_VERSION_ADVICE_TYPE, RENAMED_TO_ADVICE_CONTEXT_KEY,
+    SCHEMA_URL_ADVICE_CONTEXT_KEY, TEMPLATE_ATTRIBUTE_ADVICE_TYPE,
 };
 
 /// Represents a sample telemetry attribute parsed from any source
@@ -147,10 +149,21 @@ impl SampleAttribute {
         }
     }
 
-    fn update_stats(&mut self, stats: &mut LiveCheckStatistics) {
+    /// Returns the name of the attribute in the registry: its own name, or the name
+    /// it has been renamed to since the semconv version of the sample. The rename is
+    /// only known once the attribute has been checked.
+    #[must_use]
+    pub fn registry_name(&self) -> &str {
+        self.live_check_result
+            .as_ref()
+            .and_then(|result| result.renamed_to.as_deref())
+            .unwrap_or(&self.name)
+    }
+
+    fn update_stats(&mut self, stats: &mut LiveCheckStatistics, scope: Option<&SampleScope>) {
         stats.inc_entity_count("attribute");
-        stats.maybe_add_live_check_result(self.live_check_result.as_ref());
-        let mut seen_attribute_name = self.name.clone();
+        stats.maybe_add_live_check_result(self.live_check_result.as_ref(), scope);
+        let mut seen_attribute_name = self.registry_name().to_owned();
         if let Some(result) = &mut self.live_check_result {
             for advice in &mut result.all_advice {
                 // If the advice is a template, adjust the name
@@ -179,13 +192,50 @@ impl LiveCheckRunner for SampleAttribute {
     ) -> Result<(), Error> {
         let mut result = LiveCheckResult::new();
         // find the attribute in the registry
-        let semconv_attribute = {
+        let mut semconv_attribute = {
             if let Some(attribute) = live_checker.find_attribute(&self.name) {
                 Some(attribute)
             } else {
                 live_checker.find_template(&self.name)
             }
         };
+        // the attribute may have been renamed since the semconv version of the sample
+        let renamed_to = if semconv_attribute.is_none() {
+            live_checker.find_renamed_attribute(&self.name, parent_signal)
+        } else {
+            None
+        };
+        if let Some(renamed_to) = &renamed_to {
+            semconv_attribute = live_checker
+                .find_attribute(renamed_to)
+                .or_else(|| live_checker.find_template(renamed_to));
+            let schema_url = parent_signal.schema_url().unwrap_or_default();
+            let sample_ref = SampleRef::Attribute(self);
+            let finding = FindingBuilder::new(OUTDATED_SEMCONV_VERSION_ADVICE_TYPE)
+                .context(json!({
+                    ATTRIBUTE_NAME_ADVICE_CONTEXT_KEY: self.name.clone(),
+                    RENAMED_TO_ADVICE_CONTEXT_KEY: renamed_to,
+                    SCHEMA_URL_ADVICE_CONTEXT_KEY: schema_url,
+                }))
+                .message(format!(
+                    "Attribute '{}' has been renamed to '{renamed_to}' since the semantic conventions version of the sample ({schema_url}).",
+                    self.name
+                ))
+                .level(FindingLevel::Violation)
+                .signal(parent_signal)
+                .build_and_emit(
+                    &sample_ref,
+                    live_checker.otlp_emitter.as_ref().map(|rc| rc.as_ref()),
+                    parent_signal,
+                );
+
+            result.add_advice(
+                finding,
+                live_checker.finding_modifier.as_ref(),
+                &sample_ref,
+                parent_signal,
+            );
+        }
         if semconv_attribute.is_none() {
             let sample_ref = SampleRef::Attribute(self);
             let finding = FindingBuilder::new(MISSING_ATTRIBUTE_ADVICE_TYPE)
@@ -202,7 +252,12 @@ impl LiveCheckRunner for SampleAttribute {
                     parent_signal,
                 );
 
-            result.add_advice(finding, live_checker.finding_modifier.as_ref(), &sample_ref);
+            result.add_advice(
+                finding,
+                live_checker.finding_modifier.as_ref(),
+                &sample_ref,
+                parent_signal,
+            );
         } else {
             // Provide an info advice if the attribute is a template
             if let Some(attribute) = &semconv_attribute {
@@ -219,7 +274,12 @@ impl LiveCheckRunner for SampleAttribute {
                             parent_signal,
                         );
 
-                    result.add_advice(finding, live_checker.finding_modifier.as_ref(), &sample_ref);
+                    result.add_advice(
+                        finding,
+                        live_checker.finding_modifier.as_ref(),
+                        &sample_ref,
+                        parent_signal,
+                    );
                 }
             }
         }
@@ -238,10 +298,12 @@ impl LiveCheckRunner for SampleAttribute {
                 advice_list,
                 live_checker.finding_modifier.as_ref(),
                 &sample_ref,
+                parent_signal,
             );
         }
+        result.renamed_to = renamed_to;
         self.live_check_result = Some(result);
-        self.update_stats(stats);
+        self.update_stats(stats, parent_signal.scope());
         Ok(())
     }
 }
@@ -2,16 +2,18 @@
 
 //! Intermediary format for telemetry sample logd
 
-use std::rc::Rc;
+use std::{rc::Rc, sync::Arc};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use weaver_checker::FindingLevel;
 
 use crate::{
     advice::FindingBuilder, live_checker::LiveChecker, sample_attribute::SampleAttribute,
-    sample_resource::SampleResource, Error, LiveCheckResult, LiveCheckRunner, LiveCheckStatistics,
-    Sample, SampleRef, VersionedSignal, MISSING_EVENT_ADVICE_TYPE,
+    sample_resource::SampleResource, sample_scope::SampleScope, Error, LiveCheckResult,
+    LiveCheckRunner, LiveCheckStatistics, Sample, SampleRef, VersionedSignal,
+    MISSING_EVENT_ADVICE_TYPE,
 };
 
 /// Represents a sample telemetry log parsed from any source
@@ -23,8 +25,9 @@ pub struct SampleLog {
     pub severity_number: Option<i32>,
     /// Severity text (e.g., "INFO", "ERROR")
     pub severity_text: Option<String>,
-    /// Body of the event (from the log record body)
-    pub body: Option<String>,
+    /// Body of the event (from the log record body), structured bodies are
+    /// kept as JSON maps and arrays
+    pub body: Option<Value>,
     /// The event's attributes
     #[serde(default)]
     pub attributes: Vec<SampleAttribute>,
@@ -32,11 +35,18 @@ pub struct SampleLog {
     pub trace_id: Option<String>,
     /// Span ID if the event is correlated with a span
     pub span_id: Option<String>,
+    /// The schema URL of the semantic conventions the log was produced with,
+    /// taken from its instrumentation scope or resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_url: Option<String>,
     /// Live check result
     pub live_check_result: Option<LiveCheckResult>,
     /// Reference to the parent resource (not serialized)
     #[serde(skip)]
-    pub resource: Option<Rc<SampleResource>>,
+    pub resource: Option<Arc<SampleResource>>,
+    /// The instrumentation scope that produced the signal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Arc<SampleScope>>,
 }
 
 impl LiveCheckRunner for SampleLog {
@@ -68,10 +78,22 @@ impl LiveCheckRunner for SampleLog {
                         parent_signal,
                     );
                 let sample_ref = SampleRef::Log(self);
-                result.add_advice(finding, live_checker.finding_modifier.as_ref(), &sample_ref);
+                result.add_advice(
+                    finding,
+                    live_checker.finding_modifier.as_ref(),
+                    &sample_ref,
+                    parent_signal,
+                );
             };
             semconv_event
         };
+        // Check attributes first, so the advisors know their names in the registry
+        self.attributes.run_live_check(
+            live_checker,
+            stats,
+            semconv_event.clone(),
+            parent_signal,
+        )?;
         for advisor in live_checker.advisors.iter_mut() {
             let sample_ref = SampleRef::Log(self);
             let advice_list = advisor.advise(
@@ -85,19 +107,13 @@ impl LiveCheckRunner for SampleLog {
                 advice_list,
                 live_checker.finding_modifier.as_ref(),
                 &sample_ref,
+                parent_signal,
             );
         }
-        // Check attributes
-        self.attributes.run_live_check(
-            live_checker,
-            stats,
-            semconv_event.clone(),
-            parent_signal,
-        )?;
 
         self.live_check_result = Some(result);
         stats.inc_entity_count("log");
-        stats.maybe_add_live_check_result(self.live_check_result.as_ref());
+        stats.maybe_add_live_check_result(self.live_check_result.as_ref(), parent_signal.scope());
         stats.add_event_name_to_coverage(self.event_name.clone());
         Ok(())
     }
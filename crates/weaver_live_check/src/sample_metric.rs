@@ -2,18 +2,21 @@
 
 //! Intermediary format for telemetry sample spans
 
-use std::rc::Rc;
+use std::{rc::Rc, sync::Arc};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Value};
 use weaver_checker::FindingLevel;
 use weaver_semconv::group::InstrumentSpec;
 
 use crate::{
     advice::FindingBuilder, live_checker::LiveChecker, sample_attribute::SampleAttribute,
-    sample_resource::SampleResource, Advisable, Error, LiveCheckResult, LiveCheckRunner,
-    LiveCheckStatistics, Sample, SampleRef, VersionedSignal, MISSING_METRIC_ADVICE_TYPE,
+    sample_resource::SampleResource, sample_scope::SampleScope, Advisable, Error, LiveCheckResult,
+    LiveCheckRunner, LiveCheckStatistics, Sample, SampleRef, VersionedSignal,
+    METRIC_NAME_ADVICE_CONTEXT_KEY, MISSING_METRIC_ADVICE_TYPE,
+    OUTDATED_SEMCONV_VERSION_ADVICE_TYPE, RENAMED_TO_ADVICE_CONTEXT_KEY,
+    SCHEMA_URL_ADVICE_CONTEXT_KEY,
 };
 
 /// Represents the instrument type of a metric
@@ -64,6 +67,10 @@ impl Advisable for SampleNumberDataPoint {
     fn entity_type(&self) -> &str {
         "data_point"
     }
+
+    fn cardinality_attributes(&self) -> &[SampleAttribute] {
+        &self.attributes
+    }
 }
 
 impl LiveCheckRunner for SampleNumberDataPoint {
@@ -74,10 +81,11 @@ impl LiveCheckRunner for SampleNumberDataPoint {
         parent_group: Option<Rc<VersionedSignal>>,
         parent_signal: &Sample,
     ) -> Result<(), Error> {
-        self.live_check_result =
-            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
+        // Attributes are checked first, so the advisors know their names in the registry
         self.attributes
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
+        self.live_check_result =
+            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
         self.exemplars
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
         Ok(())
@@ -124,6 +132,10 @@ impl Advisable for SampleHistogramDataPoint {
     fn entity_type(&self) -> &str {
         "data_point"
     }
+
+    fn cardinality_attributes(&self) -> &[SampleAttribute] {
+        &self.attributes
+    }
 }
 
 impl LiveCheckRunner for SampleHistogramDataPoint {
@@ -134,10 +146,11 @@ impl LiveCheckRunner for SampleHistogramDataPoint {
         parent_group: Option<Rc<VersionedSignal>>,
         parent_signal: &Sample,
     ) -> Result<(), Error> {
-        self.live_check_result =
-            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
+        // Attributes are checked first, so the advisors know their names in the registry
         self.attributes
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
+        self.live_check_result =
+            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
         self.exemplars
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
         Ok(())
@@ -199,6 +212,10 @@ impl Advisable for SampleExponentialHistogramDataPoint {
     fn entity_type(&self) -> &str {
         "data_point"
     }
+
+    fn cardinality_attributes(&self) -> &[SampleAttribute] {
+        &self.attributes
+    }
 }
 
 impl LiveCheckRunner for SampleExponentialHistogramDataPoint {
@@ -209,10 +226,11 @@ impl LiveCheckRunner for SampleExponentialHistogramDataPoint {
         parent_group: Option<Rc<VersionedSignal>>,
         parent_signal: &Sample,
     ) -> Result<(), Error> {
-        self.live_check_result =
-            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
+        // Attributes are checked first, so the advisors know their names in the registry
         self.attributes
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
+        self.live_check_result =
+            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
         self.exemplars
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
         Ok(())
@@ -284,11 +302,18 @@ pub struct SampleMetric {
     pub unit: String,
     /// Data points of the metric.
     pub data_points: Option<DataPoints>,
+    /// The schema URL of the semantic conventions the metric was produced with,
+    /// taken from its instrumentation scope or resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_url: Option<String>,
     /// Live check result
     pub live_check_result: Option<LiveCheckResult>,
     /// Reference to the parent resource (not serialized)
     #[serde(skip)]
-    pub resource: Option<Rc<SampleResource>>,
+    pub resource: Option<Arc<SampleResource>>,
+    /// The instrumentation scope that produced the signal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Arc<SampleScope>>,
 }
 
 impl LiveCheckRunner for SampleMetric {
@@ -301,7 +326,42 @@ impl LiveCheckRunner for SampleMetric {
     ) -> Result<(), Error> {
         let mut result = LiveCheckResult::new();
         // find the metric in the registry
-        let semconv_metric = live_checker.find_metric(&self.name);
+        let mut semconv_metric = live_checker.find_metric(&self.name);
+        // the metric may have been renamed since the semconv version of the sample
+        let renamed_to = if semconv_metric.is_none() {
+            live_checker.find_renamed_metric(&self.name, parent_signal)
+        } else {
+            None
+        };
+        if let Some(renamed_to) = &renamed_to {
+            semconv_metric = live_checker.find_metric(renamed_to);
+            let schema_url = parent_signal.schema_url().unwrap_or_default();
+            let finding = FindingBuilder::new(OUTDATED_SEMCONV_VERSION_ADVICE_TYPE)
+                .context(json!({
+                    METRIC_NAME_ADVICE_CONTEXT_KEY: self.name.clone(),
+                    RENAMED_TO_ADVICE_CONTEXT_KEY: renamed_to,
+                    SCHEMA_URL_ADVICE_CONTEXT_KEY: schema_url,
+                }))
+                .message(format!(
+                    "Metric '{}' has been renamed to '{renamed_to}' since the semantic conventions version of the sample ({schema_url}).",
+                    self.name
+                ))
+                .level(FindingLevel::Violation)
+                .signal(parent_signal)
+                .build_and_emit(
+                    &SampleRef::Metric(self),
+                    live_checker.otlp_emitter.as_ref().map(|rc| rc.as_ref()),
+                    parent_signal,
+                );
+
+            let sample_ref = SampleRef::Metric(self);
+            result.add_advice(
+                finding,
+                live_checker.finding_modifier.as_ref(),
+                &sample_ref,
+                parent_signal,
+            );
+        }
         if semconv_metric.is_none() {
             let finding = FindingBuilder::new(MISSING_METRIC_ADVICE_TYPE)
                 .message("Metric does not exist in the registry.")
@@ -314,7 +374,12 @@ impl LiveCheckRunner for SampleMetric {
                 );
 
             let sample_ref = SampleRef::Metric(self);
-            result.add_advice(finding, live_checker.finding_modifier.as_ref(), &sample_ref);
+            result.add_advice(
+                finding,
+                live_checker.finding_modifier.as_ref(),
+                &sample_ref,
+                parent_signal,
+            );
         };
         for advisor in live_checker.advisors.iter_mut() {
             let sample_ref = SampleRef::Metric(self);
@@ -329,6 +394,7 @@ impl LiveCheckRunner for SampleMetric {
                 advice_list,
                 live_checker.finding_modifier.as_ref(),
                 &sample_ref,
+                parent_signal,
             );
         }
         // Get advice for the data points
@@ -362,8 +428,8 @@ impl LiveCheckRunner for SampleMetric {
 
         self.live_check_result = Some(result);
         stats.inc_entity_count("metric");
-        stats.maybe_add_live_check_result(self.live_check_result.as_ref());
-        stats.add_metric_name_to_coverage(self.name.clone());
+        stats.maybe_add_live_check_result(self.live_check_result.as_ref(), parent_signal.scope());
+        stats.add_metric_name_to_coverage(renamed_to.unwrap_or_else(|| self.name.clone()));
         Ok(())
     }
 }
@@ -18,6 +18,9 @@ pub struct SampleResource {
     /// The attributes of the resource
     #[serde(default)]
     pub attributes: Vec<SampleAttribute>,
+    /// The schema URL of the semantic conventions the resource was produced with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_url: Option<String>,
     /// Live check result
     pub live_check_result: Option<LiveCheckResult>,
 }
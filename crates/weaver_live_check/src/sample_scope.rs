@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Intermediary format for telemetry sample instrumentation scopes
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::sample_attribute::SampleAttribute;
+
+/// Represents the instrumentation scope (library) that produced a signal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SampleScope {
+    /// The name of the instrumentation scope
+    #[serde(default)]
+    pub name: String,
+    /// The version of the instrumentation scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The schema URL of the semantic conventions the instrumentation scope uses
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_url: Option<String>,
+    /// The attributes of the instrumentation scope
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<SampleAttribute>,
+}
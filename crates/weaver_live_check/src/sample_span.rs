@@ -2,16 +2,20 @@
 
 //! Intermediary format for telemetry sample spans
 
-use std::rc::Rc;
+use std::{rc::Rc, sync::Arc};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use weaver_checker::FindingLevel;
 use weaver_semconv::group::SpanKindSpec;
 
 use crate::{
-    live_checker::LiveChecker, sample_attribute::SampleAttribute, sample_resource::SampleResource,
-    Advisable, Error, LiveCheckResult, LiveCheckRunner, LiveCheckStatistics, Sample, SampleRef,
-    VersionedSignal,
+    advice::FindingBuilder, live_checker::LiveChecker, sample_attribute::SampleAttribute,
+    sample_resource::SampleResource, sample_scope::SampleScope, Advisable, Error, LiveCheckResult,
+    LiveCheckRunner, LiveCheckStatistics, Sample, SampleRef, VersionedSignal,
+    EXPECTED_VALUE_ADVICE_CONTEXT_KEY, SPAN_NAME_ADVICE_CONTEXT_KEY,
+    SPAN_NAME_MISMATCH_ADVICE_TYPE, SPAN_TYPE_ADVICE_CONTEXT_KEY,
 };
 
 /// The status code of the span
@@ -53,11 +57,27 @@ pub struct SampleSpan {
     /// SpanLinks
     #[serde(default)]
     pub span_links: Vec<SampleSpanLink>,
+    /// The schema URL of the semantic conventions the span was produced with,
+    /// taken from its instrumentation scope or resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_url: Option<String>,
     /// Live check result
     pub live_check_result: Option<LiveCheckResult>,
     /// Reference to the parent resource (not serialized)
     #[serde(skip)]
-    pub resource: Option<Rc<SampleResource>>,
+    pub resource: Option<Arc<SampleResource>>,
+    /// The instrumentation scope that produced the signal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Arc<SampleScope>>,
+    /// The ID of the trace the span belongs to, as a hex string
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+    /// The ID of the span, as a hex string
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span_id: Option<String>,
+    /// The ID of the parent span, as a hex string. None for a root span.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_span_id: Option<String>,
 }
 
 impl Advisable for SampleSpan {
@@ -70,18 +90,74 @@ impl Advisable for SampleSpan {
     }
 }
 
-impl LiveCheckRunner for SampleSpan {
-    fn run_live_check(
+impl SampleSpan {
+    /// Returns a copy of the span without its attributes, events and links, to be
+    /// passed as the parent signal of its own checks.
+    pub(crate) fn parent_signal(&mut self) -> Sample {
+        let attributes = std::mem::take(&mut self.attributes);
+        let span_events = std::mem::take(&mut self.span_events);
+        let span_links = std::mem::take(&mut self.span_links);
+        let parent_signal = Sample::Span(self.clone());
+        self.attributes = attributes;
+        self.span_events = span_events;
+        self.span_links = span_links;
+        parent_signal
+    }
+
+    /// Checks the span, and returns the span of the registry it was matched to by
+    /// its name, if any.
+    pub(crate) fn check(
         &mut self,
         live_checker: &mut LiveChecker,
         stats: &mut LiveCheckStatistics,
         parent_group: Option<Rc<VersionedSignal>>,
         parent_signal: &Sample,
-    ) -> Result<(), Error> {
-        self.live_check_result =
-            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
+    ) -> Result<Option<Rc<VersionedSignal>>, Error> {
+        let mut result = LiveCheckResult::new();
+        // Match the span to a span type of the registry by its name
+        let matched_span = live_checker
+            .find_span(self)
+            .map(|(span, expected_name)| {
+                if let (Some(expected_name), Some(span_type)) = (expected_name, span.span_type())
+                {
+                    let finding = FindingBuilder::new(SPAN_NAME_MISMATCH_ADVICE_TYPE)
+                        .context(json!({
+                            SPAN_NAME_ADVICE_CONTEXT_KEY: self.name,
+                            SPAN_TYPE_ADVICE_CONTEXT_KEY: span_type,
+                            EXPECTED_VALUE_ADVICE_CONTEXT_KEY: expected_name,
+                        }))
+                        .message(format!(
+                            "Span name '{}' does not follow the name template of span '{}'; expected '{}'.",
+                            self.name, span_type, expected_name
+                        ))
+                        .level(FindingLevel::Violation)
+                        .signal(parent_signal)
+                        .build_and_emit(
+                            &SampleRef::Span(self),
+                            live_checker.otlp_emitter.as_ref().map(|rc| rc.as_ref()),
+                            parent_signal,
+                        );
+                    let sample_ref = SampleRef::Span(self);
+                    result.add_advice(
+                        finding,
+                        live_checker.finding_modifier.as_ref(),
+                        &sample_ref,
+                        parent_signal,
+                    );
+                }
+                span
+            });
+        let semconv_span = matched_span.clone().or_else(|| parent_group.clone());
+        // Attributes are checked first, so the advisors know their names in the registry
         self.attributes
-            .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
+            .run_live_check(live_checker, stats, semconv_span.clone(), parent_signal)?;
+        self.live_check_result = Some(self.run_advisors_with(
+            result,
+            live_checker,
+            stats,
+            semconv_span,
+            parent_signal,
+        )?);
         self.span_events.run_live_check(
             live_checker,
             stats,
@@ -90,6 +166,19 @@ impl LiveCheckRunner for SampleSpan {
         )?;
         self.span_links
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
+        Ok(matched_span)
+    }
+}
+
+impl LiveCheckRunner for SampleSpan {
+    fn run_live_check(
+        &mut self,
+        live_checker: &mut LiveChecker,
+        stats: &mut LiveCheckStatistics,
+        parent_group: Option<Rc<VersionedSignal>>,
+        parent_signal: &Sample,
+    ) -> Result<(), Error> {
+        let _ = self.check(live_checker, stats, parent_group, parent_signal)?;
         Ok(())
     }
 }
@@ -124,10 +213,11 @@ impl LiveCheckRunner for SampleSpanEvent {
         parent_group: Option<Rc<VersionedSignal>>,
         parent_signal: &Sample,
     ) -> Result<(), Error> {
-        self.live_check_result =
-            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
+        // Attributes are checked first, so the advisors know their names in the registry
         self.attributes
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
+        self.live_check_result =
+            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
         Ok(())
     }
 }
@@ -160,10 +250,11 @@ impl LiveCheckRunner for SampleSpanLink {
         parent_group: Option<Rc<VersionedSignal>>,
         parent_signal: &Sample,
     ) -> Result<(), Error> {
-        self.live_check_result =
-            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
+        // Attributes are checked first, so the advisors know their names in the registry
         self.attributes
             .run_live_check(live_checker, stats, parent_group.clone(), parent_signal)?;
+        self.live_check_result =
+            Some(self.run_advisors(live_checker, stats, parent_group.clone(), parent_signal)?);
         Ok(())
     }
 }
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Intermediary format for telemetry sample traces
+
+use std::rc::Rc;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    live_checker::LiveChecker, sample_span::SampleSpan, Error, LiveCheckResult, LiveCheckRunner,
+    LiveCheckStatistics, Sample, SampleRef, VersionedSignal,
+};
+
+/// Represents a sample trace: the spans sharing a trace ID, assembled by the ingester
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SampleTrace {
+    /// The ID of the trace, as a hex string
+    pub trace_id: String,
+    /// The spans of the trace, in the order they were received
+    #[serde(default)]
+    pub spans: Vec<SampleSpan>,
+    /// Live check result on the structure of the trace
+    pub live_check_result: Option<LiveCheckResult>,
+}
+
+impl SampleTrace {
+    /// Create an empty trace with the given ID
+    #[must_use]
+    pub fn new(trace_id: String) -> Self {
+        SampleTrace {
+            trace_id,
+            spans: Vec::new(),
+            live_check_result: None,
+        }
+    }
+}
+
+impl LiveCheckRunner for SampleTrace {
+    fn run_live_check(
+        &mut self,
+        live_checker: &mut LiveChecker,
+        stats: &mut LiveCheckStatistics,
+        _parent_group: Option<Rc<VersionedSignal>>,
+        parent_signal: &Sample,
+    ) -> Result<(), Error> {
+        // Each span is checked as a signal of its own, and matched to a span of the
+        // registry for the trace advisors
+        let mut span_signals = Vec::with_capacity(self.spans.len());
+        for span in self.spans.iter_mut() {
+            let span_signal = span.parent_signal();
+            span_signals.push(span.check(live_checker, stats, None, &span_signal)?);
+        }
+
+        let mut result = LiveCheckResult::new();
+        for advisor in live_checker.trace_advisors.iter_mut() {
+            for finding in advisor.advise(self, &span_signals)? {
+                let finding = finding.signal(parent_signal).build_and_emit(
+                    &SampleRef::Trace(self),
+                    live_checker.otlp_emitter.as_deref(),
+                    parent_signal,
+                );
+                result.add_advice(
+                    finding,
+                    live_checker.finding_modifier.as_ref(),
+                    &SampleRef::Trace(self),
+                    parent_signal,
+                );
+            }
+        }
+        self.live_check_result = Some(result);
+        stats.inc_entity_count("trace");
+        stats.maybe_add_live_check_result(self.live_check_result.as_ref(), parent_signal.scope());
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Renamings of telemetry between versions of the semantic conventions.
+//!
+//! Telemetry produced with an older version of the conventions may use names that
+//! have since been renamed. When a sample carries a schema URL, its names are
+//! translated forward with the renamings of the later versions before being checked.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use weaver_semconv::deprecated::Deprecated;
+use weaver_semconv::group::GroupType;
+use weaver_semconv::schema_url::SchemaUrl;
+use weaver_version::upgrade::Upgrade;
+use weaver_version::{VersionSpec, Versions};
+
+use crate::{Error, VersionedRegistry};
+
+/// The renamings between the versions of a semantic convention registry, loaded from
+/// OpenTelemetry schema files and historical versions of the registry.
+#[derive(Clone, Default)]
+pub struct SchemaHistory {
+    versions: Versions,
+}
+
+impl SchemaHistory {
+    /// Create an empty history.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the versions of an OpenTelemetry schema file.
+    pub fn add_schema_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+        let versions = Versions::load_from_file(path).map_err(|e| Error::ConfigError {
+            error: e.to_string(),
+        })?;
+        self.versions.extend(versions);
+        Ok(())
+    }
+
+    /// Add the renamings recorded by a historical version of the registry, i.e. its
+    /// attributes and metrics deprecated with `renamed_to`. They are considered to
+    /// happen in the version of the registry, given by its schema URL.
+    pub fn add_registry(
+        &mut self,
+        schema_url: &str,
+        registry: &VersionedRegistry,
+    ) -> Result<(), Error> {
+        let version = schema_version(schema_url).ok_or_else(|| Error::ConfigError {
+            error: format!(
+                "The version of the historical registry `{schema_url}` is not a semantic version."
+            ),
+        })?;
+
+        let mut attributes = BTreeMap::new();
+        let mut metrics = BTreeMap::new();
+        let add_rename = |renames: &mut BTreeMap<String, String>,
+                          name: &str,
+                          deprecated: &Option<Deprecated>| {
+            if let Some(Deprecated::Renamed { renamed_to, .. }) = deprecated {
+                _ = renames.insert(name.to_owned(), renamed_to.clone());
+            }
+        };
+        match registry {
+            VersionedRegistry::V1(registry) => {
+                for group in &registry.groups {
+                    if group.r#type == GroupType::Metric {
+                        if let Some(metric_name) = &group.metric_name {
+                            add_rename(&mut metrics, metric_name, &group.deprecated);
+                        }
+                    }
+                    for attribute in &group.attributes {
+                        add_rename(&mut attributes, &attribute.name, &attribute.deprecated);
+                    }
+                }
+            }
+            VersionedRegistry::V2(registry) => {
+                for metric in &registry.registry.metrics {
+                    add_rename(&mut metrics, &metric.name, &metric.common.deprecated);
+                }
+                for attribute in &registry.registry.attributes {
+                    add_rename(
+                        &mut attributes,
+                        &attribute.key,
+                        &attribute.common.deprecated,
+                    );
+                }
+            }
+        }
+
+        self.versions
+            .insert(version, VersionSpec::from_renames(attributes, metrics));
+        Ok(())
+    }
+
+    /// Returns true if no renamings are known.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.versions.is_empty()
+    }
+
+    /// Returns the upgrade of telemetry produced with the given schema URL to the
+    /// latest known version, or None if the schema URL has no semantic version or no
+    /// known version is later than it.
+    #[must_use]
+    pub fn upgrade(&self, schema_url: &str) -> Option<Upgrade<'_>> {
+        let version = schema_version(schema_url)?;
+        let latest = self.versions.latest_version()?;
+        let upgrade = self.versions.upgrade(&version.into(), &latest);
+        (!upgrade.is_empty()).then_some(upgrade)
+    }
+}
+
+/// Returns the semantic conventions version of a schema URL
+/// (e.g. `1.26.0` for `https://opentelemetry.io/schemas/1.26.0`).
+pub(crate) fn schema_version(schema_url: &str) -> Option<semver::Version> {
+    let schema_url = SchemaUrl::try_from(schema_url).ok()?;
+    semver::Version::parse(schema_url.version()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_version() {
+        assert_eq!(
+            schema_version("https://opentelemetry.io/schemas/1.26.0"),
+            Some(semver::Version::new(1, 26, 0))
+        );
+        assert_eq!(
+            schema_version("https://opentelemetry.io/schemas/latest"),
+            None
+        );
+        assert_eq!(schema_version("not a url"), None);
+    }
+
+    #[test]
+    fn test_upgrade() {
+        let mut history = SchemaHistory::new();
+        assert!(history.is_empty());
+        history.add_schema_file("data/schema-history.yaml").unwrap();
+        assert!(!history.is_empty());
+
+        let upgrade = history
+            .upgrade("https://opentelemetry.io/schemas/1.20.0")
+            .unwrap();
+        assert_eq!(
+            upgrade.span_attribute("GET", "http.method"),
+            "http.request.method"
+        );
+        assert_eq!(
+            upgrade.metric_name("system.memory.used"),
+            "system.memory.usage"
+        );
+
+        let upgrade = history
+            .upgrade("https://opentelemetry.io/schemas/1.21.0")
+            .unwrap();
+        assert_eq!(upgrade.span_attribute("GET", "http.method"), "http.method");
+
+        assert!(history
+            .upgrade("https://opentelemetry.io/schemas/latest")
+            .is_none());
+        assert!(history
+            .upgrade("https://opentelemetry.io/schemas/1.26.0")
+            .is_none());
+    }
+}
@@ -8,8 +8,13 @@
 
 use serde::Serialize;
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
 
-use crate::{FindingLevel, LiveCheckResult, PolicyFinding, VersionedRegistry};
+use crate::{
+    advice::RegoStatistics, sample_scope::SampleScope, FindingLevel, LiveCheckResult,
+    PolicyFinding, VersionedRegistry,
+};
 use weaver_semconv::group::GroupType;
 
 /// Cumulative statistics that track all telemetry data
@@ -31,6 +36,9 @@ pub struct CumulativeStatistics {
     pub(crate) advice_type_counts: HashMap<String, usize>,
     /// The number of entities with each advice message
     pub(crate) advice_message_counts: HashMap<String, usize>,
+    /// The number of advisories by instrumentation scope (library) name, for the
+    /// samples produced by a known scope
+    pub(crate) advice_scope_counts: HashMap<String, usize>,
     /// The number of each attribute seen from the registry
     pub(crate) seen_registry_attributes: HashMap<String, usize>,
     /// The number of each non-registry attribute seen
@@ -45,6 +53,55 @@ pub struct CumulativeStatistics {
     pub(crate) seen_non_registry_events: HashMap<String, usize>,
     /// Fraction of the registry covered by the attributes, metrics, and events
     pub(crate) registry_coverage: f32,
+    /// The estimated number of distinct values of each attribute by metric
+    pub(crate) metric_attribute_cardinality: HashMap<String, HashMap<String, u64>>,
+    /// The estimated number of distinct values of each metric attribute across all metrics
+    pub(crate) attribute_cardinality: HashMap<String, u64>,
+    /// The throughput of the session, when measured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) throughput: Option<ThroughputStatistics>,
+}
+
+/// Throughput of a live check session
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThroughputStatistics {
+    /// The number of samples checked
+    pub(crate) samples: usize,
+    /// The time spent checking the samples, summed over the workers, in seconds
+    pub(crate) checking_seconds: f64,
+    /// The number of samples the workers check per second
+    pub(crate) samples_per_second: f64,
+    /// The number of workers checking the samples in parallel
+    pub(crate) workers: usize,
+    /// The evaluations of the rego policies
+    pub(crate) rego: RegoStatistics,
+}
+
+impl ThroughputStatistics {
+    /// Create the throughput statistics of `samples` checked by `workers` in
+    /// `checking_time`, summed over the workers. The time spent waiting for samples
+    /// is not included.
+    #[must_use]
+    pub fn new(
+        samples: usize,
+        checking_time: Duration,
+        workers: usize,
+        rego: RegoStatistics,
+    ) -> Self {
+        let checking_seconds = checking_time.as_secs_f64();
+        let samples_per_second = if checking_seconds > 0.0 {
+            samples as f64 * workers as f64 / checking_seconds
+        } else {
+            0.0
+        };
+        ThroughputStatistics {
+            samples,
+            checking_seconds,
+            samples_per_second,
+            workers,
+            rego,
+        }
+    }
 }
 
 impl CumulativeStatistics {
@@ -62,6 +119,7 @@ impl CumulativeStatistics {
             no_advice_count: 0,
             advice_type_counts: HashMap::new(),
             advice_message_counts: HashMap::new(),
+            advice_scope_counts: HashMap::new(),
             seen_registry_attributes: seen_attributes,
             seen_non_registry_attributes: HashMap::new(),
             seen_registry_metrics: seen_metrics,
@@ -69,6 +127,9 @@ impl CumulativeStatistics {
             seen_registry_events: seen_events,
             seen_non_registry_events: HashMap::new(),
             registry_coverage: 0.0,
+            metric_attribute_cardinality: HashMap::new(),
+            attribute_cardinality: HashMap::new(),
+            throughput: None,
         }
     }
 
@@ -135,8 +196,14 @@ impl CumulativeStatistics {
         self.total_entities += 1;
     }
 
-    /// Add an advice to the statistics
-    pub(crate) fn add_advice(&mut self, advice: &PolicyFinding) {
+    /// Add an advice to the statistics, given the scope of the sample it was given on
+    pub(crate) fn add_advice(&mut self, advice: &PolicyFinding, scope: Option<&SampleScope>) {
+        if let Some(scope) = scope {
+            *self
+                .advice_scope_counts
+                .entry(scope.name.clone())
+                .or_insert(0) += 1;
+        }
         *self.advice_level_counts.entry(advice.level).or_insert(0) += 1;
         *self
             .advice_type_counts
@@ -205,6 +272,85 @@ impl CumulativeStatistics {
         }
     }
 
+    /// Set the estimated number of distinct values of a metric attribute
+    pub(crate) fn set_cardinality(
+        &mut self,
+        metric_name: &str,
+        attribute_name: &str,
+        metric_cardinality: u64,
+        attribute_cardinality: u64,
+    ) {
+        let _ = self
+            .metric_attribute_cardinality
+            .entry(metric_name.to_owned())
+            .or_default()
+            .insert(attribute_name.to_owned(), metric_cardinality);
+        let _ = self
+            .attribute_cardinality
+            .insert(attribute_name.to_owned(), attribute_cardinality);
+    }
+
+    /// Add the counts of other statistics, gathered on the same registry
+    pub(crate) fn merge(&mut self, other: CumulativeStatistics) {
+        fn add_counts<K: Eq + Hash>(counts: &mut HashMap<K, usize>, other: HashMap<K, usize>) {
+            for (key, count) in other {
+                *counts.entry(key).or_insert(0) += count;
+            }
+        }
+
+        self.total_entities += other.total_entities;
+        add_counts(
+            &mut self.total_entities_by_type,
+            other.total_entities_by_type,
+        );
+        self.total_advisories += other.total_advisories;
+        add_counts(&mut self.advice_level_counts, other.advice_level_counts);
+        add_counts(
+            &mut self.highest_advice_level_counts,
+            other.highest_advice_level_counts,
+        );
+        self.no_advice_count += other.no_advice_count;
+        add_counts(&mut self.advice_type_counts, other.advice_type_counts);
+        add_counts(&mut self.advice_message_counts, other.advice_message_counts);
+        add_counts(&mut self.advice_scope_counts, other.advice_scope_counts);
+        add_counts(
+            &mut self.seen_registry_attributes,
+            other.seen_registry_attributes,
+        );
+        add_counts(
+            &mut self.seen_non_registry_attributes,
+            other.seen_non_registry_attributes,
+        );
+        add_counts(&mut self.seen_registry_metrics, other.seen_registry_metrics);
+        add_counts(
+            &mut self.seen_non_registry_metrics,
+            other.seen_non_registry_metrics,
+        );
+        add_counts(&mut self.seen_registry_events, other.seen_registry_events);
+        add_counts(
+            &mut self.seen_non_registry_events,
+            other.seen_non_registry_events,
+        );
+        // The estimates only grow over a session, the largest one is the latest
+        for (metric_name, attributes) in other.metric_attribute_cardinality {
+            let cardinality = self
+                .metric_attribute_cardinality
+                .entry(metric_name)
+                .or_default();
+            for (attribute_name, estimate) in attributes {
+                let current = cardinality.entry(attribute_name).or_insert(0);
+                *current = (*current).max(estimate);
+            }
+        }
+        for (attribute_name, estimate) in other.attribute_cardinality {
+            let current = self
+                .attribute_cardinality
+                .entry(attribute_name)
+                .or_insert(0);
+            *current = (*current).max(estimate);
+        }
+    }
+
     /// Are there any violations in the statistics?
     pub(crate) fn has_violations(&self) -> bool {
         self.highest_advice_level_counts
@@ -265,13 +411,18 @@ pub enum LiveCheckStatistics {
 }
 
 impl LiveCheckStatistics {
-    /// Add a live check result to the stats
-    pub fn maybe_add_live_check_result(&mut self, live_check_result: Option<&LiveCheckResult>) {
+    /// Add a live check result to the stats. `scope` is the instrumentation scope of
+    /// the sample the result is for, when known.
+    pub fn maybe_add_live_check_result(
+        &mut self,
+        live_check_result: Option<&LiveCheckResult>,
+        scope: Option<&SampleScope>,
+    ) {
         if let Self::Cumulative(stats) = self {
             if let Some(result) = live_check_result {
                 for advice in &result.all_advice {
                     // Count of total advisories
-                    stats.add_advice(advice);
+                    stats.add_advice(advice, scope);
                 }
                 // Count of samples with the highest advice level
                 if let Some(highest_advice_level) = &result.highest_advice_level {
@@ -317,6 +468,39 @@ impl LiveCheckStatistics {
         }
     }
 
+    /// Set the estimated number of distinct values of a metric attribute
+    pub fn set_cardinality(
+        &mut self,
+        metric_name: &str,
+        attribute_name: &str,
+        metric_cardinality: u64,
+        attribute_cardinality: u64,
+    ) {
+        if let Self::Cumulative(stats) = self {
+            stats.set_cardinality(
+                metric_name,
+                attribute_name,
+                metric_cardinality,
+                attribute_cardinality,
+            );
+        }
+    }
+
+    /// Add the counts of other statistics, gathered on the same registry, e.g. by
+    /// another worker of the session
+    pub fn merge(&mut self, other: LiveCheckStatistics) {
+        if let (Self::Cumulative(stats), Self::Cumulative(other)) = (self, other) {
+            stats.merge(other);
+        }
+    }
+
+    /// Set the throughput of the session
+    pub fn set_throughput(&mut self, throughput: ThroughputStatistics) {
+        if let Self::Cumulative(stats) = self {
+            stats.throughput = Some(throughput);
+        }
+    }
+
     /// Are there any violations in the statistics?
     #[must_use]
     pub fn has_violations(&self) -> bool {
@@ -337,6 +521,7 @@ impl LiveCheckStatistics {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::advice::FindingBuilder;
     use weaver_forge::registry::ResolvedRegistry;
 
     #[test]
@@ -391,4 +576,58 @@ mod tests {
         assert!(!disabled_stats.has_violations()); // Always false for disabled
         assert!(!normal_stats.has_violations()); // No violations added yet
     }
+
+    #[test]
+    fn test_merge_statistics() {
+        let registry = ResolvedRegistry {
+            groups: vec![],
+            registry_url: String::new(),
+        };
+        let versioned_registry = VersionedRegistry::V1(Box::new(registry));
+        let mut stats =
+            LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&versioned_registry));
+        let mut worker_stats = stats.clone();
+
+        stats.inc_entity_count("attribute");
+        stats.add_attribute_name_to_coverage("test.attribute".to_owned());
+        stats.set_cardinality("test.metric", "test.attribute", 5, 5);
+        worker_stats.inc_entity_count("attribute");
+        worker_stats.inc_entity_count("span");
+        worker_stats.add_attribute_name_to_coverage("test.attribute".to_owned());
+        worker_stats.set_cardinality("test.metric", "test.attribute", 8, 12);
+        let scope = SampleScope {
+            name: "io.opentelemetry.jdbc".to_owned(),
+            version: None,
+            schema_url: None,
+            attributes: vec![],
+        };
+        let mut result = LiveCheckResult::new();
+        result.all_advice.push(
+            FindingBuilder::new("missing_attribute")
+                .message("Missing attribute")
+                .level(FindingLevel::Violation)
+                .build(),
+        );
+        stats.maybe_add_live_check_result(Some(&result), Some(&scope));
+        worker_stats.maybe_add_live_check_result(Some(&result), Some(&scope));
+        // Findings on samples without a known scope are not counted by scope
+        worker_stats.maybe_add_live_check_result(Some(&result), None);
+        stats.merge(worker_stats);
+
+        let LiveCheckStatistics::Cumulative(cumulative) = &stats else {
+            panic!("Expected Cumulative statistics");
+        };
+        assert_eq!(cumulative.total_entities, 3);
+        assert_eq!(cumulative.total_entities_by_type["attribute"], 2);
+        assert_eq!(cumulative.total_entities_by_type["span"], 1);
+        assert_eq!(cumulative.seen_non_registry_attributes["test.attribute"], 2);
+        assert_eq!(
+            cumulative.metric_attribute_cardinality["test.metric"]["test.attribute"],
+            8
+        );
+        assert_eq!(cumulative.attribute_cardinality["test.attribute"], 12);
+        assert_eq!(cumulative.total_advisories, 3);
+        assert_eq!(cumulative.advice_scope_counts.len(), 1);
+        assert_eq!(cumulative.advice_scope_counts["io.opentelemetry.jdbc"], 2);
+    }
 }
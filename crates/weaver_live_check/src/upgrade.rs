@@ -0,0 +1,356 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Upgrade of telemetry samples to a later version of the semantic conventions.
+//!
+//! The transformations of an OpenTelemetry schema file are applied to the samples,
+//! from the version given by their schema URL to the target version, as the schema
+//! processor of the OpenTelemetry Collector does. Samples without a schema URL, or
+//! produced with the target version or a later one, are left unchanged.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use weaver_semconv::schema_url::SchemaUrl;
+use weaver_version::upgrade::Upgrade;
+use weaver_version::Versions;
+
+use crate::sample_attribute::SampleAttribute;
+use crate::sample_metric::DataPoints;
+use crate::sample_resource::SampleResource;
+use crate::schema_history::schema_version;
+use crate::{Error, Sample};
+
+/// Upgrades samples to a target version with the transformations of a schema file.
+pub struct SampleUpgrader {
+    versions: Versions,
+    target: semver::Version,
+}
+
+impl SampleUpgrader {
+    /// Creates an upgrader to the given target version, or to the latest version of
+    /// the schema file if None.
+    pub fn new(versions: Versions, target: Option<semver::Version>) -> Result<Self, Error> {
+        let target = match target {
+            Some(target) => target,
+            None => {
+                versions
+                    .latest_version()
+                    .map(Into::into)
+                    .ok_or_else(|| Error::ConfigError {
+                        error: "The schema file has no versions.".to_owned(),
+                    })?
+            }
+        };
+        Ok(Self { versions, target })
+    }
+
+    /// Creates an upgrader from an OpenTelemetry schema file.
+    pub fn from_schema_file<P: AsRef<Path>>(
+        path: P,
+        target: Option<semver::Version>,
+    ) -> Result<Self, Error> {
+        let versions = Versions::load_from_file(path).map_err(|e| Error::ConfigError {
+            error: e.to_string(),
+        })?;
+        Self::new(versions, target)
+    }
+
+    /// Returns the version the samples are upgraded to.
+    #[must_use]
+    pub fn target(&self) -> &semver::Version {
+        &self.target
+    }
+
+    /// Upgrades a sample in place and returns true if it was produced with an earlier
+    /// version than the target. The schema URL of an upgraded sample is set to the
+    /// target version.
+    pub fn upgrade(&self, sample: &mut Sample) -> bool {
+        if let Sample::Trace(trace) = sample {
+            // The spans of a trace may come from services using different versions.
+            let mut upgraded = false;
+            for span in &mut trace.spans {
+                let mut span_sample = Sample::Span(span.clone());
+                if let (true, Sample::Span(upgraded_span)) =
+                    (self.upgrade(&mut span_sample), span_sample)
+                {
+                    *span = upgraded_span;
+                    upgraded = true;
+                }
+            }
+            return upgraded;
+        }
+        let Some(schema_url) = sample.schema_url().map(ToOwned::to_owned) else {
+            return false;
+        };
+        let Some(upgrade) = self.upgrade_from(&schema_url) else {
+            return false;
+        };
+        let target_url = self.target_url(&schema_url);
+
+        match sample {
+            Sample::Resource(resource) => {
+                upgrade_resource(resource, &upgrade);
+                resource.schema_url = Some(target_url);
+                return true;
+            }
+            Sample::Span(span) => {
+                for attribute in &mut span.attributes {
+                    attribute.name = upgrade.span_attribute(&span.name, &attribute.name);
+                }
+                for event in &mut span.span_events {
+                    upgrade_attributes(&mut event.attributes, |name| {
+                        upgrade.span_event_attribute(&span.name, &event.name, name)
+                    });
+                    event.name = upgrade.span_event_name(&event.name);
+                }
+                for link in &mut span.span_links {
+                    upgrade_attributes(&mut link.attributes, |name| upgrade.attribute(name));
+                }
+                span.schema_url = Some(target_url);
+            }
+            Sample::Metric(metric) => {
+                let metric_name = metric.name.clone();
+                let upgrade_attribute = |name: &str| upgrade.metric_attribute(&metric_name, name);
+                match &mut metric.data_points {
+                    Some(DataPoints::Number(points)) => {
+                        for point in points {
+                            upgrade_attributes(&mut point.attributes, upgrade_attribute);
+                            for exemplar in &mut point.exemplars {
+                                upgrade_attributes(
+                                    &mut exemplar.filtered_attributes,
+                                    upgrade_attribute,
+                                );
+                            }
+                        }
+                    }
+                    Some(DataPoints::Histogram(points)) => {
+                        for point in points {
+                            upgrade_attributes(&mut point.attributes, upgrade_attribute);
+                            for exemplar in &mut point.exemplars {
+                                upgrade_attributes(
+                                    &mut exemplar.filtered_attributes,
+                                    upgrade_attribute,
+                                );
+                            }
+                        }
+                    }
+                    Some(DataPoints::ExponentialHistogram(points)) => {
+                        for point in points {
+                            upgrade_attributes(&mut point.attributes, upgrade_attribute);
+                            for exemplar in &mut point.exemplars {
+                                upgrade_attributes(
+                                    &mut exemplar.filtered_attributes,
+                                    upgrade_attribute,
+                                );
+                            }
+                        }
+                    }
+                    None => {}
+                }
+                metric.name = upgrade.metric_name(&metric_name);
+                metric.schema_url = Some(target_url);
+            }
+            Sample::Log(log) => {
+                upgrade_attributes(&mut log.attributes, |name| upgrade.log_attribute(name));
+                log.schema_url = Some(target_url);
+            }
+            // Standalone attributes, span events and span links have no schema URL.
+            // Traces are upgraded span by span above.
+            Sample::Attribute(_)
+            | Sample::SpanEvent(_)
+            | Sample::SpanLink(_)
+            | Sample::Trace(_) => return false,
+        }
+
+        // Keep the resource of the signal consistent with the resource sample.
+        let resource = match sample {
+            Sample::Span(span) => span.resource.as_mut(),
+            Sample::Metric(metric) => metric.resource.as_mut(),
+            Sample::Log(log) => log.resource.as_mut(),
+            _ => None,
+        };
+        if let Some(resource) = resource {
+            let resource = Arc::make_mut(resource);
+            if let Some(upgrade) = resource
+                .schema_url
+                .as_deref()
+                .and_then(|schema_url| self.upgrade_from(schema_url))
+            {
+                upgrade_resource(resource, &upgrade);
+                resource.schema_url = resource
+                    .schema_url
+                    .as_deref()
+                    .map(|schema_url| self.target_url(schema_url));
+            }
+        }
+        true
+    }
+
+    /// Returns the upgrade from the version of the schema URL to the target version,
+    /// or None if there is nothing to upgrade.
+    fn upgrade_from(&self, schema_url: &str) -> Option<Upgrade<'_>> {
+        let version = schema_version(schema_url)?;
+        (version < self.target).then(|| {
+            self.versions
+                .upgrade(&version.into(), &self.target.clone().into())
+        })
+    }
+
+    /// Returns the schema URL with its version replaced by the target version.
+    fn target_url(&self, schema_url: &str) -> String {
+        let prefix = SchemaUrl::try_from(schema_url)
+            .ok()
+            .and_then(|url| {
+                schema_url
+                    .trim_end_matches('/')
+                    .strip_suffix(url.version())
+                    .map(ToOwned::to_owned)
+            })
+            .unwrap_or_default();
+        format!("{prefix}{}", self.target)
+    }
+}
+
+/// Upgrades the attributes of a resource.
+fn upgrade_resource(resource: &mut SampleResource, upgrade: &Upgrade<'_>) {
+    upgrade_attributes(&mut resource.attributes, |name| {
+        upgrade.resource_attribute(name)
+    });
+}
+
+/// Renames attributes with the given function.
+fn upgrade_attributes<F: Fn(&str) -> String>(attributes: &mut [SampleAttribute], new_name: F) {
+    for attribute in attributes {
+        attribute.name = new_name(&attribute.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn samples() -> Vec<Sample> {
+        serde_json::from_value(json!([
+            {
+                "resource": {
+                    "attributes": [{"name": "service.name", "value": "test"}],
+                    "schema_url": "https://opentelemetry.io/schemas/1.20.0"
+                }
+            },
+            {
+                "span": {
+                    "name": "GET /",
+                    "kind": "server",
+                    "attributes": [
+                        {"name": "http.method", "value": "GET"},
+                        {"name": "http.status_code", "value": 200}
+                    ],
+                    "span_events": [{
+                        "name": "message",
+                        "attributes": [{"name": "message.type", "value": "SENT"}]
+                    }],
+                    "span_links": [],
+                    "schema_url": "https://opentelemetry.io/schemas/1.20.0"
+                }
+            },
+            {
+                "metric": {
+                    "name": "system.memory.used",
+                    "instrument": "updowncounter",
+                    "unit": "By",
+                    "data_points": [{
+                        "attributes": [{"name": "system.memory.type", "value": "used"}],
+                        "value": 1024,
+                        "flags": 0
+                    }],
+                    "schema_url": "https://opentelemetry.io/schemas/1.25.0"
+                }
+            },
+            {
+                "span": {
+                    "name": "GET /",
+                    "kind": "server",
+                    "attributes": [{"name": "http.method", "value": "GET"}],
+                    "span_events": [],
+                    "span_links": []
+                }
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_upgrade() {
+        let upgrader = SampleUpgrader::from_schema_file("data/schema-history.yaml", None).unwrap();
+        assert_eq!(upgrader.target(), &semver::Version::new(1, 26, 0));
+
+        let mut samples = samples();
+        let upgraded = samples
+            .iter_mut()
+            .map(|sample| upgrader.upgrade(sample))
+            .collect::<Vec<_>>();
+        assert_eq!(upgraded, vec![true, true, true, false]);
+
+        let Sample::Span(span) = &samples[1] else {
+            panic!("Expected a span");
+        };
+        let names = span
+            .attributes
+            .iter()
+            .map(|attribute| attribute.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["http.request.method", "http.response.status_code"]
+        );
+        assert_eq!(
+            span.schema_url.as_deref(),
+            Some("https://opentelemetry.io/schemas/1.26.0")
+        );
+        assert_eq!(span.span_events[0].name, "rpc.message");
+        assert_eq!(span.span_events[0].attributes[0].name, "rpc.message.type");
+
+        let Sample::Metric(metric) = &samples[2] else {
+            panic!("Expected a metric");
+        };
+        assert_eq!(metric.name, "system.memory.usage");
+        let Some(DataPoints::Number(points)) = &metric.data_points else {
+            panic!("Expected number data points");
+        };
+        assert_eq!(points[0].attributes[0].name, "system.memory.state");
+
+        // Samples without a schema URL are left unchanged.
+        let Sample::Span(span) = &samples[3] else {
+            panic!("Expected a span");
+        };
+        assert_eq!(span.attributes[0].name, "http.method");
+    }
+
+    #[test]
+    fn test_upgrade_to_target() {
+        let upgrader = SampleUpgrader::from_schema_file(
+            "data/schema-history.yaml",
+            Some(semver::Version::new(1, 21, 0)),
+        )
+        .unwrap();
+        let mut samples = samples();
+        assert!(upgrader.upgrade(&mut samples[1]));
+        // The metric is already later than the target version.
+        assert!(!upgrader.upgrade(&mut samples[2]));
+
+        let Sample::Span(span) = &samples[1] else {
+            panic!("Expected a span");
+        };
+        assert_eq!(span.attributes[0].name, "http.request.method");
+        assert_eq!(
+            span.schema_url.as_deref(),
+            Some("https://opentelemetry.io/schemas/1.21.0")
+        );
+        let Sample::Metric(metric) = &samples[2] else {
+            panic!("Expected a metric");
+        };
+        assert_eq!(metric.name, "system.memory.used");
+    }
+}
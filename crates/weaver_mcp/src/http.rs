@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Streamable HTTP transport (with SSE) for the MCP server.
+//!
+//! One HTTP server can serve many MCP clients: each client session gets its
+//! own service, built by a factory, so the registry is only resolved once.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::{StreamableHttpServerConfig, StreamableHttpService};
+use weaver_common::http_auth::is_bearer_authorized;
+
+use crate::{McpError, WeaverMcpService};
+
+/// Path of the MCP endpoint served over HTTP.
+pub const MCP_HTTP_PATH: &str = "/mcp";
+
+/// Returns a router serving the MCP server over streamable HTTP on
+/// [`MCP_HTTP_PATH`].
+///
+/// `service_factory` is called for every new client session. If
+/// `bearer_token` is set, requests without the matching
+/// `Authorization: Bearer <token>` header are rejected with a 401.
+pub fn http_router<F>(service_factory: F, bearer_token: Option<String>) -> Router
+where
+    F: Fn() -> WeaverMcpService + Send + Sync + 'static,
+{
+    let service = StreamableHttpService::new(
+        move || Ok(service_factory()),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig::default(),
+    );
+    let router = Router::new().nest_service(MCP_HTTP_PATH, service);
+    match bearer_token {
+        Some(token) => router.layer(middleware::from_fn_with_state(
+            Arc::<str>::from(token),
+            check_bearer_token,
+        )),
+        None => router,
+    }
+}
+
+/// Serves the MCP server over streamable HTTP until the process receives
+/// CTRL+C.
+pub(crate) async fn serve_http(
+    service: WeaverMcpService,
+    bind: SocketAddr,
+    bearer_token: Option<String>,
+) -> Result<(), McpError> {
+    let router = http_router(move || service.clone(), bearer_token);
+    let listener = tokio::net::TcpListener::bind(bind)
+        .await
+        .map_err(|e| McpError(format!("Failed to bind {bind}: {e}")))?;
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await
+        .map_err(|e| McpError(e.to_string()))
+}
+
+/// Rejects the requests without the expected bearer token.
+async fn check_bearer_token(
+    State(expected): State<Arc<str>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    if is_bearer_authorized(authorization, &expected) {
+        next.run(request).await
+    } else {
+        (StatusCode::UNAUTHORIZED, "Missing or invalid bearer token").into_response()
+    }
+}
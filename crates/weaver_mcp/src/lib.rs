@@ -3,7 +3,7 @@
 //! MCP (Model Context Protocol) server for the semantic convention registry.
 //!
 //! This crate provides an MCP server that exposes the semantic conventions
-//! registry to LLMs. It supports 8 tools:
+//! registry to LLMs. It supports 10 tools:
 //!
 //! - `search` - Search across all registry items
 //! - `get_attribute` - Get a specific attribute by key
@@ -13,13 +13,21 @@
 //! - `get_entity` - Get a specific entity by type
 //! - `live_check` - Validate telemetry samples (with full/findings_only output modes)
 //! - `browse_namespace` - Browse attribute namespace hierarchy
+//! - `suggest_attributes` - Suggest existing attributes to reuse for a concept
+//! - `validate_snippet` - Validate a YAML snippet against the registry and its policies
 //!
-//! The server uses the rmcp SDK with JSON-RPC 2.0 over stdio for communication.
+//! The server uses the rmcp SDK with JSON-RPC 2.0, over stdio or over
+//! streamable HTTP (with SSE) so that a single server can serve a whole team.
 
+mod http;
 mod service;
+mod snippet;
 
+pub use http::{http_router, MCP_HTTP_PATH};
 pub use service::WeaverMcpService;
+pub use snippet::{SnippetDiagnostic, SnippetValidation, SnippetValidator};
 
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -94,19 +102,51 @@ pub fn run(registry: ForgeResolvedRegistry) -> Result<(), McpError> {
 ///
 /// Returns an error if there's an IO error during communication.
 pub fn run_with_config(registry: ForgeResolvedRegistry, config: McpConfig) -> Result<(), McpError> {
+    run_service(WeaverMcpService::new(Arc::new(registry), config))
+}
+
+/// Run the given MCP service over stdio.
+///
+/// This function blocks until the server is shut down (e.g., when stdin is closed).
+///
+/// # Errors
+///
+/// Returns an error if there's an IO error during communication.
+pub fn run_service(service: WeaverMcpService) -> Result<(), McpError> {
     // Create a tokio runtime for the async rmcp server
     let rt = tokio::runtime::Runtime::new().map_err(|e| McpError(e.to_string()))?;
 
-    rt.block_on(async { run_async(registry, config).await })
+    rt.block_on(async { run_async(service).await })
+}
+
+/// Run the given MCP service over streamable HTTP.
+///
+/// The MCP endpoint is served on [`MCP_HTTP_PATH`]. This function blocks until
+/// the process receives CTRL+C.
+///
+/// # Arguments
+///
+/// * `service` - The MCP service, shared by all the client sessions.
+/// * `bind` - The address to bind the HTTP server to.
+/// * `bearer_token` - The bearer token required from the clients, if any.
+///
+/// # Errors
+///
+/// Returns an error if the address can't be bound or if there's an IO error.
+pub fn run_http(
+    service: WeaverMcpService,
+    bind: SocketAddr,
+    bearer_token: Option<String>,
+) -> Result<(), McpError> {
+    let rt = tokio::runtime::Runtime::new().map_err(|e| McpError(e.to_string()))?;
+
+    rt.block_on(async { http::serve_http(service, bind, bearer_token).await })
 }
 
 /// Run the MCP server asynchronously.
 ///
 /// This is the async implementation that uses rmcp's stdio transport.
-async fn run_async(registry: ForgeResolvedRegistry, config: McpConfig) -> Result<(), McpError> {
-    let registry = Arc::new(registry);
-    let service = WeaverMcpService::new(registry, config);
-
+async fn run_async(service: WeaverMcpService) -> Result<(), McpError> {
     let server = service
         .serve(stdio())
         .await
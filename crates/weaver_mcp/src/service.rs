@@ -17,7 +17,9 @@ use serde::Deserialize;
 use serde_json::json;
 use weaver_forge::v2::registry::ForgeResolvedRegistry;
 use weaver_live_check::advice::{
-    Advisor, DeprecatedAdvisor, EnumAdvisor, RegoAdvisor, StabilityAdvisor, TypeAdvisor,
+    Advisor, BodyAdvisor, BucketAdvisor, ConstraintAdvisor, DeprecatedAdvisor, EntityAdvisor,
+    EnumAdvisor, OrphanSpanAdvisor, RegoAdvisor, SpanEventAdvisor, StabilityAdvisor, TraceAdvisor,
+    TypeAdvisor,
 };
 use weaver_live_check::live_checker::LiveChecker;
 use weaver_live_check::{
@@ -27,6 +29,7 @@ use weaver_live_check::{
 use weaver_search::{SearchContext, SearchType};
 use weaver_semconv::stability::Stability;
 
+use crate::snippet::SnippetValidator;
 use crate::McpConfig;
 
 /// MCP service for the semantic convention registry.
@@ -40,6 +43,8 @@ use crate::McpConfig;
 /// - `get_entity` - Get a specific entity by type
 /// - `live_check` - Validate telemetry samples against the registry
 /// - `browse_namespace` - Browse attribute namespace hierarchy
+/// - `suggest_attributes` - Suggest existing attributes to reuse for a concept
+/// - `validate_snippet` - Validate a YAML snippet against the registry and its policies
 #[derive(Clone)]
 pub struct WeaverMcpService {
     search_context: Arc<SearchContext>,
@@ -49,6 +54,8 @@ pub struct WeaverMcpService {
     advice_policies: Option<PathBuf>,
     /// Path to jq preprocessor script for Rego policies.
     advice_preprocessor: Option<PathBuf>,
+    /// Validator of YAML snippets, if snippet validation is available.
+    snippet_validator: Option<SnippetValidator>,
     /// Tool router for handling tool calls.
     tool_router: ToolRouter<Self>,
 }
@@ -70,17 +77,28 @@ impl WeaverMcpService {
             versioned_registry,
             advice_policies: config.advice_policies,
             advice_preprocessor: config.advice_preprocessor,
+            snippet_validator: None,
             tool_router: Self::tool_router(),
         }
     }
 
+    /// Enables the `validate_snippet` tool, backed by the given validator.
+    #[must_use]
+    pub fn with_snippet_validator(mut self, validator: SnippetValidator) -> Self {
+        self.snippet_validator = Some(validator);
+        self
+    }
+
     /// Create a LiveChecker for a single live_check call.
     ///
     /// LiveChecker contains Rc internally and cannot be stored in the async service.
     /// We create it fresh for each call.
     fn create_live_checker(&self) -> Result<LiveChecker, String> {
-        let mut live_checker =
-            LiveChecker::new(Arc::clone(&self.versioned_registry), default_advisors());
+        let mut live_checker = LiveChecker::new(
+            Arc::clone(&self.versioned_registry),
+            default_advisors(&self.versioned_registry),
+        );
+        live_checker.trace_advisors = default_trace_advisors(&self.versioned_registry);
 
         // Add RegoAdvisor for policy-based advice
         let rego_advisor = RegoAdvisor::new(
@@ -96,15 +114,29 @@ impl WeaverMcpService {
 }
 
 /// Create the default advisors for live check.
-fn default_advisors() -> Vec<Box<dyn Advisor>> {
+fn default_advisors(registry: &VersionedRegistry) -> Vec<Box<dyn Advisor>> {
     vec![
         Box::new(DeprecatedAdvisor),
         Box::new(StabilityAdvisor),
         Box::new(TypeAdvisor),
         Box::new(EnumAdvisor),
+        Box::new(ConstraintAdvisor::default()),
+        Box::new(BodyAdvisor),
+        Box::new(BucketAdvisor),
+        Box::new(EntityAdvisor::new(registry)),
     ]
 }
 
+/// Create the default advisors for the structure of assembled traces.
+fn default_trace_advisors(registry: &VersionedRegistry) -> Vec<Box<dyn TraceAdvisor>> {
+    let mut advisors: Vec<Box<dyn TraceAdvisor>> = Vec::new();
+    if let Some(advisor) = SpanEventAdvisor::new(registry) {
+        advisors.push(Box::new(advisor));
+    }
+    advisors.push(Box::new(OrphanSpanAdvisor));
+    advisors
+}
+
 /// Extract all findings from a `LiveCheckResult` as compact JSON values.
 /// Includes all levels (Violation, Improvement, Information) so the caller can decide relevance.
 fn extract_findings(result: &Option<LiveCheckResult>) -> Vec<serde_json::Value> {
@@ -197,6 +229,14 @@ fn collect_compact_findings(samples: &[Sample]) -> Vec<serde_json::Value> {
                     out.push(json!({"type": "log", "attribute_findings": parts}));
                 }
             }
+            Sample::Trace(t) => {
+                let findings = extract_findings(&t.live_check_result);
+                if !findings.is_empty() {
+                    out.push(json!({"type": "trace", "findings": findings}));
+                }
+                let spans: Vec<_> = t.spans.iter().cloned().map(Sample::Span).collect();
+                out.extend(collect_compact_findings(&spans));
+            }
         }
     }
     out
@@ -208,7 +248,9 @@ impl ServerHandler for WeaverMcpService {
         ServerInfo {
             instructions: Some(
                 "MCP server for OpenTelemetry semantic conventions. Use 'search' to find \
-                 conventions, 'get_*' tools to get details, and 'live_check' to validate samples."
+                 conventions, 'get_*' tools to get details, and 'live_check' to validate samples. \
+                 When drafting new conventions, use 'suggest_attributes' to find attributes to \
+                 reuse and 'validate_snippet' to check the YAML before proposing it."
                     .into(),
             ),
             capabilities: ServerCapabilities::builder().enable_tools().build(),
@@ -356,6 +398,29 @@ pub struct BrowseNamespaceParams {
     prefix: Option<String>,
 }
 
+/// Parameters for the suggest_attributes tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SuggestAttributesParams {
+    /// Free-text description of the concept to capture
+    /// (e.g., "the HTTP method of the incoming request").
+    description: String,
+    /// Maximum suggestions to return (1-50, default 10).
+    #[serde(default = "default_suggestion_limit")]
+    limit: usize,
+}
+
+fn default_suggestion_limit() -> usize {
+    10
+}
+
+/// Parameters for the validate_snippet tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ValidateSnippetParams {
+    /// A semantic convention YAML file, in the v1 (`groups:`) or v2
+    /// (`file_format: definition/2`) format.
+    yaml: String,
+}
+
 // =============================================================================
 // Tool Implementations
 // =============================================================================
@@ -529,6 +594,47 @@ impl WeaverMcpService {
             .browse_namespace(params.prefix.as_deref());
         serde_json::to_string_pretty(&info).unwrap_or_else(|e| format!("Error: {e}"))
     }
+
+    /// Suggest existing attributes to reuse for a described concept.
+    #[tool(
+        name = "suggest_attributes",
+        description = "Suggest existing semantic convention attributes to reuse for a concept \
+                       described in free text, before defining a new attribute. Each term of the \
+                       description is matched independently against attribute keys, briefs and \
+                       notes; attributes matching the most terms come first. Deprecated \
+                       attributes are never suggested."
+    )]
+    fn suggest_attributes(
+        &self,
+        Parameters(params): Parameters<SuggestAttributesParams>,
+    ) -> String {
+        let suggestions = self
+            .search_context
+            .suggest_attributes(&params.description, params.limit.clamp(1, 50));
+        let result_json = json!({
+            "suggestions": suggestions,
+            "count": suggestions.len(),
+        });
+        serde_json::to_string_pretty(&result_json).unwrap_or_else(|e| format!("Error: {e}"))
+    }
+
+    /// Validate a semantic convention YAML snippet.
+    #[tool(
+        name = "validate_snippet",
+        description = "Validate a semantic convention YAML snippet (v1 or v2 format) before \
+                       proposing it. The snippet is parsed and resolved together with the loaded \
+                       registry, so it can reference existing attributes, and the configured \
+                       before_resolution and after_resolution Rego policies are run. Returns \
+                       'valid', the parsing and resolution diagnostics, and the policy findings \
+                       introduced by the snippet."
+    )]
+    fn validate_snippet(&self, Parameters(params): Parameters<ValidateSnippetParams>) -> String {
+        let Some(validator) = &self.snippet_validator else {
+            return "Snippet validation is not available on this server".to_owned();
+        };
+        let validation = validator(&params.yaml);
+        serde_json::to_string_pretty(&validation).unwrap_or_else(|e| format!("Error: {e}"))
+    }
 }
 
 #[cfg(test)]
@@ -558,6 +664,7 @@ mod tests {
                         weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
                     ),
                     examples: None,
+                    constraints: None,
                     common: CommonFields {
                         brief: "HTTP request method".to_owned(),
                         note: "".to_owned(),
@@ -575,6 +682,7 @@ mod tests {
                     requirement_level: Some(
                         weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
                     ),
+                    advice: None,
                     attributes: vec![],
                     entity_associations: vec![],
                     common: CommonFields {
@@ -591,9 +699,12 @@ mod tests {
                     kind: SpanKindSpec::Client,
                     name: SpanName {
                         note: "HTTP client span".to_owned(),
+                        template: None,
+                        fallbacks: vec![],
                     },
                     attributes: vec![],
                     entity_associations: vec![],
+                    events: vec![],
                     common: CommonFields {
                         brief: "HTTP client span".to_owned(),
                         note: "".to_owned(),
@@ -1214,4 +1325,62 @@ mod tests {
         assert!(!attr_findings.is_empty());
         assert_eq!(attr_findings[0]["name"], "nonexistent.resource.attr");
     }
+
+    // =========================================================================
+    // Authoring Tool Tests
+    // =========================================================================
+
+    #[test]
+    fn test_suggest_attributes_tool() {
+        let service = create_test_service();
+
+        let params = SuggestAttributesParams {
+            description: "the method of the HTTP request".to_owned(),
+            limit: 10,
+        };
+        let result = service.suggest_attributes(Parameters(params));
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["count"], 1);
+        let suggestion = &parsed["suggestions"][0];
+        assert_eq!(suggestion["key"], "http.request.method");
+        assert_eq!(
+            suggestion["matched_terms"],
+            json!(["http", "method", "request"])
+        );
+    }
+
+    #[test]
+    fn test_validate_snippet_unavailable() {
+        let service = create_test_service();
+
+        let params = ValidateSnippetParams {
+            yaml: "groups: []".to_owned(),
+        };
+        let result = service.validate_snippet(Parameters(params));
+
+        assert!(result.contains("not available"));
+    }
+
+    #[test]
+    fn test_validate_snippet_tool() {
+        use crate::snippet::SnippetValidation;
+        use weaver_common::diagnostic::DiagnosticMessages;
+
+        let service = create_test_service().with_snippet_validator(Arc::new(|yaml: &str| {
+            assert_eq!(yaml, "groups: []");
+            SnippetValidation::new(DiagnosticMessages::empty(), vec![], vec![])
+        }));
+
+        let params = ValidateSnippetParams {
+            yaml: "groups: []".to_owned(),
+        };
+        let result = service.validate_snippet(Parameters(params));
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["valid"], true);
+        assert_eq!(parsed["diagnostics"], json!([]));
+        assert_eq!(parsed["before_resolution"], json!([]));
+        assert_eq!(parsed["after_resolution"], json!([]));
+    }
 }
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation of semantic convention YAML snippets drafted by the MCP clients.
+//!
+//! Parsing and resolving a snippet requires the registry definitions and the
+//! policy engine, which are owned by the application running the server, so the
+//! validation itself is provided as a [`SnippetValidator`].
+
+use std::sync::Arc;
+
+use serde::Serialize;
+use weaver_checker::{FindingLevel, PolicyFinding};
+use weaver_common::diagnostic::DiagnosticMessages;
+
+/// Parses and resolves a YAML snippet (v1 or v2 semconv) against the served
+/// registry, and runs the policies on the result.
+pub type SnippetValidator = Arc<dyn Fn(&str) -> SnippetValidation + Send + Sync>;
+
+/// The result of the validation of a YAML snippet.
+#[derive(Debug, Serialize)]
+pub struct SnippetValidation {
+    /// True if the snippet resolves without errors and has no policy violations.
+    pub valid: bool,
+    /// The parsing and resolution diagnostics.
+    pub diagnostics: Vec<SnippetDiagnostic>,
+    /// The findings of the `before_resolution` policies on the snippet.
+    pub before_resolution: Vec<PolicyFinding>,
+    /// The findings of the `after_resolution` policies introduced by the snippet.
+    pub after_resolution: Vec<PolicyFinding>,
+}
+
+/// A parsing or resolution diagnostic.
+#[derive(Debug, Serialize)]
+pub struct SnippetDiagnostic {
+    /// `error` or `warning`.
+    pub severity: &'static str,
+    /// The message of the diagnostic.
+    pub message: String,
+}
+
+impl SnippetValidation {
+    /// Builds the result of a validation.
+    #[must_use]
+    pub fn new(
+        diagnostics: DiagnosticMessages,
+        before_resolution: Vec<PolicyFinding>,
+        after_resolution: Vec<PolicyFinding>,
+    ) -> Self {
+        let valid = !diagnostics.has_error()
+            && before_resolution
+                .iter()
+                .chain(&after_resolution)
+                .all(|finding| finding.level != FindingLevel::Violation);
+        let diagnostics = diagnostics
+            .into_inner()
+            .into_iter()
+            .map(|diagnostic| SnippetDiagnostic {
+                severity: if diagnostic.is_error() {
+                    "error"
+                } else {
+                    "warning"
+                },
+                message: diagnostic.message().to_owned(),
+            })
+            .collect();
+        Self {
+            valid,
+            diagnostics,
+            before_resolution,
+            after_resolution,
+        }
+    }
+}
@@ -4,11 +4,12 @@
 //! Please refer to the [OpenTelemetry Schema OTEP](https://github.com/open-telemetry/oteps/blob/main/text/0152-telemetry-schemas.md)
 //! for more information.
 
-use crate::Error::{InvalidTelemetrySchema, TelemetrySchemaNotFound};
+use crate::Error::{InvalidSchemaVersion, InvalidTelemetrySchema, TelemetrySchemaNotFound};
 use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
-use weaver_version::Versions;
+use weaver_version::{Version, VersionSpec, Versions};
 
 /// Errors emitted by this crate.
 #[derive(thiserror::Error, Debug, Clone, Deserialize, Serialize, Diagnostic)]
@@ -28,6 +29,17 @@ pub enum Error {
         /// The error that occurred.
         error: String,
     },
+
+    /// The version to add to an OTel Telemetry schema is invalid.
+    #[error("Invalid version {version} for the OTel telemetry schema (path_or_url: {path_or_url:?}). {error}")]
+    InvalidSchemaVersion {
+        /// The path or the url to the telemetry schema file.
+        path_or_url: String,
+        /// The version to add.
+        version: String,
+        /// The error that occurred.
+        error: String,
+    },
 }
 
 impl From<Error> for DiagnosticMessages {
@@ -78,11 +90,87 @@ impl TelemetrySchema {
 
         Ok(schema)
     }
+
+    /// Adds a version section to a telemetry schema file and returns the updated
+    /// file content. The file itself is not modified.
+    ///
+    /// The new section is inserted at the top of the `versions` (newest first),
+    /// the rest of the file (including comments) is preserved. The `schema_url`
+    /// is updated to the new version when it ends with the latest version of the
+    /// file.
+    pub fn append_version<P: AsRef<std::path::Path>>(
+        path: P,
+        version: &semver::Version,
+        spec: &VersionSpec,
+    ) -> Result<String, Error> {
+        let path_or_url = path.as_ref().to_string_lossy().to_string();
+        let invalid_schema = |error: String| InvalidTelemetrySchema {
+            path_or_url: path_or_url.clone(),
+            error,
+        };
+        let invalid_version = |error: String| InvalidSchemaVersion {
+            path_or_url: path_or_url.clone(),
+            version: version.to_string(),
+            error,
+        };
+
+        let schema = Self::try_from_file(path.as_ref())?;
+        let content =
+            std::fs::read_to_string(path.as_ref()).map_err(|e| invalid_schema(e.to_string()))?;
+
+        let mut schema_url = schema.schema_url.clone();
+        if let Some(latest) = schema.versions.as_ref().and_then(Versions::latest_version) {
+            if Version::from(version.clone()) <= latest {
+                return Err(invalid_version(format!(
+                    "The version must be greater than the latest version of the schema ({latest})."
+                )));
+            }
+            if let Some(base_url) = schema_url.strip_suffix(&format!("/{latest}")) {
+                schema_url = format!("{base_url}/{version}");
+            }
+        }
+
+        let section = serde_yaml::to_string(&BTreeMap::from([(version, spec)]))
+            .map_err(|e| invalid_version(e.to_string()))?;
+        let section: String = section.lines().map(|line| format!("  {line}\n")).collect();
+
+        let mut updated = String::with_capacity(content.len() + section.len());
+        let mut inserted = false;
+        for line in content.lines() {
+            if line.starts_with("schema_url:") {
+                updated.push_str(&format!("schema_url: {schema_url}\n"));
+                continue;
+            }
+            updated.push_str(line);
+            updated.push('\n');
+            let key = line.split('#').next().unwrap_or_default().trim_end();
+            if !inserted && key == "versions:" {
+                updated.push_str(&section);
+                inserted = true;
+            }
+        }
+        if !inserted {
+            if schema.versions.is_some() {
+                return Err(invalid_schema(
+                    "The `versions` section must be a block mapping to add a version.".to_owned(),
+                ));
+            }
+            updated.push_str("versions:\n");
+            updated.push_str(&section);
+        }
+
+        // Make sure the updated schema is still valid.
+        let _: TelemetrySchema =
+            serde_yaml::from_str(&updated).map_err(|e| invalid_schema(e.to_string()))?;
+        Ok(updated)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::TelemetrySchema;
+    use crate::{Error, TelemetrySchema};
+    use std::collections::BTreeMap;
+    use weaver_version::VersionSpec;
 
     #[test]
     fn test_try_from_file() {
@@ -90,4 +178,45 @@ mod tests {
         assert_eq!(schema.file_format, "1.1.0");
         assert_eq!(schema.schema_url, "https://opentelemetry.io/schemas/1.27.0");
     }
+
+    #[test]
+    fn test_append_version() {
+        let spec = VersionSpec::from_renames(
+            BTreeMap::from([("db.name".to_owned(), "db.namespace".to_owned())]),
+            BTreeMap::from([(
+                "http.client.duration".to_owned(),
+                "http.client.request.duration".to_owned(),
+            )]),
+        );
+        let version = semver::Version::new(1, 28, 0);
+        let updated =
+            TelemetrySchema::append_version("tests/test_data/1.27.0.yaml", &version, &spec)
+                .unwrap();
+
+        let schema: TelemetrySchema = serde_yaml::from_str(&updated).unwrap();
+        assert_eq!(schema.schema_url, "https://opentelemetry.io/schemas/1.28.0");
+        let versions = schema.versions.unwrap();
+        assert_eq!(versions.latest_version().unwrap().to_string(), "1.28.0");
+        let (_, latest) = versions.versions_desc().into_iter().next().unwrap();
+        assert_eq!(
+            latest.all.as_ref().unwrap().changes[0]
+                .rename_attributes
+                .attribute_map["db.name"],
+            "db.namespace"
+        );
+        assert_eq!(
+            latest.metrics.as_ref().unwrap().changes[0].rename_metrics["http.client.duration"],
+            "http.client.request.duration"
+        );
+        // Comments of the original file are preserved.
+        assert!(
+            updated.contains("# https://github.com/open-telemetry/semantic-conventions/pull/1216")
+        );
+
+        let older = semver::Version::new(1, 26, 0);
+        assert!(matches!(
+            TelemetrySchema::append_version("tests/test_data/1.27.0.yaml", &older, &spec),
+            Err(Error::InvalidSchemaVersion { .. })
+        ));
+    }
 }
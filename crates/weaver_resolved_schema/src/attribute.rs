@@ -14,7 +14,7 @@ use std::ops::Not;
 #[cfg(test)]
 use weaver_semconv::attribute::PrimitiveOrArrayTypeSpec;
 use weaver_semconv::attribute::{
-    AttributeRole, AttributeSpec, AttributeType, Examples, RequirementLevel,
+    AttributeRole, AttributeSpec, AttributeType, Examples, RequirementLevel, ValueConstraints,
 };
 use weaver_semconv::deprecated::Deprecated;
 use weaver_semconv::stability::Stability;
@@ -41,6 +41,10 @@ pub struct Attribute {
     /// be reported without encapsulating it into a sequence/dictionary.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub examples: Option<Examples>,
+    /// Constraints on the values of the attribute (pattern, range, length).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<ValueConstraints>,
     /// Associates a tag ("sub-group") to the attribute. It carries no
     /// particular semantic meaning but can be used e.g. for filtering
     /// in the markdown generator.
@@ -126,6 +130,7 @@ impl Attribute {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: brief.as_ref().to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -149,6 +154,7 @@ impl Attribute {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
             brief: brief.as_ref().to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -172,6 +178,7 @@ impl Attribute {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Double),
             brief: brief.as_ref().to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -199,6 +206,7 @@ impl Attribute {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Boolean),
             brief: brief.into().to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -139,9 +139,11 @@ impl ResolvedTelemetrySchema {
             metric_requirement_level: Some(
                 weaver_semconv::attribute::BasicRequirementLevelSpec::Recommended,
             ),
+            advice: None,
             body: None,
             annotations: None,
             entity_associations: vec![],
+            span_name: None,
             visibility: None,
             is_v2: false,
         });
@@ -189,9 +191,11 @@ impl ResolvedTelemetrySchema {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             body: None,
             annotations: None,
             entity_associations: vec![],
+            span_name: None,
             visibility: None,
             is_v2: false,
         });
@@ -875,9 +879,11 @@ mod tests {
             instrument: Some(InstrumentSpec::Counter),
             unit: Some("{request}".to_owned()),
             metric_requirement_level: Some(BasicRequirementLevelSpec::Required),
+            advice: None,
             body: None,
             annotations: None,
             entity_associations: vec![],
+            span_name: None,
             visibility: None,
             is_v2: false,
         });
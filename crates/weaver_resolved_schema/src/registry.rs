@@ -18,10 +18,11 @@ use crate::registry::GroupStats::{
 use serde::{Deserialize, Serialize};
 use weaver_semconv::attribute::BasicRequirementLevelSpec;
 use weaver_semconv::deprecated::Deprecated;
-use weaver_semconv::group::{GroupType, InstrumentSpec, SpanKindSpec};
+use weaver_semconv::group::{GroupType, InstrumentSpec, MetricAdviceSpec, SpanKindSpec};
 use weaver_semconv::provenance::Provenance;
 use weaver_semconv::stability::Stability;
 use weaver_semconv::v2::attribute_group::AttributeGroupVisibilitySpec;
+use weaver_semconv::v2::span::SpanName;
 use weaver_semconv::YamlValue;
 
 /// A semantic convention registry.
@@ -118,6 +119,12 @@ pub struct Group {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metric_requirement_level: Option<BasicRequirementLevelSpec>,
+    /// Advisory parameters for the instrument recording the metric, such as
+    /// the recommended histogram bucket boundaries.
+    /// Note: This field is only valid if type is metric.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advice: Option<MetricAdviceSpec>,
     /// The name of the event. If not specified, the prefix is used.
     /// If prefix is empty (or unspecified), name is required.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -141,6 +148,12 @@ pub struct Group {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub entity_associations: Vec<String>,
+    /// The name specification of a span, including its optional templates.
+    /// This is only used for v2 conversion.
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    #[schemars(skip)]
+    pub span_name: Option<SpanName>,
     /// Visibility of the attribute group.
     /// This is only used for v2 conversion.
     #[serde(default)]
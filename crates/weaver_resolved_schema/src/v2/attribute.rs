@@ -5,7 +5,7 @@ use std::fmt::Display;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use weaver_semconv::{
-    attribute::{AttributeType, Examples},
+    attribute::{AttributeType, Examples, ValueConstraints},
     v2::CommonFields,
 };
 
@@ -30,6 +30,10 @@ pub struct Attribute {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub examples: Option<Examples>,
+    /// Constraints on the values of the attribute (pattern, range, length).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<ValueConstraints>,
     /// Common fields (like brief, note, attributes).
     #[serde(flatten)]
     pub common: CommonFields,
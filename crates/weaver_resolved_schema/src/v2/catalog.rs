@@ -134,6 +134,7 @@ mod test {
             key: key.clone(),
             r#type: atype.clone(),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: brief.clone(),
                 note: note.clone(),
@@ -149,6 +150,7 @@ mod test {
             r#type: atype.clone(),
             brief: brief.clone(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             sampling_relevant: Some(true),
@@ -169,6 +171,7 @@ mod test {
             r#type: atype.clone(),
             brief: brief.clone(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             sampling_relevant: Some(true),
@@ -232,6 +232,7 @@ pub fn convert_v1_to_v2(
                 key: a.name,
                 r#type: a.r#type,
                 examples: a.examples,
+                constraints: a.constraints,
                 common: CommonFields {
                     brief: a.brief,
                     note: a.note,
@@ -294,11 +295,13 @@ pub fn convert_v1_to_v2(
                             .span_kind
                             .clone()
                             .unwrap_or(weaver_semconv::group::SpanKindSpec::Internal),
-                        // TODO - Pass advanced name controls through V1 groups.
-                        name: SpanName {
+                        name: g.span_name.clone().unwrap_or_else(|| SpanName {
                             note: g.name.clone().unwrap_or_default(),
-                        },
+                            template: None,
+                            fallbacks: vec![],
+                        }),
                         entity_associations: g.entity_associations.clone(),
+                        events: g.events.clone(),
                         common: CommonFields {
                             brief: g.brief.clone(),
                             note: g.note.clone(),
@@ -333,11 +336,13 @@ pub fn convert_v1_to_v2(
                                 .span_kind
                                 .clone()
                                 .unwrap_or(weaver_semconv::group::SpanKindSpec::Internal),
-                            // TODO - Pass advanced name controls through V1 groups.
-                            name: SpanName {
+                            name: g.span_name.clone().unwrap_or_else(|| SpanName {
                                 note: g.name.clone().unwrap_or_default(),
-                            },
+                                template: None,
+                                fallbacks: vec![],
+                            }),
                             entity_associations: g.entity_associations.clone(),
+                            events: g.events.clone(),
                             common: CommonFields {
                                 brief: g.brief.clone(),
                                 note: g.note.clone(),
@@ -450,6 +455,7 @@ pub fn convert_v1_to_v2(
                     attributes: metric_attributes,
                     entity_associations: g.entity_associations.clone(),
                     requirement_level: g.metric_requirement_level.clone(),
+                    advice: g.advice.clone(),
                     common: CommonFields {
                         brief: g.brief.clone(),
                         note: g.note.clone(),
@@ -695,6 +701,7 @@ mod tests {
                 ),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
                     weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
@@ -719,6 +726,7 @@ mod tests {
                 ),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
                     weaver_semconv::attribute::BasicRequirementLevelSpec::Recommended,
@@ -761,12 +769,14 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: Some("my span name".to_owned()),
                     lineage: None,
                     display_name: None,
                     body: None,
                     annotations: None,
                     entity_associations: vec![],
+                    span_name: None,
                     visibility: None,
                     is_v2: false,
                 },
@@ -786,12 +796,14 @@ mod tests {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: Some("my span name".to_owned()),
                     lineage: Some(refinement_span_lineage),
                     display_name: None,
                     body: None,
                     annotations: None,
                     entity_associations: vec![],
+                    span_name: None,
                     visibility: None,
                     is_v2: false,
                 },
@@ -835,6 +847,7 @@ mod tests {
                 ),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
                     weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
@@ -859,6 +872,7 @@ mod tests {
                 ),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
                     weaver_semconv::attribute::BasicRequirementLevelSpec::Recommended,
@@ -901,12 +915,14 @@ mod tests {
                     instrument: Some(weaver_semconv::group::InstrumentSpec::UpDownCounter),
                     unit: Some("s".to_owned()),
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     lineage: None,
                     display_name: None,
                     body: None,
                     annotations: None,
                     entity_associations: vec![],
+                    span_name: None,
                     visibility: None,
                     is_v2: false,
                 },
@@ -926,12 +942,14 @@ mod tests {
                     instrument: Some(weaver_semconv::group::InstrumentSpec::UpDownCounter),
                     unit: Some("s".to_owned()),
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     lineage: Some(refinement_metric_lineage),
                     display_name: None,
                     body: None,
                     annotations: None,
                     entity_associations: vec![],
+                    span_name: None,
                     visibility: None,
                     is_v2: false,
                 },
@@ -973,6 +991,7 @@ mod tests {
                 ),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
                     weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
@@ -1009,12 +1028,14 @@ mod tests {
                 instrument: None,
                 unit: None,
                 metric_requirement_level: None,
+                advice: None,
                 name: Some("my-event".to_owned()),
                 lineage: None,
                 display_name: None,
                 body: None,
                 annotations: None,
                 entity_associations: vec![],
+                span_name: None,
                 visibility: None,
                 is_v2: false,
             }],
@@ -1040,6 +1061,7 @@ mod tests {
                 ),
                 brief: "".to_owned(),
                 examples: None,
+                constraints: None,
                 tag: None,
                 requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
                     weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
@@ -1076,6 +1098,7 @@ mod tests {
                 instrument: None,
                 unit: None,
                 metric_requirement_level: None,
+                advice: None,
                 name: Some("my-entity".to_owned()),
                 lineage: Some(GroupLineage::new(Provenance::new(
                     "https://my.dependency.url/1.0.0".try_into().unwrap(),
@@ -1085,6 +1108,7 @@ mod tests {
                 body: None,
                 annotations: None,
                 entity_associations: vec![],
+                span_name: None,
                 visibility: None,
                 is_v2: false,
             }],
@@ -1145,6 +1169,7 @@ mod tests {
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: "test brief".to_owned(),
                 note: "test note".to_owned(),
@@ -1168,6 +1193,7 @@ mod tests {
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: "test brief".to_owned(),
                 note: "test note".to_owned(),
@@ -1185,6 +1211,7 @@ mod tests {
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: "test brief".to_owned(),
                 note: "test note".to_owned(),
@@ -1203,6 +1230,7 @@ mod tests {
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: "test brief".to_owned(),
                 note: "test note".to_owned(),
@@ -1246,6 +1274,7 @@ mod tests {
             attributes: vec![],
             entity_associations: vec![],
             requirement_level: None,
+            advice: None,
             common: CommonFields::default(),
             provenance: Default::default(),
         });
@@ -1257,6 +1286,7 @@ mod tests {
             attributes: vec![],
             entity_associations: vec![],
             requirement_level: None,
+            advice: None,
             common: CommonFields::default(),
             provenance: Default::default(),
         });
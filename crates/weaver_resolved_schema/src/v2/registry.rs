@@ -253,6 +253,7 @@ mod test {
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: "test".to_owned(),
                 note: "".to_owned(),
@@ -269,9 +270,12 @@ mod test {
                 kind: SpanKindSpec::Client,
                 name: SpanName {
                     note: "test".to_owned(),
+                    template: None,
+                    fallbacks: vec![],
                 },
                 attributes: vec![],
                 entity_associations: vec![],
+                events: vec![],
                 common: CommonFields {
                     brief: "test".to_owned(),
                     note: "".to_owned(),
@@ -288,6 +292,7 @@ mod test {
                 attributes: vec![],
                 entity_associations: vec![],
                 requirement_level: None,
+                advice: None,
                 common: CommonFields {
                     brief: "test".to_owned(),
                     note: "".to_owned(),
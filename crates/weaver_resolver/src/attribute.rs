@@ -205,6 +205,7 @@ impl AttributeCatalog {
                         r#type: root_attr.attribute.r#type.clone(),
                         brief: attr_lineage.brief(brief, &root_attr.attribute.brief),
                         examples: attr_lineage.examples(examples, &root_attr.attribute.examples),
+                        constraints: root_attr.attribute.constraints.clone(),
                         tag: attr_lineage.tag(tag, &root_attr.attribute.tag),
                         requirement_level: attr_lineage.requirement_level(
                             requirement_level,
@@ -259,6 +260,7 @@ impl AttributeCatalog {
                 r#type,
                 brief,
                 examples,
+                constraints,
                 tag,
                 requirement_level,
                 sampling_relevant,
@@ -277,6 +279,7 @@ impl AttributeCatalog {
                     r#type: r#type.clone(),
                     brief: brief.clone().unwrap_or_default(),
                     examples: examples.clone(),
+                    constraints: constraints.clone(),
                     tag: tag.clone(),
                     requirement_level: requirement_level.clone(),
                     sampling_relevant: *sampling_relevant,
@@ -421,6 +424,7 @@ impl AttributeLookup for V2Schema {
                         r#type: attr.r#type.clone(),
                         brief: attr.common.brief.clone(),
                         examples: attr.examples.clone(),
+                        constraints: attr.constraints.clone(),
                         tag: None,
                         requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
                             weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
@@ -561,6 +565,7 @@ mod tests {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Boolean),
             brief: "brief".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level,
             sampling_relevant: Some(false),
@@ -584,6 +589,7 @@ mod tests {
             key: "error.type".to_owned(),
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             examples: None,
+            constraints: None,
             common: Default::default(),
             provenance: Default::default(),
         };
@@ -658,6 +664,7 @@ mod tests {
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "brief v1".to_owned(),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: RequirementLevel::Basic(Recommended),
             sampling_relevant: None,
@@ -693,6 +700,7 @@ mod tests {
             key: attr_name.to_owned(),
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             examples: None,
+            constraints: None,
             common: Default::default(),
             provenance: Default::default(),
         };
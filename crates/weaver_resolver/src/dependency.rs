@@ -11,9 +11,10 @@ use weaver_resolved_schema::ResolvedTelemetrySchema as V1Schema;
 use weaver_resolved_schema::{attribute::UnresolvedAttribute, v2::Signal};
 use weaver_semconv::attribute::{AttributeRole, BasicRequirementLevelSpec, RequirementLevel};
 use weaver_semconv::deprecated::Deprecated;
-use weaver_semconv::group::{GroupType, InstrumentSpec, SpanKindSpec};
+use weaver_semconv::group::{GroupType, InstrumentSpec, MetricAdviceSpec, SpanKindSpec};
 use weaver_semconv::group::{GroupWildcard, ImportsWithProvenance};
 use weaver_semconv::stability::Stability;
+use weaver_semconv::v2::span::SpanName;
 
 use crate::{
     attribute::{AttributeCatalog, AttributeSource},
@@ -41,8 +42,14 @@ pub(crate) struct GroupSummary {
     pub unit: Option<String>,
     /// The requirement level of the metric.
     pub metric_requirement_level: Option<BasicRequirementLevelSpec>,
+    /// Advisory parameters for the instrument recording the metric.
+    pub advice: Option<MetricAdviceSpec>,
     /// Specifies the kind of the span.
     pub span_kind: Option<SpanKindSpec>,
+    /// The name specification of the span, including its optional templates.
+    pub span_name: Option<SpanName>,
+    /// The names of the events that may be recorded on the span.
+    pub events: Vec<String>,
     /// The attributes from this group before being completely resolved to a catalog.
     pub attributes: Vec<UnresolvedAttribute>,
     /// The annotations of the group.
@@ -64,7 +71,10 @@ impl GroupSummary {
             instrument: group.instrument.clone(),
             unit: group.unit.clone(),
             metric_requirement_level: group.metric_requirement_level.clone(),
+            advice: group.advice.clone(),
             span_kind: group.span_kind.clone(),
+            span_name: group.span_name.clone(),
+            events: group.events.clone(),
             attributes: vec![], // Will be set during the dependency or registry loops.
             annotations: group.annotations.clone(),
         }
@@ -194,6 +204,7 @@ fn convert_v2_attribute(
         r#type: attr.r#type.clone(),
         brief: attr.common.brief.clone(),
         examples: attr.examples.clone(),
+        constraints: attr.constraints.clone(),
         tag: None,
         requirement_level,
         sampling_relevant: None,
@@ -266,6 +277,7 @@ impl ImportableDependency for V2Schema {
                 instrument: Some(m.instrument.clone()),
                 unit: Some(m.unit.clone()),
                 metric_requirement_level: None,
+                advice: m.advice.clone(),
                 name: None,
                 lineage: Some(weaver_resolved_schema::lineage::GroupLineage::new(
                     get_source_provenance(&m.provenance),
@@ -274,6 +286,7 @@ impl ImportableDependency for V2Schema {
                 body: None,
                 annotations: Some(m.common.annotations.clone()),
                 entity_associations: m.entity_associations.clone(),
+                span_name: None,
                 visibility: None,
                 is_v2: true,
             });
@@ -317,6 +330,7 @@ impl ImportableDependency for V2Schema {
                 instrument: None,
                 unit: None,
                 metric_requirement_level: None,
+                advice: None,
                 name: Some(e.name.to_string()),
                 lineage: Some(weaver_resolved_schema::lineage::GroupLineage::new(
                     get_source_provenance(&e.provenance),
@@ -325,6 +339,7 @@ impl ImportableDependency for V2Schema {
                 body: None,
                 annotations: Some(e.common.annotations.clone()),
                 entity_associations: e.entity_associations.clone(),
+                span_name: None,
                 visibility: None,
                 is_v2: true,
             });
@@ -392,6 +407,7 @@ impl ImportableDependency for V2Schema {
                 instrument: None,
                 unit: None,
                 metric_requirement_level: None,
+                advice: None,
                 name: Some(e.r#type.to_string()),
                 lineage: Some(weaver_resolved_schema::lineage::GroupLineage::new(
                     get_source_provenance(&e.provenance),
@@ -400,6 +416,7 @@ impl ImportableDependency for V2Schema {
                 body: None,
                 annotations: Some(e.common.annotations.clone()),
                 entity_associations: vec![],
+                span_name: None,
                 visibility: None,
                 is_v2: true,
             });
@@ -438,11 +455,12 @@ impl ImportableDependency for V2Schema {
                 deprecated: s.common.deprecated.clone(),
                 attributes,
                 span_kind: Some(s.kind.clone()),
-                events: vec![],
+                events: s.events.clone(),
                 metric_name: None,
                 instrument: None,
                 unit: None,
                 metric_requirement_level: None,
+                advice: None,
                 name: Some(s.r#type.to_string()),
                 lineage: Some(weaver_resolved_schema::lineage::GroupLineage::new(
                     get_source_provenance(&s.provenance),
@@ -451,6 +469,7 @@ impl ImportableDependency for V2Schema {
                 body: None,
                 annotations: Some(s.common.annotations.clone()),
                 entity_associations: s.entity_associations.clone(),
+                span_name: Some(s.name.clone()),
                 visibility: None,
                 is_v2: true,
             });
@@ -497,12 +516,14 @@ impl ImportableDependency for V2Schema {
                 instrument: None,
                 unit: None,
                 metric_requirement_level: None,
+                advice: None,
                 name: None,
                 lineage: None,
                 display_name: None,
                 body: None,
                 annotations: Some(ag.common.annotations.clone()),
                 entity_associations: vec![],
+                span_name: None,
                 visibility: None,
                 is_v2: true,
             });
@@ -567,6 +588,7 @@ impl GroupRefinementLookup for V1Schema {
                         r#type: a.r#type.clone(),
                         brief: Some(a.brief.clone()),
                         examples: a.examples.clone(),
+                        constraints: a.constraints.clone(),
                         tag: a.tag.clone(),
                         requirement_level: a.requirement_level.clone(),
                         sampling_relevant: a.sampling_relevant,
@@ -608,12 +630,14 @@ impl GroupRefinementLookup for V2Schema {
                 instrument: Some(m.instrument.clone()),
                 unit: Some(m.unit.clone()),
                 metric_requirement_level: None,
+                advice: m.advice.clone(),
                 name: None,
                 lineage: None,
                 display_name: None,
                 body: None,
                 annotations: Some(m.common.annotations.clone()),
                 entity_associations: m.entity_associations.clone(),
+                span_name: None,
                 visibility: None,
                 is_v2: true,
             })
@@ -638,12 +662,14 @@ impl GroupRefinementLookup for V2Schema {
                         instrument: None,
                         unit: None,
                         metric_requirement_level: None,
+                        advice: None,
                         name: Some(e.name.to_string()),
                         lineage: None,
                         display_name: None,
                         body: None,
                         annotations: Some(e.common.annotations.clone()),
                         entity_associations: e.entity_associations.clone(),
+                        span_name: None,
                         visibility: None,
                         is_v2: true,
                     })
@@ -669,12 +695,14 @@ impl GroupRefinementLookup for V2Schema {
                         instrument: None,
                         unit: None,
                         metric_requirement_level: None,
+                        advice: None,
                         name: Some(e.r#type.to_string()),
                         lineage: None,
                         display_name: None,
                         body: None,
                         annotations: Some(e.common.annotations.clone()),
                         entity_associations: vec![],
+                        span_name: None,
                         visibility: None,
                         is_v2: true,
                     })
@@ -693,6 +721,7 @@ impl GroupRefinementLookup for V2Schema {
                         r#type: a.r#type.clone(),
                         brief: Some(a.common.brief.clone()),
                         examples: a.examples.clone(),
+                        constraints: a.constraints.clone(),
                         tag: None,
                         requirement_level: RequirementLevel::Basic(
                             BasicRequirementLevelSpec::Recommended,
@@ -799,12 +828,14 @@ mod tests {
                         instrument: Default::default(),
                         unit: Default::default(),
                         metric_requirement_level: Default::default(),
+                        advice: None,
                         name: Default::default(),
                         lineage: Default::default(),
                         display_name: Default::default(),
                         body: Default::default(),
                         annotations: Default::default(),
                         entity_associations: Default::default(),
+                        span_name: None,
                         visibility: Default::default(),
                         is_v2: Default::default(),
                     },
@@ -824,12 +855,14 @@ mod tests {
                         instrument: Default::default(),
                         unit: Default::default(),
                         metric_requirement_level: Default::default(),
+                        advice: None,
                         name: Default::default(),
                         lineage: Default::default(),
                         display_name: Default::default(),
                         body: Default::default(),
                         annotations: Default::default(),
                         entity_associations: Default::default(),
+                        span_name: None,
                         visibility: Default::default(),
                         is_v2: Default::default(),
                     },
@@ -843,6 +876,7 @@ mod tests {
                     ),
                     brief: Default::default(),
                     examples: Default::default(),
+                    constraints: None,
                     tag: Default::default(),
                     requirement_level: Default::default(),
                     sampling_relevant: Default::default(),
@@ -885,6 +919,7 @@ mod tests {
                     attributes: vec![],
                     entity_associations: vec![],
                     requirement_level: None,
+                    advice: None,
                     common: Default::default(),
                     provenance: Default::default(),
                 }],
@@ -900,9 +935,12 @@ mod tests {
                     kind: weaver_semconv::group::SpanKindSpec::Client,
                     name: weaver_semconv::v2::span::SpanName {
                         note: "test".to_owned(),
+                        template: None,
+                        fallbacks: vec![],
                     },
                     attributes: vec![],
                     entity_associations: vec![],
+                    events: vec![],
                     common: Default::default(),
                     provenance: Default::default(),
                 }],
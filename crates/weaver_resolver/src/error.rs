@@ -166,6 +166,20 @@ pub enum Error {
         signal_type: String,
     },
 
+    /// An invalid span name template.
+    #[error("Invalid span name template `{template}` in group '{group_id}': {error}")]
+    #[diagnostic(help(
+        "Placeholders must be written as `{{attribute.key}}` and reference attributes of the span."
+    ))]
+    InvalidSpanNameTemplate {
+        /// The id of the span group.
+        group_id: String,
+        /// The invalid template.
+        template: String,
+        /// The error that occurred.
+        error: String,
+    },
+
     /// A duplicate attribute id error.
     #[error("The attribute id `{attribute_id}` is declared multiple times in the following groups:\n{group_ids:?}")]
     DuplicateAttributeId {
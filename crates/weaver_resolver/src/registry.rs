@@ -191,6 +191,7 @@ pub(crate) fn resolve_registry_with_dependencies(
         },
     );
     check_root_attribute_id_duplicates(&result, &attr_name_index, &mut errors);
+    check_span_name_templates(&result, &attr_name_index, &mut errors);
 
     WResult::OkWithNFEs(result, errors)
 }
@@ -289,6 +290,41 @@ pub fn check_root_attribute_id_duplicates(
     errors.extend(local_errors);
 }
 
+/// Checks that the span name templates of every span group are well-formed and only
+/// reference attributes declared (or inherited) by the span.
+fn check_span_name_templates(
+    registry: &Registry,
+    attr_name_index: &[String],
+    errors: &mut Vec<Error>,
+) {
+    for group in registry.groups.iter() {
+        let Some(span_name) = group.span_name.as_ref() else {
+            continue;
+        };
+        let attr_ids: HashSet<&str> = group
+            .attributes
+            .iter()
+            .map(|attr_ref| attr_name_index[attr_ref.0 as usize].as_str())
+            .collect();
+        for template in span_name.templates() {
+            let error = template.validate().err().or_else(|| {
+                template
+                    .attributes()
+                    .into_iter()
+                    .find(|key| !attr_ids.contains(key))
+                    .map(|key| format!("attribute `{key}` is not defined on the span"))
+            });
+            if let Some(error) = error {
+                errors.push(Error::InvalidSpanNameTemplate {
+                    group_id: group.id.clone(),
+                    template: template.to_string(),
+                    error,
+                });
+            }
+        }
+    }
+}
+
 /// Creates a group from a semantic convention group specification.
 /// Note: this function does not resolve references.
 fn group_from_spec(group: GroupSpecWithProvenance) -> UnresolvedGroup {
@@ -316,12 +352,14 @@ fn group_from_spec(group: GroupSpecWithProvenance) -> UnresolvedGroup {
             instrument: group.spec.instrument,
             unit: group.spec.unit,
             metric_requirement_level: group.spec.metric_requirement_level,
+            advice: group.spec.advice,
             name: group.spec.name,
             lineage: Some(GroupLineage::new(group.provenance.clone())),
             display_name: group.spec.display_name,
             body: group.spec.body,
             annotations: group.spec.annotations,
             entity_associations: group.spec.entity_associations,
+            span_name: group.spec.span_name,
             visibility: group.spec.visibility.clone(),
             is_v2: group.spec.is_v2,
         },
@@ -533,9 +571,12 @@ fn resolve_extends_references(ureg: &mut UnresolvedRegistry) -> Result<(), Error
                         unresolved_group.group.instrument = parent_summary.instrument.clone();
                         unresolved_group.group.unit = parent_summary.unit.clone();
                         unresolved_group.group.span_kind = parent_summary.span_kind;
+                        unresolved_group.group.span_name = parent_summary.span_name.clone();
+                        unresolved_group.group.events = parent_summary.events.clone();
                         unresolved_group.group.metric_name = parent_summary.metric_name.clone();
                         unresolved_group.group.metric_requirement_level =
                             parent_summary.metric_requirement_level.clone();
+                        unresolved_group.group.advice = parent_summary.advice.clone();
 
                         // Optionally copy over fields if refinements have not set them.
                         if unresolved_group.group.stability.is_none() {
@@ -822,6 +863,7 @@ fn resolve_inheritance_attr(
                     r#type: parent_type,
                     brief: parent_brief,
                     examples: parent_examples,
+                    constraints: parent_constraints,
                     tag: parent_tag,
                     requirement_level: parent_requirement_level,
                     sampling_relevant: parent_sampling_relevant,
@@ -839,6 +881,7 @@ fn resolve_inheritance_attr(
                         r#type: parent_type.clone(),
                         brief: lineage.optional_brief(brief, parent_brief),
                         examples: lineage.examples(examples, parent_examples),
+                        constraints: parent_constraints.clone(),
                         tag: lineage.tag(tag, parent_tag),
                         requirement_level: lineage
                             .requirement_level(requirement_level, parent_requirement_level),
@@ -1321,6 +1364,7 @@ groups:
                 ),
                 brief: Default::default(),
                 examples: Default::default(),
+                constraints: None,
                 tag: Default::default(),
                 requirement_level: Default::default(),
                 sampling_relevant: Default::default(),
@@ -1358,12 +1402,14 @@ groups:
                     instrument: Default::default(),
                     unit: Default::default(),
                     metric_requirement_level: Default::default(),
+                    advice: None,
                     name: Default::default(),
                     lineage: Default::default(),
                     display_name: Default::default(),
                     body: Default::default(),
                     annotations: Default::default(),
                     entity_associations: Default::default(),
+                    span_name: None,
                     visibility: Default::default(),
                     is_v2: false,
                 },
@@ -1406,6 +1452,7 @@ groups:
                 ),
                 brief: Default::default(),
                 examples: Default::default(),
+                constraints: None,
                 tag: Default::default(),
                 requirement_level: Default::default(),
                 sampling_relevant: Default::default(),
@@ -1447,12 +1494,14 @@ groups:
                         instrument: Default::default(),
                         unit: Default::default(),
                         metric_requirement_level: Default::default(),
+                        advice: None,
                         name: Default::default(),
                         lineage: Default::default(),
                         display_name: Default::default(),
                         body: Default::default(),
                         annotations: Default::default(),
                         entity_associations: Default::default(),
+                        span_name: None,
                         visibility: Default::default(),
                         is_v2: false,
                     },
@@ -1482,12 +1531,14 @@ groups:
                         instrument: Default::default(),
                         unit: Default::default(),
                         metric_requirement_level: Default::default(),
+                        advice: None,
                         name: Default::default(),
                         lineage: Default::default(),
                         display_name: Default::default(),
                         body: Default::default(),
                         annotations: Default::default(),
                         entity_associations: Default::default(),
+                        span_name: None,
                         visibility: Default::default(),
                         is_v2: false,
                     },
@@ -1517,12 +1568,14 @@ groups:
                         instrument: Default::default(),
                         unit: Default::default(),
                         metric_requirement_level: Default::default(),
+                        advice: None,
                         name: Default::default(),
                         lineage: Default::default(),
                         display_name: Default::default(),
                         body: Default::default(),
                         annotations: Default::default(),
                         entity_associations: Default::default(),
+                        span_name: None,
                         visibility: Default::default(),
                         is_v2: false,
                     },
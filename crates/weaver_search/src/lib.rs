@@ -10,7 +10,9 @@
 
 mod types;
 
-pub use types::{NamespaceAttribute, NamespaceInfo, ScoredResult, SearchResult, SearchType};
+pub use types::{
+    AttributeSuggestion, NamespaceAttribute, NamespaceInfo, ScoredResult, SearchResult, SearchType,
+};
 
 use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
@@ -22,6 +24,12 @@ use weaver_forge::v2::{
 use weaver_semconv::attribute::AttributeType;
 use weaver_semconv::stability::Stability;
 
+/// Words of a description that are too common to select attributes.
+const STOP_WORDS: &[&str] = &[
+    "and", "are", "for", "from", "has", "how", "into", "its", "not", "that", "the", "this", "was",
+    "when", "which", "with",
+];
+
 //TODO: Consider using a fuzzy matching crate for improved search capabilities.
 // e.g. Tantivy - https://github.com/open-telemetry/weaver/pull/1076#discussion_r2640681775
 
@@ -324,6 +332,58 @@ impl SearchContext {
             max_depth,
         }
     }
+
+    /// Suggest existing attributes to reuse for a concept described in free text,
+    /// e.g. "the HTTP method of the incoming request".
+    ///
+    /// Unlike `search`, the terms of the description are matched independently:
+    /// attributes matching the most terms come first, then the highest scores.
+    /// Deprecated attributes are never suggested.
+    #[must_use]
+    pub fn suggest_attributes(&self, description: &str, limit: usize) -> Vec<AttributeSuggestion> {
+        let description = description.to_lowercase();
+        let mut terms: Vec<&str> = description
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|term| term.len() > 2 && !STOP_WORDS.contains(term))
+            .collect();
+        terms.sort_unstable();
+        terms.dedup();
+
+        let mut suggestions: Vec<AttributeSuggestion> = self
+            .items
+            .iter()
+            .filter(|item| !item.is_deprecated())
+            .filter_map(|item| {
+                let SearchableItem::Attribute(attribute) = item else {
+                    return None;
+                };
+                let mut score = 0;
+                let mut matched_terms = Vec::new();
+                for term in &terms {
+                    let term_score = score_match(term, item, &self.separator);
+                    if term_score > 0 {
+                        score += term_score;
+                        matched_terms.push((*term).to_owned());
+                    }
+                }
+                (!matched_terms.is_empty()).then(|| AttributeSuggestion {
+                    score,
+                    matched_terms,
+                    attribute: Arc::clone(attribute),
+                })
+            })
+            .collect();
+
+        suggestions.sort_by(|a, b| {
+            b.matched_terms
+                .len()
+                .cmp(&a.matched_terms.len())
+                .then(b.score.cmp(&a.score))
+                .then_with(|| a.attribute.key.cmp(&b.attribute.key))
+        });
+        suggestions.truncate(limit);
+        suggestions
+    }
 }
 
 /// Search mode with total count: perform fuzzy matching with scoring and return (results, total).
@@ -581,6 +641,7 @@ mod tests {
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: brief.to_owned(),
                 note: note.to_owned(),
@@ -603,6 +664,7 @@ mod tests {
             key: key.to_owned(),
             r#type: AttributeType::Template(weaver_semconv::attribute::TemplateTypeSpec::String),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: brief.to_owned(),
                 note: "".to_owned(),
@@ -621,6 +683,7 @@ mod tests {
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
             examples: None,
+            constraints: None,
             common: CommonFields {
                 brief: brief.to_owned(),
                 note: "".to_owned(),
@@ -663,6 +726,7 @@ mod tests {
                     requirement_level: Some(
                         weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
                     ),
+                    advice: None,
                     attributes: vec![],
                     entity_associations: vec![],
                     common: CommonFields {
@@ -679,9 +743,12 @@ mod tests {
                     kind: SpanKindSpec::Client,
                     name: SpanName {
                         note: "HTTP client span".to_owned(),
+                        template: None,
+                        fallbacks: vec![],
                     },
                     attributes: vec![],
                     entity_associations: vec![],
+                    events: vec![],
                     common: CommonFields {
                         brief: "HTTP client span".to_owned(),
                         note: "".to_owned(),
@@ -1018,6 +1085,56 @@ mod tests {
         assert_eq!(dev_item.stability(), &Stability::Development);
     }
 
+    // =========================================================================
+    // Attribute Suggestion Tests
+    // =========================================================================
+
+    #[test]
+    fn test_suggest_attributes() {
+        let registry = make_test_registry();
+        let ctx = SearchContext::from_registry(&registry);
+
+        let suggestions = ctx.suggest_attributes("The status code of the HTTP response", 10);
+        assert_eq!(suggestions[0].attribute.key, "http.response.status_code");
+        assert_eq!(
+            suggestions[0].matched_terms,
+            vec!["code", "http", "response", "status"]
+        );
+        // Attributes matching fewer terms are ranked after.
+        assert!(suggestions
+            .iter()
+            .skip(1)
+            .all(|s| s.matched_terms.len() < 4));
+        assert!(suggestions
+            .iter()
+            .any(|s| s.attribute.key == "http.request.method"));
+
+        // Only attributes are suggested, up to the limit.
+        let suggestions = ctx.suggest_attributes("http", 1);
+        assert_eq!(suggestions.len(), 1);
+        assert!(ctx.suggest_attributes("service exception", 10).is_empty());
+        assert!(ctx.suggest_attributes("the of", 10).is_empty());
+    }
+
+    #[test]
+    fn test_suggest_attributes_skips_deprecated() {
+        let registry = ForgeResolvedRegistry {
+            registry: Registry {
+                attributes: vec![
+                    make_attribute("db.system", "Database system", "", true),
+                    make_attribute("db.system.name", "Database system name", "", false),
+                ],
+                ..make_test_registry().registry
+            },
+            ..make_test_registry()
+        };
+        let ctx = SearchContext::from_registry(&registry);
+
+        let suggestions = ctx.suggest_attributes("database system", 10);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].attribute.key, "db.system.name");
+    }
+
     // =========================================================================
     // Namespace Browsing Tests
     // =========================================================================
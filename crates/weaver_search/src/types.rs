@@ -66,6 +66,18 @@ impl NamespaceAttribute {
     }
 }
 
+/// An existing attribute suggested for reuse, see `SearchContext::suggest_attributes`.
+#[derive(Debug, Serialize, Clone)]
+pub struct AttributeSuggestion {
+    /// The sum of the relevance scores of the matched terms.
+    pub score: u32,
+    /// The terms of the description matched by the attribute.
+    pub matched_terms: Vec<String>,
+    /// The full attribute.
+    #[serde(flatten)]
+    pub attribute: Arc<Attribute>,
+}
+
 /// Information about a namespace and its contents.
 #[derive(Debug, Serialize, Clone)]
 pub struct NamespaceInfo {
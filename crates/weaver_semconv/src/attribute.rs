@@ -107,6 +107,9 @@ pub enum AttributeSpec {
         /// be reported without encapsulating it into a sequence/dictionary.
         #[serde(skip_serializing_if = "Option::is_none")]
         examples: Option<Examples>,
+        /// Constraints on the values of the attribute (pattern, range, length).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        constraints: Option<ValueConstraints>,
         /// Associates a tag ("sub-group") to the attribute. It carries no
         /// particular semantic meaning but can be used e.g. for filtering
         /// in the markdown generator.
@@ -488,6 +491,119 @@ impl From<&str> for ValueSpec {
     }
 }
 
+/// Constraints on the values of an attribute, in addition to its type.
+///
+/// For array types, the constraints apply to each element of the array.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq, Hash, JsonSchema, PartialOrd, Ord,
+)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ValueConstraints {
+    /// A regular expression string values must match. The expression is not
+    /// anchored, use `^` and `$` to match the whole value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// The minimum (inclusive) of int and double values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<ConstraintBound>,
+    /// The maximum (inclusive) of int and double values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<ConstraintBound>,
+    /// The minimum length of string values, in characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u64>,
+    /// The maximum length of string values, in characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u64>,
+}
+
+/// A bound of a value range constraint.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash, JsonSchema, PartialOrd, Ord,
+)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(untagged)]
+pub enum ConstraintBound {
+    /// An integer bound.
+    Int(i64),
+    /// A double bound.
+    #[cfg_attr(feature = "openapi", schema(value_type = f64))]
+    Double(OrderedF64),
+}
+
+impl ConstraintBound {
+    /// Returns the bound as a double.
+    #[must_use]
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            ConstraintBound::Int(v) => *v as f64,
+            ConstraintBound::Double(v) => v.0,
+        }
+    }
+}
+
+impl Display for ConstraintBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstraintBound::Int(v) => write!(f, "{v}"),
+            ConstraintBound::Double(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+impl ValueConstraints {
+    /// Checks that the constraints are well-formed and apply to the given
+    /// attribute type.
+    pub fn validate(&self, attr_type: &AttributeType) -> Result<(), String> {
+        let (is_string, is_number) = match attr_type {
+            PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String)
+            | PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Strings)
+            | Template(TemplateTypeSpec::String)
+            | Template(TemplateTypeSpec::Strings) => (true, false),
+            PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int)
+            | PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Ints)
+            | PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Double)
+            | PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Doubles)
+            | Template(TemplateTypeSpec::Int)
+            | Template(TemplateTypeSpec::Ints)
+            | Template(TemplateTypeSpec::Double)
+            | Template(TemplateTypeSpec::Doubles) => (false, true),
+            _ => (false, false),
+        };
+        if !is_string
+            && (self.pattern.is_some() || self.min_length.is_some() || self.max_length.is_some())
+        {
+            return Err(format!(
+                "`pattern`, `min_length` and `max_length` only apply to string attributes, not to `{attr_type}`."
+            ));
+        }
+        if !is_number && (self.min.is_some() || self.max.is_some()) {
+            return Err(format!(
+                "`min` and `max` only apply to int and double attributes, not to `{attr_type}`."
+            ));
+        }
+        if let Some(pattern) = &self.pattern {
+            if let Err(e) = regex::Regex::new(pattern) {
+                return Err(format!("Invalid `pattern` regular expression: {e}"));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min.as_f64() > max.as_f64() {
+                return Err(format!("`min` ({min}) is greater than `max` ({max})."));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_length, self.max_length) {
+            if min > max {
+                return Err(format!(
+                    "`min_length` ({min}) is greater than `max_length` ({max})."
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// The different types of examples.
 #[derive(
     Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash, JsonSchema, PartialOrd, Ord,
@@ -1007,6 +1123,7 @@ mod tests {
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
             brief: Some("brief".to_owned()),
             examples: Some(Examples::Int(42)),
+            constraints: None,
             tag: Some("tag".to_owned()),
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             sampling_relevant: Some(true),
@@ -1403,6 +1520,30 @@ mod tests {
         assert!(!PrimitiveOrArrayTypeSpec::Double.is_compatible(&PrimitiveOrArrayTypeSpec::Int));
         assert!(!PrimitiveOrArrayTypeSpec::Doubles.is_compatible(&PrimitiveOrArrayTypeSpec::Ints));
     }
+
+    #[test]
+    fn test_value_constraints_validate() {
+        let int = PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int);
+        let string = PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String);
+
+        let range: ValueConstraints = serde_yaml::from_str("min: 0\nmax: 65535").unwrap();
+        assert_eq!(range.min, Some(ConstraintBound::Int(0)));
+        assert!(range.validate(&int).is_ok());
+        assert!(range.validate(&string).is_err());
+
+        let pattern: ValueConstraints =
+            serde_yaml::from_str("pattern: '^[a-z]+$'\nmax_length: 8").unwrap();
+        assert!(pattern.validate(&string).is_ok());
+        assert!(pattern.validate(&int).is_err());
+
+        let invalid_regex: ValueConstraints = serde_yaml::from_str("pattern: '[a-z'").unwrap();
+        assert!(invalid_regex.validate(&string).is_err());
+
+        let inverted: ValueConstraints = serde_yaml::from_str("min: 10\nmax: 1.5").unwrap();
+        assert!(inverted.validate(&int).is_err());
+
+        assert!(serde_yaml::from_str::<ValueConstraints>("minimum: 1").is_err());
+    }
 }
 
 /// An attribute definition with its provenance (path or URL).
@@ -19,7 +19,9 @@ use crate::group::InstrumentSpec::{Counter, Gauge, Histogram, UpDownCounter};
 use crate::provenance::Provenance;
 use crate::semconv::Imports;
 use crate::stability::Stability;
+use crate::unit::validate_metric_unit;
 use crate::v2::attribute_group::AttributeGroupVisibilitySpec;
+use crate::v2::span::SpanName;
 use crate::{Error, YamlValue};
 use weaver_common::result::WResult;
 
@@ -105,6 +107,11 @@ pub struct GroupSpec {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metric_requirement_level: Option<BasicRequirementLevelSpec>,
+    /// Advisory parameters for the instrument recording the metric, such as
+    /// the recommended histogram bucket boundaries.
+    /// Note: This field is only valid if type is metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advice: Option<MetricAdviceSpec>,
     /// The name of the event (valid only when the group `type` is `event`).
     ///
     /// Note: If not specified, the prefix is used. If the prefix is empty (or unspecified), the name is required.
@@ -134,6 +141,13 @@ pub struct GroupSpec {
     #[schemars(skip)]
     pub include_groups: Vec<String>,
 
+    /// The name specification of a span, including its optional templates.
+    /// This parameter must not be provided in yaml, it's only used to convert v2 schema into v1
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    #[schemars(skip)]
+    pub span_name: Option<SpanName>,
+
     /// Visibility of the attribute group.
     /// This parameter must not be provided in yaml, it's only used to convert v2 schema into v1
     #[serde(default)]
@@ -299,12 +313,13 @@ impl GroupSpec {
                         .to_owned(),
                 });
             }
-            if self.unit.is_none() {
-                errors.push(Error::InvalidMetric {
+            match &self.unit {
+                None => errors.push(Error::InvalidMetric {
                     path_or_url: path_or_url.to_owned(),
                     group_id: self.id.clone(),
                     error: "This group contains a metric type but the unit is not set.".to_owned(),
-                });
+                }),
+                Some(unit) => errors.extend(validate_metric_unit(unit, &self.id, path_or_url)),
             }
             if self.metric_requirement_level.is_none() {
                 errors.push(Error::MissingMetricRequirementLevelWarning {
@@ -312,6 +327,22 @@ impl GroupSpec {
                     group_id: self.id.clone(),
                 });
             }
+            if let Some(advice) = &self.advice {
+                if let Err(error) = advice.validate(self.instrument.as_ref()) {
+                    errors.push(Error::InvalidMetric {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: self.id.clone(),
+                        error,
+                    });
+                }
+            }
+        } else if self.advice.is_some() {
+            errors.push(Error::InvalidGroup {
+                path_or_url: path_or_url.to_owned(),
+                group_id: self.id.clone(),
+                error: "This group contains an advice field but the type is not set to metric."
+                    .to_owned(),
+            });
         }
 
         if matches!(self.deprecated, Some(Deprecated::Unspecified { .. })) {
@@ -330,6 +361,7 @@ impl GroupSpec {
                     deprecated,
                     stability,
                     r#type,
+                    constraints,
                     ..
                 } => {
                     if brief.is_none() && deprecated.is_none() {
@@ -402,6 +434,15 @@ impl GroupSpec {
                                 .to_owned(),
                         });
                     }
+
+                    if let Some(Err(error)) = constraints.as_ref().map(|c| c.validate(r#type)) {
+                        errors.push(Error::InvalidAttribute {
+                            path_or_url: path_or_url.to_owned(),
+                            group_id: self.id.clone(),
+                            attribute_id: attribute.id(),
+                            error: format!("Invalid value constraints. {error}"),
+                        });
+                    }
                 }
                 AttributeSpec::Ref { .. } => {}
             }
@@ -663,6 +704,42 @@ impl Display for InstrumentSpec {
     }
 }
 
+/// Advisory parameters of a metric instrument, as defined by the OpenTelemetry
+/// [instrument advisory parameters](https://opentelemetry.io/docs/specs/otel/metrics/api/#instrument-advisory-parameters).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(deny_unknown_fields)]
+pub struct MetricAdviceSpec {
+    /// The recommended explicit bucket boundaries of a histogram, in strictly
+    /// increasing order and expressed in the unit of the metric.
+    /// Note: only valid if the instrument is histogram.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explicit_bucket_boundaries: Option<Vec<f64>>,
+}
+
+impl MetricAdviceSpec {
+    /// Checks the advice against the instrument of the metric.
+    pub(crate) fn validate(&self, instrument: Option<&InstrumentSpec>) -> Result<(), String> {
+        let Some(boundaries) = &self.explicit_bucket_boundaries else {
+            return Ok(());
+        };
+        if instrument.is_some_and(|instrument| *instrument != Histogram) {
+            return Err(
+                "Explicit bucket boundaries are only valid for histogram metrics.".to_owned(),
+            );
+        }
+        if boundaries.iter().any(|boundary| !boundary.is_finite()) {
+            return Err("Explicit bucket boundaries must be finite numbers.".to_owned());
+        }
+        if boundaries.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(format!(
+                "Explicit bucket boundaries must be in strictly increasing order, found {boundaries:?}."
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// A group spec with its provenance (path or URL).
 #[derive(Debug, Clone)]
 pub struct GroupSpecWithProvenance {
@@ -720,6 +797,7 @@ mod tests {
                     note: "".to_owned(),
                 }),
                 examples: Some(Examples::String("test".to_owned())),
+                constraints: None,
                 tag: None,
                 requirement_level: Default::default(),
                 sampling_relevant: None,
@@ -733,11 +811,13 @@ mod tests {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: None,
             display_name: None,
             body: None,
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -893,6 +973,7 @@ mod tests {
                     note: "".to_owned(),
                 }),
                 examples: Some(Examples::String("test".to_owned())),
+                constraints: None,
                 tag: None,
                 requirement_level: Default::default(),
                 sampling_relevant: None,
@@ -906,11 +987,13 @@ mod tests {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: None,
             display_name: None,
             body: None,
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -929,6 +1012,7 @@ mod tests {
                 note: "".to_owned(),
             }),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -958,6 +1042,7 @@ mod tests {
                 note: "".to_owned(),
             }),
             examples: None,
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -987,6 +1072,7 @@ mod tests {
                 note: "".to_owned(),
             }),
             examples: Some(Examples::String("test".to_owned())),
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -1015,6 +1101,7 @@ mod tests {
                 note: "".to_owned(),
             }),
             examples: Some(Examples::String("test".to_owned())),
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -1054,6 +1141,7 @@ mod tests {
                 note: "".to_owned(),
             }),
             examples: Some(Examples::String("test".to_owned())),
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -1092,6 +1180,7 @@ mod tests {
                 note: "".to_owned(),
             }),
             examples: Some(Examples::String("test".to_owned())),
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -1121,6 +1210,7 @@ mod tests {
                     note: "note".to_owned(),
                 }),
                 examples: Some(Examples::String("test".to_owned())),
+                constraints: None,
                 tag: None,
                 requirement_level: Default::default(),
                 sampling_relevant: None,
@@ -1147,6 +1237,7 @@ mod tests {
                 stability: Some(Stability::Stable),
                 deprecated: None,
                 examples: Some(Examples::String("test".to_owned())),
+                constraints: None,
                 tag: None,
                 requirement_level: Default::default(),
                 sampling_relevant: None,
@@ -1196,6 +1287,7 @@ mod tests {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             display_name: None,
             attributes: vec![],
             body: Some(AnyValueSpec::String {
@@ -1212,6 +1304,7 @@ mod tests {
             }),
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -1415,6 +1508,7 @@ mod tests {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             display_name: None,
             attributes: vec![],
             body: Some(AnyValueSpec::String {
@@ -1431,6 +1525,7 @@ mod tests {
             }),
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -1561,6 +1656,7 @@ mod tests {
                     note: "".to_owned(),
                 }),
                 examples: Some(Examples::String("test".to_owned())),
+                constraints: None,
                 tag: None,
                 requirement_level: Default::default(),
                 sampling_relevant: None,
@@ -1574,11 +1670,13 @@ mod tests {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: None,
             display_name: None,
             body: None,
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -1646,7 +1744,7 @@ mod tests {
         group.r#type = GroupType::Metric;
         group.metric_name = Some("test".to_owned());
         group.instrument = Some(Counter);
-        group.unit = Some("test".to_owned());
+        group.unit = Some("{test}".to_owned());
         group.metric_requirement_level = Some(BasicRequirementLevelSpec::Recommended);
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
@@ -1725,6 +1823,7 @@ mod tests {
                 note: "".to_owned(),
             }),
             examples: Some(Examples::String("test".to_owned())),
+            constraints: None,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -1749,11 +1848,13 @@ mod tests {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: None,
             display_name: None,
             body: None,
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -1841,7 +1942,7 @@ mod tests {
         group.r#type = GroupType::Metric;
         group.metric_name = Some("test".to_owned());
         group.instrument = Some(Counter);
-        group.unit = Some("test".to_owned());
+        group.unit = Some("{test}".to_owned());
         group.metric_requirement_level = Some(BasicRequirementLevelSpec::Recommended);
         assert!(group
             .validate("<test>")
@@ -1906,11 +2007,13 @@ mod tests {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: None,
             display_name: None,
             body: None,
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -1957,6 +2060,7 @@ mod tests {
                     note: "".to_owned(),
                 }),
                 examples: Some(Examples::String("test".to_owned())),
+                constraints: None,
                 tag: None,
                 requirement_level: Default::default(),
                 sampling_relevant: None,
@@ -1970,11 +2074,13 @@ mod tests {
             instrument: Some(Gauge),
             unit: Some("{thing}".to_owned()),
             metric_requirement_level: Some(BasicRequirementLevelSpec::Recommended),
+            advice: None,
             name: None,
             display_name: None,
             body: None,
             annotations: None,
             entity_associations: vec!["test".to_owned()],
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -2033,11 +2139,13 @@ mod tests {
             instrument: Some(Counter),
             unit: Some("{request}".to_owned()),
             metric_requirement_level: None,
+            advice: None,
             name: None,
             display_name: None,
             body: None,
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
@@ -2117,6 +2225,69 @@ groups:
         );
     }
 
+    #[test]
+    fn test_validate_metric_advice() {
+        let yaml = r#"
+groups:
+  - id: metric.http.server.request.duration
+    type: metric
+    brief: "Duration of HTTP server requests."
+    stability: stable
+    metric_name: http.server.request.duration
+    instrument: histogram
+    unit: "s"
+    metric_requirement_level: required
+    advice:
+      explicit_bucket_boundaries: [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1, 2.5, 5, 10]
+"#;
+        let semconv: crate::semconv::SemConvSpecV1 =
+            serde_yaml::from_str(yaml).expect("Failed to parse YAML");
+        let group = semconv.groups.first().expect("Expected at least one group");
+        assert_eq!(
+            group
+                .advice
+                .as_ref()
+                .and_then(|advice| advice.explicit_bucket_boundaries.as_ref())
+                .map(Vec::len),
+            Some(11)
+        );
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        // Boundaries must be strictly increasing
+        let mut invalid = group.clone();
+        invalid.advice = Some(MetricAdviceSpec {
+            explicit_bucket_boundaries: Some(vec![0.1, 0.5, 0.5, 1.0]),
+        });
+        assert!(matches!(
+            invalid.validate("<test>").into_result_failing_non_fatal(),
+            Err(InvalidMetric { error, .. }) if error.contains("strictly increasing")
+        ));
+
+        // Boundaries are only valid for histograms
+        let mut invalid = group.clone();
+        invalid.instrument = Some(Counter);
+        assert!(matches!(
+            invalid.validate("<test>").into_result_failing_non_fatal(),
+            Err(InvalidMetric { error, .. }) if error.contains("only valid for histogram")
+        ));
+
+        // Advice is only valid for metrics
+        let mut invalid = group.clone();
+        invalid.r#type = GroupType::Event;
+        invalid.name = Some("test".to_owned());
+        invalid.metric_name = None;
+        invalid.instrument = None;
+        invalid.unit = None;
+        invalid.metric_requirement_level = None;
+        assert!(matches!(
+            invalid.validate("<test>").into_result_failing_non_fatal(),
+            Err(InvalidGroup { error, .. }) if error.contains("advice")
+        ));
+    }
+
     #[test]
     fn test_missing_metric_requirement_level_future_mode() {
         use miette::{Diagnostic, Severity};
@@ -2141,11 +2312,13 @@ groups:
             instrument: Some(Counter),
             unit: Some("{request}".to_owned()),
             metric_requirement_level: None,
+            advice: None,
             name: None,
             display_name: None,
             body: None,
             annotations: None,
             entity_associations: Vec::new(),
+            span_name: None,
             visibility: None,
             is_v2: false,
         };
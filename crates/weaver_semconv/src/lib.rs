@@ -25,6 +25,7 @@ pub mod schema_url;
 pub mod semconv;
 pub mod stability;
 pub mod stats;
+pub mod unit;
 pub mod v2;
 
 /// An error that can occur while loading a semantic convention registry.
@@ -250,6 +251,35 @@ pub enum Error {
         error: String,
     },
 
+    /// This warning indicates that the unit of a metric is not a valid UCUM unit.
+    #[error(
+        "The metric group `{group_id}` has an invalid unit. {error}\nProvenance: {path_or_url:?}"
+    )]
+    #[diagnostic(severity(Warning))]
+    InvalidMetricUnit {
+        /// The path or URL of the semantic convention asset.
+        path_or_url: String,
+        /// The group id of the metric.
+        group_id: String,
+        /// The reason of the error.
+        error: String,
+    },
+
+    /// This warning indicates that the unit of a metric is a common non-canonical
+    /// spelling of a UCUM unit (e.g. `bytes` instead of `By`).
+    #[error("The metric group `{group_id}` uses the non-canonical unit `{unit}`, use `{canonical}` instead.\nProvenance: {path_or_url:?}")]
+    #[diagnostic(severity(Warning))]
+    NonCanonicalMetricUnit {
+        /// The path or URL of the semantic convention asset.
+        path_or_url: String,
+        /// The group id of the metric.
+        group_id: String,
+        /// The unit of the metric.
+        unit: String,
+        /// The canonical form of the unit.
+        canonical: String,
+    },
+
     /// This indicates that any_value is invalid.
     #[error("The value `{value_id}` in the group `{group_id}` is invalid. {error}\nProvenance: {path_or_url:?}")]
     #[diagnostic(severity(Warning))]
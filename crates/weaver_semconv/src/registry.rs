@@ -305,6 +305,7 @@ mod tests {
                             ),
                             brief: None,
                             examples: None,
+                            constraints: None,
                             tag: None,
                             requirement_level: Default::default(),
                             sampling_relevant: None,
@@ -320,6 +321,7 @@ mod tests {
                         instrument: None,
                         unit: None,
                         metric_requirement_level: None,
+                        advice: None,
                         brief: "brief".to_owned(),
                         note: "note".to_owned(),
                         extends: None,
@@ -332,6 +334,7 @@ mod tests {
                         body: None,
                         annotations: None,
                         entity_associations: Vec::new(),
+                        span_name: None,
                         visibility: None,
                         is_v2: false,
                     }],
@@ -351,6 +354,7 @@ mod tests {
                         instrument: None,
                         unit: None,
                         metric_requirement_level: None,
+                        advice: None,
                         brief: "brief".to_owned(),
                         note: "note".to_owned(),
                         extends: None,
@@ -363,6 +367,7 @@ mod tests {
                         body: None,
                         annotations: None,
                         entity_associations: Vec::new(),
+                        span_name: None,
                         visibility: None,
                         is_v2: false,
                     }],
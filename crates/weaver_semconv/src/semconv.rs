@@ -134,8 +134,7 @@ impl Versioned {
     pub fn validate(self, provenance: &str) -> WResult<Self, Error> {
         match self {
             Versioned::V1(v1) => v1.validate(provenance).map(Versioned::V1),
-            // TODO - what validation is needed on V2?
-            Versioned::V2(v2) => WResult::Ok(Versioned::V2(v2)),
+            Versioned::V2(v2) => v2.validate(provenance).map(Versioned::V2),
         }
     }
 }
@@ -385,20 +384,52 @@ impl SemConvSpecWithProvenance {
             Ok(value) => value,
             Err(e) => return WResult::FatalErr(e),
         };
+        Self::from_yaml(yaml_value, &path, provenance)
+    }
+
+    /// Creates a semantic convention spec with provenance from a YAML string,
+    /// e.g. a snippet that is not (yet) part of a registry.
+    ///
+    /// # Arguments:
+    ///
+    /// * `provenance` - The provenance of the semantic convention spec.
+    /// * `content` - The YAML content of the semantic convention spec (v1 or v2).
+    ///
+    /// # Returns
+    ///
+    /// The semantic convention with provenance or an error if the semantic
+    /// convention spec is invalid.
+    pub fn from_string(
+        provenance: Provenance,
+        content: &str,
+    ) -> WResult<SemConvSpecWithProvenance, Error> {
+        let path = provenance.path.clone();
+        match serde_yaml::from_str(content) {
+            Ok(yaml_value) => Self::from_yaml(yaml_value, &path, provenance),
+            Err(e) => WResult::FatalErr(Error::DeserializationError {
+                path_or_url: path,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    /// Parses and validates a semantic convention spec from a YAML value.
+    fn from_yaml(
+        yaml_value: serde_yaml::Value,
+        path: &str,
+        provenance: Provenance,
+    ) -> WResult<SemConvSpecWithProvenance, Error> {
         let mut warnings = Vec::new();
 
-        let raw_spec = match from_yaml_value(yaml_value, &path, &mut warnings) {
+        let raw_spec = match from_yaml_value(yaml_value, path, &mut warnings) {
             Ok(semconv_spec) => {
                 // Important note: the resolution process expects this step of validation to be done for
                 // each semantic convention spec.
-                semconv_spec.validate(&path)
+                semconv_spec.validate(path)
             }
             Err(e) => WResult::FatalErr(e),
         };
-        let result = raw_spec.map(|spec| SemConvSpecWithProvenance {
-            spec,
-            provenance: provenance.clone(),
-        });
+        let result = raw_spec.map(|spec| SemConvSpecWithProvenance { spec, provenance });
         if warnings.is_empty() {
             result
         } else {
@@ -746,6 +777,7 @@ mod tests {
                     crate::attribute::PrimitiveOrArrayTypeSpec::Int,
                 ),
                 examples: None,
+                constraints: None,
                 common: CommonFields {
                     brief: "test attribute".to_owned(),
                     note: "".to_owned(),
@@ -842,6 +874,37 @@ attributes:
         assert_eq!("span.group2", group_ids[1]);
     }
 
+    #[test]
+    fn test_semconv_spec_with_provenance_from_string() {
+        let spec = r#"
+        groups:
+          - id: "registry.snippet"
+            type: attribute_group
+            brief: "A snippet"
+            attributes:
+              - id: "snippet.attr"
+                type: string
+                stability: development
+                brief: "An attribute"
+                examples: ["example"]
+        "#;
+        let provenance =
+            Provenance::new(crate::schema_url::SchemaUrl::new_unknown(), "snippet.yaml");
+
+        let semconv_spec = SemConvSpecWithProvenance::from_string(provenance.clone(), spec)
+            .into_result_failing_non_fatal()
+            .unwrap();
+        assert_eq!(semconv_spec.provenance, provenance);
+        assert_eq!(semconv_spec.into_v1().spec.groups.len(), 1);
+
+        let result = SemConvSpecWithProvenance::from_string(provenance, "groups: [")
+            .into_result_failing_non_fatal();
+        assert!(matches!(
+            result,
+            Err(Error::DeserializationError { path_or_url, .. }) if path_or_url == "snippet.yaml"
+        ));
+    }
+
     #[test]
     fn test_error_message_bad_format() {
         let spec = r#"
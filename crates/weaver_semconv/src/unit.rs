@@ -0,0 +1,518 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parsing of metric units written with the case-sensitive variant of the
+//! [Unified Code for Units of Measure](https://ucum.org/ucum) (UCUM), as required by the
+//! OpenTelemetry [unit guidelines](https://github.com/open-telemetry/opentelemetry-specification/tree/main/specification/metrics/semantic_conventions#instrument-units).
+//!
+//! The subset of UCUM used for telemetry is supported: the SI base and derived units, the
+//! information units (`bit`, `By`), the time units (`min`, `h`, `d`, ...), `%`, the decimal
+//! and binary prefixes, integer exponents and factors, the `.` and `/` operators,
+//! parentheses and curly-brace annotations (e.g. `{request}`).
+//!
+//! Unlike UCUM, annotations are not ignored when comparing units: `{request}` and
+//! `{packet}` are both dimensionless but they don't count the same thing.
+
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+use crate::Error;
+
+/// A unit that can't be parsed.
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+#[error("`{unit}` is not a valid UCUM unit. {reason}")]
+pub struct InvalidUnit {
+    /// The unit.
+    pub unit: String,
+    /// Why the unit is invalid.
+    pub reason: String,
+}
+
+/// A unit reduced to a scale factor applied to a product of base units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unit {
+    factor: f64,
+    /// Exponent of each base unit and annotation.
+    dimensions: BTreeMap<String, i32>,
+}
+
+/// Atoms: symbol, factor, base units and whether prefixes are allowed.
+#[allow(clippy::type_complexity)]
+const ATOMS: &[(&str, f64, &[(&str, i32)], bool)] = &[
+    ("m", 1.0, &[("m", 1)], true),
+    ("g", 1.0, &[("g", 1)], true),
+    ("s", 1.0, &[("s", 1)], true),
+    ("A", 1.0, &[("A", 1)], true),
+    ("K", 1.0, &[("K", 1)], true),
+    ("mol", 1.0, &[("mol", 1)], true),
+    ("cd", 1.0, &[("cd", 1)], true),
+    ("rad", 1.0, &[("rad", 1)], true),
+    ("sr", 1.0, &[("rad", 2)], true),
+    ("bit", 1.0, &[("bit", 1)], true),
+    ("By", 8.0, &[("bit", 1)], true),
+    ("Bd", 1.0, &[("s", -1)], true),
+    ("Hz", 1.0, &[("s", -1)], true),
+    ("N", 1000.0, &[("g", 1), ("m", 1), ("s", -2)], true),
+    ("Pa", 1000.0, &[("g", 1), ("m", -1), ("s", -2)], true),
+    ("J", 1000.0, &[("g", 1), ("m", 2), ("s", -2)], true),
+    ("W", 1000.0, &[("g", 1), ("m", 2), ("s", -3)], true),
+    ("C", 1.0, &[("A", 1), ("s", 1)], true),
+    (
+        "V",
+        1000.0,
+        &[("g", 1), ("m", 2), ("s", -3), ("A", -1)],
+        true,
+    ),
+    (
+        "Ohm",
+        1000.0,
+        &[("g", 1), ("m", 2), ("s", -3), ("A", -2)],
+        true,
+    ),
+    (
+        "S",
+        0.001,
+        &[("g", -1), ("m", -2), ("s", 3), ("A", 2)],
+        true,
+    ),
+    (
+        "F",
+        0.001,
+        &[("g", -1), ("m", -2), ("s", 4), ("A", 2)],
+        true,
+    ),
+    ("l", 0.001, &[("m", 3)], true),
+    ("L", 0.001, &[("m", 3)], true),
+    ("t", 1e6, &[("g", 1)], true),
+    // Celsius has an offset, it can't be converted to kelvins with a scale factor.
+    ("Cel", 1.0, &[("Cel", 1)], true),
+    // The bel is logarithmic.
+    ("B", 1.0, &[("B", 1)], true),
+    ("min", 60.0, &[("s", 1)], false),
+    ("h", 3600.0, &[("s", 1)], false),
+    ("d", 86_400.0, &[("s", 1)], false),
+    ("wk", 604_800.0, &[("s", 1)], false),
+    ("mo", 2_629_800.0, &[("s", 1)], false),
+    ("a", 31_557_600.0, &[("s", 1)], false),
+    ("deg", PI / 180.0, &[("rad", 1)], false),
+    ("%", 0.01, &[], false),
+];
+
+const PREFIXES: &[(&str, f64)] = &[
+    ("Ki", 1024.0),
+    ("Mi", 1_048_576.0),
+    ("Gi", 1_073_741_824.0),
+    ("Ti", 1_099_511_627_776.0),
+    ("da", 10.0),
+    ("Y", 1e24),
+    ("Z", 1e21),
+    ("E", 1e18),
+    ("P", 1e15),
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("h", 1e2),
+    ("d", 1e-1),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("u", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+    ("f", 1e-15),
+    ("a", 1e-18),
+    ("z", 1e-21),
+    ("y", 1e-24),
+];
+
+/// Common non-canonical spellings and their canonical UCUM form.
+const NON_CANONICAL: &[(&str, &str)] = &[
+    ("second", "s"),
+    ("seconds", "s"),
+    ("sec", "s"),
+    ("secs", "s"),
+    ("millisecond", "ms"),
+    ("milliseconds", "ms"),
+    ("msec", "ms"),
+    ("microsecond", "us"),
+    ("microseconds", "us"),
+    ("µs", "us"),
+    ("μs", "us"),
+    ("nanosecond", "ns"),
+    ("nanoseconds", "ns"),
+    ("minute", "min"),
+    ("minutes", "min"),
+    ("hour", "h"),
+    ("hours", "h"),
+    ("day", "d"),
+    ("days", "d"),
+    ("byte", "By"),
+    ("bytes", "By"),
+    ("B", "By"),
+    ("KB", "kBy"),
+    ("kB", "kBy"),
+    ("KBy", "kBy"),
+    ("MB", "MBy"),
+    ("GB", "GBy"),
+    ("TB", "TBy"),
+    ("KiB", "KiBy"),
+    ("MiB", "MiBy"),
+    ("GiB", "GiBy"),
+    ("TiB", "TiBy"),
+    ("bits", "bit"),
+    ("percent", "%"),
+    ("ratio", "1"),
+    ("count", "{count}"),
+    ("celsius", "Cel"),
+    ("°C", "Cel"),
+    ("degC", "Cel"),
+    ("hertz", "Hz"),
+    ("meter", "m"),
+    ("meters", "m"),
+    ("watt", "W"),
+    ("watts", "W"),
+    ("joule", "J"),
+    ("joules", "J"),
+    ("volt", "V"),
+    ("volts", "V"),
+    ("ampere", "A"),
+    ("amperes", "A"),
+];
+
+impl Unit {
+    /// Parses a UCUM unit.
+    pub fn parse(unit: &str) -> Result<Self, InvalidUnit> {
+        let invalid = |reason: String| InvalidUnit {
+            unit: unit.to_owned(),
+            reason,
+        };
+        if unit.is_empty() {
+            return Err(invalid("The unit is empty.".to_owned()));
+        }
+        if unit.chars().any(char::is_whitespace) {
+            return Err(invalid("Units must not contain spaces.".to_owned()));
+        }
+        let mut parser = Parser {
+            input: unit,
+            pos: 0,
+        };
+        let parsed = parser.parse_term().map_err(invalid)?;
+        if parser.pos < unit.len() {
+            return Err(invalid(format!(
+                "Unexpected `{}` at position {}.",
+                &unit[parser.pos..],
+                parser.pos
+            )));
+        }
+        Ok(parsed)
+    }
+
+    /// Returns the factor to multiply a value expressed in this unit by to express it in
+    /// `other` (e.g. `0.001` from `ms` to `s`), or `None` if the units don't measure the
+    /// same thing.
+    #[must_use]
+    pub fn conversion_factor(&self, other: &Unit) -> Option<f64> {
+        (self.dimensions == other.dimensions).then(|| self.factor / other.factor)
+    }
+
+    fn one() -> Self {
+        Unit {
+            factor: 1.0,
+            dimensions: BTreeMap::new(),
+        }
+    }
+
+    /// Multiplies this unit by `other` raised to `exponent`. Fails if an exponent
+    /// overflows.
+    fn combine(mut self, other: &Unit, exponent: i32) -> Result<Self, String> {
+        self.factor *= other.factor.powi(exponent);
+        for (dimension, other_exponent) in &other.dimensions {
+            let entry = self.dimensions.entry(dimension.clone()).or_default();
+            *entry = other_exponent
+                .checked_mul(exponent)
+                .and_then(|exponent| entry.checked_add(exponent))
+                .ok_or_else(|| format!("The exponent of `{dimension}` is too large."))?;
+            if *entry == 0 {
+                _ = self.dimensions.remove(dimension);
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// Returns the canonical UCUM spelling of a common non-canonical unit (e.g. `By` for
+/// `bytes` or `B`), if `unit` is one.
+#[must_use]
+pub fn canonical_form(unit: &str) -> Option<&'static str> {
+    NON_CANONICAL
+        .iter()
+        .find(|(alias, _)| *alias == unit)
+        .map(|(_, canonical)| *canonical)
+}
+
+/// Checks the unit of a metric, returning a warning if it isn't a canonical UCUM unit.
+pub(crate) fn validate_metric_unit(unit: &str, group_id: &str, path_or_url: &str) -> Option<Error> {
+    if unit.is_empty() {
+        return None;
+    }
+    if let Some(canonical) = canonical_form(unit) {
+        return Some(Error::NonCanonicalMetricUnit {
+            path_or_url: path_or_url.to_owned(),
+            group_id: group_id.to_owned(),
+            unit: unit.to_owned(),
+            canonical: canonical.to_owned(),
+        });
+    }
+    Unit::parse(unit)
+        .err()
+        .map(|error| Error::InvalidMetricUnit {
+            path_or_url: path_or_url.to_owned(),
+            group_id: group_id.to_owned(),
+            error: error.to_string(),
+        })
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+        let start = self.pos;
+        while let Some(c) = self.peek().filter(|c| predicate(*c)) {
+            self.pos += c.len_utf8();
+        }
+        &self.input[start..self.pos]
+    }
+
+    /// term := ['/'] component (('.' | '/') component)*
+    fn parse_term(&mut self) -> Result<Unit, String> {
+        let mut unit = if self.peek() == Some('/') {
+            Unit::one()
+        } else {
+            self.parse_component()?
+        };
+        loop {
+            let exponent = match self.peek() {
+                Some('.') => 1,
+                Some('/') => -1,
+                _ => return Ok(unit),
+            };
+            self.pos += 1;
+            unit = unit.combine(&self.parse_component()?, exponent)?;
+        }
+    }
+
+    /// component := '(' term ')' | annotation | factor | simple_unit [exponent] [annotation]
+    fn parse_component(&mut self) -> Result<Unit, String> {
+        match self.peek() {
+            None => Err("Unexpected end of unit.".to_owned()),
+            Some('(') => {
+                self.pos += 1;
+                let unit = self.parse_term()?;
+                if self.peek() != Some(')') {
+                    return Err("Missing closing parenthesis.".to_owned());
+                }
+                self.pos += 1;
+                Ok(unit)
+            }
+            Some('{') => self.parse_annotation(),
+            Some(c) if c.is_ascii_digit() => {
+                let digits = self.take_while(|c| c.is_ascii_digit());
+                let factor = digits
+                    .parse::<f64>()
+                    .map_err(|e| format!("Invalid factor `{digits}`: {e}"))?;
+                Ok(Unit {
+                    factor,
+                    dimensions: BTreeMap::new(),
+                })
+            }
+            Some(_) => {
+                let token = self
+                    .take_while(|c| !matches!(c, '.' | '/' | '(' | ')' | '{' | '}' | '+' | '-'));
+                // Digits ending the symbol are the exponent (e.g. `m2`).
+                let symbol = token.trim_end_matches(|c: char| c.is_ascii_digit());
+                let mut digits = token[symbol.len()..].to_owned();
+                let symbol = symbol.to_owned();
+                let mut sign = 1;
+                if digits.is_empty() {
+                    if let Some(c @ ('+' | '-')) = self.peek() {
+                        self.pos += 1;
+                        sign = if c == '-' { -1 } else { 1 };
+                        digits = self.take_while(|c| c.is_ascii_digit()).to_owned();
+                        if digits.is_empty() {
+                            return Err(format!("Missing exponent after `{symbol}{c}`."));
+                        }
+                    }
+                }
+                if symbol.is_empty() {
+                    return Err(format!("Missing unit before `{digits}`."));
+                }
+                let exponent = if digits.is_empty() {
+                    1
+                } else {
+                    digits
+                        .parse::<i32>()
+                        .map_err(|e| format!("Invalid exponent `{digits}`: {e}"))?
+                };
+                let mut unit = Unit::one().combine(&simple_unit(&symbol)?, sign * exponent)?;
+                if self.peek() == Some('{') {
+                    unit = unit.combine(&self.parse_annotation()?, 1)?;
+                }
+                Ok(unit)
+            }
+        }
+    }
+
+    fn parse_annotation(&mut self) -> Result<Unit, String> {
+        self.pos += 1;
+        let annotation = self.take_while(|c| c != '}').to_owned();
+        if self.peek() != Some('}') {
+            return Err("Missing closing brace.".to_owned());
+        }
+        self.pos += 1;
+        if !annotation.chars().all(|c| c.is_ascii_graphic() && c != '{') {
+            return Err(format!(
+                "The annotation `{{{annotation}}}` must only contain printable ASCII characters."
+            ));
+        }
+        Ok(Unit {
+            factor: 1.0,
+            dimensions: BTreeMap::from([(format!("{{{annotation}}}"), 1)]),
+        })
+    }
+}
+
+/// simple_unit := [prefix] atom
+fn simple_unit(symbol: &str) -> Result<Unit, String> {
+    let atom = |symbol: &str| ATOMS.iter().find(|(atom, ..)| *atom == symbol);
+    let to_unit = |factor: f64, dimensions: &[(&str, i32)]| Unit {
+        factor,
+        dimensions: dimensions
+            .iter()
+            .map(|(dimension, exponent)| ((*dimension).to_owned(), *exponent))
+            .collect(),
+    };
+    if let Some((_, factor, dimensions, _)) = atom(symbol) {
+        return Ok(to_unit(*factor, dimensions));
+    }
+    for (prefix, prefix_factor) in PREFIXES {
+        if let Some((_, factor, dimensions, metric)) = symbol.strip_prefix(prefix).and_then(atom) {
+            if !metric {
+                return Err(format!(
+                    "The unit `{}` can't have a prefix.",
+                    &symbol[prefix.len()..]
+                ));
+            }
+            return Ok(to_unit(prefix_factor * factor, dimensions));
+        }
+    }
+    Err(format!(
+        "Unknown unit `{symbol}`. Use an annotation (e.g. `{{{symbol}}}`) to count things."
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn factor(from: &str, to: &str) -> Option<f64> {
+        Unit::parse(from)
+            .unwrap()
+            .conversion_factor(&Unit::parse(to).unwrap())
+    }
+
+    #[test]
+    fn test_parse_valid_units() {
+        for unit in [
+            "1",
+            "s",
+            "ms",
+            "By",
+            "KiBy",
+            "By/s",
+            "1/s",
+            "/s",
+            "%",
+            "Cel",
+            "{request}",
+            "{request}/s",
+            "{packet}/(s.m2)",
+            "m2",
+            "s-1",
+            "m+2",
+            "kg.m/s2",
+            "min",
+            "mo",
+            "cd",
+            "hPa",
+            "dB",
+            "By{cached}",
+            "10",
+        ] {
+            assert!(Unit::parse(unit).is_ok(), "`{unit}` should be valid");
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_units() {
+        for (unit, reason) in [
+            ("", "empty"),
+            ("m s", "spaces"),
+            ("seconds", "Unknown unit `seconds`"),
+            ("kmin", "can't have a prefix"),
+            ("{request", "Missing closing brace"),
+            ("(m/s", "Missing closing parenthesis"),
+            ("m/", "Unexpected end"),
+            ("s-", "Missing exponent"),
+            ("{requête}", "printable ASCII"),
+            ("m)", "Unexpected `)`"),
+            ("m2147483647.m", "too large"),
+            ("m2147483647.m2147483647", "too large"),
+            ("m-2147483647/m2", "too large"),
+        ] {
+            let error = Unit::parse(unit).unwrap_err();
+            assert!(
+                error.reason.contains(reason),
+                "`{unit}`: expected `{reason}` in `{}`",
+                error.reason
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversion_factor() {
+        assert_eq!(factor("ms", "s"), Some(0.001));
+        assert_eq!(factor("s", "ms"), Some(1000.0));
+        assert_eq!(factor("min", "s"), Some(60.0));
+        assert_eq!(factor("KiBy", "By"), Some(1024.0));
+        assert_eq!(factor("bit", "By"), Some(0.125));
+        assert_eq!(factor("kBy/s", "By/s"), Some(1000.0));
+        assert_eq!(factor("Hz", "1/s"), Some(1.0));
+        assert_eq!(factor("s-1", "/s"), Some(1.0));
+        assert_eq!(factor("%", "1"), Some(0.01));
+        assert_eq!(factor("{request}/s", "{request}/s"), Some(1.0));
+        assert_eq!(factor("s", "By"), None);
+        assert_eq!(factor("Cel", "K"), None);
+        assert_eq!(factor("{request}", "{packet}"), None);
+        assert_eq!(factor("{request}", "1"), None);
+    }
+
+    #[test]
+    fn test_validate_metric_unit() {
+        assert!(validate_metric_unit("s", "g", "p").is_none());
+        assert!(validate_metric_unit("", "g", "p").is_none());
+        assert!(matches!(
+            validate_metric_unit("bytes", "g", "p"),
+            Some(Error::NonCanonicalMetricUnit { canonical, .. }) if canonical == "By"
+        ));
+        assert!(matches!(
+            validate_metric_unit("requests", "g", "p"),
+            Some(Error::InvalidMetricUnit { .. })
+        ));
+    }
+}
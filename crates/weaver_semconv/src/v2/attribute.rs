@@ -8,7 +8,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    attribute::{AttributeRole, AttributeSpec, AttributeType, Examples, RequirementLevel},
+    attribute::{
+        AttributeRole, AttributeSpec, AttributeType, Examples, RequirementLevel, ValueConstraints,
+    },
     deprecated::Deprecated,
     stability::Stability,
     v2::{signal_id::SignalId, CommonFields},
@@ -123,6 +125,10 @@ pub struct AttributeDef {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub examples: Option<Examples>,
+    /// Constraints on the values of the attribute (pattern, range, length).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<ValueConstraints>,
     /// Common fields (like brief, note, attributes).
     #[serde(flatten)]
     pub common: CommonFields,
@@ -137,6 +143,7 @@ impl AttributeDef {
             r#type: self.r#type,
             brief: Some(self.common.brief),
             examples: self.examples,
+            constraints: self.constraints,
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
@@ -82,11 +82,13 @@ impl AttributeGroup {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     display_name: None,
                     body: None,
                     annotations: None,
                     entity_associations: vec![],
+                    span_name: None,
                     visibility: Some(AttributeGroupVisibilitySpec::Internal),
                     is_v2: true,
                 }
@@ -111,6 +113,7 @@ impl AttributeGroup {
                     instrument: None,
                     unit: None,
                     metric_requirement_level: None,
+                    advice: None,
                     name: None,
                     display_name: None,
                     body: None,
@@ -120,6 +123,7 @@ impl AttributeGroup {
                         Some(public.common.annotations)
                     },
                     entity_associations: vec![],
+                    span_name: None,
                     visibility: Some(AttributeGroupVisibilitySpec::Public),
                     is_v2: true,
                 }
@@ -92,6 +92,7 @@ impl Entity {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: Some(self.r#type.into_v1()),
             display_name: None,
             body: None,
@@ -101,6 +102,7 @@ impl Entity {
                 Some(self.common.annotations)
             },
             entity_associations: Default::default(),
+            span_name: None,
             visibility: None,
             is_v2: true,
         }
@@ -134,6 +136,7 @@ impl EntityRefinement {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: Some(self.id.into_v1()),
             display_name: None,
             body: None,
@@ -143,6 +146,7 @@ impl EntityRefinement {
                 Some(self.annotations)
             },
             entity_associations: Default::default(),
+            span_name: None,
             visibility: None,
             is_v2: true,
         }
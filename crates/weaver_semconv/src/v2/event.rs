@@ -95,6 +95,7 @@ impl Event {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: Some(self.name.into_v1()),
             display_name: None,
             body: None,
@@ -104,6 +105,7 @@ impl Event {
                 Some(self.common.annotations)
             },
             entity_associations: self.entity_associations,
+            span_name: None,
             visibility: None,
             is_v2: true,
         }
@@ -132,6 +134,7 @@ impl EventRefinement {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: Some(self.id.into_v1()),
             display_name: None,
             body: None,
@@ -141,6 +144,7 @@ impl EventRefinement {
                 Some(self.annotations)
             },
             entity_associations: self.entity_associations,
+            span_name: None,
             visibility: None,
             is_v2: true,
         }
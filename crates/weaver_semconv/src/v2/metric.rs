@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     attribute::BasicRequirementLevelSpec,
     deprecated::Deprecated,
-    group::{GroupSpec, InstrumentSpec},
+    group::{GroupSpec, InstrumentSpec, MetricAdviceSpec},
     stability::Stability,
     v2::{
         attribute::{split_attributes_and_groups, AttributeOrGroupRef},
@@ -47,6 +47,10 @@ pub struct Metric {
     /// The requirement level of the metric. Defaults to 'recommended' when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub requirement_level: Option<BasicRequirementLevelSpec>,
+    /// Advisory parameters for the instrument recording the metric, such as
+    /// the recommended histogram bucket boundaries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advice: Option<MetricAdviceSpec>,
     /// Common fields (like brief, note, annotations).
     #[serde(flatten)]
     pub common: CommonFields,
@@ -109,6 +113,7 @@ impl Metric {
             instrument: Some(self.instrument),
             unit: Some(self.unit),
             metric_requirement_level: self.requirement_level,
+            advice: self.advice,
             name: None,
             display_name: None,
             body: None,
@@ -118,6 +123,7 @@ impl Metric {
                 Some(self.common.annotations)
             },
             entity_associations: self.entity_associations,
+            span_name: None,
             visibility: None,
             is_v2: true,
         }
@@ -146,6 +152,7 @@ impl MetricRefinement {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: None,
             display_name: None,
             body: None,
@@ -155,6 +162,7 @@ impl MetricRefinement {
                 Some(self.annotations)
             },
             entity_associations: self.entity_associations,
+            span_name: None,
             visibility: None,
             is_v2: true,
         }
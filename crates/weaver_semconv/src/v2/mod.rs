@@ -12,13 +12,15 @@ use crate::{
     group::GroupSpec,
     semconv::{Imports, SemConvSpecV1},
     stability::Stability,
+    unit::validate_metric_unit,
     v2::{
         attribute::AttributeDef, attribute_group::AttributeGroup, entity::Entity,
         entity::EntityRefinement, event::Event, event::EventRefinement, metric::Metric,
         metric::MetricRefinement, span::Span, span::SpanRefinement,
     },
-    YamlValue,
+    Error, YamlValue,
 };
+use weaver_common::result::WResult;
 
 pub mod attribute;
 pub mod attribute_group;
@@ -115,6 +117,18 @@ impl SemConvSpecV2 {
         serde_json::from_value(schema).expect("Failed to deserialize schema")
     }
 
+    /// Validates invariants on the model.
+    pub(crate) fn validate(self, provenance: &str) -> WResult<Self, Error> {
+        let errors = self
+            .metrics
+            .iter()
+            .filter_map(|metric| {
+                validate_metric_unit(&metric.unit, &format!("metric.{}", metric.name), provenance)
+            })
+            .collect();
+        WResult::with_non_fatal_errors(self, errors)
+    }
+
     /// Converts the version 2 schema into the version 1 group spec.
     pub(crate) fn into_v1_specification(self, file_name: &str) -> SemConvSpecV1 {
         log::debug!("Translating v2 spec into v1 spec for {file_name}");
@@ -308,6 +322,8 @@ groups:
   span_kind: client
   stability: stable
   is_v2: true
+  span_name:
+    note: "{some} {name}"
 - id: test
   type: attribute_group
   brief: test
@@ -395,6 +411,7 @@ groups:
                     crate::attribute::PrimitiveOrArrayTypeSpec::String,
                 ),
                 examples: None,
+                constraints: None,
                 common: CommonFields {
                     brief: "test".to_owned(),
                     note: "".to_owned(),
@@ -3,7 +3,9 @@
 //! The new way we want to define spans going forward.
 
 use std::collections::BTreeMap;
+use std::fmt::Display;
 
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -77,6 +79,10 @@ pub struct Span {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub entity_associations: Vec<String>,
+    /// The names of the events that may be recorded on this span.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<String>,
     /// Common fields (like brief, note, annotations).
     #[serde(flatten)]
     pub common: CommonFields,
@@ -140,14 +146,16 @@ impl Span {
             deprecated: self.common.deprecated,
             attributes: attribute_refs,
             span_kind: Some(self.kind),
-            events: vec![],
+            events: self.events,
             metric_name: None,
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: Some(format!("{}", &self.r#type)),
             display_name: None,
             body: None,
+            span_name: Some(self.name),
             annotations: if self.common.annotations.is_empty() {
                 None
             } else {
@@ -182,9 +190,11 @@ impl SpanRefinement {
             instrument: None,
             unit: None,
             metric_requirement_level: None,
+            advice: None,
             name: Some(format!("{}", &self.id)),
             display_name: None,
             body: None,
+            span_name: None,
             annotations: if self.annotations.is_empty() {
                 None
             } else {
@@ -205,6 +215,165 @@ impl SpanRefinement {
 pub struct SpanName {
     /// Required description of how a span name should be created.
     pub note: String,
+    /// Optional machine-readable template of the span name, e.g.
+    /// `{http.request.method} {http.route}`. Placeholders between braces
+    /// reference attributes of the span.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<SpanNameTemplate>,
+    /// Templates to use, in order, when the attributes of the `template` are
+    /// not all set on the span, e.g. `{http.request.method}`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fallbacks: Vec<SpanNameTemplate>,
+}
+
+impl SpanName {
+    /// Returns the templates of the span name, the main template first.
+    pub fn templates(&self) -> impl Iterator<Item = &SpanNameTemplate> {
+        self.template.iter().chain(self.fallbacks.iter())
+    }
+
+    /// Returns the template the name of a span should follow: the first
+    /// template whose attributes are all set on the span.
+    pub fn applicable_template<F: Fn(&str) -> bool>(
+        &self,
+        has_attribute: F,
+    ) -> Option<&SpanNameTemplate> {
+        self.templates().find(|template| {
+            template
+                .segments()
+                .map(|segments| {
+                    segments.iter().all(|segment| match segment {
+                        TemplateSegment::Literal(_) => true,
+                        TemplateSegment::Attribute(key) => has_attribute(key),
+                    })
+                })
+                .unwrap_or(false)
+        })
+    }
+}
+
+/// A span name template, e.g. `{http.request.method} {http.route}`.
+///
+/// Placeholders between braces are replaced by the value of the attribute they
+/// name, the rest of the template is literal text.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(transparent)]
+pub struct SpanNameTemplate(pub String);
+
+/// A part of a span name template.
+#[derive(Debug, Clone, PartialEq)]
+enum TemplateSegment<'a> {
+    /// Literal text.
+    Literal(&'a str),
+    /// A placeholder for the value of an attribute.
+    Attribute(&'a str),
+}
+
+impl SpanNameTemplate {
+    /// Checks the syntax of the template.
+    pub fn validate(&self) -> Result<(), String> {
+        self.segments().map(|_| ())
+    }
+
+    /// Returns the attributes referenced by the template, or nothing if the
+    /// template is invalid.
+    #[must_use]
+    pub fn attributes(&self) -> Vec<&str> {
+        self.segments()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|segment| match segment {
+                TemplateSegment::Attribute(key) => Some(key),
+                TemplateSegment::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    /// Renders the template with the given attribute values. Returns None if the
+    /// template is invalid or an attribute has no value.
+    pub fn render<F: Fn(&str) -> Option<String>>(&self, value: F) -> Option<String> {
+        self.segments()
+            .ok()?
+            .into_iter()
+            .map(|segment| match segment {
+                TemplateSegment::Literal(text) => Some(text.to_owned()),
+                TemplateSegment::Attribute(key) => value(key),
+            })
+            .collect()
+    }
+
+    /// Returns true if a span name has the shape of the template, i.e. its literal
+    /// text matches and each placeholder matches a non-empty part of the name.
+    ///
+    /// The template is compiled on each call, use [`Self::to_regex`] to match many
+    /// names.
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        self.to_regex().is_some_and(|regex| regex.is_match(name))
+    }
+
+    /// Compiles the template into an anchored regex matching the span names that
+    /// have the shape of the template (see [`Self::matches`]). Returns None if the
+    /// template is invalid.
+    #[must_use]
+    pub fn to_regex(&self) -> Option<Regex> {
+        let pattern: String = self
+            .segments()
+            .ok()?
+            .into_iter()
+            .map(|segment| match segment {
+                TemplateSegment::Literal(text) => regex::escape(text),
+                TemplateSegment::Attribute(_) => "(?s:.+)".to_owned(),
+            })
+            .collect();
+        Regex::new(&format!("^{pattern}$")).ok()
+    }
+
+    /// Splits the template into literal text and placeholders.
+    fn segments(&self) -> Result<Vec<TemplateSegment<'_>>, String> {
+        let mut segments = Vec::new();
+        let mut rest = self.0.as_str();
+        if rest.trim().is_empty() {
+            return Err("the template is empty".to_owned());
+        }
+        while !rest.is_empty() {
+            match rest.find(['{', '}']) {
+                None => {
+                    segments.push(TemplateSegment::Literal(rest));
+                    rest = "";
+                }
+                Some(start) if rest[start..].starts_with('}') => {
+                    return Err(format!("unexpected `}}` at `{}`", &rest[start..]));
+                }
+                Some(start) => {
+                    if start > 0 {
+                        segments.push(TemplateSegment::Literal(&rest[..start]));
+                    }
+                    let placeholder = &rest[start + 1..];
+                    let end = placeholder
+                        .find(['{', '}'])
+                        .filter(|end| placeholder[*end..].starts_with('}'))
+                        .ok_or_else(|| format!("unclosed placeholder at `{}`", &rest[start..]))?;
+                    let key = placeholder[..end].trim();
+                    if key.is_empty() {
+                        return Err("empty placeholder `{}`".to_owned());
+                    }
+                    segments.push(TemplateSegment::Attribute(key));
+                    rest = &placeholder[end + 1..];
+                }
+            }
+        }
+        Ok(segments)
+    }
+}
+
+impl Display for SpanNameTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 /// A refinement of an Attribute for a span.
@@ -276,11 +445,76 @@ brief: Test span
 name: my_span
 span_kind: client
 stability: stable
+span_name:
+  note: "{some} {name}"
 is_v2: true
 "#,
         );
     }
 
+    #[test]
+    fn test_span_name_template() {
+        let name: SpanName = serde_yaml::from_str(
+            r#"note: "{http.request.method} {http.route}"
+template: "{http.request.method} {http.route}"
+fallbacks:
+  - "{http.request.method}"
+  - HTTP
+"#,
+        )
+        .expect("Failed to parse YAML string");
+        let template = name.template.as_ref().expect("template");
+        assert_eq!(
+            template.attributes(),
+            vec!["http.request.method", "http.route"]
+        );
+        assert!(template.matches("GET /users/{id}"));
+        assert!(!template.matches("GET"));
+        assert!(!template.matches(" /users"));
+        assert!(template.matches("GET /users\n/orders"));
+        let regex = template.to_regex().expect("valid template");
+        assert!(regex.is_match("GET /users/{id}"));
+        assert!(!regex.is_match("GET"));
+        // Literal text is not interpreted as a regex
+        let literal = SpanNameTemplate("{rpc.method} (.*)".to_owned());
+        assert!(literal.matches("Get (.*)"));
+        assert!(!literal.matches("Get foo"));
+        assert_eq!(
+            template
+                .render(|key| (key == "http.request.method").then(|| "GET".to_owned()))
+                .as_deref(),
+            None
+        );
+        assert_eq!(
+            template
+                .render(|key| Some(if key == "http.route" { "/" } else { "GET" }.to_owned()))
+                .as_deref(),
+            Some("GET /")
+        );
+
+        // The first template whose attributes are all set applies.
+        let applicable = |keys: &[&str]| {
+            name.applicable_template(|key| keys.contains(&key))
+                .map(ToString::to_string)
+        };
+        assert_eq!(
+            applicable(&["http.request.method", "http.route"]).as_deref(),
+            Some("{http.request.method} {http.route}")
+        );
+        assert_eq!(
+            applicable(&["http.request.method"]).as_deref(),
+            Some("{http.request.method}")
+        );
+        assert_eq!(applicable(&[]).as_deref(), Some("HTTP"));
+
+        for invalid in ["", "{a", "a}", "{}", "{a{b}}"] {
+            assert!(
+                SpanNameTemplate(invalid.to_owned()).validate().is_err(),
+                "`{invalid}` should be invalid"
+            );
+        }
+    }
+
     fn parse_and_translate_refinement(v2: &str, v1: &str) {
         let span = serde_yaml::from_str::<SpanRefinement>(v2).expect("Failed to parse YAML string");
         let expected =
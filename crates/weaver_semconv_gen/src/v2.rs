@@ -112,6 +112,7 @@ fn resolved_metric<AC: AttributeCatalog>(m: &Metric, catalog: &AC) -> ResolvedId
                 key: attr.key.clone(),
                 r#type: attr.r#type.clone(),
                 examples: attr.examples.clone(),
+                constraints: attr.constraints.clone(),
                 common: attr.common.clone(),
                 provenance: Default::default(),
             },
@@ -124,6 +125,7 @@ fn resolved_metric<AC: AttributeCatalog>(m: &Metric, catalog: &AC) -> ResolvedId
             instrument: m.instrument.clone(),
             unit: m.unit.clone(),
             requirement_level: m.requirement_level.clone(),
+            advice: m.advice.clone(),
             attributes,
             entity_associations: m.entity_associations.clone(),
             common: m.common.clone(),
@@ -147,6 +149,7 @@ fn resolved_span<AC: AttributeCatalog>(s: &Span, catalog: &AC) -> ResolvedId {
                 key: attr.key.clone(),
                 r#type: attr.r#type.clone(),
                 examples: attr.examples.clone(),
+                constraints: attr.constraints.clone(),
                 common: attr.common.clone(),
                 provenance: Default::default(),
             },
@@ -161,6 +164,7 @@ fn resolved_span<AC: AttributeCatalog>(s: &Span, catalog: &AC) -> ResolvedId {
             attributes,
             kind: s.kind.clone(),
             entity_associations: s.entity_associations.clone(),
+            events: s.events.clone(),
             common: s.common.clone(),
             provenance: Default::default(),
         },
@@ -182,6 +186,7 @@ fn resolved_event<AC: AttributeCatalog>(s: &Event, catalog: &AC) -> ResolvedId {
                 key: attr.key.clone(),
                 r#type: attr.r#type.clone(),
                 examples: attr.examples.clone(),
+                constraints: attr.constraints.clone(),
                 common: attr.common.clone(),
                 provenance: Default::default(),
             },
@@ -214,6 +219,7 @@ fn resolved_entity<AC: AttributeCatalog>(s: &Entity, catalog: &AC) -> ResolvedId
                 key: attr.key.clone(),
                 r#type: attr.r#type.clone(),
                 examples: attr.examples.clone(),
+                constraints: attr.constraints.clone(),
                 common: attr.common.clone(),
                 provenance: Default::default(),
             },
@@ -233,6 +239,7 @@ fn resolved_entity<AC: AttributeCatalog>(s: &Entity, catalog: &AC) -> ResolvedId
                 key: attr.key.clone(),
                 r#type: attr.r#type.clone(),
                 examples: attr.examples.clone(),
+                constraints: attr.constraints.clone(),
                 common: attr.common.clone(),
                 provenance: Default::default(),
             },
@@ -264,6 +271,7 @@ fn resolved_attribute_group<AC: AttributeCatalog>(s: &AttributeGroup, catalog: &
             key: attr.key.clone(),
             r#type: attr.r#type.clone(),
             examples: attr.examples.clone(),
+            constraints: attr.constraints.clone(),
             common: attr.common.clone(),
             provenance: Default::default(),
         });
@@ -285,6 +293,7 @@ fn resolved_attribute(attr: &Attribute) -> ResolvedId {
             key: attr.key.clone(),
             r#type: attr.r#type.clone(),
             examples: attr.examples.clone(),
+            constraints: attr.constraints.clone(),
             common: attr.common.clone(),
             provenance: Default::default(),
         },
@@ -468,6 +477,7 @@ mod tests {
                 key: "attr1".to_owned(),
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 examples: None,
+                constraints: None,
                 common: CommonFields::default(),
                 provenance: Default::default(),
             }],
@@ -485,6 +495,8 @@ mod tests {
                     kind: weaver_semconv::group::SpanKindSpec::Client,
                     name: SpanName {
                         note: "note".to_owned(),
+                        template: None,
+                        fallbacks: vec![],
                     },
                     attributes: vec![SpanAttributeRef {
                         base: AttributeRef(0),
@@ -494,6 +506,7 @@ mod tests {
                         sampling_relevant: None,
                     }],
                     entity_associations: vec![],
+                    events: vec![],
                     common: CommonFields::default(),
                     provenance: Default::default(),
                 }],
@@ -504,6 +517,7 @@ mod tests {
                     requirement_level: Some(
                         weaver_semconv::attribute::BasicRequirementLevelSpec::Recommended,
                     ),
+                    advice: None,
                     attributes: vec![MetricAttributeRef {
                         base: AttributeRef(0),
                         requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
@@ -547,6 +561,8 @@ mod tests {
                         kind: weaver_semconv::group::SpanKindSpec::Client,
                         name: SpanName {
                             note: "note".to_owned(),
+                            template: None,
+                            fallbacks: vec![],
                         },
                         attributes: vec![SpanAttributeRef {
                             base: AttributeRef(0),
@@ -556,6 +572,7 @@ mod tests {
                             sampling_relevant: None,
                         }],
                         entity_associations: vec![],
+                        events: vec![],
                         common: CommonFields::default(),
                         provenance: Default::default(),
                     },
@@ -569,6 +586,7 @@ mod tests {
                         requirement_level: Some(
                             weaver_semconv::attribute::BasicRequirementLevelSpec::Required,
                         ),
+                        advice: None,
                         attributes: vec![MetricAttributeRef {
                             base: AttributeRef(0),
                             requirement_level: weaver_semconv::attribute::RequirementLevel::Basic(
@@ -6,13 +6,16 @@ use schemars::JsonSchema;
 use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::BufReader;
+use std::ops::Bound;
 use std::path::Path;
 
 use crate::all_changes::AllChanges;
 use crate::logs_changes::LogsChanges;
 use crate::metrics_changes::MetricsChanges;
 use crate::resource_changes::ResourceChanges;
+use crate::span_events_changes::SpanEventsChanges;
 use crate::spans_changes::SpansChanges;
+use crate::upgrade::Upgrade;
 use logs_changes::LogsChange;
 use metrics_changes::MetricsChange;
 use resource_changes::ResourceChange;
@@ -24,7 +27,9 @@ pub mod logs_changes;
 pub mod metrics_changes;
 pub mod resource_changes;
 pub mod schema_changes;
+pub mod span_events_changes;
 pub mod spans_changes;
+pub mod upgrade;
 pub mod v2;
 
 /// An error that can occur while loading or resolving version changes.
@@ -58,6 +63,24 @@ pub enum Error {
 #[derive(PartialOrd, PartialEq)]
 pub struct Version(semver::Version);
 
+impl From<semver::Version> for Version {
+    fn from(version: semver::Version) -> Self {
+        Version(version)
+    }
+}
+
+impl From<Version> for semver::Version {
+    fn from(version: Version) -> Self {
+        version.0
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 /// List of versions with their changes.
 #[derive(Serialize, Deserialize, Debug, Default, Clone, JsonSchema)]
 #[serde(transparent)]
@@ -71,14 +94,22 @@ pub struct Versions {
 pub struct VersionSpec {
     /// The changes to apply to the following telemetry data: resource attributes,
     /// span attributes, span event attributes, log attributes, metric attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<AllChanges>,
     /// The changes to apply to the metrics specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<MetricsChanges>,
     /// The changes to apply to the logs specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub logs: Option<LogsChanges>,
     /// The changes to apply to the spans specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub spans: Option<SpansChanges>,
+    /// The changes to apply to the span events specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span_events: Option<SpanEventsChanges>,
     /// The changes to apply to the resource specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<ResourceChanges>,
 }
 
@@ -275,6 +306,21 @@ impl Versions {
         }
     }
 
+    /// Returns the transformations to upgrade telemetry produced for the version `from`
+    /// to the version `to`, i.e. the changes of the versions after `from` up to `to`.
+    #[must_use]
+    pub fn upgrade(&self, from: &Version, to: &Version) -> Upgrade<'_> {
+        if from.0 >= to.0 {
+            return Upgrade::new(Vec::new());
+        }
+        Upgrade::new(
+            self.versions
+                .range((Bound::Excluded(&from.0), Bound::Included(&to.0)))
+                .map(|(_, spec)| spec)
+                .collect(),
+        )
+    }
+
     /// Update the current `Versions` to include the transformations of the parent `Versions`.
     /// Transformations of the current `Versions` take precedence over the parent `Versions`.
     pub fn extend(&mut self, parent_versions: Versions) {
@@ -290,6 +336,15 @@ impl Versions {
         }
     }
 
+    /// Adds the changes of the given version. When the version already has changes,
+    /// these take precedence over the given ones.
+    pub fn insert(&mut self, version: semver::Version, spec: VersionSpec) {
+        match self.versions.get_mut(&version) {
+            Some(current_spec) => current_spec.extend(spec),
+            None => _ = self.versions.insert(version, spec),
+        }
+    }
+
     /// Returns true if the `Versions` is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -304,9 +359,59 @@ impl Versions {
 }
 
 impl VersionSpec {
+    /// Creates a `VersionSpec` renaming the given attributes (in all the signals) and
+    /// metrics.
+    #[must_use]
+    pub fn from_renames(
+        attributes: BTreeMap<String, String>,
+        metrics: BTreeMap<String, String>,
+    ) -> VersionSpec {
+        let all = (!attributes.is_empty()).then(|| AllChanges {
+            changes: vec![all_changes::AllChange {
+                rename_attributes: all_changes::RenameAttributes {
+                    attribute_map: attributes,
+                },
+            }],
+        });
+        let metrics = (!metrics.is_empty()).then(|| MetricsChanges {
+            changes: vec![MetricsChange {
+                rename_metrics: metrics,
+                ..Default::default()
+            }],
+        });
+        VersionSpec {
+            all,
+            metrics,
+            logs: None,
+            spans: None,
+            span_events: None,
+            resources: None,
+        }
+    }
+
     /// Update the current `VersionSpec` to include the transformations of the parent `VersionSpec`.
     /// Transformations of the current `VersionSpec` take precedence over the parent `VersionSpec`.
     pub fn extend(&mut self, parent_spec: VersionSpec) {
+        // Process all
+        if let Some(all) = parent_spec.all {
+            let local_all = self.all.get_or_insert_with(AllChanges::default);
+            let mut all_change = all_changes::AllChange::default();
+            for change in all.changes {
+                for (old, new) in change.rename_attributes.attribute_map {
+                    if !local_all
+                        .changes
+                        .iter()
+                        .any(|c| c.rename_attributes.attribute_map.contains_key(&old))
+                    {
+                        _ = all_change.rename_attributes.attribute_map.insert(old, new);
+                    }
+                }
+            }
+            if !all_change.rename_attributes.attribute_map.is_empty() {
+                local_all.changes.push(all_change);
+            }
+        }
+
         // Process resources
         if let Some(resources) = parent_spec.resources {
             let mut resource_change = ResourceChange::default();
@@ -487,6 +592,31 @@ impl VersionSpec {
                 }
             }
         }
+
+        // Process span events
+        if let Some(span_events) = parent_spec.span_events {
+            let local_span_events = self
+                .span_events
+                .get_or_insert_with(SpanEventsChanges::default);
+            for mut change in span_events.changes {
+                // renamings already present in local changes are skipped
+                for local_change in &local_span_events.changes {
+                    change
+                        .rename_events
+                        .name_map
+                        .retain(|old, _| !local_change.rename_events.name_map.contains_key(old));
+                    change.rename_attributes.attribute_map.retain(|old, _| {
+                        !local_change
+                            .rename_attributes
+                            .attribute_map
+                            .contains_key(old)
+                    });
+                }
+                if !change.rename_events.is_empty() || !change.rename_attributes.is_empty() {
+                    local_span_events.changes.push(change);
+                }
+            }
+        }
     }
 }
 
@@ -704,6 +834,71 @@ mod tests {
         assert_eq!("metric_2", changes.get_metric_name("m2"));
     }
 
+    #[test]
+    fn test_upgrade_to_latest() {
+        let versions: Versions = Versions::load_from_file("data/parent_versions.yaml").unwrap();
+        let latest = versions.latest_version().unwrap();
+
+        let upgrade = versions.upgrade(&semver::Version::new(1, 19, 0).into(), &latest);
+        // Renamed in 1.20.0, then again in 1.21.0
+        assert_eq!(
+            "network.protocol.name",
+            upgrade.span_attribute("GET", "net.app.protocol.name")
+        );
+        assert_eq!(
+            "http.request.method",
+            upgrade.span_attribute("GET", "http.method")
+        );
+        assert_eq!(
+            "process.runtime.jvm.cpu.recent_utilization",
+            upgrade.metric_name("process.runtime.jvm.cpu.utilization")
+        );
+        // Renamed in 1.19.0, already applied
+        assert_eq!(
+            "http.user_agent",
+            upgrade.span_attribute("GET", "http.user_agent")
+        );
+
+        assert!(versions
+            .upgrade(&semver::Version::new(1, 21, 0).into(), &latest)
+            .is_empty());
+
+        // The `all` section applies to every signal
+        let mut versions = Versions::default();
+        versions.insert(
+            semver::Version::new(1, 2, 0),
+            crate::VersionSpec::from_renames(
+                [("old.attr".to_owned(), "new.attr".to_owned())].into(),
+                [("old.metric".to_owned(), "new.metric".to_owned())].into(),
+            ),
+        );
+        // Existing changes take precedence
+        versions.insert(
+            semver::Version::new(1, 2, 0),
+            crate::VersionSpec::from_renames(
+                [
+                    ("old.attr".to_owned(), "other.attr".to_owned()),
+                    ("legacy.attr".to_owned(), "new.attr".to_owned()),
+                ]
+                .into(),
+                [].into(),
+            ),
+        );
+        let upgrade = versions.upgrade(
+            &semver::Version::new(1, 1, 0).into(),
+            &semver::Version::new(1, 2, 0).into(),
+        );
+        assert_eq!("new.attr", upgrade.resource_attribute("old.attr"));
+        assert_eq!(
+            "new.attr",
+            upgrade.metric_attribute("old.metric", "old.attr")
+        );
+        assert_eq!("new.attr", upgrade.log_attribute("old.attr"));
+        assert_eq!("new.attr", upgrade.span_attribute("GET", "old.attr"));
+        assert_eq!("new.metric", upgrade.metric_name("old.metric"));
+        assert_eq!("new.attr", upgrade.span_attribute("GET", "legacy.attr"));
+    }
+
     #[test]
     fn test_override() {
         let parent_versions = Versions::load_from_file("data/parent_versions.yaml").unwrap();
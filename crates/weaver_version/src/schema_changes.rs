@@ -4,7 +4,7 @@
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// The type of schema item.
 #[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Copy, Clone, JsonSchema)]
@@ -94,6 +94,20 @@ pub enum SchemaItemChange {
     },
 }
 
+/// Returns the old to new names of the renamed items in the given changes.
+#[must_use]
+pub fn renames(changes: &[SchemaItemChange]) -> BTreeMap<String, String> {
+    changes
+        .iter()
+        .filter_map(|change| match change {
+            SchemaItemChange::Renamed {
+                old_name, new_name, ..
+            } => Some((old_name.clone(), new_name.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
 impl SchemaChanges {
     /// Create a new instance of `SchemaChanges`.
     #[must_use]
@@ -258,6 +272,21 @@ impl SchemaChanges {
             .unwrap_or_default()
     }
 
+    /// Returns the old to new names of the items of the given type that have been renamed.
+    #[must_use]
+    pub fn renames(&self, item_type: SchemaItemType) -> BTreeMap<String, String> {
+        self.changes
+            .get(&item_type)
+            .map(|changes| renames(changes))
+            .unwrap_or_default()
+    }
+
+    /// Returns the registry manifest of the head version of the schema.
+    #[must_use]
+    pub fn head_manifest(&self) -> &RegistryManifest {
+        &self.head
+    }
+
     /// Add a `SchemaChange` to the list of changes for the specified schema item type.
     pub fn add_change(&mut self, item_type: SchemaItemType, change: SchemaItemChange) {
         self.changes
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Changes to apply to the span events for a specific version.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Changes to apply to the span events for a specific version.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpanEventsChanges {
+    /// Changes to apply to the span events for a specific version.
+    pub changes: Vec<SpanEventsChange>,
+}
+
+/// Changes to apply to the span events for a specific version.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpanEventsChange {
+    /// A collection of rename operations to apply to the span event names.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "RenameEvents::is_empty")]
+    pub rename_events: RenameEvents,
+    /// A collection of rename operations to apply to the span event attributes.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "RenameAttributes::is_empty")]
+    pub rename_attributes: RenameAttributes,
+}
+
+/// A collection of rename operations to apply to the span event names.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RenameEvents {
+    /// A collection of rename operations to apply to the span event names.
+    pub name_map: HashMap<String, String>,
+}
+
+impl RenameEvents {
+    /// Returns true if there is no event to rename.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.name_map.is_empty()
+    }
+}
+
+/// A collection of rename operations to apply to the span event attributes.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RenameAttributes {
+    /// A collection of rename operations to apply to the span event attributes.
+    pub attribute_map: HashMap<String, String>,
+    /// The names of the spans the rename operations apply to (all the spans if empty).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub apply_to_spans: Vec<String>,
+    /// The names of the events the rename operations apply to (all the events if
+    /// empty).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub apply_to_events: Vec<String>,
+}
+
+impl RenameAttributes {
+    /// Returns true if there is no attribute to rename.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.attribute_map.is_empty()
+    }
+}
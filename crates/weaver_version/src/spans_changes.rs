@@ -28,4 +28,8 @@ pub struct SpansChange {
 pub struct RenameAttributes {
     /// A collection of rename operations to apply to the span attributes.
     pub attribute_map: HashMap<String, String>,
+    /// The names of the spans the rename operations apply to (all the spans if empty).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub apply_to_spans: Vec<String>,
 }
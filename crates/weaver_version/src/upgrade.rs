@@ -0,0 +1,338 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Upgrade of telemetry to a later version of a schema.
+//!
+//! The transformations of the versions between the version of the telemetry and
+//! the target version are applied one version after the other, in ascending order,
+//! as the schema processor of the OpenTelemetry Collector does. Within a version,
+//! the `all` section is applied first, then the section of the signal, change by
+//! change.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::VersionSpec;
+
+/// The transformations to upgrade telemetry from a version of a schema to a later
+/// one.
+pub struct Upgrade<'a> {
+    /// The changes of the versions to apply, in ascending order.
+    specs: Vec<&'a VersionSpec>,
+}
+
+impl<'a> Upgrade<'a> {
+    /// Creates an upgrade applying the given version changes, in ascending order.
+    pub(crate) fn new(specs: Vec<&'a VersionSpec>) -> Self {
+        Self { specs }
+    }
+
+    /// Returns true if the upgrade doesn't apply any version changes, i.e. the
+    /// telemetry is already at the target version (or a later one).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.specs.is_empty()
+    }
+
+    /// Returns the new name of a resource attribute.
+    #[must_use]
+    pub fn resource_attribute(&self, name: &str) -> String {
+        self.specs.iter().fold(name.to_owned(), |name, spec| {
+            let name = rename_all(spec, name);
+            spec.resources
+                .iter()
+                .flat_map(|resources| &resources.changes)
+                .fold(name, |name, change| {
+                    rename(&change.rename_attributes.attribute_map, name)
+                })
+        })
+    }
+
+    /// Returns the new name of an attribute of the given span. Renamings restricted
+    /// to other spans with `apply_to_spans` are ignored.
+    #[must_use]
+    pub fn span_attribute(&self, span_name: &str, name: &str) -> String {
+        self.specs.iter().fold(name.to_owned(), |name, spec| {
+            let name = rename_all(spec, name);
+            spec.spans
+                .iter()
+                .flat_map(|spans| &spans.changes)
+                .filter(|change| {
+                    let apply_to_spans = &change.rename_attributes.apply_to_spans;
+                    apply_to_spans.is_empty() || apply_to_spans.iter().any(|s| s == span_name)
+                })
+                .fold(name, |name, change| {
+                    rename(&change.rename_attributes.attribute_map, name)
+                })
+        })
+    }
+
+    /// Returns the new name of a log attribute.
+    #[must_use]
+    pub fn log_attribute(&self, name: &str) -> String {
+        self.specs.iter().fold(name.to_owned(), |name, spec| {
+            let name = rename_all(spec, name);
+            spec.logs
+                .iter()
+                .flat_map(|logs| &logs.changes)
+                .fold(name, |name, change| {
+                    rename(&change.rename_attributes.attribute_map, name)
+                })
+        })
+    }
+
+    /// Returns the new name of an attribute of telemetry without a section of its own
+    /// (e.g. span links), to which only the `all` section applies.
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> String {
+        self.specs
+            .iter()
+            .fold(name.to_owned(), |name, spec| rename_all(spec, name))
+    }
+
+    /// Returns the new name of a span event.
+    #[must_use]
+    pub fn span_event_name(&self, event_name: &str) -> String {
+        self.upgrade_span_event("", event_name, None).0
+    }
+
+    /// Returns the new name of an attribute of the given event (named as in the
+    /// telemetry to upgrade) of the given span. Renamings restricted to other spans or
+    /// events with `apply_to_spans` or `apply_to_events` are ignored; the events match
+    /// the name of the event at their version.
+    #[must_use]
+    pub fn span_event_attribute(&self, span_name: &str, event_name: &str, name: &str) -> String {
+        self.upgrade_span_event(span_name, event_name, Some(name.to_owned()))
+            .1
+            .unwrap_or_else(|| name.to_owned())
+    }
+
+    /// Upgrades a span event name and, optionally, one of its attributes together, as
+    /// the renamings of the attributes depend on the name of the event at each version.
+    fn upgrade_span_event(
+        &self,
+        span_name: &str,
+        event_name: &str,
+        attribute: Option<String>,
+    ) -> (String, Option<String>) {
+        let mut event_name = event_name.to_owned();
+        let mut attribute = attribute;
+        for spec in &self.specs {
+            attribute = attribute.map(|name| rename_all(spec, name));
+            for change in spec.span_events.iter().flat_map(|events| &events.changes) {
+                let rename_attributes = &change.rename_attributes;
+                let apply_to_spans = &rename_attributes.apply_to_spans;
+                let apply_to_events = &rename_attributes.apply_to_events;
+                if (apply_to_spans.is_empty() || apply_to_spans.iter().any(|s| s == span_name))
+                    && (apply_to_events.is_empty() || apply_to_events.contains(&event_name))
+                {
+                    attribute =
+                        attribute.map(|name| rename(&rename_attributes.attribute_map, name));
+                }
+                event_name = rename(&change.rename_events.name_map, event_name);
+            }
+        }
+        (event_name, attribute)
+    }
+
+    /// Returns the new name of a metric.
+    #[must_use]
+    pub fn metric_name(&self, name: &str) -> String {
+        self.upgrade_metric(name, None).0
+    }
+
+    /// Returns the new name of an attribute of the given metric (named as in the
+    /// telemetry to upgrade). Renamings restricted to other metrics with
+    /// `apply_to_metrics` are ignored; they match the name of the metric at their
+    /// version.
+    #[must_use]
+    pub fn metric_attribute(&self, metric_name: &str, name: &str) -> String {
+        self.upgrade_metric(metric_name, Some(name.to_owned()))
+            .1
+            .unwrap_or_else(|| name.to_owned())
+    }
+
+    /// Upgrades a metric name and, optionally, one of its attributes together, as
+    /// the renamings of the attributes depend on the name of the metric at each
+    /// version.
+    fn upgrade_metric(
+        &self,
+        metric_name: &str,
+        attribute: Option<String>,
+    ) -> (String, Option<String>) {
+        let mut metric_name = metric_name.to_owned();
+        let mut attribute = attribute;
+        for spec in &self.specs {
+            attribute = attribute.map(|name| rename_all(spec, name));
+            for change in spec.metrics.iter().flat_map(|metrics| &metrics.changes) {
+                let apply_to_metrics = &change.rename_attributes.apply_to_metrics;
+                if apply_to_metrics.is_empty() || apply_to_metrics.contains(&metric_name) {
+                    attribute =
+                        attribute.map(|name| rename(&change.rename_attributes.attribute_map, name));
+                }
+                metric_name = rename(&change.rename_metrics, metric_name);
+            }
+        }
+        (metric_name, attribute)
+    }
+}
+
+/// Applies the `all` section of a version to an attribute name.
+fn rename_all(spec: &VersionSpec, name: String) -> String {
+    spec.all
+        .iter()
+        .flat_map(|all| &all.changes)
+        .fold(name, |name, change| {
+            rename(&change.rename_attributes.attribute_map, name)
+        })
+}
+
+/// Returns the new name of `name` in a rename map, or `name` if it isn't renamed.
+fn rename<M: RenameMap>(renames: &M, name: String) -> String {
+    renames.new_name(&name).cloned().unwrap_or(name)
+}
+
+/// The maps of renamings used in the different sections of a schema file.
+trait RenameMap {
+    /// Returns the new name of `name`, if it is renamed.
+    fn new_name(&self, name: &str) -> Option<&String>;
+}
+
+impl RenameMap for BTreeMap<String, String> {
+    fn new_name(&self, name: &str) -> Option<&String> {
+        self.get(name)
+    }
+}
+
+impl RenameMap for HashMap<String, String> {
+    fn new_name(&self, name: &str) -> Option<&String> {
+        self.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Version, Versions};
+
+    fn versions() -> Versions {
+        serde_yaml::from_str(
+            r#"
+1.2.0:
+  all:
+    changes:
+      - rename_attributes:
+          attribute_map:
+            c: d
+  spans:
+    changes:
+      - rename_attributes:
+          attribute_map:
+            b: c
+          apply_to_spans: [GET]
+  metrics:
+    changes:
+      - rename_attributes:
+          attribute_map:
+            state: status
+          apply_to_metrics: [memory.usage]
+  span_events:
+    changes:
+      - rename_events:
+          name_map:
+            exception: error
+      - rename_attributes:
+          attribute_map:
+            message: error.message
+          apply_to_spans: [GET]
+          apply_to_events: [error]
+1.1.0:
+  resources:
+    changes:
+      - rename_attributes:
+          attribute_map:
+            a: b
+  spans:
+    changes:
+      - rename_attributes:
+          attribute_map:
+            a: b
+  logs:
+    changes:
+      - rename_attributes:
+          attribute_map:
+            a: b
+  metrics:
+    changes:
+      - rename_metrics:
+          memory.used: memory.usage
+      - rename_attributes:
+          attribute_map:
+            type: state
+1.0.0:
+  all:
+    changes:
+      - rename_attributes:
+          attribute_map:
+            z: a
+"#,
+        )
+        .unwrap()
+    }
+
+    fn version(version: &str) -> Version {
+        semver::Version::parse(version).unwrap().into()
+    }
+
+    #[test]
+    fn test_upgrade() {
+        let versions = versions();
+        let upgrade = versions.upgrade(&version("1.0.0"), &version("1.2.0"));
+        assert!(!upgrade.is_empty());
+
+        // Renamings are chained across the versions, `all` applies to every signal.
+        assert_eq!(upgrade.resource_attribute("a"), "b");
+        assert_eq!(upgrade.log_attribute("a"), "b");
+        assert_eq!(upgrade.span_attribute("GET", "a"), "c");
+        assert_eq!(upgrade.span_attribute("POST", "a"), "b");
+        assert_eq!(upgrade.attribute("a"), "a");
+        assert_eq!(upgrade.attribute("c"), "d");
+
+        // `apply_to_metrics` matches the name of the metric at its version.
+        assert_eq!(upgrade.metric_name("memory.used"), "memory.usage");
+        assert_eq!(upgrade.metric_attribute("memory.used", "type"), "status");
+        assert_eq!(upgrade.metric_attribute("cpu.time", "type"), "state");
+
+        // `apply_to_events` matches the name of the event at its version.
+        assert_eq!(upgrade.span_event_name("exception"), "error");
+        assert_eq!(
+            upgrade.span_event_attribute("GET", "exception", "message"),
+            "error.message"
+        );
+        assert_eq!(
+            upgrade.span_event_attribute("POST", "exception", "message"),
+            "message"
+        );
+        assert_eq!(
+            upgrade.span_event_attribute("GET", "retry", "message"),
+            "message"
+        );
+        assert_eq!(upgrade.span_event_attribute("GET", "retry", "c"), "d");
+
+        // The changes of the source version are already applied.
+        assert_eq!(
+            versions
+                .upgrade(&version("1.1.0"), &version("1.2.0"))
+                .resource_attribute("a"),
+            "a"
+        );
+        // The changes after the target version are not applied.
+        let upgrade = versions.upgrade(&version("0.9.0"), &version("1.1.0"));
+        assert_eq!(upgrade.log_attribute("z"), "b");
+        assert_eq!(upgrade.span_attribute("GET", "b"), "b");
+
+        assert!(versions
+            .upgrade(&version("1.2.0"), &version("1.2.0"))
+            .is_empty());
+        assert!(versions
+            .upgrade(&version("1.3.0"), &version("1.2.0"))
+            .is_empty());
+    }
+}
@@ -3,8 +3,10 @@
 //! Manage command line arguments
 
 use crate::diagnostic::DiagnosticCommand;
+use crate::policy::PolicyCommand;
 use crate::registry::RegistryCommand;
 use crate::serve::ServeCommand;
+use crate::telemetry::TelemetryCommand;
 use clap::{Args, Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -59,6 +61,10 @@ pub enum Commands {
     Registry(RegistryCommand),
     /// Manage Diagnostic Messages
     Diagnostic(DiagnosticCommand),
+    /// Manage Rego Policies
+    Policy(PolicyCommand),
+    /// Process Telemetry Data
+    Telemetry(TelemetryCommand),
     /// Generate shell completions
     Completion(CompletionCommand),
     /// Start the API server (Experimental)
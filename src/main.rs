@@ -21,8 +21,10 @@ use crate::diagnostic::DEFAULT_DIAGNOSTIC_TEMPLATES;
 
 mod cli;
 mod diagnostic;
+mod policy;
 mod registry;
 mod serve;
+mod telemetry;
 mod weaver;
 
 /// Default value for `--diagnostic-format`.
@@ -150,7 +152,9 @@ fn run_command(cli: &Cli) -> ExitDirectives {
     let cmd_result = match &cli.command {
         Some(Commands::Registry(params)) => semconv_registry(params, cfg, &auth),
         Some(Commands::Diagnostic(params)) => diagnostic::diagnostic(params),
-        Some(Commands::Serve(params)) => serve::command(params, &auth),
+        Some(Commands::Policy(params)) => policy::policy(params),
+        Some(Commands::Telemetry(params)) => telemetry::telemetry(params),
+        Some(Commands::Serve(params)) => serve::command(params, cfg, &auth),
         Some(Commands::Completion(completions)) => {
             if let Err(e) = generate_completion(&completions.shell, &completions.completion_file) {
                 log_error(&e);
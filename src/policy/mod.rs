@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Commands to manage Rego policies.
+
+mod test;
+
+use crate::CmdResult;
+use clap::{Args, Subcommand};
+use miette::Diagnostic;
+use serde::Serialize;
+use std::path::PathBuf;
+use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
+
+/// Errors emitted by the `policy` sub-commands.
+#[derive(thiserror::Error, Debug, Serialize, Diagnostic)]
+#[non_exhaustive]
+pub enum Error {
+    /// A policy test failed.
+    #[error("Policy test `{test}` failed: {reason}")]
+    PolicyTestFailed {
+        /// The name of the test.
+        test: String,
+        /// The reason of the failure.
+        reason: String,
+    },
+
+    /// A YAML policy test file is invalid.
+    #[error("Invalid policy test file {path}: {error}")]
+    InvalidPolicyTestFile {
+        /// The path of the test file.
+        path: PathBuf,
+        /// The error that occurred.
+        error: String,
+    },
+
+    /// No test was found in the given paths.
+    #[error("No policy test found in {paths:?}")]
+    #[diagnostic(help(
+        "Rego tests are `test_*` rules, YAML test cases are defined in `*_test.yaml` files."
+    ))]
+    NoPolicyTest {
+        /// The paths searched for tests.
+        paths: Vec<PathBuf>,
+    },
+}
+
+impl From<Error> for DiagnosticMessages {
+    fn from(error: Error) -> Self {
+        DiagnosticMessages::new(vec![DiagnosticMessage::new(error)])
+    }
+}
+
+/// Parameters for the `policy` command
+#[derive(Debug, Args)]
+pub struct PolicyCommand {
+    /// Define the sub-commands for the `policy` command
+    #[clap(subcommand)]
+    pub command: PolicySubCommand,
+}
+
+/// Sub-commands to manage Rego policies.
+#[derive(Debug, Subcommand)]
+#[clap(verbatim_doc_comment)]
+pub enum PolicySubCommand {
+    /// Runs the tests of Rego policies.
+    ///
+    /// Two kinds of tests are supported:
+    /// - Rego unit tests: `test_*` rules (OPA convention), typically defined in `*_test.rego`
+    ///   files. A test passes if its rule evaluates to `true`.
+    /// - YAML test cases: `*_test.yaml` files listing registry fixtures and the finding ids
+    ///   expected for each policy stage.
+    ///
+    /// The process exits with a code of 0 if all the tests pass.
+    Test(test::PolicyTestArgs),
+}
+
+/// Manage Rego policies.
+pub fn policy(command: &PolicyCommand) -> CmdResult {
+    match &command.command {
+        PolicySubCommand::Test(args) => {
+            CmdResult::new(test::command(args), Some(args.diagnostic.clone()))
+        }
+    }
+}
@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs the tests of Rego policies.
+
+use crate::policy::Error;
+use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::weaver::WeaverEngine;
+use crate::{DiagnosticArgs, ExitDirectives};
+use clap::Args;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use weaver_checker::{BaselineEntry, Engine, PolicyFinding, PolicyTestResult, SEMCONV_REGO};
+use weaver_common::diagnostic::DiagnosticMessages;
+use weaver_common::http_auth::HttpAuthResolver;
+use weaver_common::vdir::VirtualDirectoryPath;
+use weaver_common::{log_error, log_info, log_success};
+
+/// Suffix of the YAML test case files.
+const YAML_TEST_SUFFIXES: [&str; 2] = ["_test.yaml", "_test.yml"];
+
+/// Parameters for the `policy test` sub-command
+#[derive(Debug, Args)]
+pub struct PolicyTestArgs {
+    /// Policy files or directories. All the `.rego` files (policies and tests) and the
+    /// `*_test.yaml` test case files found in the directories are loaded.
+    #[arg(required = true)]
+    pub paths: Vec<PathBuf>,
+
+    /// Display the coverage report of the Rego unit tests.
+    #[arg(long, default_value = "false")]
+    pub coverage: bool,
+
+    /// Parameters to specify the diagnostic format.
+    #[command(flatten)]
+    pub diagnostic: DiagnosticArgs,
+}
+
+/// A YAML file of policy test cases.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyTestFile {
+    /// The test cases.
+    cases: Vec<PolicyTestCase>,
+}
+
+/// A policy test case: a registry fixture and the finding ids expected for
+/// each policy stage. Stages without expectations are not checked.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyTestCase {
+    /// The name of the test case.
+    name: String,
+    /// The registry fixture, relative to the test file.
+    registry: PathBuf,
+    /// Whether the registry fixture is a v2 registry.
+    #[serde(default)]
+    v2: bool,
+    /// The finding ids expected from the `before_resolution` policies.
+    before_resolution: Option<BTreeSet<String>>,
+    /// The finding ids expected from the `after_resolution` policies.
+    after_resolution: Option<BTreeSet<String>>,
+}
+
+/// Runs the tests of Rego policies.
+pub(crate) fn command(args: &PolicyTestArgs) -> Result<ExitDirectives, DiagnosticMessages> {
+    let mut results = run_rego_tests(args)?;
+    for test_file in yaml_test_files(&args.paths) {
+        results.extend(run_yaml_tests(args, &test_file)?);
+    }
+    if results.is_empty() {
+        return Err(Error::NoPolicyTest {
+            paths: args.paths.clone(),
+        }
+        .into());
+    }
+
+    let mut diag_msgs = DiagnosticMessages::empty();
+    for result in &results {
+        match &result.failure {
+            None => log_success(&result.name),
+            Some(reason) => {
+                log_error(format!("{}: {reason}", result.name));
+                diag_msgs.extend(
+                    Error::PolicyTestFailed {
+                        test: result.name.clone(),
+                        reason: reason.clone(),
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+    let failed = results.iter().filter(|r| !r.passed()).count();
+    log_info(format!(
+        "{} policy tests, {} passed, {failed} failed",
+        results.len(),
+        results.len() - failed
+    ));
+
+    if !diag_msgs.is_empty() {
+        return Err(diag_msgs);
+    }
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+/// Loads the policies and runs the Rego unit tests (`test_*` rules).
+#[allow(clippy::print_stdout)] // The coverage report is displayed on stdout.
+fn run_rego_tests(args: &PolicyTestArgs) -> Result<Vec<PolicyTestResult>, DiagnosticMessages> {
+    let mut engine = Engine::new();
+    if args.coverage {
+        engine.enable_coverage();
+    }
+    // Same setup as the registry policy engine.
+    _ = engine.add_policy("defaults/rego/semconv.rego", SEMCONV_REGO)?;
+    for path in &args.paths {
+        engine.add_policy_from_file_or_dir(path)?;
+    }
+
+    let results = engine.run_tests()?;
+    if args.coverage && !results.is_empty() {
+        println!("{}", engine.coverage_report()?);
+    }
+    Ok(results)
+}
+
+/// Returns the YAML test case files found in the given paths.
+fn yaml_test_files(paths: &[PathBuf]) -> Vec<PathBuf> {
+    let is_test_file = |path: &Path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| YAML_TEST_SUFFIXES.iter().any(|s| name.ends_with(s)))
+    };
+    let mut files: Vec<PathBuf> = paths
+        .iter()
+        .flat_map(|path| walkdir::WalkDir::new(path).into_iter().flatten())
+        .filter(|entry| entry.file_type().is_file() && is_test_file(entry.path()))
+        .map(|entry| entry.into_path())
+        .collect();
+    files.sort();
+    files
+}
+
+/// Runs the test cases of a YAML test file.
+fn run_yaml_tests(
+    args: &PolicyTestArgs,
+    test_file: &Path,
+) -> Result<Vec<PolicyTestResult>, DiagnosticMessages> {
+    let invalid_file = |error: String| Error::InvalidPolicyTestFile {
+        path: test_file.to_path_buf(),
+        error,
+    };
+    let content = std::fs::read_to_string(test_file).map_err(|e| invalid_file(e.to_string()))?;
+    let test_cases: PolicyTestFile =
+        serde_yaml::from_str(&content).map_err(|e| invalid_file(e.to_string()))?;
+    let base_dir = test_file.parent().unwrap_or_else(|| Path::new(""));
+
+    let policy_args = PolicyArgs {
+        policies: args
+            .paths
+            .iter()
+            .map(|path| VirtualDirectoryPath::LocalFolder {
+                path: path.to_string_lossy().to_string(),
+            })
+            .collect(),
+        skip_policies: false,
+        display_policy_coverage: false,
+    };
+
+    Ok(test_cases
+        .cases
+        .iter()
+        .map(|case| {
+            let name = format!("{}::{}", test_file.display(), case.name);
+            let failure = run_yaml_test(&policy_args, base_dir, case).err();
+            PolicyTestResult { name, failure }
+        })
+        .collect())
+}
+
+/// Runs a YAML test case, returns the reason of the failure if any.
+fn run_yaml_test(
+    policy_args: &PolicyArgs,
+    base_dir: &Path,
+    case: &PolicyTestCase,
+) -> Result<(), String> {
+    let registry_args = RegistryArgs {
+        registry: VirtualDirectoryPath::LocalFolder {
+            path: base_dir.join(&case.registry).to_string_lossy().to_string(),
+        },
+        follow_symlinks: false,
+        include_unreferenced: false,
+        v2: case.v2,
+    };
+    let auth = HttpAuthResolver::empty();
+    let weaver = WeaverEngine::new(&registry_args, policy_args, &auth);
+    let to_reason = |e: crate::weaver::Error| e.to_string();
+
+    // Warnings emitted while loading the fixture are not relevant to the test.
+    let mut ignored = DiagnosticMessages::empty();
+    let loaded = weaver
+        .load_main_definitions(&mut ignored)
+        .map_err(to_reason)?;
+    let before_resolution = loaded.before_resolution_findings().map_err(to_reason)?;
+    let resolved = weaver.resolve(loaded, &mut ignored).map_err(to_reason)?;
+    let after_resolution = resolved.after_resolution_findings().map_err(to_reason)?;
+
+    let mut mismatches = vec![];
+    for (stage, expected, findings) in [
+        (
+            "before_resolution",
+            &case.before_resolution,
+            before_resolution,
+        ),
+        ("after_resolution", &case.after_resolution, after_resolution),
+    ] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let observed = finding_ids(&findings);
+        if &observed != expected {
+            mismatches.push(format!(
+                "{stage} findings {observed:?} don't match the expected {expected:?}"
+            ));
+        }
+    }
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches.join(", "))
+    }
+}
+
+/// Returns the ids of the findings (the policy-specific id for legacy
+/// `semconv_attribute` findings).
+fn finding_ids(findings: &[PolicyFinding]) -> BTreeSet<String> {
+    findings
+        .iter()
+        .map(|finding| BaselineEntry::from_finding(finding).id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Commands};
+    use crate::policy::test::PolicyTestArgs;
+    use crate::policy::{PolicyCommand, PolicySubCommand};
+    use crate::run_command;
+    use std::path::PathBuf;
+
+    fn policy_test_cli(path: &str) -> Cli {
+        Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            allow_git_credentials: false,
+            config: None,
+            command: Some(Commands::Policy(PolicyCommand {
+                command: PolicySubCommand::Test(PolicyTestArgs {
+                    paths: vec![PathBuf::from(path)],
+                    coverage: true,
+                    diagnostic: Default::default(),
+                }),
+            })),
+        }
+    }
+
+    #[test]
+    fn test_policy_tests_pass() {
+        let exit_directive = run_command(&policy_test_cli("tests/policy_test/passing"));
+        assert_eq!(exit_directive.exit_code, 0);
+    }
+
+    #[test]
+    fn test_policy_tests_fail() {
+        let exit_directive = run_command(&policy_test_cli("tests/policy_test/failing"));
+        assert_eq!(exit_directive.exit_code, 1);
+    }
+}
@@ -7,9 +7,12 @@ use crate::weaver::WeaverEngine;
 use crate::{DiagnosticArgs, ExitDirectives};
 use clap::Args;
 use log::info;
-use weaver_common::diagnostic::DiagnosticMessages;
+use std::path::PathBuf;
+use weaver_checker::{BaselineTracker, FindingBaseline};
+use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
 use weaver_common::http_auth::HttpAuthResolver;
 use weaver_common::vdir::VirtualDirectoryPath;
+use weaver_common::{log_info, log_success};
 use weaver_config::WeaverConfig;
 use weaver_semconv::registry_repo::RegistryRepo;
 
@@ -24,6 +27,16 @@ pub struct RegistryCheckArgs {
     #[arg(long)]
     baseline_registry: Option<VirtualDirectoryPath>,
 
+    /// Path to a findings baseline file. Policy findings recorded in the baseline are
+    /// not reported, and baseline entries that no longer occur are reported as warnings
+    #[arg(long)]
+    findings_baseline: Option<PathBuf>,
+
+    /// Record the current policy findings into the `--findings-baseline` file instead
+    /// of reporting them
+    #[arg(long, requires = "findings_baseline")]
+    write_baseline: bool,
+
     /// Policy parameters
     #[command(flatten)]
     policy: PolicyArgs,
@@ -36,13 +49,26 @@ pub struct RegistryCheckArgs {
 /// Check a semantic convention registry.
 pub(crate) fn command(
     args: &RegistryCheckArgs,
-    _cfg: Option<&WeaverConfig>,
+    cfg: Option<&WeaverConfig>,
     auth: &HttpAuthResolver,
 ) -> Result<ExitDirectives, DiagnosticMessages> {
     let mut diag_msgs = DiagnosticMessages::empty();
     info!("Weaver Registry Check");
     info!("Checking registry `{}`", args.registry.registry);
-    let weaver = WeaverEngine::new(&args.registry, &args.policy, auth);
+    let mut weaver = WeaverEngine::new(&args.registry, &args.policy, auth);
+    if let Some(wc) = cfg {
+        weaver = weaver.with_lint_config(wc.lint.clone());
+    }
+
+    // Initialize the findings baseline if provided.
+    let baseline_tracker = match args.findings_baseline.as_ref() {
+        Some(_) if args.write_baseline => Some(BaselineTracker::recording()),
+        Some(path) => Some(BaselineTracker::new(FindingBaseline::load(path)?)),
+        None => None,
+    };
+    if let Some(tracker) = baseline_tracker.as_ref() {
+        weaver = weaver.with_baseline_tracker(tracker.clone());
+    }
 
     // Initialize the main registry.
     let main_resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
@@ -65,6 +91,33 @@ pub(crate) fn command(
         main_resolved.check_comparison_after_resolution(&b, &mut diag_msgs)?;
     }
 
+    if let (Some(tracker), Some(path)) = (baseline_tracker, args.findings_baseline.as_ref()) {
+        if args.write_baseline {
+            let baseline = tracker.recorded();
+            baseline.save(path)?;
+            log_success(format!(
+                "Findings baseline written to `{}` ({} findings)",
+                path.display(),
+                baseline.findings.len()
+            ));
+        } else if !args.policy.skip_policies {
+            log_info(format!(
+                "{} known findings suppressed by the baseline `{}`",
+                tracker.suppressed_count(),
+                path.display()
+            ));
+            diag_msgs.extend_from_vec(
+                tracker
+                    .stale_entries()
+                    .into_iter()
+                    .map(|entry| {
+                        DiagnosticMessage::new(weaver_checker::Error::StaleBaselineEntry { entry })
+                    })
+                    .collect(),
+            );
+        }
+    }
+
     if !diag_msgs.is_empty() {
         return Err(diag_msgs);
     }
@@ -82,10 +135,37 @@ mod tests {
     use crate::registry::{
         semconv_registry, PolicyArgs, RegistryArgs, RegistryCommand, RegistrySubCommand,
     };
-    use crate::run_command;
+    use crate::{process_diagnostics, run_command};
+    use std::path::Path;
+    use weaver_checker::lint::{LintConfig, LintRule};
+    use weaver_checker::{BaselineEntry, FindingBaseline};
     use weaver_common::http_auth::HttpAuthResolver;
     use weaver_common::vdir::VirtualDirectoryPath;
 
+    fn check_with_findings_baseline(path: &Path, write_baseline: bool) -> RegistryCommand {
+        RegistryCommand {
+            command: RegistrySubCommand::Check(RegistryCheckArgs {
+                registry: RegistryArgs {
+                    registry: VirtualDirectoryPath::LocalFolder {
+                        path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
+                    },
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    v2: false,
+                },
+                baseline_registry: None,
+                findings_baseline: Some(path.to_path_buf()),
+                write_baseline,
+                policy: PolicyArgs {
+                    policies: vec![],
+                    skip_policies: false,
+                    display_policy_coverage: false,
+                },
+                diagnostic: Default::default(),
+            }),
+        }
+    }
+
     #[test]
     fn test_registry_check_exit_code() {
         let cli = Cli {
@@ -105,6 +185,8 @@ mod tests {
                         v2: false,
                     },
                     baseline_registry: None,
+                    findings_baseline: None,
+                    write_baseline: false,
                     policy: PolicyArgs {
                         policies: vec![],
                         skip_policies: true,
@@ -137,6 +219,8 @@ mod tests {
                         v2: false,
                     },
                     baseline_registry: None,
+                    findings_baseline: None,
+                    write_baseline: false,
                     policy: PolicyArgs {
                         policies: vec![],
                         skip_policies: false,
@@ -165,6 +249,8 @@ mod tests {
                     v2: false,
                 },
                 baseline_registry: None,
+                findings_baseline: None,
+                write_baseline: false,
                 policy: PolicyArgs {
                     policies: vec![],
                     skip_policies: false,
@@ -205,6 +291,8 @@ mod tests {
                     v2: true,
                 },
                 baseline_registry: None,
+                findings_baseline: None,
+                write_baseline: false,
                 policy: PolicyArgs {
                     policies: vec![],
                     skip_policies: false,
@@ -246,6 +334,8 @@ mod tests {
                 baseline_registry: Some(VirtualDirectoryPath::LocalFolder {
                     path: "tests/v2_check_baseline/base".to_owned(),
                 }),
+                findings_baseline: None,
+                write_baseline: false,
                 policy: PolicyArgs {
                     policies: vec![],
                     skip_policies: false,
@@ -286,6 +376,8 @@ mod tests {
                     v2: true,
                 },
                 baseline_registry: None,
+                findings_baseline: None,
+                write_baseline: false,
                 policy: PolicyArgs {
                     policies: vec![],
                     skip_policies: false,
@@ -311,4 +403,205 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_findings_baseline() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let baseline_path = temp_dir.path().join("findings-baseline.yaml");
+
+        // Record the current findings, the policy violations are not reported.
+        let cmd = check_with_findings_baseline(&baseline_path, true);
+        let cmd_result = semconv_registry(&cmd, None, &HttpAuthResolver::empty());
+        assert_eq!(process_diagnostics(cmd_result).exit_code, 0);
+        let mut baseline =
+            FindingBaseline::load(&baseline_path).expect("Baseline should have been written");
+        assert!(!baseline.findings.is_empty());
+
+        // Known findings are suppressed.
+        let cmd = check_with_findings_baseline(&baseline_path, false);
+        let cmd_result = semconv_registry(&cmd, None, &HttpAuthResolver::empty());
+        assert_eq!(process_diagnostics(cmd_result).exit_code, 0);
+
+        // A finding removed from the baseline is reported again, and an entry
+        // that no longer occurs is reported as stale.
+        let removed = baseline
+            .findings
+            .pop_first()
+            .expect("Baseline should not be empty");
+        let _ = baseline.findings.insert(BaselineEntry {
+            id: "fixed_violation".to_owned(),
+            signal_type: None,
+            signal_name: None,
+            attribute: None,
+        });
+        baseline
+            .save(&baseline_path)
+            .expect("Failed to save baseline");
+        let cmd_result = semconv_registry(&cmd, None, &HttpAuthResolver::empty());
+        let diag_msgs = cmd_result
+            .command_result
+            .expect_err("New finding should be reported");
+        let messages: Vec<String> = diag_msgs
+            .into_inner()
+            .iter()
+            .map(|msg| format!("{msg:?}"))
+            .collect();
+        assert!(messages
+            .iter()
+            .any(|msg| msg.contains("policy_violation") && msg.contains(&removed.id)));
+        assert!(messages
+            .iter()
+            .any(|msg| msg.contains("stale_baseline_entry") && msg.contains("fixed_violation")));
+    }
+
+    #[test]
+    fn test_v2_inline_suppressions() {
+        let registry_cmd = RegistryCommand {
+            command: RegistrySubCommand::Check(RegistryCheckArgs {
+                registry: RegistryArgs {
+                    registry: VirtualDirectoryPath::LocalFolder {
+                        path: "tests/v2_check_suppressions/".to_owned(),
+                    },
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    v2: true,
+                },
+                baseline_registry: None,
+                findings_baseline: None,
+                write_baseline: false,
+                policy: PolicyArgs {
+                    policies: vec![],
+                    skip_policies: false,
+                    display_policy_coverage: false,
+                },
+                diagnostic: Default::default(),
+            }),
+        };
+        let cmd_result = semconv_registry(&registry_cmd, None, &HttpAuthResolver::empty());
+        let diag_msgs = cmd_result
+            .command_result
+            .clone()
+            .expect_err("Suppressed findings should be reported");
+        let messages: Vec<String> = diag_msgs
+            .into_inner()
+            .iter()
+            .map(|msg| format!("{msg:?}"))
+            .collect();
+        assert!(!messages.iter().any(|msg| msg.contains("policy_violation")));
+        assert!(messages.iter().any(|msg| msg.contains("suppressed_finding")
+            && msg.contains("Kept for compatibility with existing dashboards.")));
+        // Suppressed findings don't fail the check.
+        assert_eq!(process_diagnostics(cmd_result).exit_code, 0);
+    }
+
+    #[test]
+    fn test_v2_lint_rules() {
+        let registry_cmd = RegistryCommand {
+            command: RegistrySubCommand::Check(RegistryCheckArgs {
+                registry: RegistryArgs {
+                    registry: VirtualDirectoryPath::LocalFolder {
+                        path: "tests/v2_check_lint/".to_owned(),
+                    },
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    v2: true,
+                },
+                baseline_registry: None,
+                findings_baseline: None,
+                write_baseline: false,
+                policy: PolicyArgs {
+                    policies: vec![],
+                    skip_policies: false,
+                    display_policy_coverage: false,
+                },
+                diagnostic: Default::default(),
+            }),
+        };
+        let cfg = weaver_config::WeaverConfig {
+            lint: LintConfig {
+                rules: vec![
+                    LintRule::AttributeName,
+                    LintRule::BriefPeriod,
+                    LintRule::MetricName,
+                ],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let cmd_result = semconv_registry(&registry_cmd, Some(&cfg), &HttpAuthResolver::empty());
+        let diag_msgs = cmd_result
+            .command_result
+            .clone()
+            .expect_err("Lint findings should be reported");
+        let messages: Vec<String> = diag_msgs
+            .into_inner()
+            .iter()
+            .map(|msg| format!("{msg:?}"))
+            .collect();
+        assert!(messages.iter().any(|msg| msg.contains("policy_violation")
+            && msg.contains("\"attribute_name\"")
+            && msg.contains("my.Attr")));
+        assert!(messages.iter().any(|msg| msg.contains("policy_violation")
+            && msg.contains("\"metric_name\"")
+            && msg.contains("my.requests_total")));
+        assert!(messages
+            .iter()
+            .any(|msg| msg.contains("suppressed_finding") && msg.contains("brief_period")));
+        assert_eq!(process_diagnostics(cmd_result).exit_code, 1);
+
+        // Without a lint configuration, the registry is valid.
+        let cmd_result = semconv_registry(&registry_cmd, None, &HttpAuthResolver::empty());
+        assert_eq!(process_diagnostics(cmd_result).exit_code, 0);
+
+        // The lint rules don't depend on the policies.
+        let mut registry_cmd = registry_cmd;
+        if let RegistrySubCommand::Check(args) = &mut registry_cmd.command {
+            args.policy.skip_policies = true;
+        }
+        let cmd_result = semconv_registry(&registry_cmd, Some(&cfg), &HttpAuthResolver::empty());
+        assert_eq!(process_diagnostics(cmd_result).exit_code, 1);
+    }
+
+    #[test]
+    fn test_v2_span_name_templates() {
+        let registry_cmd = RegistryCommand {
+            command: RegistrySubCommand::Check(RegistryCheckArgs {
+                registry: RegistryArgs {
+                    registry: VirtualDirectoryPath::LocalFolder {
+                        path: "tests/v2_check_span_name/".to_owned(),
+                    },
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    v2: true,
+                },
+                baseline_registry: None,
+                findings_baseline: None,
+                write_baseline: false,
+                policy: PolicyArgs {
+                    policies: vec![],
+                    skip_policies: true,
+                    display_policy_coverage: false,
+                },
+                diagnostic: Default::default(),
+            }),
+        };
+        let cmd_result = semconv_registry(&registry_cmd, None, &HttpAuthResolver::empty());
+        let diag_msgs = cmd_result
+            .command_result
+            .clone()
+            .expect_err("The fallback referencing an unknown attribute should be reported");
+        let messages: Vec<String> = diag_msgs
+            .into_inner()
+            .iter()
+            .map(|msg| format!("{msg:?}"))
+            .collect();
+        let template_errors: Vec<&String> = messages
+            .iter()
+            .filter(|msg| msg.contains("InvalidSpanNameTemplate"))
+            .collect();
+        assert_eq!(template_errors.len(), 1);
+        assert!(template_errors[0].contains("{http.request.method} {url.template}"));
+        assert!(template_errors[0].contains("attribute `url.template` is not defined on the span"));
+        assert_eq!(process_diagnostics(cmd_result).exit_code, 1);
+    }
 }
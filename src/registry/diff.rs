@@ -2,7 +2,7 @@
 
 //! Generate a diff between two versions of a semantic convention registry.
 
-use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::registry::{load_config, Error, PolicyArgs, RegistryArgs};
 use crate::weaver::WeaverEngine;
 use crate::{DiagnosticArgs, ExitDirectives};
 use clap::Args;
@@ -12,7 +12,7 @@ use std::path::PathBuf;
 use weaver_common::diagnostic::DiagnosticMessages;
 use weaver_common::http_auth::HttpAuthResolver;
 use weaver_common::vdir::VirtualDirectoryPath;
-use weaver_config::WeaverConfig;
+use weaver_config::{override_if_set, CliOverrides, DiffConfig, WeaverConfig};
 use weaver_forge::{OutputProcessor, OutputTarget};
 use weaver_semconv::registry_repo::RegistryRepo;
 
@@ -20,24 +20,30 @@ use weaver_semconv::registry_repo::RegistryRepo;
 pub(crate) static DEFAULT_DIFF_TEMPLATES: Dir<'_> = include_dir!("defaults/diff_templates");
 
 /// Parameters for the `registry diff` sub-command
+///
+/// Each setting may also be provided in `.weaver.toml`. CLI flags always take
+/// precedence over config values, which take precedence over hardcoded defaults.
 #[derive(Debug, Args)]
 pub struct RegistryDiffArgs {
     /// Parameters to specify the semantic convention registry
     #[command(flatten)]
     registry: RegistryArgs,
 
-    /// Parameters to specify the baseline semantic convention registry
+    /// Parameters to specify the baseline semantic convention registry.
+    /// Required, either on the command line or in `.weaver.toml`.
     #[arg(long)]
-    baseline_registry: VirtualDirectoryPath,
+    baseline_registry: Option<VirtualDirectoryPath>,
 
     /// Format used to render the schema changes. Predefined formats are: ansi, json,
     /// and markdown.
-    #[arg(long, alias = "diff-format", default_value = "ansi")]
-    format: String,
+    /// (default: ansi)
+    #[arg(long, alias = "diff-format")]
+    format: Option<String>,
 
     /// Path to the directory where the schema changes templates are located.
-    #[arg(long, alias = "diff-template", default_value = "diff_templates")]
-    templates: PathBuf,
+    /// (default: diff_templates)
+    #[arg(long, alias = "diff-template")]
+    templates: Option<PathBuf>,
 
     /// Path to the directory where the generated artifacts will be saved.
     /// If not specified, the diff report is printed to stdout
@@ -49,12 +55,56 @@ pub struct RegistryDiffArgs {
     pub(crate) diagnostic: DiagnosticArgs,
 }
 
+impl CliOverrides for RegistryDiffArgs {
+    type Config = DiffConfig;
+    const SUBCOMMAND: &'static str = "diff";
+
+    fn extract_config(weaver_config: &WeaverConfig) -> DiffConfig {
+        weaver_config.diff.clone()
+    }
+
+    fn cli_only_args() -> &'static [&'static str] {
+        &[
+            "registry",             // RegistryArgs (invocation-specific)
+            "follow_symlinks",      // RegistryArgs
+            "include_unreferenced", // RegistryArgs
+            "v2",                   // RegistryArgs
+            "diagnostic_format",    // DiagnosticArgs
+            "diagnostic_template",  // DiagnosticArgs
+            "diagnostic_stdout",    // DiagnosticArgs
+        ]
+    }
+
+    fn apply_overrides(&self, config: &mut DiffConfig) {
+        if let Some(baseline) = &self.baseline_registry {
+            config.baseline_registry = Some(baseline.to_string());
+        }
+        override_if_set!(config.format, self.format);
+        override_if_set!(config.templates, self.templates);
+        override_if_set!(config.output, self.output, optional);
+    }
+}
+
 /// Generate a diff between two versions of a semantic convention registry.
 pub(crate) fn command(
     args: &RegistryDiffArgs,
-    _cfg: Option<&WeaverConfig>,
+    cfg: Option<&WeaverConfig>,
     auth: &HttpAuthResolver,
 ) -> Result<ExitDirectives, DiagnosticMessages> {
+    let config = load_config(args, cfg);
+    let mut registry_args = args.registry.clone();
+    if let Some(wc) = cfg {
+        super::apply_registry_config(&mut registry_args, &wc.registry);
+    }
+    let baseline_registry: VirtualDirectoryPath = config
+        .baseline_registry
+        .as_deref()
+        .ok_or_else(|| Error::Config {
+            error: "A baseline registry is required: pass `--baseline-registry` or set `diff.baseline_registry` in `.weaver.toml`".to_owned(),
+        })?
+        .parse()
+        .map_err(DiagnosticMessages::from_error)?;
+
     let mut diag_msgs = DiagnosticMessages::empty();
     // TODO - make sure policy is disabled.
     let policy_config = PolicyArgs {
@@ -62,16 +112,16 @@ pub(crate) fn command(
         skip_policies: true,
         display_policy_coverage: false,
     };
-    let weaver = WeaverEngine::new(&args.registry, &policy_config, auth);
+    let weaver = WeaverEngine::new(&registry_args, &policy_config, auth);
 
     info!("Weaver Registry Diff");
-    info!("Checking registry `{}`", args.registry.registry);
+    info!("Checking registry `{}`", registry_args.registry);
 
-    let registry_path = args.registry.registry.clone();
+    let registry_path = registry_args.registry.clone();
     let main_registry_repo =
         RegistryRepo::try_new_with_auth(None, &registry_path, &mut vec![], auth)?;
     let baseline_registry_repo =
-        RegistryRepo::try_new_with_auth(None, &args.baseline_registry, &mut vec![], auth)?;
+        RegistryRepo::try_new_with_auth(None, &baseline_registry, &mut vec![], auth)?;
 
     let main = weaver.load_definitions(main_registry_repo, &mut diag_msgs)?;
     let baseline = weaver.load_definitions(baseline_registry_repo, &mut diag_msgs)?;
@@ -85,12 +135,12 @@ pub(crate) fn command(
         return Err(diag_msgs);
     }
 
-    let target = OutputTarget::from_optional_dir(args.output.as_ref());
+    let target = OutputTarget::from_optional_dir(config.output.as_ref());
     let mut output = OutputProcessor::new(
-        &args.format,
+        &config.format,
         "diff",
         Some(&DEFAULT_DIFF_TEMPLATES),
-        Some(args.templates.clone()),
+        Some(config.templates.clone()),
         target,
     )?;
 
@@ -110,6 +160,7 @@ pub(crate) fn command(
 mod tests {
     use crate::cli::{Cli, Commands};
     use crate::registry::diff::RegistryDiffArgs;
+    use crate::registry::tests::assert_config_cli_consistency;
     use crate::registry::{
         semconv_registry, RegistryArgs, RegistryCommand, RegistrySubCommand, VirtualDirectoryPath,
     };
@@ -136,11 +187,11 @@ mod tests {
                         include_unreferenced: false,
                         v2: false,
                     },
-                    baseline_registry: VirtualDirectoryPath::LocalFolder {
+                    baseline_registry: Some(VirtualDirectoryPath::LocalFolder {
                         path: "tests/diff/registry_baseline/".to_owned(),
-                    },
-                    format: "json".to_owned(),
-                    templates: Default::default(),
+                    }),
+                    format: Some("json".to_owned()),
+                    templates: None,
                     output: None,
                     diagnostic: Default::default(),
                 }),
@@ -169,11 +220,11 @@ mod tests {
                     include_unreferenced: false,
                     v2: false,
                 },
-                baseline_registry: VirtualDirectoryPath::LocalFolder {
+                baseline_registry: Some(VirtualDirectoryPath::LocalFolder {
                     path: "tests/diff/registry_baseline/".to_owned(),
-                },
-                format: "json".to_owned(),
-                templates: Default::default(),
+                }),
+                format: Some("json".to_owned()),
+                templates: None,
                 output: Some(temp_dir.path().to_path_buf()),
                 diagnostic: Default::default(),
             }),
@@ -215,4 +266,33 @@ mod tests {
         assert_eq!(schema_changes.count_event_changes(), 5);
         assert_eq!(schema_changes.count_resource_changes(), 5);
     }
+
+    #[test]
+    fn test_registry_diff_requires_baseline() {
+        let registry_cmd = RegistryCommand {
+            command: RegistrySubCommand::Diff(RegistryDiffArgs {
+                registry: RegistryArgs {
+                    registry: VirtualDirectoryPath::LocalFolder {
+                        path: "tests/diff/registry_head/".to_owned(),
+                    },
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    v2: false,
+                },
+                baseline_registry: None,
+                format: None,
+                templates: None,
+                output: None,
+                diagnostic: Default::default(),
+            }),
+        };
+
+        let cmd_result = semconv_registry(&registry_cmd, None, &HttpAuthResolver::empty());
+        assert!(cmd_result.command_result.is_err());
+    }
+
+    #[test]
+    fn config_fields_match_cli_args() {
+        assert_config_cli_consistency::<RegistryDiffArgs>();
+    }
 }
@@ -9,13 +9,16 @@ use weaver_common::diagnostic::{DiagnosticMessages, ResultExt};
 use weaver_common::log_success;
 use weaver_emit::{emit, ExporterConfig, RegistryVersion};
 
-use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::registry::{load_config, PolicyArgs, RegistryArgs};
 use crate::weaver::WeaverEngine;
 use crate::{DiagnosticArgs, ExitDirectives};
 use weaver_common::http_auth::HttpAuthResolver;
-use weaver_config::WeaverConfig;
+use weaver_config::{override_if_set, CliOverrides, EmitConfig, WeaverConfig};
 
 /// Parameters for the `registry emit` sub-command
+///
+/// Each setting may also be provided in `.weaver.toml`. CLI flags always take
+/// precedence over config values, which take precedence over hardcoded defaults.
 #[derive(Debug, Args)]
 pub struct RegistryEmitArgs {
     /// Parameters to specify the semantic convention registry
@@ -31,55 +34,93 @@ pub struct RegistryEmitArgs {
     pub diagnostic: DiagnosticArgs,
 
     /// Write the telemetry to standard output
-    #[arg(long)]
-    stdout: bool,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    stdout: Option<bool>,
 
     /// Endpoint for the OTLP receiver. OTEL_EXPORTER_OTLP_ENDPOINT env var will override this.
-    #[arg(long, default_value = weaver_emit::DEFAULT_OTLP_ENDPOINT)]
-    endpoint: String,
+    /// (default: http://localhost:4317)
+    #[arg(long)]
+    endpoint: Option<String>,
+}
+
+impl CliOverrides for RegistryEmitArgs {
+    type Config = EmitConfig;
+    const SUBCOMMAND: &'static str = "emit";
+
+    fn extract_config(weaver_config: &WeaverConfig) -> EmitConfig {
+        weaver_config.emit.clone()
+    }
+
+    fn cli_only_args() -> &'static [&'static str] {
+        &[
+            "registry",                // RegistryArgs (invocation-specific)
+            "follow_symlinks",         // RegistryArgs
+            "include_unreferenced",    // RegistryArgs
+            "v2",                      // RegistryArgs
+            "policy",                  // PolicyArgs
+            "skip_policies",           // PolicyArgs
+            "display_policy_coverage", // PolicyArgs
+            "diagnostic_format",       // DiagnosticArgs
+            "diagnostic_template",     // DiagnosticArgs
+            "diagnostic_stdout",       // DiagnosticArgs
+        ]
+    }
+
+    fn apply_overrides(&self, config: &mut EmitConfig) {
+        override_if_set!(config.stdout, self.stdout);
+        override_if_set!(config.endpoint, self.endpoint);
+    }
 }
 
 /// Emit all spans in the resolved registry.
 pub(crate) fn command(
     args: &RegistryEmitArgs,
-    _cfg: Option<&WeaverConfig>,
+    cfg: Option<&WeaverConfig>,
     auth: &HttpAuthResolver,
 ) -> Result<ExitDirectives, DiagnosticMessages> {
+    let config = load_config(args, cfg);
+    let mut registry_args = args.registry.clone();
+    let mut policy_args = args.policy.clone();
+    if let Some(wc) = cfg {
+        super::apply_registry_config(&mut registry_args, &wc.registry);
+        super::apply_policy_config(&mut policy_args, &wc.policy);
+    }
+
     info!("Weaver Registry Emit");
-    info!("Resolving registry `{}`", args.registry.registry);
+    info!("Resolving registry `{}`", registry_args.registry);
 
     let mut diag_msgs = DiagnosticMessages::empty();
 
-    let exporter_config = if args.stdout {
+    let exporter_config = if config.stdout {
         ExporterConfig::Stdout
     } else {
         ExporterConfig::Otlp {
-            endpoint: args.endpoint.clone(),
+            endpoint: config.endpoint.clone(),
         }
     };
-    let weaver = WeaverEngine::new(&args.registry, &args.policy, auth);
+    let weaver = WeaverEngine::new(&registry_args, &policy_args, auth);
     let resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
     match resolved {
         crate::weaver::Resolved::V2(v) => {
-            info!("Emitting v2 registry `{}`", args.registry.registry);
+            info!("Emitting v2 registry `{}`", registry_args.registry);
             emit(
                 RegistryVersion::V2(v.template_schema()),
-                &args.registry.registry.to_string(),
+                &registry_args.registry.to_string(),
                 &exporter_config,
             )
             .combine_diag_msgs_with(&diag_msgs)?;
         }
         crate::weaver::Resolved::V1(v) => {
-            info!("Emitting v1 registry `{}`", args.registry.registry);
+            info!("Emitting v1 registry `{}`", registry_args.registry);
             emit(
                 RegistryVersion::V1(v.template_schema()),
-                &args.registry.registry.to_string(),
+                &registry_args.registry.to_string(),
                 &exporter_config,
             )
             .combine_diag_msgs_with(&diag_msgs)?;
         }
     }
-    log_success(format!("Emitted registry `{}`", args.registry.registry));
+    log_success(format!("Emitted registry `{}`", registry_args.registry));
 
     if diag_msgs.has_error() {
         return Err(diag_msgs);
@@ -95,6 +136,7 @@ pub(crate) fn command(
 mod tests {
     use crate::cli::{Cli, Commands};
     use crate::registry::emit::RegistryEmitArgs;
+    use crate::registry::tests::assert_config_cli_consistency;
     use crate::registry::{PolicyArgs, RegistryArgs, RegistryCommand, RegistrySubCommand};
     use crate::run_command;
     use weaver_common::vdir::VirtualDirectoryPath;
@@ -123,8 +165,8 @@ mod tests {
                         display_policy_coverage: false,
                     },
                     diagnostic: Default::default(),
-                    stdout: true,
-                    endpoint: "".to_owned(),
+                    stdout: Some(true),
+                    endpoint: None,
                 }),
             })),
         };
@@ -133,4 +175,9 @@ mod tests {
         // The command should succeed.
         assert_eq!(exit_directive.exit_code, 0);
     }
+
+    #[test]
+    fn config_fields_match_cli_args() {
+        assert_config_cli_consistency::<RegistryEmitArgs>();
+    }
 }
@@ -20,24 +20,30 @@ use crate::{DiagnosticArgs, ExitDirectives};
 use weaver_common::http_auth::HttpAuthResolver;
 use weaver_common::vdir::VirtualDirectory;
 use weaver_common::vdir::VirtualDirectoryPath;
-use weaver_config::WeaverConfig as ProjectWeaverConfig;
+use weaver_config::{
+    override_if_set, CliOverrides, GenerateConfig, WeaverConfig as ProjectWeaverConfig,
+};
 
 /// Parameters for the `registry generate` sub-command
+///
+/// Each setting may also be provided in `.weaver.toml`, either in the `[generate]`
+/// section or in a named `[generate.targets.<name>]` entry. CLI flags always take
+/// precedence over config values, which take precedence over hardcoded defaults.
 #[derive(Debug, Args)]
 pub struct RegistryGenerateArgs {
-    /// Target to generate the artifacts for.
-    #[arg(default_value = "")]
-    pub target: String,
+    /// Target to generate the artifacts for. If `.weaver.toml` defines a named
+    /// target with this name, its settings are used.
+    /// (default: the templates root)
+    pub target: Option<String>,
 
     /// Path to the directory where the generated artifacts will be saved.
-    /// Default is the `output` directory.
-    #[arg(default_value = "output")]
-    pub output: PathBuf,
+    /// (default: output)
+    pub output: Option<PathBuf>,
 
     /// Path to the directory where the templates are located.
-    /// Default is the `templates` directory.
-    #[arg(short = 't', long, default_value = "templates")]
-    pub templates: VirtualDirectoryPath,
+    /// (default: templates)
+    #[arg(short = 't', long)]
+    pub templates: Option<VirtualDirectoryPath>,
 
     /// List of `weaver.yaml` configuration files to use. When there is a conflict, the last one
     /// will override the previous ones for the keys that are defined in both.
@@ -53,6 +59,10 @@ pub struct RegistryGenerateArgs {
     #[arg(long)]
     pub params: Option<PathBuf>,
 
+    /// Generate every named target defined under `[generate.targets]` in `.weaver.toml`.
+    #[arg(long, conflicts_with_all = ["target", "output"])]
+    pub all: bool,
+
     /// Parameters to specify the semantic convention registry
     #[command(flatten)]
     registry: RegistryArgs,
@@ -71,6 +81,49 @@ pub struct RegistryGenerateArgs {
     pub diagnostic: DiagnosticArgs,
 }
 
+impl CliOverrides for RegistryGenerateArgs {
+    type Config = GenerateConfig;
+    const SUBCOMMAND: &'static str = "generate";
+
+    fn extract_config(weaver_config: &ProjectWeaverConfig) -> GenerateConfig {
+        weaver_config.generate.clone()
+    }
+
+    fn config_only_fields() -> &'static [&'static str] {
+        &[
+            "targets", // named target tables, selected by `target` or `--all`
+        ]
+    }
+
+    fn cli_only_args() -> &'static [&'static str] {
+        &[
+            "param",                   // key=value pairs, use a `params` file in config
+            "all",                     // selects the named targets to run
+            "future",                  // same as the global `--future` flag
+            "registry",                // RegistryArgs (invocation-specific)
+            "follow_symlinks",         // RegistryArgs
+            "include_unreferenced",    // RegistryArgs
+            "v2",                      // RegistryArgs
+            "policy",                  // PolicyArgs
+            "skip_policies",           // PolicyArgs
+            "display_policy_coverage", // PolicyArgs
+            "diagnostic_format",       // DiagnosticArgs
+            "diagnostic_template",     // DiagnosticArgs
+            "diagnostic_stdout",       // DiagnosticArgs
+        ]
+    }
+
+    fn apply_overrides(&self, config: &mut GenerateConfig) {
+        override_if_set!(config.target, self.target);
+        override_if_set!(config.output, self.output);
+        if let Some(templates) = &self.templates {
+            config.templates = templates.to_string();
+        }
+        override_if_set!(config.config, self.config, optional);
+        override_if_set!(config.params, self.params, optional);
+    }
+}
+
 /// Utility function to parse key-value pairs from the command line.
 pub(crate) fn parse_key_val(s: &str) -> Result<(String, Value), Error> {
     let pos = s.find('=').ok_or_else(|| Error::InvalidParam {
@@ -84,48 +137,110 @@ pub(crate) fn parse_key_val(s: &str) -> Result<(String, Value), Error> {
     Ok((s[..pos].to_string(), value))
 }
 
+/// Build the list of generate runs for this invocation.
+///
+/// With `--all`, every named target from `.weaver.toml` is returned. Otherwise a
+/// single run is returned, using the named target matching the requested target
+/// (if any) layered over the `[generate]` section. CLI overrides are applied last.
+fn generate_runs(
+    args: &RegistryGenerateArgs,
+    cfg: Option<&ProjectWeaverConfig>,
+) -> Result<Vec<GenerateConfig>, Error> {
+    let base = cfg
+        .map(RegistryGenerateArgs::extract_config)
+        .unwrap_or_default();
+    let mut runs = if args.all {
+        if base.targets.is_empty() {
+            return Err(Error::Config {
+                error: "`--all` requires at least one `[generate.targets.<name>]` entry in `.weaver.toml`".to_owned(),
+            });
+        }
+        base.targets
+            .keys()
+            .filter_map(|name| base.for_target(name))
+            .collect()
+    } else {
+        let name = args.target.as_deref().unwrap_or(&base.target);
+        vec![base.for_target(name).unwrap_or(base)]
+    };
+    for run in &mut runs {
+        args.apply_overrides(run);
+    }
+    Ok(runs)
+}
+
 /// Generate artifacts from a semantic convention registry.
 pub(crate) fn command(
     args: &RegistryGenerateArgs,
-    _cfg: Option<&ProjectWeaverConfig>,
+    cfg: Option<&ProjectWeaverConfig>,
     auth: &HttpAuthResolver,
 ) -> Result<ExitDirectives, DiagnosticMessages> {
+    let runs = generate_runs(args, cfg)?;
+
+    // `args` is borrowed immutably from the dispatch chain, so shared overrides
+    // need owned copies to mutate.
+    let mut registry_args = args.registry.clone();
+    let mut policy_args = args.policy.clone();
+    if let Some(wc) = cfg {
+        super::apply_registry_config(&mut registry_args, &wc.registry);
+        super::apply_policy_config(&mut policy_args, &wc.policy);
+    }
+
     info!(
         "Generating artifacts for the registry `{}`",
-        args.registry.registry
+        registry_args.registry
     );
 
     let mut diag_msgs = DiagnosticMessages::empty();
-    let weaver = WeaverEngine::new(&args.registry, &args.policy, auth);
+    let mut weaver = WeaverEngine::new(&registry_args, &policy_args, auth);
+    if let Some(wc) = cfg {
+        weaver = weaver.with_lint_config(wc.lint.clone());
+    }
     let resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
-    let params = generate_params(args)?;
-    let templates_dir =
-        VirtualDirectory::try_new_with_auth(&args.templates, auth).map_err(|e| {
-            Error::InvalidParams {
-                params_file: PathBuf::from(args.templates.to_string()),
+    resolved.check_after_resolution_policy(&mut diag_msgs)?;
+
+    for run in &runs {
+        if runs.len() > 1 {
+            info!(
+                "Generating target `{}` into `{}`",
+                run.target,
+                run.output.display()
+            );
+        }
+        let params = generate_params_shared(&args.param, &run.params)?;
+        let templates: VirtualDirectoryPath =
+            run.templates
+                .parse()
+                .map_err(|e: weaver_common::Error| Error::InvalidTemplates {
+                    templates: run.templates.clone(),
+                    error: e.to_string(),
+                })?;
+        let templates_dir = VirtualDirectory::try_new_with_auth(&templates, auth).map_err(|e| {
+            Error::InvalidTemplates {
+                templates: run.templates.clone(),
                 error: e.to_string(),
             }
         })?;
-    let loader =
-        FileSystemFileLoader::try_new(resolve_templates_root(&templates_dir), &args.target)?;
-    let config = if let Some(paths) = &args.config {
-        WeaverConfig::try_from_config_files(paths)
-    } else {
-        WeaverConfig::try_from_path(loader.root())
-    }?;
-    let mut output = OutputProcessor::from_template_config(
-        config,
-        loader,
-        params,
-        OutputTarget::Directory(args.output.clone()),
-    )?;
-    resolved.check_after_resolution_policy(&mut diag_msgs)?;
-    match &resolved {
-        crate::weaver::Resolved::V2(v) => {
-            output.generate(v.template_schema())?;
-        }
-        crate::weaver::Resolved::V1(v) => {
-            output.generate(v.template_schema())?;
+        let loader =
+            FileSystemFileLoader::try_new(resolve_templates_root(&templates_dir), &run.target)?;
+        let config = if let Some(paths) = &run.config {
+            WeaverConfig::try_from_config_files(paths)
+        } else {
+            WeaverConfig::try_from_path(loader.root())
+        }?;
+        let mut output = OutputProcessor::from_template_config(
+            config,
+            loader,
+            params,
+            OutputTarget::Directory(run.output.clone()),
+        )?;
+        match &resolved {
+            crate::weaver::Resolved::V2(v) => {
+                output.generate(v.template_schema())?;
+            }
+            crate::weaver::Resolved::V1(v) => {
+                output.generate(v.template_schema())?;
+            }
         }
     }
 
@@ -157,10 +272,6 @@ fn resolve_templates_root(templates_dir: &VirtualDirectory) -> PathBuf {
 /// The `--params` argument (if provided) is used to load the parameters from a YAML file.
 /// Then the key-value pairs from the `--param` arguments are added to the parameters.
 /// So `--param key=value` will override the value of `key` if it exists in the YAML file.
-fn generate_params(args: &RegistryGenerateArgs) -> Result<Params, Error> {
-    generate_params_shared(&args.param, &args.params)
-}
-
 pub(crate) fn generate_params_shared(
     direct: &Option<Vec<(String, Value)>>,
     file: &Option<PathBuf>,
@@ -197,9 +308,17 @@ mod tests {
 
     use crate::cli::{Cli, Commands};
     use crate::registry::generate::RegistryGenerateArgs;
-    use crate::registry::{PolicyArgs, RegistryArgs, RegistryCommand, RegistrySubCommand};
-    use crate::run_command;
+    use crate::registry::tests::assert_config_cli_consistency;
+    use crate::registry::{
+        semconv_registry, PolicyArgs, RegistryArgs, RegistryCommand, RegistrySubCommand,
+    };
+    use crate::{process_diagnostics, run_command};
+    use std::collections::BTreeMap;
+    use weaver_common::http_auth::HttpAuthResolver;
     use weaver_common::vdir::VirtualDirectoryPath;
+    use weaver_config::{
+        GenerateConfig, GenerateTargetConfig, WeaverConfig as ProjectWeaverConfig,
+    };
 
     #[test]
     fn test_registry_generate() {
@@ -216,14 +335,15 @@ mod tests {
             config: None,
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Generate(RegistryGenerateArgs {
-                    target: "rust".to_owned(),
-                    output: temp_output.clone(),
-                    templates: VirtualDirectoryPath::LocalFolder {
+                    target: Some("rust".to_owned()),
+                    output: Some(temp_output.clone()),
+                    templates: Some(VirtualDirectoryPath::LocalFolder {
                         path: "crates/weaver_codegen_test/templates/".to_owned(),
-                    },
+                    }),
                     config: None,
                     param: None,
                     params: None,
+                    all: false,
                     registry: RegistryArgs {
                         registry: VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
@@ -297,14 +417,15 @@ mod tests {
             config: None,
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Generate(RegistryGenerateArgs {
-                    target: "rust".to_owned(),
-                    output: temp_output.clone(),
-                    templates: VirtualDirectoryPath::LocalFolder {
+                    target: Some("rust".to_owned()),
+                    output: Some(temp_output.clone()),
+                    templates: Some(VirtualDirectoryPath::LocalFolder {
                         path: "crates/weaver_codegen_test/templates/".to_owned(),
-                    },
+                    }),
                     config: None,
                     param: None,
                     params: None,
+                    all: false,
                     registry: RegistryArgs {
                         registry: VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
@@ -342,11 +463,11 @@ mod tests {
             config: None,
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Generate(RegistryGenerateArgs {
-                    target: "rust".to_owned(),
-                    output: temp_output.clone(),
-                    templates: VirtualDirectoryPath::LocalFolder {
+                    target: Some("rust".to_owned()),
+                    output: Some(temp_output.clone()),
+                    templates: Some(VirtualDirectoryPath::LocalFolder {
                         path: "crates/weaver_codegen_test/templates/".to_owned(),
-                    },
+                    }),
                     config: Some(vec![
                         PathBuf::from(
                             "crates/weaver_codegen_test/templates/registry/alt_weaver.yaml",
@@ -357,6 +478,7 @@ mod tests {
                     ]),
                     param: None,
                     params: None,
+                    all: false,
                     registry: RegistryArgs {
                         registry: VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
@@ -460,14 +582,15 @@ mod tests {
                 config: None,
                 command: Some(Commands::Registry(RegistryCommand {
                     command: RegistrySubCommand::Generate(RegistryGenerateArgs {
-                        target: "rust".to_owned(),
-                        output: temp_output.clone(),
-                        templates: VirtualDirectoryPath::LocalFolder {
+                        target: Some("rust".to_owned()),
+                        output: Some(temp_output.clone()),
+                        templates: Some(VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/templates/".to_owned(),
-                        },
+                        }),
                         config: None,
                         param: None,
                         params: None,
+                        all: false,
                         registry: RegistryArgs {
                             registry: VirtualDirectoryPath::LocalFolder {
                                 path: "data/symbolic_test/".to_owned(),
@@ -539,14 +662,15 @@ mod tests {
             config: None,
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Generate(RegistryGenerateArgs {
-                    target: "markdown".to_owned(),
-                    output: temp_output.to_path_buf(),
-                    templates: VirtualDirectoryPath::LocalFolder {
+                    target: Some("markdown".to_owned()),
+                    output: Some(temp_output.to_path_buf()),
+                    templates: Some(VirtualDirectoryPath::LocalFolder {
                         path: "tests/v2_forge/templates/".to_owned(),
-                    },
+                    }),
                     config: None,
                     param: None,
                     params: None,
+                    all: false,
                     registry: RegistryArgs {
                         registry: VirtualDirectoryPath::LocalFolder {
                             path: "tests/v2_forge/model/".to_owned(),
@@ -574,4 +698,123 @@ mod tests {
         let expected_output = Path::new("tests/v2_forge/expected_output");
         assert!(diff_dir(expected_output, temp_output).unwrap());
     }
+
+    #[test]
+    fn test_registry_generate_all_targets() {
+        let rust_output = TempDir::new().expect("Failed to create temporary directory");
+        let alt_output = TempDir::new().expect("Failed to create temporary directory");
+        let weaver_config = ProjectWeaverConfig {
+            generate: GenerateConfig {
+                templates: "crates/weaver_codegen_test/templates/".to_owned(),
+                targets: BTreeMap::from([
+                    (
+                        "rust".to_owned(),
+                        GenerateTargetConfig {
+                            output: Some(rust_output.path().to_path_buf()),
+                            ..Default::default()
+                        },
+                    ),
+                    (
+                        "rust-attributes".to_owned(),
+                        GenerateTargetConfig {
+                            target: Some("rust".to_owned()),
+                            output: Some(alt_output.path().to_path_buf()),
+                            config: Some(vec![
+                                PathBuf::from(
+                                    "crates/weaver_codegen_test/templates/registry/alt_weaver.yaml",
+                                ),
+                                PathBuf::from(
+                                    "crates/weaver_codegen_test/templates/registry/rust/weaver.yaml",
+                                ),
+                            ]),
+                            ..Default::default()
+                        },
+                    ),
+                ]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let registry_cmd = RegistryCommand {
+            command: RegistrySubCommand::Generate(RegistryGenerateArgs {
+                target: None,
+                output: None,
+                templates: None,
+                config: None,
+                param: None,
+                params: None,
+                all: true,
+                registry: RegistryArgs {
+                    registry: VirtualDirectoryPath::LocalFolder {
+                        path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
+                    },
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    v2: false,
+                },
+                policy: PolicyArgs {
+                    policies: vec![],
+                    skip_policies: true,
+                    display_policy_coverage: false,
+                },
+                future: false,
+                diagnostic: Default::default(),
+            }),
+        };
+
+        let cmd_result = semconv_registry(
+            &registry_cmd,
+            Some(&weaver_config),
+            &HttpAuthResolver::empty(),
+        );
+        // Resolution warnings are reported as diagnostics but do not fail the command.
+        assert_eq!(process_diagnostics(cmd_result).exit_code, 0);
+
+        // The default rust target generates metrics, the alternate config does not.
+        assert!(rust_output.path().join("metrics").join("mod.rs").is_file());
+        assert!(alt_output
+            .path()
+            .join("attributes")
+            .join("mod.rs")
+            .is_file());
+        assert!(!alt_output.path().join("metrics").exists());
+    }
+
+    #[test]
+    fn test_registry_generate_all_requires_targets() {
+        let registry_cmd = RegistryCommand {
+            command: RegistrySubCommand::Generate(RegistryGenerateArgs {
+                target: None,
+                output: None,
+                templates: None,
+                config: None,
+                param: None,
+                params: None,
+                all: true,
+                registry: RegistryArgs {
+                    registry: VirtualDirectoryPath::LocalFolder {
+                        path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
+                    },
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    v2: false,
+                },
+                policy: PolicyArgs {
+                    policies: vec![],
+                    skip_policies: true,
+                    display_policy_coverage: false,
+                },
+                future: false,
+                diagnostic: Default::default(),
+            }),
+        };
+
+        let cmd_result = semconv_registry(&registry_cmd, None, &HttpAuthResolver::empty());
+        assert!(cmd_result.command_result.is_err());
+    }
+
+    #[test]
+    fn config_fields_match_cli_args() {
+        assert_config_cli_consistency::<RegistryGenerateArgs>();
+    }
 }
@@ -19,38 +19,67 @@ use super::otlp::conversion::{
 };
 use super::otlp::grpc_stubs::proto::resource::v1::Resource;
 use super::otlp::{listen_otlp_requests, OtlpRequest};
+use crate::registry::load_config;
 use crate::{DiagnosticArgs, ExitDirectives};
 use weaver_common::diagnostic::DiagnosticMessages;
 use weaver_common::http_auth::HttpAuthResolver;
 use weaver_common::log_success;
-use weaver_config::WeaverConfig;
+use weaver_config::{override_if_set, CliOverrides, InferConfig, WeaverConfig};
 
 /// Parameters for the `registry infer` sub-command
+///
+/// Each setting may also be provided in `.weaver.toml`. CLI flags always take
+/// precedence over config values, which take precedence over hardcoded defaults.
 #[derive(Debug, Args)]
 pub struct RegistryInferArgs {
     /// Parameters to specify the diagnostic format.
     #[command(flatten)]
     pub diagnostic: DiagnosticArgs,
 
-    /// Output folder for generated YAML files.
-    #[arg(short, long, default_value = "./inferred-registry/")]
-    output: PathBuf,
+    /// Output folder for generated YAML files. (default: ./inferred-registry/)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
 
-    /// Address used by the gRPC OTLP listener.
-    #[arg(long, default_value = "0.0.0.0")]
-    grpc_address: String,
+    /// Address used by the gRPC OTLP listener. (default: 0.0.0.0)
+    #[arg(long)]
+    grpc_address: Option<String>,
 
-    /// Port used by the gRPC OTLP listener.
-    #[arg(long, default_value = "4317")]
-    grpc_port: u16,
+    /// Port used by the gRPC OTLP listener. (default: 4317)
+    #[arg(long)]
+    grpc_port: Option<u16>,
 
-    /// Port used by the HTTP admin server (endpoints: /stop).
-    #[arg(long, default_value = "8080")]
-    admin_port: u16,
+    /// Port used by the HTTP admin server (endpoints: /stop). (default: 8080)
+    #[arg(long)]
+    admin_port: Option<u16>,
 
-    /// Seconds of inactivity before auto-stop (0 = never).
-    #[arg(long, default_value = "60")]
-    inactivity_timeout: u64,
+    /// Seconds of inactivity before auto-stop (0 = never). (default: 60)
+    #[arg(long)]
+    inactivity_timeout: Option<u64>,
+}
+
+impl CliOverrides for RegistryInferArgs {
+    type Config = InferConfig;
+    const SUBCOMMAND: &'static str = "infer";
+
+    fn extract_config(weaver_config: &WeaverConfig) -> InferConfig {
+        weaver_config.infer.clone()
+    }
+
+    fn cli_only_args() -> &'static [&'static str] {
+        &[
+            "diagnostic_format",   // DiagnosticArgs
+            "diagnostic_template", // DiagnosticArgs
+            "diagnostic_stdout",   // DiagnosticArgs
+        ]
+    }
+
+    fn apply_overrides(&self, config: &mut InferConfig) {
+        override_if_set!(config.output, self.output);
+        override_if_set!(config.grpc_address, self.grpc_address);
+        override_if_set!(config.grpc_port, self.grpc_port);
+        override_if_set!(config.admin_port, self.admin_port);
+        override_if_set!(config.inactivity_timeout, self.inactivity_timeout);
+    }
 }
 
 /// Accumulates resource attributes from an OTLP Resource into the accumulator.
@@ -58,6 +87,7 @@ fn accumulate_resource(resource: Option<Resource>, accumulator: &mut Accumulated
     if let Some(resource) = resource {
         let mut sample_resource = SampleResource {
             attributes: Vec::new(),
+            schema_url: None,
             live_check_result: None,
         };
         for attribute in resource.attributes {
@@ -111,8 +141,13 @@ fn process_otlp_request(request: OtlpRequest, accumulator: &mut AccumulatedSampl
                             attributes: Vec::new(),
                             span_events: Vec::new(),
                             span_links: Vec::new(),
+                            schema_url: None,
                             live_check_result: None,
                             resource: None,
+                            scope: None,
+                            trace_id: None,
+                            span_id: None,
+                            parent_span_id: None,
                         };
                         for attribute in span.attributes {
                             sample_span
@@ -146,15 +181,18 @@ fn process_otlp_request(request: OtlpRequest, accumulator: &mut AccumulatedSampl
             info!("Received error: {:?}", e);
             true // Continue processing
         }
+        OtlpRequest::Tick => true,
     }
 }
 
 /// Infer a semantic convention registry from OTLP telemetry.
 pub(crate) fn command(
     args: &RegistryInferArgs,
-    _cfg: Option<&WeaverConfig>,
+    cfg: Option<&WeaverConfig>,
     _auth: &HttpAuthResolver,
 ) -> Result<ExitDirectives, DiagnosticMessages> {
+    let config = load_config(args, cfg);
+
     log::warn!(
         "The `registry infer` command is experimental and not yet stable. \
         The generated schema format, command options, and output may change in future versions."
@@ -163,22 +201,23 @@ pub(crate) fn command(
     info!("Weaver Registry Infer");
     info!(
         "Starting OTLP gRPC server on {}:{}",
-        args.grpc_address, args.grpc_port
+        config.grpc_address, config.grpc_port
     );
 
     // Start the OTLP gRPC server and get an iterator of requests
     let (requests, _report_sender) = listen_otlp_requests(
-        &args.grpc_address,
-        args.grpc_port,
-        args.admin_port,
-        Duration::from_secs(args.inactivity_timeout),
+        &config.grpc_address,
+        config.grpc_port,
+        config.admin_port,
+        Duration::from_secs(config.inactivity_timeout),
+        None,
     )
     .map_err(DiagnosticMessages::from)?;
 
     info!("OTLP gRPC server started. Waiting for telemetry...");
     info!(
         "To stop: press CTRL+C, send SIGHUP, or POST to http://localhost:{}/stop",
-        args.admin_port
+        config.admin_port
     );
 
     // Accumulate samples
@@ -200,7 +239,7 @@ pub(crate) fn command(
         info!("No telemetry data received. No YAML file generated.");
     } else {
         // Create output directory
-        fs::create_dir_all(&args.output).map_err(|e| {
+        fs::create_dir_all(&config.output).map_err(|e| {
             DiagnosticMessages::from(super::otlp::Error::OtlpError {
                 error: format!("Failed to create output directory: {}", e),
             })
@@ -215,7 +254,7 @@ pub(crate) fn command(
         })?;
 
         // Write to file
-        let output_path = args.output.join("registry.yaml");
+        let output_path = config.output.join("registry.yaml");
         fs::write(&output_path, yaml).map_err(|e| {
             DiagnosticMessages::from(super::otlp::Error::OtlpError {
                 error: format!("Failed to write file: {}", e),
@@ -232,3 +271,14 @@ pub(crate) fn command(
         warnings: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RegistryInferArgs;
+    use crate::registry::tests::assert_config_cli_consistency;
+
+    #[test]
+    fn config_fields_match_cli_args() {
+        assert_config_cli_consistency::<RegistryInferArgs>();
+    }
+}
@@ -4,8 +4,10 @@
 //! - Comparing it to a semantic convention registry.
 //! - Running built-in and custom policies to provide advice on how to improve the telemetry.
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use clap::Args;
 use include_dir::{include_dir, Dir};
@@ -14,24 +16,31 @@ use log::info;
 use weaver_common::diagnostic::DiagnosticMessages;
 use weaver_common::http_auth::HttpAuthResolver;
 use weaver_common::log_success;
+use weaver_common::vdir::VirtualDirectoryPath;
 use weaver_config::{override_if_set, CliOverrides, FieldMapping, LiveCheckConfig, WeaverConfig};
 use weaver_forge::{OutputProcessor, OutputTarget};
 use weaver_live_check::advice::{
-    Advisor, DeprecatedAdvisor, EnumAdvisor, RegoAdvisor, StabilityAdvisor, TypeAdvisor,
+    Advisor, BodyAdvisor, BucketAdvisor, ChildSpanAdvisor, ConstraintAdvisor, DeprecatedAdvisor,
+    EntityAdvisor, EnumAdvisor, OrphanSpanAdvisor, RegoAdvisor, SpanEventAdvisor, StabilityAdvisor,
+    TraceAdvisor, TypeAdvisor,
 };
+use weaver_live_check::cardinality::CardinalityTracker;
 use weaver_live_check::finding_modifier::FindingModifier;
 use weaver_live_check::json_file_ingester::JsonFileIngester;
 use weaver_live_check::json_stdin_ingester::JsonStdinIngester;
 use weaver_live_check::live_checker::LiveChecker;
+use weaver_live_check::prometheus_ingester::{PrometheusIngester, PrometheusSource};
+use weaver_live_check::schema_history::SchemaHistory;
 use weaver_live_check::text_file_ingester::TextFileIngester;
 use weaver_live_check::text_stdin_ingester::TextStdinIngester;
 use weaver_live_check::{
     CumulativeStatistics, DisabledStatistics, Error, Ingester, LiveCheckReport, LiveCheckRunner,
-    LiveCheckStatistics, Sample, VersionedRegistry,
+    LiveCheckStatistics, Sample, ThroughputStatistics, VersionedRegistry,
 };
+use weaver_semconv::registry_repo::RegistryRepo;
 
 use crate::registry::{load_config, PolicyArgs, RegistryArgs};
-use crate::weaver::WeaverEngine;
+use crate::weaver::{Resolved, WeaverEngine};
 use crate::{DiagnosticArgs, ExitDirectives};
 
 use super::otlp::otlp_ingester::OtlpIngester;
@@ -47,6 +56,7 @@ enum InputSource {
     File(PathBuf),
     Stdin,
     Otlp,
+    Url(String),
 }
 
 impl From<String> for InputSource {
@@ -54,6 +64,7 @@ impl From<String> for InputSource {
         match s.to_lowercase().as_str() {
             "stdin" | "s" => InputSource::Stdin,
             "otlp" | "o" => InputSource::Otlp,
+            url if url.starts_with("http://") || url.starts_with("https://") => InputSource::Url(s),
             _ => InputSource::File(PathBuf::from(s)),
         }
     }
@@ -64,12 +75,16 @@ impl From<String> for InputSource {
 enum InputFormat {
     Text,
     Json,
+    Prometheus,
+    Otlp,
 }
 
 impl From<String> for InputFormat {
     fn from(s: String) -> Self {
         match s.to_lowercase().as_str() {
             "json" | "js" => InputFormat::Json,
+            "prometheus" | "prom" | "openmetrics" => InputFormat::Prometheus,
+            "otlp" => InputFormat::Otlp,
             _ => InputFormat::Text,
         }
     }
@@ -93,13 +108,17 @@ pub struct RegistryLiveCheckArgs {
     #[command(flatten)]
     pub diagnostic: DiagnosticArgs,
 
-    /// Where to read the input telemetry from. {file path} | stdin | otlp
+    /// Where to read the input telemetry from. {file path} | {http(s) URL} | stdin | otlp
     /// (default: otlp)
+    ///
+    /// URLs are scraped once and require `--input-format prometheus`.
     #[arg(long)]
     input_source: Option<String>,
 
-    /// The format of the input telemetry. (Not required for OTLP). text | json
+    /// The format of the input telemetry. (Not required for OTLP). text | json | prometheus | otlp
     /// (default: json)
+    ///
+    /// `otlp` replays a file of OTLP requests recorded with `--otlp-record`.
     #[arg(long)]
     input_format: Option<String>,
 
@@ -162,6 +181,30 @@ pub struct RegistryLiveCheckArgs {
     #[clap(long)]
     inactivity_timeout: Option<u64>,
 
+    /// Assemble OTLP spans into traces, buffering the spans of each trace for this
+    /// many seconds before checking it as a whole. (default: 0, disabled)
+    ///
+    /// Assembled traces get structural checks: events recorded on spans whose type
+    /// does not declare them (v2 registries only), and spans whose parent is not part
+    /// of the trace.
+    #[clap(long)]
+    otlp_trace_window: Option<u64>,
+
+    /// Report the CLIENT and PRODUCER spans of the assembled traces that have no SERVER
+    /// or CONSUMER child span.
+    ///
+    /// Only enable it when the downstream services are expected to be instrumented:
+    /// calls to uninstrumented services, such as databases, have no child span.
+    #[clap(long, num_args = 0..=1, default_missing_value = "true")]
+    otlp_expect_child_spans: Option<bool>,
+
+    /// Record the OTLP requests received by the listener in this file.
+    ///
+    /// The recording can be replayed later with `--input-source <FILE> --input-format otlp`,
+    /// e.g. to check the same traffic with new policies.
+    #[clap(long)]
+    otlp_record: Option<PathBuf>,
+
     /// Advice policies directory. Set this to override the default policies.
     #[arg(long)]
     advice_policies: Option<PathBuf>,
@@ -173,6 +216,38 @@ pub struct RegistryLiveCheckArgs {
     /// versus processing the data for every sample.
     #[arg(long)]
     advice_preprocessor: Option<PathBuf>,
+
+    /// Number of worker threads checking the samples in parallel. (default: 1)
+    ///
+    /// Each worker runs its own advisors. The metric attribute cardinality and the cache of
+    /// the rego results are shared, and the samples are reported in the order they were
+    /// received. OTLP log emission always uses a single worker.
+    #[arg(long)]
+    advice_workers: Option<usize>,
+
+    /// OpenTelemetry schema file used to translate telemetry produced with an older
+    /// semconv version. Can be repeated.
+    ///
+    /// Samples carrying a schema URL (from their resource or instrumentation scope) have
+    /// the names renamed in later versions translated before being checked, and get an
+    /// `outdated_semconv_version` finding instead of `missing_attribute` or `missing_metric`.
+    #[arg(long)]
+    schema_file: Option<Vec<PathBuf>>,
+
+    /// Historical version of the registry used to translate telemetry produced with an
+    /// older semconv version. Can be repeated.
+    ///
+    /// Its attributes and metrics deprecated with `renamed_to` are used like the renamings
+    /// of a schema file, for the version of its schema URL.
+    #[arg(long)]
+    historical_registry: Option<Vec<VirtualDirectoryPath>>,
+
+    /// Number of distinct values above which a metric attribute is reported as
+    /// `high_cardinality`. (default: 100)
+    ///
+    /// Distinct values are counted per metric and attribute over the whole session.
+    #[arg(long)]
+    cardinality_threshold: Option<u64>,
 }
 
 impl CliOverrides for RegistryLiveCheckArgs {
@@ -222,6 +297,14 @@ impl CliOverrides for RegistryLiveCheckArgs {
                 config_name: "emit_otlp_logs_stdout",
                 cli_name: "otlp_logs_stdout",
             },
+            FieldMapping {
+                config_name: "schema_files",
+                cli_name: "schema_file",
+            },
+            FieldMapping {
+                config_name: "historical_registries",
+                cli_name: "historical_registry",
+            },
         ]
     }
 
@@ -239,25 +322,183 @@ impl CliOverrides for RegistryLiveCheckArgs {
             self.advice_preprocessor,
             optional
         );
+        override_if_set!(config.advice_workers, self.advice_workers);
         override_if_set!(config.otlp.grpc_address, self.otlp_grpc_address);
         override_if_set!(config.otlp.grpc_port, self.otlp_grpc_port);
         override_if_set!(config.otlp.admin_port, self.admin_port);
         override_if_set!(config.otlp.inactivity_timeout, self.inactivity_timeout);
+        override_if_set!(config.otlp.trace_window, self.otlp_trace_window);
+        override_if_set!(config.otlp.expect_child_spans, self.otlp_expect_child_spans);
+        override_if_set!(config.otlp.record, self.otlp_record, optional);
         override_if_set!(config.emit.otlp_logs, self.emit_otlp_logs);
         override_if_set!(config.emit.otlp_logs_endpoint, self.otlp_logs_endpoint);
         override_if_set!(config.emit.otlp_logs_stdout, self.otlp_logs_stdout);
+        override_if_set!(config.schema_files, self.schema_file);
+        override_if_set!(config.cardinality_threshold, self.cardinality_threshold);
+        if let Some(registries) = &self.historical_registry {
+            config.historical_registries = registries.iter().map(ToString::to_string).collect();
+        }
     }
 }
 
-fn default_advisors() -> Vec<Box<dyn Advisor>> {
+/// The built-in advisors run by every live check.
+pub(crate) fn default_advisors(registry: &VersionedRegistry) -> Vec<Box<dyn Advisor>> {
     vec![
         Box::new(DeprecatedAdvisor),
         Box::new(StabilityAdvisor),
         Box::new(TypeAdvisor),
         Box::new(EnumAdvisor),
+        Box::new(ConstraintAdvisor::default()),
+        Box::new(BodyAdvisor),
+        Box::new(BucketAdvisor),
+        Box::new(EntityAdvisor::new(registry)),
     ]
 }
 
+/// The built-in advisors run on the traces assembled by the OTLP ingester.
+pub(crate) fn default_trace_advisors(
+    registry: &VersionedRegistry,
+    expect_child_spans: bool,
+) -> Vec<Box<dyn TraceAdvisor>> {
+    let mut advisors: Vec<Box<dyn TraceAdvisor>> = Vec::new();
+    if let Some(advisor) = SpanEventAdvisor::new(registry) {
+        advisors.push(Box::new(advisor));
+    }
+    if expect_child_spans {
+        advisors.push(Box::new(ChildSpanAdvisor));
+    }
+    advisors.push(Box::new(OrphanSpanAdvisor));
+    advisors
+}
+
+/// Check the samples with several live checkers running in parallel, each built by
+/// `new_live_checker` in its own worker thread, and pass the checked samples to
+/// `on_sample` in the order they were received.
+///
+/// Returns the number of samples checked and the time spent checking them, summed
+/// over the workers.
+fn check_samples_in_parallel(
+    samples: impl Iterator<Item = Sample>,
+    workers: usize,
+    new_live_checker: &(dyn Fn() -> LiveChecker + Sync),
+    stats: &mut LiveCheckStatistics,
+    on_sample: &mut dyn FnMut(Sample) -> Result<(), DiagnosticMessages>,
+) -> Result<(usize, Duration), DiagnosticMessages> {
+    std::thread::scope(|scope| {
+        let (work_sender, work_receiver) = mpsc::sync_channel::<(usize, Sample)>(workers * 2);
+        let work_receiver = Arc::new(Mutex::new(work_receiver));
+        let (result_sender, result_receiver) = mpsc::channel();
+
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                let work_receiver = Arc::clone(&work_receiver);
+                let result_sender = result_sender.clone();
+                // Each worker accumulates its own statistics, merged at the end
+                let mut worker_stats = stats.clone();
+                scope.spawn(move || {
+                    let mut live_checker = new_live_checker();
+                    let mut checking_time = Duration::ZERO;
+                    loop {
+                        let work = work_receiver
+                            .lock()
+                            .expect("Failed to acquire lock on live check samples")
+                            .recv();
+                        let Ok((index, mut sample)) = work else {
+                            break;
+                        };
+                        let started = Instant::now();
+                        let parent_signal = sample.parent_signal();
+                        let result = sample
+                            .run_live_check(
+                                &mut live_checker,
+                                &mut worker_stats,
+                                None,
+                                &parent_signal,
+                            )
+                            .map(|()| sample);
+                        checking_time += started.elapsed();
+                        if result_sender.send((index, result)).is_err() {
+                            break;
+                        }
+                    }
+                    (worker_stats, checking_time)
+                })
+            })
+            .collect();
+        drop(result_sender);
+
+        // The samples are passed on in order, as soon as all the previous ones are checked
+        let mut checked = BTreeMap::new();
+        let mut next_index = 0;
+        let mut pass_on = |checked: &mut BTreeMap<usize, Result<Sample, Error>>,
+                           next_index: &mut usize|
+         -> Result<(), DiagnosticMessages> {
+            while let Some(result) = checked.remove(next_index) {
+                *next_index += 1;
+                on_sample(result?)?;
+            }
+            Ok(())
+        };
+        for (index, sample) in samples.enumerate() {
+            if work_sender.send((index, sample)).is_err() {
+                break;
+            }
+            for (index, result) in result_receiver.try_iter() {
+                let _ = checked.insert(index, result);
+            }
+            pass_on(&mut checked, &mut next_index)?;
+        }
+        drop(work_sender);
+        for (index, result) in result_receiver {
+            let _ = checked.insert(index, result);
+            pass_on(&mut checked, &mut next_index)?;
+        }
+
+        let mut checking_time = Duration::ZERO;
+        for handle in handles {
+            let (worker_stats, worker_time) = handle.join().expect("Live check worker panicked");
+            stats.merge(worker_stats);
+            checking_time += worker_time;
+        }
+        Ok((next_index, checking_time))
+    })
+}
+
+/// Load the renamings of the configured schema files and historical registries, if any.
+fn load_schema_history(
+    config: &LiveCheckConfig,
+    weaver: &WeaverEngine<'_>,
+    auth: &HttpAuthResolver,
+    diag_msgs: &mut DiagnosticMessages,
+) -> Result<Option<SchemaHistory>, DiagnosticMessages> {
+    if config.schema_files.is_empty() && config.historical_registries.is_empty() {
+        return Ok(None);
+    }
+    let mut schema_history = SchemaHistory::new();
+    for path in &config.schema_files {
+        schema_history.add_schema_file(path)?;
+    }
+    for registry in &config.historical_registries {
+        info!("Resolving historical registry `{registry}`");
+        let registry_path: VirtualDirectoryPath =
+            registry.parse().map_err(DiagnosticMessages::from_error)?;
+        let repo = RegistryRepo::try_new_with_auth(None, &registry_path, &mut vec![], auth)?;
+        let loaded = weaver.load_definitions(repo, diag_msgs)?;
+        let (schema_url, registry) = match weaver.resolve(loaded, diag_msgs)? {
+            Resolved::V1(resolved_v1) => (
+                resolved_v1.resolved_schema().schema_url.clone(),
+                VersionedRegistry::V1(Box::new(resolved_v1.into_template_schema())),
+            ),
+            Resolved::V2(resolved_v2) => (
+                resolved_v2.template_schema().schema_url.to_string(),
+                VersionedRegistry::V2(Box::new(resolved_v2.into_template_schema())),
+            ),
+        };
+        schema_history.add_registry(&schema_url, &registry)?;
+    }
+    Ok(Some(schema_history))
+}
+
 /// Generate output for a complete report - handles line-oriented special case
 fn generate_report(
     output: &mut OutputProcessor,
@@ -339,26 +580,31 @@ pub(crate) fn command(
     let weaver = WeaverEngine::new(&registry_args, &policy_args, auth);
     let resolved_registry = weaver.load_and_resolve_main(&mut diag_msgs)?;
     let registry = match resolved_registry {
-        crate::weaver::Resolved::V2(resolved_v2) => {
+        Resolved::V2(resolved_v2) => {
             resolved_v2.check_after_resolution_policy(&mut diag_msgs)?;
             VersionedRegistry::V2(Box::new(resolved_v2.into_template_schema()))
         }
-        crate::weaver::Resolved::V1(resolved_v1) => {
+        Resolved::V1(resolved_v1) => {
             resolved_v1.check_after_resolution_policy(&mut diag_msgs)?;
             VersionedRegistry::V1(Box::new(resolved_v1.into_template_schema()))
         }
     };
     // Create the live checker with advisors
-    let mut live_checker = LiveChecker::new(Arc::new(registry), default_advisors());
+    let advisors = default_advisors(&registry);
+    let trace_advisors = default_trace_advisors(&registry, config.otlp.expect_child_spans);
+    let mut live_checker = LiveChecker::new(Arc::new(registry), advisors);
+    live_checker.trace_advisors = trace_advisors;
 
     live_checker.finding_modifier = FindingModifier::from_filters(&config.finding_filters);
+    live_checker.schema_history = load_schema_history(&config, &weaver, auth, &mut diag_msgs)?;
+    live_checker.cardinality = CardinalityTracker::new(config.cardinality_threshold);
 
     let rego_advisor = RegoAdvisor::new(
         &live_checker,
         &config.advice_policies,
         &config.advice_preprocessor,
     )?;
-    live_checker.add_advisor(Box::new(rego_advisor));
+    live_checker.add_advisor(Box::new(rego_advisor.clone()));
 
     // Prepare the ingester
     let mut admin_report_sender: Option<AdminReportSender> = None;
@@ -371,12 +617,50 @@ pub(crate) fn command(
 
         (InputSource::Stdin, InputFormat::Json) => JsonStdinIngester::new().ingest()?,
 
+        (InputSource::File(path), InputFormat::Prometheus) => {
+            PrometheusIngester::from_file(path, &live_checker.registry).ingest()?
+        }
+
+        (InputSource::Stdin, InputFormat::Prometheus) => {
+            PrometheusIngester::new(PrometheusSource::Stdin, &live_checker.registry).ingest()?
+        }
+
+        (InputSource::Url(url), InputFormat::Prometheus) => {
+            PrometheusIngester::new(PrometheusSource::Url(url.clone()), &live_checker.registry)
+                .ingest()?
+        }
+
+        (InputSource::File(path), InputFormat::Otlp) => OtlpIngester {
+            otlp_grpc_address: config.otlp.grpc_address.clone(),
+            otlp_grpc_port: config.otlp.grpc_port,
+            admin_port: config.otlp.admin_port,
+            inactivity_timeout: config.otlp.inactivity_timeout,
+            trace_window: config.otlp.trace_window,
+            record: None,
+        }
+        .replay(path)?,
+
+        (InputSource::Stdin, InputFormat::Otlp) => {
+            return Err(DiagnosticMessages::from(Error::ConfigError {
+                error: "OTLP recordings can only be replayed from a file".to_owned(),
+            }));
+        }
+
+        (InputSource::Url(_), _) => {
+            return Err(DiagnosticMessages::from(Error::ConfigError {
+                error: "URL input sources are only supported with --input-format prometheus"
+                    .to_owned(),
+            }));
+        }
+
         (InputSource::Otlp, _) => {
             let otlp = OtlpIngester {
                 otlp_grpc_address: config.otlp.grpc_address.clone(),
                 otlp_grpc_port: config.otlp.grpc_port,
                 admin_port: config.otlp.admin_port,
                 inactivity_timeout: config.otlp.inactivity_timeout,
+                trace_window: config.otlp.trace_window,
+                record: config.otlp.record.clone(),
             };
             let (iter, sender) = otlp.ingest_otlp()?;
             if is_http_output {
@@ -436,9 +720,15 @@ pub(crate) fn command(
         LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry))
     };
 
+    // The OTLP emitter cannot be shared between threads
+    let workers = if live_checker.otlp_emitter.is_some() {
+        1
+    } else {
+        config.advice_workers.max(1)
+    };
+
     let mut samples = Vec::new();
-    for mut sample in ingester {
-        sample.run_live_check(&mut live_checker, &mut stats, None, &sample.clone())?;
+    let mut on_sample = |sample: Sample| -> Result<(), DiagnosticMessages> {
         //TODO: Check for violations and set exit_code here for no-stats mode
         if report_mode {
             samples.push(sample);
@@ -446,7 +736,50 @@ pub(crate) fn command(
             // Output this sample immediately (streaming mode)
             output.generate(&sample).map_err(DiagnosticMessages::from)?;
         }
-    }
+        Ok(())
+    };
+    let (sample_count, checking_time) = if workers > 1 {
+        let registry = Arc::clone(&live_checker.registry);
+        let schema_history = live_checker.schema_history.clone();
+        let cardinality = live_checker.cardinality.clone();
+        let new_live_checker = || {
+            let mut worker_checker =
+                LiveChecker::new(Arc::clone(&registry), default_advisors(&registry));
+            worker_checker.trace_advisors =
+                default_trace_advisors(&registry, config.otlp.expect_child_spans);
+            worker_checker.finding_modifier =
+                FindingModifier::from_filters(&config.finding_filters);
+            worker_checker.schema_history = schema_history.clone();
+            worker_checker.cardinality = cardinality.clone();
+            worker_checker.add_advisor(Box::new(rego_advisor.clone()));
+            worker_checker
+        };
+        check_samples_in_parallel(
+            ingester,
+            workers,
+            &new_live_checker,
+            &mut stats,
+            &mut on_sample,
+        )?
+    } else {
+        let mut sample_count = 0;
+        let mut checking_time = Duration::ZERO;
+        for mut sample in ingester {
+            let started = Instant::now();
+            let parent_signal = sample.parent_signal();
+            sample.run_live_check(&mut live_checker, &mut stats, None, &parent_signal)?;
+            checking_time += started.elapsed();
+            sample_count += 1;
+            on_sample(sample)?;
+        }
+        (sample_count, checking_time)
+    };
+    stats.set_throughput(ThroughputStatistics::new(
+        sample_count,
+        checking_time,
+        workers,
+        rego_advisor.statistics(),
+    ));
 
     stats.finalize();
     // Set the exit_code to a non-zero code if there are any violations
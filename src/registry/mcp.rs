@@ -6,25 +6,40 @@
 //! (Model Context Protocol) server exposing the semantic conventions registry
 //! to LLMs.
 
+use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use clap::Args;
 use log::info;
 
-use crate::registry::{PolicyArgs, RegistryArgs};
-use crate::weaver::WeaverEngine;
+use crate::registry::{apply_policy_config, load_config, Error, PolicyArgs, RegistryArgs};
+use crate::weaver::{Loaded, WeaverEngine};
 use crate::{DiagnosticArgs, ExitDirectives};
+use weaver_checker::PolicyFinding;
 use weaver_common::diagnostic::DiagnosticMessages;
 use weaver_common::http_auth::HttpAuthResolver;
-use weaver_config::WeaverConfig;
+use weaver_config::{override_if_set, CliOverrides, McpConfig, WeaverConfig};
+use weaver_forge::v2::registry::ForgeResolvedRegistry;
+use weaver_mcp::{SnippetValidation, WeaverMcpService};
+
+/// File name given to the snippets validated by the `validate_snippet` tool.
+const SNIPPET_FILE_NAME: &str = "mcp-snippet.yaml";
 
 /// Parameters for the `registry mcp` subcommand.
+///
+/// Each setting may also be provided in `.weaver.toml`. CLI flags always take
+/// precedence over config values, which take precedence over hardcoded defaults.
 #[derive(Debug, Args)]
 pub struct RegistryMcpArgs {
     /// Registry arguments.
     #[command(flatten)]
     pub registry: RegistryArgs,
 
+    /// Policy arguments, used by the `validate_snippet` tool.
+    #[command(flatten)]
+    pub policy: PolicyArgs,
+
     /// Diagnostic arguments.
     #[command(flatten)]
     pub diagnostic: DiagnosticArgs,
@@ -43,55 +58,312 @@ pub struct RegistryMcpArgs {
 
     /// Namespace separator used in attribute keys. Defaults to ".".
     /// Used by namespace browsing and search token splitting.
-    #[arg(long, default_value = ".")]
-    pub namespace_separator: String,
+    #[arg(long)]
+    pub namespace_separator: Option<String>,
+
+    /// Address to serve MCP over streamable HTTP (with SSE), e.g. `127.0.0.1:8090`.
+    /// The endpoint is served on `/mcp`. If not specified, the server communicates over stdio.
+    #[arg(long)]
+    pub bind: Option<SocketAddr>,
+
+    /// Name of the environment variable holding the bearer token required by the
+    /// HTTP transport. If not specified, the HTTP endpoint is unauthenticated.
+    #[arg(long)]
+    pub auth_token_env: Option<String>,
+}
+
+impl CliOverrides for RegistryMcpArgs {
+    type Config = McpConfig;
+    const SUBCOMMAND: &'static str = "mcp";
+
+    fn extract_config(weaver_config: &WeaverConfig) -> McpConfig {
+        weaver_config.mcp.clone()
+    }
+
+    fn cli_only_args() -> &'static [&'static str] {
+        &[
+            "registry",                // RegistryArgs (invocation-specific)
+            "follow_symlinks",         // RegistryArgs
+            "include_unreferenced",    // RegistryArgs
+            "v2",                      // RegistryArgs
+            "policy",                  // PolicyArgs
+            "skip_policies",           // PolicyArgs
+            "display_policy_coverage", // PolicyArgs
+            "diagnostic_format",       // DiagnosticArgs
+            "diagnostic_template",     // DiagnosticArgs
+            "diagnostic_stdout",       // DiagnosticArgs
+        ]
+    }
+
+    fn apply_overrides(&self, config: &mut McpConfig) {
+        override_if_set!(config.advice_policies, self.advice_policies, optional);
+        override_if_set!(
+            config.advice_preprocessor,
+            self.advice_preprocessor,
+            optional
+        );
+        override_if_set!(config.namespace_separator, self.namespace_separator);
+        override_if_set!(config.bind, self.bind, optional);
+        override_if_set!(config.auth_token_env, self.auth_token_env, optional);
+    }
 }
 
 /// Run the MCP server for the semantic convention registry.
 pub(crate) fn command(
     args: &RegistryMcpArgs,
-    _cfg: Option<&WeaverConfig>,
+    cfg: Option<&WeaverConfig>,
     auth: &HttpAuthResolver,
 ) -> Result<ExitDirectives, DiagnosticMessages> {
+    let mcp_config = load_config(args, cfg);
+    let mut registry_args = args.registry.clone();
+    let mut policy_args = args.policy.clone();
+    if let Some(wc) = cfg {
+        super::apply_registry_config(&mut registry_args, &wc.registry);
+        apply_policy_config(&mut policy_args, &wc.policy);
+    }
+
+    let auth_token = match (&mcp_config.bind, &mcp_config.auth_token_env) {
+        // An empty token would let the requests without a token through
+        (Some(_), Some(var)) => Some(
+            std::env::var(var)
+                .ok()
+                .filter(|token| !token.is_empty())
+                .ok_or_else(|| Error::Config {
+                    error: format!(
+                        "The MCP auth token environment variable `{var}` is not set or is empty"
+                    ),
+                })?,
+        ),
+        (None, Some(_)) => {
+            return Err(Error::Config {
+                error: "An MCP auth token requires the HTTP transport (`--bind`)".to_owned(),
+            }
+            .into());
+        }
+        _ => None,
+    };
+
     info!("Loading semantic convention registry for MCP server");
 
-    let mut diag_msgs = DiagnosticMessages::empty();
+    let (forge_registry, snippet_checker) = load_registry(&registry_args, &policy_args, auth)?;
 
-    // Create empty policy args (MCP server doesn't need policy checks)
-    let policy_args = PolicyArgs {
-        policies: Vec::new(),
-        skip_policies: true,
-        display_policy_coverage: false,
+    // Build MCP config from the layered command line and `.weaver.toml` settings
+    let config = weaver_mcp::McpConfig {
+        advice_policies: mcp_config.advice_policies,
+        advice_preprocessor: mcp_config.advice_preprocessor,
+        namespace_separator: mcp_config.namespace_separator,
+    };
+    let service = WeaverMcpService::new(Arc::new(forge_registry), config)
+        .with_snippet_validator(Arc::new(move |yaml| snippet_checker.validate(yaml)));
+
+    // Run the MCP server
+    let result = if let Some(bind) = mcp_config.bind {
+        info!(
+            "Starting MCP server on http://{bind}{}",
+            weaver_mcp::MCP_HTTP_PATH
+        );
+        weaver_mcp::run_http(service, bind, auth_token)
+    } else {
+        info!("Starting MCP server (communicating over stdio)");
+        info!("The server will run until stdin is closed.");
+        weaver_mcp::run_service(service)
     };
+    result.map_err(DiagnosticMessages::from_error)?;
+
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+/// Loads and resolves the registry served by the MCP server, and prepares the
+/// validation of the snippets against it.
+fn load_registry(
+    registry_args: &RegistryArgs,
+    policy_args: &PolicyArgs,
+    auth: &HttpAuthResolver,
+) -> Result<(ForgeResolvedRegistry, SnippetChecker), DiagnosticMessages> {
+    let mut diag_msgs = DiagnosticMessages::empty();
 
-    // Use WeaverEngine to load and resolve the registry (always use v2)
-    let weaver = WeaverEngine::new(&args.registry, &policy_args, auth);
-    let resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
+    // Use WeaverEngine to load and resolve the registry. Policies are not
+    // checked here, only on the snippets validated by the MCP clients.
+    let weaver = WeaverEngine::new(registry_args, policy_args, auth);
+    let loaded = weaver.load_main_definitions(&mut diag_msgs)?;
+    let definitions = loaded.clone();
+    let resolved = weaver.resolve(loaded, &mut diag_msgs)?;
+    let snippet_checker = SnippetChecker {
+        registry_findings: resolved.after_resolution_findings()?,
+        definitions,
+        registry_args: registry_args.clone(),
+        policy_args: policy_args.clone(),
+        auth: auth.clone(),
+    };
 
     // Convert to V2 ForgeResolvedRegistry
     let resolved_v2 = match resolved {
         crate::weaver::Resolved::V1(v) => v.try_into().map_err(DiagnosticMessages::from_error)?,
         crate::weaver::Resolved::V2(v) => v,
     };
-    let forge_registry = resolved_v2.into_template_schema();
+    Ok((resolved_v2.into_template_schema(), snippet_checker))
+}
 
-    info!("Starting MCP server (communicating over stdio)");
-    info!("The server will run until stdin is closed.");
+/// Validates the YAML snippets drafted by the MCP clients against the
+/// registry definitions and policies.
+struct SnippetChecker {
+    /// The registry definitions, before resolution.
+    definitions: Loaded,
+    /// The after resolution findings of the registry itself, which are not
+    /// reported for the snippets.
+    registry_findings: Vec<PolicyFinding>,
+    registry_args: RegistryArgs,
+    policy_args: PolicyArgs,
+    auth: HttpAuthResolver,
+}
 
-    // Build MCP config from command line args
-    let config = weaver_mcp::McpConfig {
-        advice_policies: args.advice_policies.clone(),
-        advice_preprocessor: args.advice_preprocessor.clone(),
-        namespace_separator: args.namespace_separator.clone(),
-    };
+impl SnippetChecker {
+    /// Resolves the registry with the snippet and runs the policies.
+    fn validate(&self, yaml: &str) -> SnippetValidation {
+        let mut diag_msgs = DiagnosticMessages::empty();
+        match self.check(yaml, &mut diag_msgs) {
+            Ok((before_resolution, after_resolution)) => {
+                SnippetValidation::new(diag_msgs, before_resolution, after_resolution)
+            }
+            Err(error) => {
+                diag_msgs.extend(error.into());
+                SnippetValidation::new(diag_msgs, vec![], vec![])
+            }
+        }
+    }
 
-    // Run the MCP server
-    if let Err(e) = weaver_mcp::run_with_config(forge_registry, config) {
-        return Err(DiagnosticMessages::from_error(e));
+    /// Returns the before and after resolution findings of the snippet.
+    fn check(
+        &self,
+        yaml: &str,
+        diag_msgs: &mut DiagnosticMessages,
+    ) -> Result<(Vec<PolicyFinding>, Vec<PolicyFinding>), crate::weaver::Error> {
+        let loaded = self
+            .definitions
+            .with_snippet(SNIPPET_FILE_NAME, yaml, diag_msgs)?;
+        let before_resolution = loaded.before_resolution_findings_of(SNIPPET_FILE_NAME)?;
+        let weaver = WeaverEngine::new(&self.registry_args, &self.policy_args, &self.auth);
+        let after_resolution = weaver
+            .resolve(loaded, diag_msgs)?
+            .after_resolution_findings()?
+            .into_iter()
+            .filter(|finding| !self.registry_findings.contains(finding))
+            .collect();
+        Ok((before_resolution, after_resolution))
     }
+}
 
-    Ok(ExitDirectives {
-        exit_code: 0,
-        warnings: None,
-    })
+#[cfg(test)]
+mod tests {
+    use super::{load_registry, RegistryMcpArgs};
+    use crate::registry::tests::assert_config_cli_consistency;
+    use crate::registry::{PolicyArgs, RegistryArgs};
+    use weaver_common::http_auth::HttpAuthResolver;
+    use weaver_common::vdir::VirtualDirectoryPath;
+
+    #[test]
+    fn config_fields_match_cli_args() {
+        assert_config_cli_consistency::<RegistryMcpArgs>();
+    }
+
+    #[test]
+    fn test_validate_snippet() {
+        let registry_args = RegistryArgs {
+            registry: VirtualDirectoryPath::LocalFolder {
+                path: "tests/mcp_snippet/registry".to_owned(),
+            },
+            follow_symlinks: false,
+            include_unreferenced: false,
+            v2: false,
+        };
+        let policy_args = PolicyArgs {
+            policies: vec![VirtualDirectoryPath::LocalFolder {
+                path: "tests/mcp_snippet/policies".to_owned(),
+            }],
+            skip_policies: false,
+            display_policy_coverage: false,
+        };
+        let (registry, checker) =
+            load_registry(&registry_args, &policy_args, &HttpAuthResolver::default())
+                .expect("Failed to load the registry");
+        assert_eq!(registry.registry.metrics.len(), 1);
+
+        // A v2 snippet reusing an existing attribute. The metric of the registry
+        // without attributes is not reported.
+        let validation = checker.validate(
+            r#"
+file_format: definition/2
+metrics:
+  - name: shop.order.duration
+    stability: development
+    brief: "The duration of the orders."
+    instrument: histogram
+    unit: s
+    attributes:
+      - ref: shop.order.id
+"#,
+        );
+        assert!(validation.valid, "{validation:?}");
+        assert!(validation.after_resolution.is_empty());
+
+        // Policy violations before and after resolution.
+        let validation = checker.validate(
+            r#"
+groups:
+  - id: registry.shop.legacy
+    type: attribute_group
+    brief: "Legacy attributes."
+    attributes:
+      - id: shop.legacy.id
+        type: string
+        stability: development
+        brief: "A legacy identifier."
+        examples: ["id"]
+  - id: metric.shop.refund.count
+    type: metric
+    metric_name: shop.refund.count
+    stability: development
+    brief: "The number of refunds."
+    instrument: counter
+    unit: "{refund}"
+"#,
+        );
+        assert!(!validation.valid);
+        assert_eq!(validation.before_resolution.len(), 1);
+        assert_eq!(validation.before_resolution[0].id, "legacy_namespace");
+        assert_eq!(validation.after_resolution.len(), 1);
+        assert_eq!(
+            validation.after_resolution[0].id,
+            "metric_without_attributes"
+        );
+
+        // Unresolvable references are reported as diagnostics.
+        let validation = checker.validate(
+            r#"
+groups:
+  - id: metric.shop.refund.count
+    type: metric
+    metric_name: shop.refund.count
+    stability: development
+    brief: "The number of refunds."
+    instrument: counter
+    unit: "{refund}"
+    attributes:
+      - ref: shop.unknown
+"#,
+        );
+        assert!(!validation.valid);
+        assert!(validation
+            .diagnostics
+            .iter()
+            .any(|d| d.severity == "error" && d.message.contains("shop.unknown")));
+
+        // Invalid YAML.
+        let validation = checker.validate("groups: [");
+        assert!(!validation.valid);
+        assert_eq!(validation.diagnostics.len(), 1);
+    }
 }
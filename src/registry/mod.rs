@@ -20,6 +20,7 @@ use crate::registry::resolve::RegistryResolveArgs;
 use crate::registry::search::RegistrySearchArgs;
 use crate::registry::stats::RegistryStatsArgs;
 use crate::registry::update_markdown::RegistryUpdateMarkdownArgs;
+use crate::registry::update_schema_file::RegistryUpdateSchemaFileArgs;
 use crate::CmdResult;
 use check::RegistryCheckArgs;
 use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
@@ -34,14 +35,15 @@ mod emit;
 mod generate;
 mod infer;
 mod json_schema;
-mod live_check;
+pub(crate) mod live_check;
 mod mcp;
-mod otlp;
+pub(crate) mod otlp;
 mod package;
 mod resolve;
 mod search;
 mod stats;
 mod update_markdown;
+mod update_schema_file;
 
 /// Errors emitted by the `registry` sub-commands
 #[derive(thiserror::Error, Debug, Serialize, Diagnostic)]
@@ -55,6 +57,10 @@ pub enum Error {
     #[error("The params file `{params_file}` is invalid. {error}")]
     InvalidParams { params_file: PathBuf, error: String },
 
+    /// Invalid templates directory passed to the command line or configuration
+    #[error("The templates directory `{templates}` is invalid. {error}")]
+    InvalidTemplates { templates: String, error: String },
+
     #[error(transparent)]
     Schema(#[from] weaver_resolved_schema::error::Error),
 
@@ -139,6 +145,13 @@ pub enum RegistrySubCommand {
     /// - ...
     #[clap(verbatim_doc_comment)]
     Diff(RegistryDiffArgs),
+    /// Add a version section to an OpenTelemetry schema file.
+    ///
+    /// The `rename_attributes` and `rename_metrics` transformations of the new version
+    /// are computed from the attributes and metrics deprecated with `renamed_to` between
+    /// the baseline and the current registry.
+    #[clap(verbatim_doc_comment)]
+    UpdateSchemaFile(RegistryUpdateSchemaFileArgs),
 
     /// Emits a semantic convention registry as example signals to your OTLP receiver.
     ///
@@ -373,6 +386,10 @@ pub fn semconv_registry(
             diff::command(args, cfg, auth),
             Some(args.diagnostic.clone()),
         ),
+        RegistrySubCommand::UpdateSchemaFile(args) => CmdResult::new(
+            update_schema_file::command(args, cfg, auth),
+            Some(args.diagnostic.clone()),
+        ),
         RegistrySubCommand::LiveCheck(args) => CmdResult::new(
             live_check::command(args, cfg, auth),
             Some(args.diagnostic.clone()),
@@ -471,7 +488,8 @@ pub(crate) mod tests {
         let mut config_fields = BTreeSet::new();
         schema_field_names(root, root, "", &mut config_fields);
 
-        // Extract CLI arg names from clap introspection
+        // Extract CLI arg names from clap introspection. Registry subcommands
+        // are looked up first, then top-level commands (e.g. `serve`).
         let cmd = Cli::command();
         let registry_cmd = cmd
             .get_subcommands()
@@ -479,12 +497,19 @@ pub(crate) mod tests {
             .expect("registry subcommand");
         let sub_cmd = registry_cmd
             .get_subcommands()
+            .chain(cmd.get_subcommands())
             .find(|c| c.get_name() == A::SUBCOMMAND)
             .unwrap_or_else(|| panic!("subcommand '{}' not found", A::SUBCOMMAND));
         let cli_args: BTreeSet<String> = sub_cmd
             .get_arguments()
-            .filter_map(|arg| arg.get_long())
-            .map(|name| name.replace('-', "_"))
+            .filter_map(|arg| {
+                arg.get_long()
+                    .map(|name| name.replace('-', "_"))
+                    .or_else(|| {
+                        // Positional args have no long name; use their id.
+                        arg.is_positional().then(|| arg.get_id().to_string())
+                    })
+            })
             .collect();
 
         // Map config field names to their CLI equivalents
@@ -48,6 +48,35 @@ fn maybe_to_json(value: Option<AnyValue>) -> Option<Value> {
     }
 }
 
+/// Converts an OTLP log body to JSON, keeping the structure of maps and
+/// arrays. Bytes are converted to a hexadecimal string.
+fn body_to_json(value: AnyValue) -> Option<Value> {
+    use crate::registry::otlp::grpc_stubs::proto::common::v1::any_value::Value as GrpcValue;
+    match value.value? {
+        GrpcValue::StringValue(string) => Some(Value::String(string)),
+        GrpcValue::IntValue(int_value) => Some(Value::Number(int_value.into())),
+        GrpcValue::DoubleValue(double_value) => Some(json!(double_value)),
+        GrpcValue::BoolValue(bool_value) => Some(Value::Bool(bool_value)),
+        GrpcValue::ArrayValue(array_value) => Some(Value::Array(
+            array_value
+                .values
+                .into_iter()
+                .filter_map(body_to_json)
+                .collect(),
+        )),
+        GrpcValue::KvlistValue(kvlist_value) => Some(Value::Object(
+            kvlist_value
+                .values
+                .into_iter()
+                .filter_map(|kv| Some((kv.key, body_to_json(kv.value?)?)))
+                .collect(),
+        )),
+        GrpcValue::BytesValue(bytes) => Some(Value::String(
+            bytes.iter().map(|b| format!("{b:02x}")).collect(),
+        )),
+    }
+}
+
 /// Converts an OTLP KeyValue to a SampleAttribute
 pub fn sample_attribute_from_key_value(key_value: &KeyValue) -> SampleAttribute {
     let value = maybe_to_json(key_value.value.clone());
@@ -99,8 +128,10 @@ pub fn otlp_metric_to_sample(otlp_metric: Metric) -> SampleMetric {
         instrument: otlp_data_to_instrument(&otlp_metric.data),
         unit: otlp_metric.unit,
         data_points: otlp_data_to_data_points(&otlp_metric.data),
+        schema_url: None,
         live_check_result: None,
         resource: None,
+        scope: None,
     }
 }
 
@@ -184,7 +215,7 @@ fn unix_nanos_to_utc(time_unix_nano: u64) -> String {
 }
 
 /// Converts a span ID (8 bytes) to a hex string
-fn span_id_hex(span_id: &[u8]) -> String {
+pub(crate) fn span_id_hex(span_id: &[u8]) -> String {
     if span_id.len() == 8 {
         format!(
             "{:016x}",
@@ -196,7 +227,7 @@ fn span_id_hex(span_id: &[u8]) -> String {
 }
 
 /// Converts a trace ID (16 bytes) to a hex string
-fn trace_id_hex(trace_id: &[u8]) -> String {
+pub(crate) fn trace_id_hex(trace_id: &[u8]) -> String {
     if trace_id.len() == 16 {
         format!(
             "{:032x}",
@@ -331,10 +362,7 @@ pub fn otlp_log_record_to_sample_log(log_record: &LogRecord) -> SampleLog {
         event_name: log_record.event_name.clone(),
         severity_number: Some(log_record.severity_number),
         severity_text: Some(log_record.severity_text.clone()),
-        body: log_record
-            .body
-            .as_ref()
-            .and_then(|v| v.value.as_ref().map(|val| format!("{:?}", val))),
+        body: log_record.body.clone().and_then(body_to_json),
         attributes: log_record
             .attributes
             .iter()
@@ -356,7 +384,9 @@ pub fn otlp_log_record_to_sample_log(log_record: &LogRecord) -> SampleLog {
                 Some(span_id)
             }
         },
+        schema_url: None,
         live_check_result: None,
         resource: None,
+        scope: None,
     }
 }
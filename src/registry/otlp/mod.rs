@@ -4,6 +4,8 @@
 
 pub mod conversion;
 pub mod otlp_ingester;
+pub mod otlp_json;
+pub mod otlp_recording;
 
 use axum::extract::State;
 use axum::http::{header, StatusCode};
@@ -30,7 +32,7 @@ use std::fmt::{Display, Formatter};
 use std::net::{AddrParseError, SocketAddr};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::net::TcpListener;
 use tokio::sync::{mpsc, oneshot, watch};
 use tokio::task::JoinSet;
@@ -158,6 +160,29 @@ pub enum OtlpRequest {
 
     Error(Error),
     Stop(StopSignal),
+    /// Sent periodically when a tick interval is given to `listen_otlp_requests`,
+    /// so that pending work is processed while no request is received.
+    Tick,
+}
+
+/// An OTLP request and the time it was received.
+#[derive(Debug)]
+pub struct ReceivedRequest {
+    /// When the request was received
+    pub received_at: SystemTime,
+    /// The request
+    pub request: OtlpRequest,
+}
+
+impl ReceivedRequest {
+    /// Timestamps a request received now.
+    #[must_use]
+    pub fn now(request: OtlpRequest) -> Self {
+        Self {
+            received_at: SystemTime::now(),
+            request,
+        }
+    }
 }
 
 /// Enum to represent stop signals.
@@ -191,6 +216,9 @@ impl Display for StopSignal {
 /// through the `/stop` HTTP endpoint. When `/stop` is called, the HTTP handler
 /// stores a oneshot sender in the slot and waits for the report.
 ///
+/// When `tick_interval` is set, an `OtlpRequest::Tick` is also sent at this
+/// interval. Ticks are not counted as activity.
+///
 /// This function guarantees that the OTLP server is started and ready when the
 /// result is Ok(iterator).
 pub fn listen_otlp_requests(
@@ -198,6 +226,7 @@ pub fn listen_otlp_requests(
     grpc_port: u16,
     admin_port: u16,
     inactivity_timeout: Duration,
+    tick_interval: Option<Duration>,
 ) -> Result<(impl Iterator<Item = OtlpRequest>, AdminReportSender), Error> {
     let addr: SocketAddr =
         format!("{grpc_addr}:{grpc_port}")
@@ -269,6 +298,9 @@ pub fn listen_otlp_requests(
                         &mut tasks,
                     );
                 }
+                if let Some(tick_interval) = tick_interval {
+                    spawn_ticker(tx.clone(), tick_interval, &mut tasks);
+                }
 
                 let tokio_listener = TcpListener::from_std(listener)
                     .expect("Failed to convert std listener to tokio listener");
@@ -514,6 +546,21 @@ fn spawn_inactivity_monitor(
     });
 }
 
+/// Spawn a task sending an `OtlpRequest::Tick` at each interval.
+///
+/// A tick is skipped when the channel is full, as the consumer is busy with
+/// requests anyway.
+fn spawn_ticker(tx: mpsc::Sender<OtlpRequest>, interval: Duration, tasks: &mut JoinSet<()>) {
+    let _ = tasks.spawn(async move {
+        loop {
+            sleep(interval).await;
+            if let Err(mpsc::error::TrySendError::Closed(_)) = tx.try_send(OtlpRequest::Tick) {
+                break;
+            }
+        }
+    });
+}
+
 // Synchronous iterator wrapping a Tokio mpsc::Receiver.
 pub struct SyncReceiver<T> {
     receiver: mpsc::Receiver<T>,
@@ -620,7 +667,8 @@ mod tests {
         let inactivity_timeout = Duration::from_secs(1);
 
         let (mut receiver, _report_sender) =
-            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout).unwrap();
+            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout, None)
+                .unwrap();
         let grpc_endpoint = format!("http://127.0.0.1:{grpc_port}");
         let expected_metrics_count = 3;
         let expected_logs_count = 4;
@@ -713,7 +761,8 @@ mod tests {
         let inactivity_timeout = Duration::from_secs(5);
 
         let (mut receiver, report_sender) =
-            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout).unwrap();
+            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout, None)
+                .unwrap();
 
         // Enable report-via-HTTP mode (simulates --output http)
         report_sender.expect_report.store(true, Ordering::Relaxed);
@@ -760,7 +809,8 @@ mod tests {
         let inactivity_timeout = Duration::from_secs(5);
 
         let (mut receiver, _report_sender) =
-            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout).unwrap();
+            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout, None)
+                .unwrap();
 
         // expect_report defaults to false — /stop should return 200 immediately
 
@@ -795,7 +845,8 @@ mod tests {
         let inactivity_timeout = Duration::from_secs(5);
 
         let (_receiver, _report_sender) =
-            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout).unwrap();
+            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout, None)
+                .unwrap();
 
         // Give the server a little time to finish binding the port.
         thread::sleep(Duration::from_millis(200));
@@ -811,4 +862,24 @@ mod tests {
         let response2 = ureq::get(&url).call().expect("GET /health (2nd) failed");
         assert_eq!(response2.status(), 200);
     }
+
+    #[test]
+    fn test_ticks() {
+        let grpc_port = portpicker::pick_unused_port().expect("No free ports");
+        let admin_port = portpicker::pick_unused_port().expect("No free ports");
+
+        let (mut receiver, _report_sender) = listen_otlp_requests(
+            "127.0.0.1",
+            grpc_port,
+            admin_port,
+            Duration::from_secs(5),
+            Some(Duration::from_millis(50)),
+        )
+        .unwrap();
+
+        // Ticks are received while no request is sent
+        for _ in 0..2 {
+            assert!(matches!(receiver.next(), Some(OtlpRequest::Tick)));
+        }
+    }
 }
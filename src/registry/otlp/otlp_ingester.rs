@@ -1,25 +1,37 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! OTLP ingester
-use std::rc::Rc;
-use std::time::Duration;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 use log::info;
-use weaver_common::log_info;
+use weaver_common::{log_info, log_warn};
 use weaver_live_check::{
     sample_resource::SampleResource,
+    sample_scope::SampleScope,
     sample_span::{SampleSpan, SampleSpanEvent, SampleSpanLink},
+    sample_trace::SampleTrace,
     Error, Ingester, Sample,
 };
 
 use super::{
     conversion::{
         otlp_log_record_to_sample_log, otlp_metric_to_sample, sample_attribute_from_key_value,
-        span_kind_from_otlp_kind, status_from_otlp_status,
+        span_id_hex, span_kind_from_otlp_kind, status_from_otlp_status, trace_id_hex,
     },
-    listen_otlp_requests, AdminReportSender, OtlpRequest,
+    grpc_stubs::proto::common::v1::InstrumentationScope,
+    listen_otlp_requests,
+    otlp_recording::{OtlpRecorder, OtlpReplay, RecordingIterator},
+    AdminReportSender, OtlpRequest, ReceivedRequest,
 };
 
+/// Returns the given string (e.g. a schema URL), or None if it is empty (i.e. not set).
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
 /// An ingester for OTLP data
 pub struct OtlpIngester {
     /// The address of the OTLP gRPC server
@@ -30,22 +42,156 @@ pub struct OtlpIngester {
     pub admin_port: u16,
     /// The inactivity timeout
     pub inactivity_timeout: u64,
+    /// The time in seconds the spans of a trace are buffered before the trace is
+    /// emitted as a whole, 0 to emit the spans individually
+    pub trace_window: u64,
+    /// The file where the received requests are recorded, if any
+    pub record: Option<PathBuf>,
+}
+
+/// The maximum number of spans buffered in pending traces. When it is exceeded, the
+/// oldest traces are emitted before their window has elapsed.
+const MAX_PENDING_SPANS: usize = 100_000;
+
+/// The interval at which the listener wakes the ingester up to emit the traces whose
+/// window has elapsed, when no request is received.
+const TRACE_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A trace being assembled from the spans received so far
+struct PendingTrace {
+    /// When the first span of the trace was received
+    first_seen: SystemTime,
+    trace: SampleTrace,
 }
 
 /// Iterator for OTLP samples
 struct OtlpIterator {
-    otlp_requests: Box<dyn Iterator<Item = OtlpRequest>>,
+    otlp_requests: Box<dyn Iterator<Item = ReceivedRequest>>,
     buffer: Vec<Sample>,
+    /// When the last request was received. The trace windows are measured with the
+    /// receive times, so that a replay assembles the traces as they were received.
+    now: SystemTime,
+    /// Traces are assembled when set, and emitted once this duration has elapsed
+    /// since their first span was received
+    trace_window: Option<Duration>,
+    pending_traces: HashMap<String, PendingTrace>,
+    /// The IDs of the pending traces, in the order their first span was received
+    pending_order: VecDeque<String>,
+    /// The number of spans in the pending traces
+    pending_spans: usize,
+    /// The number of pending spans above which the oldest traces are emitted
+    max_pending_spans: usize,
+    /// Whether the pending spans have already exceeded `max_pending_spans`
+    overflowed: bool,
+    finished: bool,
 }
 
 impl OtlpIterator {
-    fn new(otlp_requests: Box<dyn Iterator<Item = OtlpRequest>>) -> Self {
+    fn new(otlp_requests: Box<dyn Iterator<Item = ReceivedRequest>>) -> Self {
         Self {
             otlp_requests,
             buffer: Vec::new(),
+            now: SystemTime::UNIX_EPOCH,
+            trace_window: None,
+            pending_traces: HashMap::new(),
+            pending_order: VecDeque::new(),
+            pending_spans: 0,
+            max_pending_spans: MAX_PENDING_SPANS,
+            overflowed: false,
+            finished: false,
+        }
+    }
+
+    /// Buffers a span in its trace, or emits it directly if traces are not assembled.
+    ///
+    /// The oldest traces are emitted as they are when more than `max_pending_spans`
+    /// spans are pending.
+    fn push_span(&mut self, span: SampleSpan) {
+        let (Some(_), Some(trace_id)) = (&self.trace_window, &span.trace_id) else {
+            self.buffer.push(Sample::Span(span));
+            return;
+        };
+        let trace_id = trace_id.clone();
+        self.pending_traces
+            .entry(trace_id.clone())
+            .or_insert_with(|| {
+                self.pending_order.push_back(trace_id.clone());
+                PendingTrace {
+                    first_seen: self.now,
+                    trace: SampleTrace::new(trace_id),
+                }
+            })
+            .trace
+            .spans
+            .push(span);
+        self.pending_spans += 1;
+
+        if self.pending_spans > self.max_pending_spans {
+            if !self.overflowed {
+                self.overflowed = true;
+                log_warn(format!(
+                    "More than {} spans are pending, the oldest traces are checked before their window has elapsed.",
+                    self.max_pending_spans
+                ));
+            }
+            while self.pending_spans > self.max_pending_spans {
+                self.emit_oldest_trace();
+            }
+        }
+    }
+
+    /// Emits the pending trace whose first span was received first.
+    fn emit_oldest_trace(&mut self) {
+        let Some(trace_id) = self.pending_order.pop_front() else {
+            return;
+        };
+        if let Some(pending) = self.pending_traces.remove(&trace_id) {
+            self.pending_spans -= pending.trace.spans.len();
+            self.buffer.push(Sample::Trace(pending.trace));
+        }
+    }
+
+    /// Emits the traces whose window has elapsed, or all of them when `all` is set,
+    /// in the order their first span was received.
+    fn flush_traces(&mut self, all: bool) {
+        let Some(window) = self.trace_window else {
+            return;
+        };
+        while let Some(trace_id) = self.pending_order.front() {
+            let elapsed = self.pending_traces.get(trace_id).map_or(true, |pending| {
+                self.now
+                    .duration_since(pending.first_seen)
+                    .is_ok_and(|elapsed| elapsed >= window)
+            });
+            if !all && !elapsed {
+                break;
+            }
+            self.emit_oldest_trace();
         }
     }
 
+    /// Converts an instrumentation scope. Its attributes are also checked as
+    /// standalone attribute samples.
+    fn sample_scope(
+        &mut self,
+        scope: Option<InstrumentationScope>,
+        schema_url: &Option<String>,
+    ) -> Option<Arc<SampleScope>> {
+        let scope = scope?;
+        let mut sample_scope = SampleScope {
+            name: scope.name,
+            version: non_empty(scope.version),
+            schema_url: schema_url.clone(),
+            attributes: Vec::new(),
+        };
+        for attribute in scope.attributes {
+            let attribute = sample_attribute_from_key_value(&attribute);
+            sample_scope.attributes.push(attribute.clone());
+            self.buffer.push(Sample::Attribute(attribute));
+        }
+        Some(Arc::new(sample_scope))
+    }
+
     fn fill_buffer_from_request(&mut self, request: OtlpRequest) -> Option<usize> {
         match request {
             OtlpRequest::Logs(logs) => {
@@ -53,6 +199,7 @@ impl OtlpIterator {
                     let rc_resource = if let Some(resource) = resource_log.resource {
                         let mut sample_resource = SampleResource {
                             attributes: Vec::new(),
+                            schema_url: non_empty(resource_log.schema_url),
                             live_check_result: None,
                         };
                         for attribute in resource.attributes {
@@ -60,7 +207,7 @@ impl OtlpIterator {
                                 .attributes
                                 .push(sample_attribute_from_key_value(&attribute));
                         }
-                        let rc = Rc::new(sample_resource);
+                        let rc = Arc::new(sample_resource);
                         self.buffer.push(Sample::Resource((*rc).clone()));
                         Some(rc)
                     } else {
@@ -68,17 +215,13 @@ impl OtlpIterator {
                     };
 
                     for scope_log in resource_log.scope_logs {
-                        if let Some(scope) = scope_log.scope {
-                            for attribute in scope.attributes {
-                                self.buffer.push(Sample::Attribute(
-                                    sample_attribute_from_key_value(&attribute),
-                                ));
-                            }
-                        }
-
+                        let schema_url = non_empty(scope_log.schema_url);
+                        let rc_scope = self.sample_scope(scope_log.scope, &schema_url);
                         for log_record in scope_log.log_records {
                             let mut sample_log = otlp_log_record_to_sample_log(&log_record);
+                            sample_log.schema_url.clone_from(&schema_url);
                             sample_log.resource = rc_resource.clone();
+                            sample_log.scope = rc_scope.clone();
                             self.buffer.push(Sample::Log(sample_log));
                         }
                     }
@@ -90,6 +233,7 @@ impl OtlpIterator {
                     let rc_resource = if let Some(resource) = resource_metric.resource {
                         let mut sample_resource = SampleResource {
                             attributes: Vec::new(),
+                            schema_url: non_empty(resource_metric.schema_url),
                             live_check_result: None,
                         };
                         for attribute in resource.attributes {
@@ -97,7 +241,7 @@ impl OtlpIterator {
                                 .attributes
                                 .push(sample_attribute_from_key_value(&attribute));
                         }
-                        let rc = Rc::new(sample_resource);
+                        let rc = Arc::new(sample_resource);
                         self.buffer.push(Sample::Resource((*rc).clone()));
                         Some(rc)
                     } else {
@@ -105,18 +249,13 @@ impl OtlpIterator {
                     };
 
                     for scope_metric in resource_metric.scope_metrics {
-                        if let Some(scope) = scope_metric.scope {
-                            // TODO SampleInstrumentationScope?
-                            for attribute in scope.attributes {
-                                self.buffer.push(Sample::Attribute(
-                                    sample_attribute_from_key_value(&attribute),
-                                ));
-                            }
-                        }
-
+                        let schema_url = non_empty(scope_metric.schema_url);
+                        let rc_scope = self.sample_scope(scope_metric.scope, &schema_url);
                         for metric in scope_metric.metrics {
                             let mut sample_metric = otlp_metric_to_sample(metric);
+                            sample_metric.schema_url.clone_from(&schema_url);
                             sample_metric.resource = rc_resource.clone();
+                            sample_metric.scope = rc_scope.clone();
                             self.buffer.push(Sample::Metric(sample_metric));
                         }
                     }
@@ -128,6 +267,7 @@ impl OtlpIterator {
                     let rc_resource = if let Some(resource) = resource_span.resource {
                         let mut sample_resource = SampleResource {
                             attributes: Vec::new(),
+                            schema_url: non_empty(resource_span.schema_url),
                             live_check_result: None,
                         };
                         for attribute in resource.attributes {
@@ -135,7 +275,7 @@ impl OtlpIterator {
                                 .attributes
                                 .push(sample_attribute_from_key_value(&attribute));
                         }
-                        let rc = Rc::new(sample_resource);
+                        let rc = Arc::new(sample_resource);
                         self.buffer.push(Sample::Resource((*rc).clone()));
                         Some(rc)
                     } else {
@@ -143,15 +283,8 @@ impl OtlpIterator {
                     };
 
                     for scope_span in resource_span.scope_spans {
-                        if let Some(scope) = scope_span.scope {
-                            // TODO SampleInstrumentationScope?
-                            for attribute in scope.attributes {
-                                self.buffer.push(Sample::Attribute(
-                                    sample_attribute_from_key_value(&attribute),
-                                ));
-                            }
-                        }
-
+                        let schema_url = non_empty(scope_span.schema_url);
+                        let rc_scope = self.sample_scope(scope_span.scope, &schema_url);
                         for span in scope_span.spans {
                             let span_kind = span.kind();
                             let mut sample_span = SampleSpan {
@@ -161,8 +294,13 @@ impl OtlpIterator {
                                 attributes: Vec::new(),
                                 span_events: Vec::new(),
                                 span_links: Vec::new(),
+                                schema_url: schema_url.clone(),
                                 live_check_result: None,
                                 resource: rc_resource.clone(),
+                                scope: rc_scope.clone(),
+                                trace_id: non_empty(trace_id_hex(&span.trace_id)),
+                                span_id: non_empty(span_id_hex(&span.span_id)),
+                                parent_span_id: non_empty(span_id_hex(&span.parent_span_id)),
                             };
                             for attribute in span.attributes {
                                 sample_span
@@ -194,29 +332,57 @@ impl OtlpIterator {
                                 }
                                 sample_span.span_links.push(sample_link);
                             }
-                            self.buffer.push(Sample::Span(sample_span));
+                            self.push_span(sample_span);
                         }
                     }
                 }
                 Some(self.buffer.len())
             }
+            OtlpRequest::Tick => Some(self.buffer.len()),
             OtlpRequest::Stop(_reason) => None,
             OtlpRequest::Error(_error) => None,
         }
     }
 }
 
+/// Converts a single OTLP request into live check samples.
+pub fn samples_from_request(request: OtlpRequest) -> Result<Vec<Sample>, Error> {
+    match request {
+        OtlpRequest::Error(error) => Err(Error::IngestError {
+            error: error.to_string(),
+        }),
+        OtlpRequest::Stop(_) => Err(Error::IngestError {
+            error: "Expected an OTLP export request, got a stop signal".to_owned(),
+        }),
+        request => {
+            let mut iterator = OtlpIterator::new(Box::new(std::iter::empty()));
+            let _ = iterator.fill_buffer_from_request(request);
+            Ok(iterator.buffer)
+        }
+    }
+}
+
 impl Iterator for OtlpIterator {
     type Item = Sample;
 
     fn next(&mut self) -> Option<Self::Item> {
         while self.buffer.is_empty() {
-            match self.otlp_requests.next() {
-                Some(request) => {
-                    let _bufsize = self.fill_buffer_from_request(request)?;
-                }
-                None => return None,
+            if self.finished {
+                return None;
             }
+            let bufsize = match self.otlp_requests.next() {
+                Some(received) => {
+                    // The traces whose window elapsed before the request was received
+                    // do not include its spans
+                    self.now = received.received_at;
+                    self.flush_traces(false);
+                    self.fill_buffer_from_request(received.request)
+                }
+                None => None,
+            };
+            // The pending traces are emitted as they are when the receiver stops.
+            self.finished = bufsize.is_none();
+            self.flush_traces(self.finished);
         }
 
         Some(self.buffer.remove(0))
@@ -236,6 +402,7 @@ impl OtlpIngester {
             self.otlp_grpc_port,
             self.admin_port,
             Duration::from_secs(self.inactivity_timeout),
+            (self.trace_window > 0).then_some(TRACE_FLUSH_INTERVAL),
         )
         .map_err(|e| Error::IngestError {
             error: format!("Failed to listen to OTLP requests: {e}"),
@@ -260,10 +427,43 @@ impl OtlpIngester {
             );
         };
 
-        Ok((
-            Box::new(OtlpIterator::new(Box::new(otlp_requests))),
-            report_sender,
-        ))
+        let otlp_requests = otlp_requests.map(ReceivedRequest::now);
+        let otlp_requests: Box<dyn Iterator<Item = ReceivedRequest>> = match &self.record {
+            Some(path) => {
+                let recorder = OtlpRecorder::create(path).map_err(|e| Error::IngestError {
+                    error: e.to_string(),
+                })?;
+                info!(
+                    "The received OTLP requests are recorded in {}.",
+                    path.display()
+                );
+                Box::new(RecordingIterator::new(otlp_requests, recorder))
+            }
+            None => Box::new(otlp_requests),
+        };
+        Ok((Box::new(self.iterator(otlp_requests)), report_sender))
+    }
+
+    /// Replay the OTLP requests of a recording made with `record`. The traces are
+    /// assembled with the times the requests were received.
+    pub fn replay(&self, path: &Path) -> Result<Box<dyn Iterator<Item = Sample>>, Error> {
+        let otlp_requests = OtlpReplay::open(path).map_err(|e| Error::IngestError {
+            error: e.to_string(),
+        })?;
+        Ok(Box::new(self.iterator(Box::new(otlp_requests))))
+    }
+
+    /// Converts the OTLP requests into samples.
+    fn iterator(&self, otlp_requests: Box<dyn Iterator<Item = ReceivedRequest>>) -> OtlpIterator {
+        let mut iterator = OtlpIterator::new(otlp_requests);
+        if self.trace_window > 0 {
+            info!(
+                "Spans are assembled into traces checked {} seconds after their first span is received.",
+                self.trace_window
+            );
+            iterator.trace_window = Some(Duration::from_secs(self.trace_window));
+        }
+        iterator
     }
 }
 
@@ -273,3 +473,160 @@ impl Ingester for OtlpIngester {
         Ok(iterator)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::registry::otlp::otlp_json::otlp_request_from_json;
+
+    fn traces_request(spans: serde_json::Value) -> OtlpRequest {
+        otlp_request_from_json(json!({
+            "resourceSpans": [{"scopeSpans": [{"spans": spans}]}]
+        }))
+        .expect("Should be a valid traces request")
+    }
+
+    fn requests() -> Vec<OtlpRequest> {
+        vec![
+            traces_request(json!([{
+                "traceId": "5b8efff798038103d269b633813fc60c",
+                "spanId": "eee19b7ec3c1b174",
+                "name": "GET /cart",
+                "kind": 3
+            }])),
+            traces_request(json!([
+                {
+                    "traceId": "5b8efff798038103d269b633813fc60c",
+                    "spanId": "eee19b7ec3c1b175",
+                    "parentSpanId": "eee19b7ec3c1b174",
+                    "name": "GET /cart",
+                    "kind": 2
+                },
+                {
+                    "traceId": "0af7651916cd43dd8448eb211c80319c",
+                    "spanId": "b7ad6b7169203331",
+                    "name": "process",
+                    "kind": 1
+                }
+            ])),
+        ]
+    }
+
+    /// The requests, received `interval` apart.
+    fn received(
+        requests: Vec<OtlpRequest>,
+        interval: Duration,
+    ) -> Box<dyn Iterator<Item = ReceivedRequest>> {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        Box::new(
+            requests
+                .into_iter()
+                .zip(0..)
+                .map(move |(request, i)| ReceivedRequest {
+                    received_at: start + interval * i,
+                    request,
+                }),
+        )
+    }
+
+    #[test]
+    fn test_samples_from_request() {
+        let samples = samples_from_request(requests().remove(1)).expect("Valid request");
+        assert_eq!(samples.len(), 2);
+
+        let error = OtlpRequest::Error(crate::registry::otlp::Error::OtlpError {
+            error: "invalid payload".to_owned(),
+        });
+        assert!(samples_from_request(error).is_err());
+    }
+
+    #[test]
+    fn test_trace_assembly() {
+        // Without a trace window, spans are emitted individually
+        let samples: Vec<_> = OtlpIterator::new(received(requests(), Duration::ZERO)).collect();
+        assert_eq!(samples.len(), 3);
+        let Sample::Span(span) = &samples[1] else {
+            panic!("Expected a span");
+        };
+        assert_eq!(
+            span.trace_id.as_deref(),
+            Some("5b8efff798038103d269b633813fc60c")
+        );
+        assert_eq!(span.span_id.as_deref(), Some("eee19b7ec3c1b175"));
+        assert_eq!(span.parent_span_id.as_deref(), Some("eee19b7ec3c1b174"));
+
+        // With a trace window, the pending traces are emitted when the requests end
+        let mut iterator = OtlpIterator::new(received(requests(), Duration::ZERO));
+        iterator.trace_window = Some(Duration::from_secs(3600));
+        let samples: Vec<_> = iterator.collect();
+        assert_eq!(samples.len(), 2);
+        let Sample::Trace(trace) = &samples[0] else {
+            panic!("Expected a trace");
+        };
+        assert_eq!(trace.trace_id, "5b8efff798038103d269b633813fc60c");
+        assert_eq!(trace.spans.len(), 2);
+        assert_eq!(trace.spans[1].parent_span_id, trace.spans[0].span_id);
+        let Sample::Trace(trace) = &samples[1] else {
+            panic!("Expected a trace");
+        };
+        assert_eq!(trace.spans.len(), 1);
+    }
+
+    #[test]
+    fn test_trace_flush() {
+        // The traces whose window has elapsed are emitted on a tick, without
+        // waiting for the next request
+        let mut ticked = requests();
+        ticked.truncate(1);
+        ticked.push(OtlpRequest::Tick);
+        let mut iterator = OtlpIterator::new(received(ticked, Duration::from_millis(100)));
+        iterator.trace_window = Some(Duration::from_millis(50));
+        let Some(Sample::Trace(trace)) = iterator.next() else {
+            panic!("Expected a trace");
+        };
+        assert_eq!(trace.spans.len(), 1);
+
+        // The windows are measured on the times the requests were received (e.g.
+        // recorded), not on the time they are processed
+        let mut iterator = OtlpIterator::new(received(requests(), Duration::from_secs(10)));
+        iterator.trace_window = Some(Duration::from_secs(5));
+        let samples: Vec<_> = iterator.collect();
+        let traces: Vec<_> = samples
+            .iter()
+            .map(|sample| match sample {
+                Sample::Trace(trace) => (trace.trace_id.as_str(), trace.spans.len()),
+                _ => panic!("Expected a trace"),
+            })
+            .collect();
+        assert_eq!(
+            traces,
+            [
+                ("5b8efff798038103d269b633813fc60c", 1),
+                ("5b8efff798038103d269b633813fc60c", 1),
+                ("0af7651916cd43dd8448eb211c80319c", 1),
+            ]
+        );
+
+        // The oldest traces are emitted when too many spans are pending
+        let mut iterator = OtlpIterator::new(received(requests(), Duration::ZERO));
+        iterator.trace_window = Some(Duration::from_secs(3600));
+        iterator.max_pending_spans = 1;
+        let Some(request) = iterator.otlp_requests.next() else {
+            panic!("Expected a request");
+        };
+        let _ = iterator.fill_buffer_from_request(request.request);
+        assert!(iterator.buffer.is_empty());
+        let Some(request) = iterator.otlp_requests.next() else {
+            panic!("Expected a request");
+        };
+        let _ = iterator.fill_buffer_from_request(request.request);
+        let [Sample::Trace(trace)] = iterator.buffer.as_slice() else {
+            panic!("Expected a trace");
+        };
+        assert_eq!(trace.trace_id, "5b8efff798038103d269b633813fc60c");
+        assert_eq!(iterator.pending_spans, 1);
+        assert_eq!(iterator.pending_order.len(), 1);
+    }
+}
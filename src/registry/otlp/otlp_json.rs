@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parsing of OTLP/JSON payloads, i.e. the JSON encoding of the OTLP export
+//! requests used by the OTLP/HTTP exporters and the collector file exporter.
+//!
+//! The payload is parsed with the serde support of `opentelemetry-proto` and
+//! transcoded through the protobuf encoding into the vendored gRPC types, so
+//! the same conversions as the gRPC receiver apply.
+
+use opentelemetry_proto::tonic::collector::{
+    logs::v1 as otlp_logs, metrics::v1 as otlp_metrics, trace::v1 as otlp_trace,
+};
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::{Error, OtlpRequest};
+
+/// Returns true if the JSON value looks like an OTLP/JSON export request.
+#[must_use]
+pub fn is_otlp_json(value: &Value) -> bool {
+    value.get("resourceSpans").is_some()
+        || value.get("resourceMetrics").is_some()
+        || value.get("resourceLogs").is_some()
+}
+
+/// Parses an OTLP/JSON traces, metrics or logs export request.
+pub fn otlp_request_from_json(value: Value) -> Result<OtlpRequest, Error> {
+    if value.get("resourceSpans").is_some() {
+        transcode::<otlp_trace::ExportTraceServiceRequest, _>(value).map(OtlpRequest::Traces)
+    } else if value.get("resourceMetrics").is_some() {
+        transcode::<otlp_metrics::ExportMetricsServiceRequest, _>(value).map(OtlpRequest::Metrics)
+    } else if value.get("resourceLogs").is_some() {
+        transcode::<otlp_logs::ExportLogsServiceRequest, _>(value).map(OtlpRequest::Logs)
+    } else {
+        Err(Error::OtlpError {
+            error: "Expected an OTLP/JSON export request with `resourceSpans`, `resourceMetrics` or `resourceLogs`".to_owned(),
+        })
+    }
+}
+
+/// Deserializes `value` as the `opentelemetry-proto` message `S` and re-encodes
+/// it as the vendored message `T`.
+fn transcode<S, T>(value: Value) -> Result<T, Error>
+where
+    S: Message + DeserializeOwned,
+    T: Message + Default,
+{
+    let message: S = serde_json::from_value(value).map_err(|e| Error::OtlpError {
+        error: format!("Invalid OTLP/JSON payload: {e}"),
+    })?;
+    T::decode(message.encode_to_vec().as_slice()).map_err(|e| Error::OtlpError {
+        error: format!("Invalid OTLP payload: {e}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{is_otlp_json, otlp_request_from_json};
+    use crate::registry::otlp::OtlpRequest;
+
+    #[test]
+    fn test_otlp_request_from_json() {
+        let payload = json!({
+            "resourceSpans": [{
+                "resource": {
+                    "attributes": [{"key": "service.name", "value": {"stringValue": "checkout"}}]
+                },
+                "scopeSpans": [{
+                    "spans": [{
+                        "traceId": "5b8efff798038103d269b633813fc60c",
+                        "spanId": "eee19b7ec3c1b174",
+                        "name": "GET /cart",
+                        "kind": 2,
+                        "startTimeUnixNano": "1544712660000000000",
+                        "endTimeUnixNano": "1544712661000000000",
+                        "attributes": [{"key": "http.request.method", "value": {"stringValue": "GET"}}]
+                    }]
+                }]
+            }]
+        });
+        assert!(is_otlp_json(&payload));
+        let Ok(OtlpRequest::Traces(request)) = otlp_request_from_json(payload) else {
+            panic!("Expected a traces request");
+        };
+        let span = &request.resource_spans[0].scope_spans[0].spans[0];
+        assert_eq!(span.name, "GET /cart");
+        assert_eq!(span.attributes[0].key, "http.request.method");
+        assert_eq!(span.trace_id.len(), 16);
+
+        assert!(!is_otlp_json(&json!([])));
+        assert!(otlp_request_from_json(json!({"spans": []})).is_err());
+    }
+}
@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Recording of the OTLP requests received by the listener, so that live-check can
+//! be re-run later on the same traffic (e.g. with new policies).
+//!
+//! A recording starts with a header (`WEAVER-OTLP/1` and a newline), followed by one
+//! record per request: a byte identifying the signal (`1` traces, `2` metrics, `3` logs),
+//! the time the request was received in milliseconds since the UNIX epoch (a protobuf
+//! varint), and the export request encoded as a length-delimited protobuf message.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+use prost::Message;
+use weaver_common::{log_error, log_warn};
+
+use super::{Error, OtlpRequest, ReceivedRequest};
+
+/// The header of a recording, identifying the format and its version.
+const HEADER: &[u8] = b"WEAVER-OTLP/1\n";
+
+const TRACES_RECORD: u8 = 1;
+const METRICS_RECORD: u8 = 2;
+const LOGS_RECORD: u8 = 3;
+
+/// Writes the OTLP requests to a recording file.
+pub struct OtlpRecorder {
+    writer: BufWriter<File>,
+}
+
+impl OtlpRecorder {
+    /// Creates (or truncates) the recording file and writes its header.
+    pub fn create(path: &Path) -> Result<Self, Error> {
+        let file = File::create(path).map_err(|e| Error::OtlpError {
+            error: format!(
+                "Failed to create the OTLP recording {}: {e}",
+                path.display()
+            ),
+        })?;
+        let mut recorder = Self {
+            writer: BufWriter::new(file),
+        };
+        recorder.write(HEADER)?;
+        Ok(recorder)
+    }
+
+    /// Records a traces, metrics or logs request, with the time it was received.
+    /// Other requests (errors, stops and ticks) are ignored.
+    ///
+    /// Every record is flushed, so the recording is usable even if the process is killed.
+    pub fn record(&mut self, received: &ReceivedRequest) -> Result<(), Error> {
+        let (record_type, message) = match &received.request {
+            OtlpRequest::Traces(request) => {
+                (TRACES_RECORD, request.encode_length_delimited_to_vec())
+            }
+            OtlpRequest::Metrics(request) => {
+                (METRICS_RECORD, request.encode_length_delimited_to_vec())
+            }
+            OtlpRequest::Logs(request) => (LOGS_RECORD, request.encode_length_delimited_to_vec()),
+            OtlpRequest::Error(_) | OtlpRequest::Stop(_) | OtlpRequest::Tick => return Ok(()),
+        };
+        let received_at = received
+            .received_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let mut header = vec![record_type];
+        prost::encoding::encode_varint(u64::try_from(received_at).unwrap_or(u64::MAX), &mut header);
+        self.write(&header)?;
+        self.write(&message)?;
+        self.writer.flush().map_err(|e| Error::OtlpError {
+            error: format!("Failed to write the OTLP recording: {e}"),
+        })
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.writer.write_all(bytes).map_err(|e| Error::OtlpError {
+            error: format!("Failed to write the OTLP recording: {e}"),
+        })
+    }
+}
+
+/// An iterator recording the OTLP requests passing through it.
+///
+/// Recording stops at the first write error, without interrupting the iteration.
+pub struct RecordingIterator<I> {
+    requests: I,
+    recorder: Option<OtlpRecorder>,
+}
+
+impl<I> RecordingIterator<I> {
+    /// Records the requests of `requests` with `recorder`.
+    pub fn new(requests: I, recorder: OtlpRecorder) -> Self {
+        Self {
+            requests,
+            recorder: Some(recorder),
+        }
+    }
+}
+
+impl<I: Iterator<Item = ReceivedRequest>> Iterator for RecordingIterator<I> {
+    type Item = ReceivedRequest;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let request = self.requests.next()?;
+        if let Some(recorder) = &mut self.recorder {
+            if let Err(error) = recorder.record(&request) {
+                log_error(format!("{error}. Recording stopped."));
+                self.recorder = None;
+            }
+        }
+        Some(request)
+    }
+}
+
+/// Reads the OTLP requests of a recording, in the order they were received, with
+/// the time they were received.
+///
+/// A truncated last record (e.g. the recording process was killed while writing it) is
+/// reported as a warning and ends the replay. A record that cannot be decoded is
+/// reported as an error, and also ends the replay.
+pub struct OtlpReplay<R> {
+    reader: R,
+    finished: bool,
+}
+
+impl OtlpReplay<BufReader<File>> {
+    /// Opens a recording file and checks its header.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let file = File::open(path).map_err(|e| Error::OtlpError {
+            error: format!("Failed to open the OTLP recording {}: {e}", path.display()),
+        })?;
+        Self::new(BufReader::new(file))
+    }
+}
+
+impl<R: Read> OtlpReplay<R> {
+    /// Reads a recording and checks its header.
+    pub fn new(mut reader: R) -> Result<Self, Error> {
+        let mut header = [0; HEADER.len()];
+        match reader.read_exact(&mut header) {
+            Ok(()) if header == HEADER => Ok(Self {
+                reader,
+                finished: false,
+            }),
+            _ => Err(Error::OtlpError {
+                error: "Not an OTLP recording (the header is missing or unsupported)".to_owned(),
+            }),
+        }
+    }
+
+    /// Reads the next record. Returns None at the end of the recording.
+    fn read_record(&mut self) -> Result<Option<ReceivedRequest>, std::io::Error> {
+        let mut record_type = [0; 1];
+        match self.reader.read_exact(&mut record_type) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        let received_at = UNIX_EPOCH + Duration::from_millis(self.read_varint()?);
+        let message = self.read_length_delimited()?;
+        let decode_error =
+            |e: prost::DecodeError| std::io::Error::new(ErrorKind::InvalidData, e.to_string());
+        let request = match record_type[0] {
+            TRACES_RECORD => {
+                OtlpRequest::Traces(Message::decode(message.as_slice()).map_err(decode_error)?)
+            }
+            METRICS_RECORD => {
+                OtlpRequest::Metrics(Message::decode(message.as_slice()).map_err(decode_error)?)
+            }
+            LOGS_RECORD => {
+                OtlpRequest::Logs(Message::decode(message.as_slice()).map_err(decode_error)?)
+            }
+            other => {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("unknown record type {other}"),
+                ))
+            }
+        };
+        Ok(Some(ReceivedRequest {
+            received_at,
+            request,
+        }))
+    }
+
+    /// Reads a protobuf varint.
+    fn read_varint(&mut self) -> Result<u64, std::io::Error> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let mut byte = [0; 1];
+            self.reader.read_exact(&mut byte)?;
+            value |= u64::from(byte[0] & 0x7f) << shift;
+            if byte[0] & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            "invalid varint",
+        ))
+    }
+
+    /// Reads a message prefixed by its length, encoded as a protobuf varint.
+    fn read_length_delimited(&mut self) -> Result<Vec<u8>, std::io::Error> {
+        let length = self.read_varint()?;
+        // Not preallocated, a corrupted length could be huge
+        let mut message = Vec::new();
+        let read = (&mut self.reader).take(length).read_to_end(&mut message)?;
+        if read as u64 != length {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+        Ok(message)
+    }
+}
+
+impl<R: Read> Iterator for OtlpReplay<R> {
+    type Item = ReceivedRequest;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.read_record() {
+            Ok(Some(request)) => Some(request),
+            Ok(None) => {
+                self.finished = true;
+                None
+            }
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => {
+                self.finished = true;
+                log_warn("The OTLP recording is truncated, its last request is skipped.");
+                None
+            }
+            Err(e) => {
+                self.finished = true;
+                let error = Error::OtlpError {
+                    error: format!("Invalid OTLP recording: {e}"),
+                };
+                log_error(&error);
+                Some(ReceivedRequest::now(OtlpRequest::Error(error)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::registry::otlp::otlp_json::otlp_request_from_json;
+
+    fn requests() -> Vec<ReceivedRequest> {
+        [
+            json!({"resourceSpans": [{"scopeSpans": [{"spans": [{"name": "GET /cart", "kind": 2}]}]}]}),
+            json!({"resourceMetrics": [{"scopeMetrics": [{"metrics": [{"name": "http.server.request.duration"}]}]}]}),
+            json!({"resourceLogs": [{"scopeLogs": [{"logRecords": [{"eventName": "session.start"}]}]}]}),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, request)| ReceivedRequest {
+            received_at: UNIX_EPOCH + Duration::from_millis(1_700_000_000_000 + 1500 * i as u64),
+            request: otlp_request_from_json(request).expect("Should be a valid request"),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let dir = tempfile::tempdir().expect("Failed to create a temporary directory");
+        let path = dir.path().join("traffic.otlp");
+
+        let recorder = OtlpRecorder::create(&path).expect("Failed to create the recording");
+        let recorded: Vec<_> = RecordingIterator::new(requests().into_iter(), recorder).collect();
+        assert_eq!(recorded.len(), 3);
+
+        let replayed: Vec<_> = OtlpReplay::open(&path)
+            .expect("Failed to open the recording")
+            .collect();
+        assert_eq!(replayed.len(), 3);
+        for (replayed, expected) in replayed.iter().zip(requests()) {
+            // The requests are replayed with the time they were received
+            assert_eq!(replayed.received_at, expected.received_at);
+            match (&replayed.request, expected.request) {
+                (OtlpRequest::Traces(replayed), OtlpRequest::Traces(expected)) => {
+                    assert_eq!(replayed, &expected);
+                }
+                (OtlpRequest::Metrics(replayed), OtlpRequest::Metrics(expected)) => {
+                    assert_eq!(replayed, &expected);
+                }
+                (OtlpRequest::Logs(replayed), OtlpRequest::Logs(expected)) => {
+                    assert_eq!(replayed, &expected);
+                }
+                (replayed, expected) => panic!("Expected {expected:?}, got {replayed:?}"),
+            }
+        }
+
+        // A truncated recording is replayed up to its last complete request
+        let bytes = std::fs::read(&path).expect("Failed to read the recording");
+        let truncated = OtlpReplay::new(&bytes[..bytes.len() - 3]).expect("Valid header");
+        assert_eq!(truncated.count(), 2);
+
+        assert!(OtlpReplay::new(&b"not a recording"[..]).is_err());
+    }
+}
@@ -2,7 +2,7 @@
 
 //! Compute stats on a semantic convention registry.
 
-use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::registry::{load_config, PolicyArgs, RegistryArgs};
 use crate::weaver::WeaverEngine;
 use crate::{DiagnosticArgs, ExitDirectives};
 use clap::Args;
@@ -12,13 +12,16 @@ use serde::Serialize;
 use std::path::PathBuf;
 use weaver_common::diagnostic::DiagnosticMessages;
 use weaver_common::http_auth::HttpAuthResolver;
-use weaver_config::WeaverConfig;
+use weaver_config::{override_if_set, CliOverrides, StatsConfig, WeaverConfig};
 use weaver_forge::{OutputProcessor, OutputTarget};
 
 /// Embedded default stats templates
 pub(crate) static DEFAULT_STATS_TEMPLATES: Dir<'_> = include_dir!("defaults/stats_templates");
 
 /// Parameters for the `registry stats` sub-command
+///
+/// Each setting may also be provided in `.weaver.toml`. CLI flags always take
+/// precedence over config values, which take precedence over hardcoded defaults.
 #[derive(Debug, Args)]
 pub struct RegistryStatsArgs {
     /// Parameters to specify the semantic convention registry
@@ -27,12 +30,14 @@ pub struct RegistryStatsArgs {
 
     /// Output format for the stats.
     /// Predefined formats are: text, json, yaml, jsonl, mute.
-    #[arg(long, default_value = "text")]
-    format: String,
+    /// (default: text)
+    #[arg(long)]
+    format: Option<String>,
 
     /// Path to the directory where the stats templates are located.
-    #[arg(long, default_value = "stats_templates")]
-    templates: PathBuf,
+    /// (default: stats_templates)
+    #[arg(long)]
+    templates: Option<PathBuf>,
 
     /// Path to the directory where the generated artifacts will be saved.
     /// If not specified, the stats are printed to stdout.
@@ -44,6 +49,33 @@ pub struct RegistryStatsArgs {
     pub diagnostic: DiagnosticArgs,
 }
 
+impl CliOverrides for RegistryStatsArgs {
+    type Config = StatsConfig;
+    const SUBCOMMAND: &'static str = "stats";
+
+    fn extract_config(weaver_config: &WeaverConfig) -> StatsConfig {
+        weaver_config.stats.clone()
+    }
+
+    fn cli_only_args() -> &'static [&'static str] {
+        &[
+            "registry",             // RegistryArgs (invocation-specific)
+            "follow_symlinks",      // RegistryArgs
+            "include_unreferenced", // RegistryArgs
+            "v2",                   // RegistryArgs
+            "diagnostic_format",    // DiagnosticArgs
+            "diagnostic_template",  // DiagnosticArgs
+            "diagnostic_stdout",    // DiagnosticArgs
+        ]
+    }
+
+    fn apply_overrides(&self, config: &mut StatsConfig) {
+        override_if_set!(config.format, self.format);
+        override_if_set!(config.templates, self.templates);
+        override_if_set!(config.output, self.output, optional);
+    }
+}
+
 /// Thin wrapper that adds a `version` field for the template to branch on.
 /// The raw stats are flattened into the same JSON level via `#[serde(flatten)]`.
 #[derive(Serialize)]
@@ -56,12 +88,18 @@ struct StatsContext<T> {
 /// Compute stats on a semantic convention registry.
 pub(crate) fn command(
     args: &RegistryStatsArgs,
-    _cfg: Option<&WeaverConfig>,
+    cfg: Option<&WeaverConfig>,
     auth: &HttpAuthResolver,
 ) -> Result<ExitDirectives, DiagnosticMessages> {
+    let config = load_config(args, cfg);
+    let mut registry_args = args.registry.clone();
+    if let Some(wc) = cfg {
+        super::apply_registry_config(&mut registry_args, &wc.registry);
+    }
+
     info!(
         "Compute statistics on the registry `{}`",
-        args.registry.registry
+        registry_args.registry
     );
 
     let mut diag_msgs = DiagnosticMessages::empty();
@@ -70,19 +108,19 @@ pub(crate) fn command(
         skip_policies: true,
         display_policy_coverage: false,
     };
-    let weaver = WeaverEngine::new(&args.registry, &policy_config, auth);
+    let weaver = WeaverEngine::new(&registry_args, &policy_config, auth);
     let resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
 
     if !diag_msgs.is_empty() {
         return Err(diag_msgs);
     }
 
-    let target = OutputTarget::from_optional_dir(args.output.as_ref());
+    let target = OutputTarget::from_optional_dir(config.output.as_ref());
     let mut output = OutputProcessor::new(
-        &args.format,
+        &config.format,
         "stats",
         Some(&DEFAULT_STATS_TEMPLATES),
-        Some(args.templates.clone()),
+        Some(config.templates.clone()),
         target,
     )
     .map_err(DiagnosticMessages::from)?;
@@ -113,3 +151,14 @@ pub(crate) fn command(
         warnings: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RegistryStatsArgs;
+    use crate::registry::tests::assert_config_cli_consistency;
+
+    #[test]
+    fn config_fields_match_cli_args() {
+        assert_config_cli_consistency::<RegistryStatsArgs>();
+    }
+}
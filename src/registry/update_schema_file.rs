@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Add a version section to an OpenTelemetry schema file from the changes between
+//! two versions of a semantic convention registry.
+
+use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::weaver::WeaverEngine;
+use crate::{DiagnosticArgs, ExitDirectives};
+use clap::Args;
+use log::info;
+use std::path::PathBuf;
+use weaver_common::diagnostic::DiagnosticMessages;
+use weaver_common::http_auth::HttpAuthResolver;
+use weaver_common::log_success;
+use weaver_common::vdir::VirtualDirectoryPath;
+use weaver_config::WeaverConfig;
+use weaver_otel_schema::TelemetrySchema;
+use weaver_semconv::registry_repo::RegistryRepo;
+use weaver_version::VersionSpec;
+
+/// Parameters for the `registry update-schema-file` sub-command
+#[derive(Debug, Args)]
+pub struct RegistryUpdateSchemaFileArgs {
+    /// Parameters to specify the semantic convention registry
+    #[command(flatten)]
+    registry: RegistryArgs,
+
+    /// Parameters to specify the baseline semantic convention registry, i.e. the
+    /// version described by the latest version section of the schema file.
+    #[arg(long)]
+    baseline_registry: VirtualDirectoryPath,
+
+    /// Path to the OpenTelemetry schema file to update.
+    #[arg(long)]
+    schema_file: PathBuf,
+
+    /// Version of the new section.
+    /// (default: the semconv version of the registry)
+    #[arg(long)]
+    schema_version: Option<String>,
+
+    /// Print the updated schema file to stdout instead of writing it.
+    #[arg(long, default_value = "false")]
+    dry_run: bool,
+
+    /// Parameters to specify the diagnostic format.
+    #[command(flatten)]
+    pub(crate) diagnostic: DiagnosticArgs,
+}
+
+/// Add a version section to an OpenTelemetry schema file.
+#[allow(clippy::print_stdout)] // The dry-run output is printed to stdout.
+pub(crate) fn command(
+    args: &RegistryUpdateSchemaFileArgs,
+    cfg: Option<&WeaverConfig>,
+    auth: &HttpAuthResolver,
+) -> Result<ExitDirectives, DiagnosticMessages> {
+    let mut registry_args = args.registry.clone();
+    if let Some(wc) = cfg {
+        super::apply_registry_config(&mut registry_args, &wc.registry);
+    }
+
+    let mut diag_msgs = DiagnosticMessages::empty();
+    let policy_config = PolicyArgs {
+        policies: vec![],
+        skip_policies: true,
+        display_policy_coverage: false,
+    };
+    let weaver = WeaverEngine::new(&registry_args, &policy_config, auth);
+
+    info!("Weaver Registry Update Schema File");
+    info!("Comparing registry `{}`", registry_args.registry);
+
+    let main_registry_repo =
+        RegistryRepo::try_new_with_auth(None, &registry_args.registry, &mut vec![], auth)?;
+    let baseline_registry_repo =
+        RegistryRepo::try_new_with_auth(None, &args.baseline_registry, &mut vec![], auth)?;
+
+    let main = weaver.load_definitions(main_registry_repo, &mut diag_msgs)?;
+    let baseline = weaver.load_definitions(baseline_registry_repo, &mut diag_msgs)?;
+    let main_resolved = weaver.resolve(main, &mut diag_msgs)?;
+    let baseline_resolved = weaver.resolve(baseline, &mut diag_msgs)?;
+    let diff = main_resolved
+        .diff(&baseline_resolved)
+        .map_err(DiagnosticMessages::from_error)?;
+
+    if diag_msgs.has_error() {
+        return Err(diag_msgs);
+    }
+
+    let version = args
+        .schema_version
+        .as_deref()
+        .unwrap_or_else(|| diff.head_semconv_version());
+    let version = semver::Version::parse(version).map_err(|e| {
+        weaver_otel_schema::Error::InvalidSchemaVersion {
+            path_or_url: args.schema_file.display().to_string(),
+            version: version.to_owned(),
+            error: format!("{e}. Use `--schema-version` to set a semantic version."),
+        }
+    })?;
+
+    let attributes = diff.renamed_attributes();
+    let metrics = diff.renamed_metrics();
+    let (renamed_attributes, renamed_metrics) = (attributes.len(), metrics.len());
+    let spec = VersionSpec::from_renames(attributes, metrics);
+    let updated = TelemetrySchema::append_version(&args.schema_file, &version, &spec)?;
+
+    if args.dry_run {
+        println!("{updated}");
+    } else {
+        std::fs::write(&args.schema_file, updated).map_err(|e| {
+            crate::registry::Error::OutputWrite {
+                path: args.schema_file.clone(),
+                error: e.to_string(),
+            }
+        })?;
+        log_success(format!(
+            "Added version {version} to `{}` ({renamed_attributes} renamed attributes, {renamed_metrics} renamed metrics)",
+            args.schema_file.display()
+        ));
+    }
+
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::update_schema_file::RegistryUpdateSchemaFileArgs;
+    use crate::registry::{
+        semconv_registry, RegistryArgs, RegistryCommand, RegistrySubCommand, VirtualDirectoryPath,
+    };
+    use std::path::PathBuf;
+    use weaver_common::http_auth::HttpAuthResolver;
+    use weaver_otel_schema::TelemetrySchema;
+
+    fn update_schema_file_cmd(
+        schema_file: PathBuf,
+        schema_version: Option<&str>,
+    ) -> RegistryCommand {
+        RegistryCommand {
+            command: RegistrySubCommand::UpdateSchemaFile(RegistryUpdateSchemaFileArgs {
+                registry: RegistryArgs {
+                    registry: VirtualDirectoryPath::LocalFolder {
+                        path: "tests/diff/registry_head/".to_owned(),
+                    },
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    v2: false,
+                },
+                baseline_registry: VirtualDirectoryPath::LocalFolder {
+                    path: "tests/diff/registry_baseline/".to_owned(),
+                },
+                schema_file,
+                schema_version: schema_version.map(ToOwned::to_owned),
+                dry_run: false,
+                diagnostic: Default::default(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_registry_update_schema_file() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let schema_file = temp_dir.path().join("schema.yaml");
+        _ = std::fs::copy("tests/diff/schema_file.yaml", &schema_file)
+            .expect("Failed to copy the schema file");
+
+        let cmd_result = semconv_registry(
+            &update_schema_file_cmd(schema_file.clone(), Some("1.1.0")),
+            None,
+            &HttpAuthResolver::empty(),
+        );
+        assert_eq!(
+            cmd_result
+                .command_result
+                .expect("Command should complete successfully")
+                .exit_code,
+            0
+        );
+
+        let schema = TelemetrySchema::try_from_file(&schema_file).expect("Invalid schema file");
+        assert_eq!(schema.schema_url, "https://opentelemetry.io/schemas/1.1.0");
+        let versions = schema.versions.expect("Versions should be defined");
+        let (version, spec) = versions.versions_desc().into_iter().next().unwrap();
+        assert_eq!(version.to_string(), "1.1.0");
+        let renamed_attributes = &spec.all.as_ref().unwrap().changes[0]
+            .rename_attributes
+            .attribute_map;
+        assert_eq!(renamed_attributes["attr1"], "attr1_bis");
+        let renamed_metrics = &spec.metrics.as_ref().unwrap().changes[0].rename_metrics;
+        assert_eq!(renamed_metrics["metric4"], "metric4_bis");
+    }
+
+    #[test]
+    fn test_registry_update_schema_file_invalid_version() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let schema_file = temp_dir.path().join("schema.yaml");
+        _ = std::fs::copy("tests/diff/schema_file.yaml", &schema_file)
+            .expect("Failed to copy the schema file");
+
+        // The registry version (`1.1`) is not a semantic version.
+        let cmd_result = semconv_registry(
+            &update_schema_file_cmd(schema_file.clone(), None),
+            None,
+            &HttpAuthResolver::empty(),
+        );
+        assert!(cmd_result.command_result.is_err());
+        assert_eq!(
+            std::fs::read_to_string(&schema_file).unwrap(),
+            std::fs::read_to_string("tests/diff/schema_file.yaml").unwrap()
+        );
+    }
+}
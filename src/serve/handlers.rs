@@ -5,19 +5,42 @@
 use std::sync::Arc;
 
 use axum::{
+    body::Bytes,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
 use schemars::schema_for;
+use serde::Deserialize;
 use serde_json::json;
+use weaver_common::log_success;
 use weaver_forge::run_filter_raw;
 
 use crate::serve::types::FilterParams;
 
-use super::server::AppState;
-use super::types::{RegistryCounts, RegistryStats, SearchParams, SearchResponse};
+use super::live_check::{parse_samples, run_live_check};
+use super::server::{SelectedRegistry, ServerState};
+use super::types::{DiffParams, RegistryStats, RegistrySummary, SearchParams, SearchResponse};
+
+/// Path parameters of the attribute endpoint.
+#[derive(Deserialize)]
+pub struct KeyPath {
+    key: String,
+}
+
+/// Path parameters of the metric and event endpoints.
+#[derive(Deserialize)]
+pub struct NamePath {
+    name: String,
+}
+
+/// Path parameters of the span and entity endpoints.
+#[derive(Deserialize)]
+pub struct TypePath {
+    #[serde(rename = "type")]
+    type_: String,
+}
 
 /// Health check.
 #[utoipa::path(
@@ -86,22 +109,8 @@ pub async fn get_schema(Path(name): Path<String>) -> impl IntoResponse {
     ),
     tag = "registry"
 )]
-pub async fn get_registry_stats(State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    let registry = &state.registry;
-
-    let stats = RegistryStats {
-        schema_url: registry.schema_url.to_string(),
-        counts: RegistryCounts {
-            attributes: registry.registry.attributes.len(),
-            metrics: registry.registry.metrics.len(),
-            spans: registry.registry.spans.len(),
-            events: registry.registry.events.len(),
-            entities: registry.registry.entities.len(),
-            attribute_groups: registry.registry.attribute_groups.len(),
-        },
-    };
-
-    Json(stats)
+pub async fn get_registry_stats(SelectedRegistry(slot): SelectedRegistry) -> impl IntoResponse {
+    Json(RegistryStats::from_registry(&slot.current().registry))
 }
 
 /// Get an attribute by key.
@@ -118,9 +127,10 @@ pub async fn get_registry_stats(State(state): State<Arc<AppState>>) -> impl Into
     tag = "registry"
 )]
 pub async fn get_registry_attribute(
-    State(state): State<Arc<AppState>>,
-    Path(key): Path<String>,
+    SelectedRegistry(slot): SelectedRegistry,
+    Path(KeyPath { key }): Path<KeyPath>,
 ) -> impl IntoResponse {
+    let state = slot.current();
     // Remove leading slash if present (from wildcard match)
     let key = key.trim_start_matches('/');
 
@@ -153,9 +163,10 @@ pub async fn get_registry_attribute(
     tag = "registry"
 )]
 pub async fn get_registry_metric(
-    State(state): State<Arc<AppState>>,
-    Path(name): Path<String>,
+    SelectedRegistry(slot): SelectedRegistry,
+    Path(NamePath { name }): Path<NamePath>,
 ) -> impl IntoResponse {
+    let state = slot.current();
     let name = name.trim_start_matches('/');
 
     // O(1) lookup via SearchContext
@@ -183,9 +194,10 @@ pub async fn get_registry_metric(
     tag = "registry"
 )]
 pub async fn get_registry_span(
-    State(state): State<Arc<AppState>>,
-    Path(span_type): Path<String>,
+    SelectedRegistry(slot): SelectedRegistry,
+    Path(TypePath { type_: span_type }): Path<TypePath>,
 ) -> impl IntoResponse {
+    let state = slot.current();
     let span_type = span_type.trim_start_matches('/');
 
     // O(1) lookup via SearchContext
@@ -213,9 +225,10 @@ pub async fn get_registry_span(
     tag = "registry"
 )]
 pub async fn get_registry_event(
-    State(state): State<Arc<AppState>>,
-    Path(name): Path<String>,
+    SelectedRegistry(slot): SelectedRegistry,
+    Path(NamePath { name }): Path<NamePath>,
 ) -> impl IntoResponse {
+    let state = slot.current();
     let name = name.trim_start_matches('/');
 
     // O(1) lookup via SearchContext
@@ -243,9 +256,10 @@ pub async fn get_registry_event(
     tag = "registry"
 )]
 pub async fn get_registry_entity(
-    State(state): State<Arc<AppState>>,
-    Path(entity_type): Path<String>,
+    SelectedRegistry(slot): SelectedRegistry,
+    Path(TypePath { type_: entity_type }): Path<TypePath>,
 ) -> impl IntoResponse {
+    let state = slot.current();
     let entity_type = entity_type.trim_start_matches('/');
 
     // O(1) lookup via SearchContext
@@ -272,9 +286,10 @@ pub async fn get_registry_entity(
     tag = "registry"
 )]
 pub async fn search_registry(
-    State(state): State<Arc<AppState>>,
+    SelectedRegistry(slot): SelectedRegistry,
     Query(params): Query<SearchParams>,
 ) -> impl IntoResponse {
+    let state = slot.current();
     // Convert Option<String> to Option<&str> for search
     let query = params.q.as_deref();
 
@@ -310,9 +325,10 @@ pub async fn search_registry(
     tag = "registry"
 )]
 pub async fn filter_registry(
-    State(state): State<Arc<AppState>>,
+    SelectedRegistry(slot): SelectedRegistry,
     Query(params): Query<FilterParams>,
 ) -> impl IntoResponse {
+    let state = slot.current();
     // TODO - Should filter be required?
     let filter = params.filter.as_deref().unwrap_or(".");
     match run_filter_raw(&state.registry, filter) {
@@ -347,3 +363,179 @@ pub async fn filter_registry(
         }
     }
 }
+
+/// Reload the registry from its source and atomically replace the served version.
+///
+/// Requires the bearer token configured with `--reload-token-env`. The previous
+/// version keeps being served if the registry can't be loaded.
+#[utoipa::path(
+    post,
+    path = "/api/v1/registry/reload",
+    responses(
+        (status = 200, description = "Stats of the reloaded registry", body = RegistryStats),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 403, description = "Registry reload is disabled"),
+        (status = 500, description = "The registry could not be loaded")
+    ),
+    tag = "registry"
+)]
+pub async fn reload_registry(
+    State(server): State<Arc<ServerState>>,
+    SelectedRegistry(slot): SelectedRegistry,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    match server.is_reload_authorized(&headers) {
+        None => {
+            return (
+                StatusCode::FORBIDDEN,
+                Json(json!({"error": "Registry reload is disabled. Set `--reload-token-env` to enable it."})),
+            )
+                .into_response();
+        }
+        Some(false) => {
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(json!({"error": "Missing or invalid bearer token"})),
+            )
+                .into_response();
+        }
+        Some(true) => {}
+    }
+
+    match slot.reload().await {
+        Ok(state) => {
+            log_success(format!("Registry `{}` reloaded", slot.name));
+            Json(RegistryStats::from_registry(&state.registry)).into_response()
+        }
+        Err(diag_msgs) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "error": format!("Failed to reload the registry `{}`", slot.name),
+                "diagnostics": diag_msgs,
+            })),
+        )
+            .into_response(),
+    }
+}
+
+/// Get the changes of the registry since a baseline registry.
+///
+/// The baseline is either the name of a served registry (see `/api/v1/registries`)
+/// or a registry path allowed with `--diff-baseline`, loaded on demand. The response has the format of
+/// `weaver registry diff --v2 --format json`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/registry/diff",
+    params(DiffParams),
+    responses(
+        (status = 200, description = "Added, renamed, obsoleted, removed and updated items by type", content_type = "application/json"),
+        (status = 400, description = "Invalid baseline registry")
+    ),
+    tag = "registry"
+)]
+pub async fn diff_registry(
+    State(server): State<Arc<ServerState>>,
+    SelectedRegistry(slot): SelectedRegistry,
+    Query(params): Query<DiffParams>,
+) -> impl IntoResponse {
+    let baseline = match server.baseline(&params.baseline).await {
+        Ok(baseline) => baseline,
+        Err(diag_msgs) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({
+                    "error": format!("Invalid baseline registry `{}`", params.baseline),
+                    "diagnostics": diag_msgs,
+                })),
+            )
+                .into_response();
+        }
+    };
+    let head = slot.current().resolved.clone();
+    match tokio::task::spawn_blocking(move || head.diff(&baseline)).await {
+        Ok(changes) => Json(changes).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({"error": format!("Failed to compute the diff: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
+/// List the served registries.
+#[utoipa::path(
+    get,
+    path = "/api/v1/registries",
+    responses(
+        (status = 200, description = "Served registries", body = Vec<RegistrySummary>)
+    ),
+    tag = "registry"
+)]
+pub async fn list_registries(State(server): State<Arc<ServerState>>) -> impl IntoResponse {
+    let registries: Vec<RegistrySummary> = server
+        .registries()
+        .map(|slot| RegistrySummary {
+            name: slot.name.clone(),
+            stats: RegistryStats::from_registry(&slot.current().registry),
+        })
+        .collect();
+    Json(registries)
+}
+
+/// Live check telemetry samples against the registry.
+///
+/// The body is either a JSON array of live-check samples (see the
+/// `LiveCheckSample` schema) or an OTLP/JSON traces, metrics or logs export
+/// request. The samples are checked by the default advisors and the Rego advice
+/// policies, and returned with their findings and the report statistics. Use
+/// `/api/v1/registries/{registry}/live-check` to check against a named registry.
+#[utoipa::path(
+    post,
+    path = "/api/v1/live-check",
+    request_body(
+        content = Object,
+        content_type = "application/json",
+        description = "Array of live-check samples, or OTLP/JSON export request"
+    ),
+    responses(
+        (status = 200, description = "Live check report with the checked samples and the statistics", content_type = "application/json"),
+        (status = 400, description = "Invalid samples"),
+        (status = 500, description = "The live check failed")
+    ),
+    tag = "live-check"
+)]
+pub async fn live_check(
+    State(server): State<Arc<ServerState>>,
+    SelectedRegistry(slot): SelectedRegistry,
+    body: Bytes,
+) -> impl IntoResponse {
+    let state = slot.current();
+    // Samples are not `Send`: they are parsed, checked and serialized on the
+    // same blocking thread.
+    let report = tokio::task::spawn_blocking(move || {
+        let samples = parse_samples(&body).map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+        let rego_advisor = state
+            .rego_advisor(&server.live_check)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        let report = run_live_check(state.live_check_registry(), rego_advisor, samples)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        serde_json::to_string(&report)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    })
+    .await
+    .unwrap_or_else(|e| {
+        Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("The live check failed: {e}"),
+        ))
+    });
+    match report {
+        Ok(report) => (
+            StatusCode::OK,
+            [(axum::http::header::CONTENT_TYPE, "application/json")],
+            report,
+        )
+            .into_response(),
+        Err((status, error)) => (status, Json(json!({"error": error}))).into_response(),
+    }
+}
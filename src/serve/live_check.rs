@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Live check of the telemetry samples posted to the server.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde_json::Value;
+use weaver_live_check::advice::RegoAdvisor;
+use weaver_live_check::live_checker::LiveChecker;
+use weaver_live_check::{
+    CumulativeStatistics, Error, LiveCheckReport, LiveCheckRunner, LiveCheckStatistics, Sample,
+    VersionedRegistry,
+};
+
+use crate::registry::live_check::default_advisors;
+use crate::registry::otlp::otlp_ingester::samples_from_request;
+use crate::registry::otlp::otlp_json::{is_otlp_json, otlp_request_from_json};
+
+/// Settings of the Rego advisor run by the live-check endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct LiveCheckSettings {
+    /// Advice policies directory, the default policies are used if `None`.
+    pub advice_policies: Option<PathBuf>,
+    /// jq script preprocessing the registry data before passing it to Rego.
+    pub advice_preprocessor: Option<PathBuf>,
+}
+
+/// Parses the body of a live-check request: either a JSON array of samples
+/// (the format read by `--input-format json`) or an OTLP/JSON export request.
+pub fn parse_samples(body: &[u8]) -> Result<Vec<Sample>, Error> {
+    let value: Value = serde_json::from_slice(body).map_err(|e| Error::IngestError {
+        error: format!("Failed to parse the JSON body: {e}"),
+    })?;
+    if is_otlp_json(&value) {
+        let request = otlp_request_from_json(value).map_err(|e| Error::IngestError {
+            error: e.to_string(),
+        })?;
+        samples_from_request(request)
+    } else {
+        serde_json::from_value(value).map_err(|e| Error::IngestError {
+            error: format!("Failed to parse the samples: {e}"),
+        })
+    }
+}
+
+/// Builds the Rego advisor of the live-check endpoints for a registry: the advice
+/// policies are compiled and the registry data is passed through the jq preprocessor.
+pub fn rego_advisor(
+    registry: Arc<VersionedRegistry>,
+    settings: &LiveCheckSettings,
+) -> Result<RegoAdvisor, Error> {
+    let live_checker = LiveChecker::new(registry, Vec::new());
+    RegoAdvisor::new(
+        &live_checker,
+        &settings.advice_policies,
+        &settings.advice_preprocessor,
+    )
+}
+
+/// Runs the default advisors and the Rego advisor on the samples and returns
+/// the report.
+///
+/// The live checker is not `Send`, so a new one is built for every request.
+pub fn run_live_check(
+    registry: Arc<VersionedRegistry>,
+    rego_advisor: RegoAdvisor,
+    samples: Vec<Sample>,
+) -> Result<LiveCheckReport, Error> {
+    let mut live_checker = LiveChecker::new(Arc::clone(&registry), default_advisors(&registry));
+    live_checker.add_advisor(Box::new(rego_advisor));
+
+    let mut stats =
+        LiveCheckStatistics::Cumulative(CumulativeStatistics::new(&live_checker.registry));
+    let mut checked = Vec::with_capacity(samples.len());
+    for mut sample in samples {
+        let parent_signal = sample.parent_signal();
+        sample.run_live_check(&mut live_checker, &mut stats, None, &parent_signal)?;
+        checked.push(sample);
+    }
+    stats.finalize();
+
+    Ok(LiveCheckReport {
+        samples: checked,
+        statistics: stats,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_samples;
+    use weaver_live_check::Sample;
+
+    #[test]
+    fn test_parse_samples() {
+        let samples =
+            parse_samples(br#"[{"attribute": {"name": "http.request.method", "value": "GET"}}]"#)
+                .expect("Samples should parse");
+        assert!(matches!(samples.as_slice(), [Sample::Attribute(_)]));
+
+        let samples = parse_samples(
+            br#"{"resourceMetrics": [{"scopeMetrics": [{"metrics": [{"name": "http.server.request.duration", "unit": "s", "histogram": {"dataPoints": [], "aggregationTemporality": 2}}]}]}]}"#,
+        )
+        .expect("OTLP/JSON should parse");
+        assert!(
+            matches!(samples.as_slice(), [Sample::Metric(m)] if m.name == "http.server.request.duration")
+        );
+
+        assert!(parse_samples(b"not json").is_err());
+        assert!(parse_samples(br#"{"foo": 1}"#).is_err());
+    }
+}
@@ -3,16 +3,30 @@
 //! Web API server for registry search, schema browsing, and more.
 
 use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use clap::Args;
 use log::info;
 use weaver_common::diagnostic::DiagnosticMessages;
+use weaver_common::vdir::VirtualDirectoryPath;
 
-use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::registry::{
+    apply_policy_config, apply_registry_config, load_config, Error, PolicyArgs, RegistryArgs,
+};
 use crate::{CmdResult, DiagnosticArgs, ExitDirectives};
+use live_check::LiveCheckSettings;
+use server::{
+    BaselineLoader, LoadedRegistry, McpSettings, RegistryLoader, RegistrySlot, ServerState,
+    DEFAULT_REGISTRY_NAME,
+};
 use weaver_common::http_auth::HttpAuthResolver;
+use weaver_config::{
+    override_if_set, CliOverrides, FieldMapping, NamedRegistryConfig, ServeConfig, WeaverConfig,
+};
 
 mod handlers;
+mod live_check;
 mod server;
 mod types;
 mod ui;
@@ -20,6 +34,9 @@ mod ui;
 pub use server::run_server;
 
 /// Parameters for the `weaver serve` command.
+///
+/// Each setting may also be provided in `.weaver.toml`. CLI flags always take
+/// precedence over config values, which take precedence over hardcoded defaults.
 #[derive(Debug, Args)]
 pub struct ServeCommand {
     /// Parameters to specify the semantic convention registry.
@@ -30,40 +47,298 @@ pub struct ServeCommand {
     #[command(flatten)]
     pub policy: PolicyArgs,
 
-    /// Address to bind the server to.
-    #[arg(long, default_value = "127.0.0.1:8080")]
-    pub bind: SocketAddr,
+    /// Address to bind the server to. (default: 127.0.0.1:8080)
+    #[arg(long)]
+    pub bind: Option<SocketAddr>,
 
     /// Allowed CORS origins (comma-separated). Use '*' for any origin.
     /// If not specified, CORS is disabled (same-origin only).
     #[arg(long)]
     pub cors_origins: Option<String>,
 
+    /// Watch the local registries and reload them when their files change.
+    /// (default: false)
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub watch: Option<bool>,
+
+    /// Name of the environment variable holding the bearer token required by
+    /// `POST /api/v1/registry/reload`. If not specified, the reload endpoints are disabled.
+    #[arg(long)]
+    pub reload_token_env: Option<String>,
+
+    /// Additional registry to serve under `/api/v1/registries/{name}/...`, in the
+    /// form `<NAME>=<REGISTRY>`. Can be repeated.
+    #[arg(long, value_parser = parse_named_registry)]
+    pub named_registry: Vec<NamedRegistryConfig>,
+
+    /// Advice policies directory used by `POST /api/v1/live-check`. Set this to
+    /// override the default live-check policies.
+    #[arg(long)]
+    pub advice_policies: Option<PathBuf>,
+
+    /// Advice preprocessor. A jq script to preprocess the registry data before
+    /// passing it to the live-check Rego policies.
+    #[arg(long)]
+    pub advice_preprocessor: Option<PathBuf>,
+
+    /// Registry path (local folder, Git repo URL or Git archive URL) allowed as
+    /// the baseline of `GET /api/v1/registry/diff`, in addition to the served
+    /// registries. Can be repeated.
+    #[arg(long)]
+    pub diff_baseline: Vec<String>,
+
+    /// Mount the MCP server, serving the main registry over streamable HTTP,
+    /// on `/mcp`. (default: false)
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub mcp: Option<bool>,
+
+    /// Name of the environment variable holding the bearer token required by
+    /// the MCP endpoint. If not specified, the MCP endpoint is unauthenticated.
+    #[arg(long)]
+    pub mcp_token_env: Option<String>,
+
     /// Parameters to specify the diagnostic format.
     #[command(flatten)]
     pub diagnostic: DiagnosticArgs,
 }
 
+impl CliOverrides for ServeCommand {
+    type Config = ServeConfig;
+    const SUBCOMMAND: &'static str = "serve";
+
+    fn extract_config(weaver_config: &WeaverConfig) -> ServeConfig {
+        weaver_config.serve.clone()
+    }
+
+    fn cli_only_args() -> &'static [&'static str] {
+        &[
+            "registry",                // RegistryArgs (invocation-specific)
+            "follow_symlinks",         // RegistryArgs
+            "include_unreferenced",    // RegistryArgs
+            "v2",                      // RegistryArgs
+            "policy",                  // PolicyArgs
+            "skip_policies",           // PolicyArgs
+            "display_policy_coverage", // PolicyArgs
+            "diagnostic_format",       // DiagnosticArgs
+            "diagnostic_template",     // DiagnosticArgs
+            "diagnostic_stdout",       // DiagnosticArgs
+        ]
+    }
+
+    fn field_mappings() -> &'static [FieldMapping] {
+        &[
+            FieldMapping {
+                config_name: "registries",
+                cli_name: "named_registry",
+            },
+            FieldMapping {
+                config_name: "diff_baselines",
+                cli_name: "diff_baseline",
+            },
+        ]
+    }
+
+    fn apply_overrides(&self, config: &mut ServeConfig) {
+        override_if_set!(config.bind, self.bind);
+        override_if_set!(config.cors_origins, self.cors_origins, optional);
+        override_if_set!(config.watch, self.watch);
+        override_if_set!(config.reload_token_env, self.reload_token_env, optional);
+        override_if_set!(config.advice_policies, self.advice_policies, optional);
+        override_if_set!(
+            config.advice_preprocessor,
+            self.advice_preprocessor,
+            optional
+        );
+        override_if_set!(config.mcp, self.mcp);
+        override_if_set!(config.mcp_token_env, self.mcp_token_env, optional);
+        if !self.named_registry.is_empty() {
+            config.registries.clone_from(&self.named_registry);
+        }
+        if !self.diff_baseline.is_empty() {
+            config.diff_baselines.clone_from(&self.diff_baseline);
+        }
+    }
+}
+
+/// Parses a `<NAME>=<REGISTRY>` named registry.
+fn parse_named_registry(value: &str) -> Result<NamedRegistryConfig, String> {
+    let (name, registry) = value
+        .split_once('=')
+        .ok_or_else(|| format!("invalid named registry `{value}`, expected `<NAME>=<REGISTRY>`"))?;
+    Ok(NamedRegistryConfig {
+        name: name.trim().to_owned(),
+        registry: registry.trim().to_owned(),
+    })
+}
+
 /// Execute the `weaver serve` command.
-pub fn command(args: &ServeCommand, auth: &HttpAuthResolver) -> CmdResult {
-    CmdResult::new(run_serve(args, auth), Some(args.diagnostic.clone()))
+pub fn command(
+    args: &ServeCommand,
+    cfg: Option<&WeaverConfig>,
+    auth: &HttpAuthResolver,
+) -> CmdResult {
+    CmdResult::new(run_serve(args, cfg, auth), Some(args.diagnostic.clone()))
 }
 
 fn run_serve(
     args: &ServeCommand,
+    cfg: Option<&WeaverConfig>,
     auth: &HttpAuthResolver,
 ) -> Result<ExitDirectives, DiagnosticMessages> {
-    // TODO: Currently the serve command takes a registry on the command line. Really we want to be
-    // able to hot load a registry from within the server. This would mean calling an API to load
-    // a new registry, and then the server would update its internal state to use the new registry.
-    // A UI could be built to allow selecting a registry file, or specifying a git repo/branch.
+    let config = load_config(args, cfg);
+    let mut registry_args = args.registry.clone();
+    let mut policy_args = args.policy.clone();
+    if let Some(wc) = cfg {
+        apply_registry_config(&mut registry_args, &wc.registry);
+        apply_policy_config(&mut policy_args, &wc.policy);
+    }
+
+    // An empty token would let the requests without a token through
+    let reload_token = match &config.reload_token_env {
+        Some(var) => Some(
+            std::env::var(var)
+                .ok()
+                .filter(|token| !token.is_empty())
+                .ok_or_else(|| Error::Config {
+                    error: format!(
+                        "The reload token environment variable `{var}` is not set or is empty"
+                    ),
+                })?,
+        ),
+        None => None,
+    };
+    let mcp_token = match &config.mcp_token_env {
+        Some(var) if config.mcp => Some(
+            std::env::var(var)
+                .ok()
+                .filter(|token| !token.is_empty())
+                .ok_or_else(|| Error::Config {
+                    error: format!(
+                        "The MCP token environment variable `{var}` is not set or is empty"
+                    ),
+                })?,
+        ),
+        _ => None,
+    };
 
-    info!("Loading registry from `{}`", args.registry.registry);
+    let mut registries = vec![(
+        DEFAULT_REGISTRY_NAME.to_owned(),
+        registry_args.registry.clone(),
+    )];
+    for named in &config.registries {
+        if registries.iter().any(|(name, _)| *name == named.name) {
+            return Err(Error::Config {
+                error: format!("The registry name `{}` is used more than once", named.name),
+            }
+            .into());
+        }
+        let registry = named
+            .registry
+            .parse()
+            .map_err(DiagnosticMessages::from_error)?;
+        registries.push((named.name.clone(), registry));
+    }
 
+    let mut slots = Vec::with_capacity(registries.len());
+    for (name, registry) in registries {
+        info!("Loading registry `{name}` from `{registry}`");
+        let watch_dir = match &registry {
+            VirtualDirectoryPath::LocalFolder { path } => Some(PathBuf::from(path)),
+            _ => None,
+        };
+        let loader: RegistryLoader = {
+            let registry_args = RegistryArgs {
+                registry,
+                ..registry_args.clone()
+            };
+            let policy_args = policy_args.clone();
+            let auth = auth.clone();
+            Arc::new(move || load_registry(&registry_args, &policy_args, &auth))
+        };
+        let slot = RegistrySlot::load(name, watch_dir, loader)?;
+        let registry = &slot.current().registry;
+        info!(
+            "Registry `{}` loaded: {} attributes, {} metrics, {} spans, {} events, {} entities",
+            slot.name,
+            registry.registry.attributes.len(),
+            registry.registry.metrics.len(),
+            registry.registry.spans.len(),
+            registry.registry.events.len(),
+            registry.registry.entities.len(),
+        );
+        slots.push(slot);
+    }
+
+    // Fail fast on invalid advice policies rather than on the first live check.
+    let live_check = LiveCheckSettings {
+        advice_policies: config.advice_policies.clone(),
+        advice_preprocessor: config.advice_preprocessor.clone(),
+    };
+    if let Some(slot) = slots.first() {
+        _ = slot.current().rego_advisor(&live_check)?;
+    }
+    // Baselines given as (allowed) registry paths are only used for diffs, without policies.
+    let baseline_loader: BaselineLoader = {
+        let registry_args = registry_args.clone();
+        let policy_args = PolicyArgs {
+            policies: vec![],
+            skip_policies: true,
+            display_policy_coverage: false,
+        };
+        let auth = auth.clone();
+        Arc::new(move |registry| {
+            let registry_args = RegistryArgs {
+                registry,
+                ..registry_args.clone()
+            };
+            load_registry(&registry_args, &policy_args, &auth).map(|loaded| loaded.resolved)
+        })
+    };
+    let mut state = ServerState::new(slots, reload_token, live_check)
+        .with_baseline_loader(baseline_loader, config.diff_baselines.clone());
+    if config.mcp {
+        state = state.with_mcp(McpSettings {
+            config: weaver_mcp::McpConfig {
+                advice_policies: config.advice_policies.clone(),
+                advice_preprocessor: config.advice_preprocessor.clone(),
+                ..Default::default()
+            },
+            bearer_token: mcp_token,
+        });
+        info!("MCP endpoint mounted on {}", weaver_mcp::MCP_HTTP_PATH);
+    }
+    info!("Starting server on {}", config.bind);
+
+    // Run the async server using tokio runtime
+    tokio::runtime::Runtime::new()
+        .expect("Failed to create tokio runtime")
+        .block_on(async {
+            run_server(
+                config.bind,
+                state,
+                config.cors_origins.as_deref(),
+                config.watch,
+            )
+            .await
+        })
+        .map_err(DiagnosticMessages::from_error)?;
+
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+/// Loads and resolves a registry as a V2 registry.
+fn load_registry(
+    registry_args: &RegistryArgs,
+    policy_args: &PolicyArgs,
+    auth: &HttpAuthResolver,
+) -> Result<LoadedRegistry, DiagnosticMessages> {
     let mut diag_msgs = DiagnosticMessages::empty();
 
     // Create a weaver engine and load/resolve the registry using V2 schema
-    let weaver = crate::weaver::WeaverEngine::new(&args.registry, &args.policy, auth);
+    let weaver = crate::weaver::WeaverEngine::new(registry_args, policy_args, auth);
     let resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
 
     // Convert to V2 ForgeResolvedRegistry
@@ -71,34 +346,22 @@ fn run_serve(
         crate::weaver::Resolved::V1(v) => v.try_into().map_err(DiagnosticMessages::from_error)?,
         crate::weaver::Resolved::V2(v) => v,
     };
-    let forge_registry = resolved_v2.into_template_schema();
 
     if !diag_msgs.is_empty() {
         // Log warnings but continue
         diag_msgs.log();
     }
+    let (resolved, registry) = resolved_v2.into_schemas();
+    Ok(LoadedRegistry { resolved, registry })
+}
 
-    info!("Registry loaded successfully");
-    info!(
-        "Found {} attributes, {} metrics, {} spans, {} events, {} entities",
-        forge_registry.registry.attributes.len(),
-        forge_registry.registry.metrics.len(),
-        forge_registry.registry.spans.len(),
-        forge_registry.registry.events.len(),
-        forge_registry.registry.entities.len(),
-    );
-    info!("Starting server on {}", args.bind);
+#[cfg(test)]
+mod tests {
+    use super::ServeCommand;
+    use crate::registry::tests::assert_config_cli_consistency;
 
-    // Run the async server using tokio runtime
-    tokio::runtime::Runtime::new()
-        .expect("Failed to create tokio runtime")
-        .block_on(async {
-            run_server(args.bind, forge_registry, args.cors_origins.as_deref()).await
-        })
-        .map_err(DiagnosticMessages::from_error)?;
-
-    Ok(ExitDirectives {
-        exit_code: 0,
-        warnings: None,
-    })
+    #[test]
+    fn config_fields_match_cli_args() {
+        assert_config_cli_consistency::<ServeCommand>();
+    }
 }
@@ -4,36 +4,358 @@
 
 #![allow(clippy::needless_for_each)]
 
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{Duration, SystemTime};
 
 use axum::{
-    http::{header, StatusCode},
-    response::{Html, IntoResponse},
-    routing::get,
+    extract::{FromRequestParts, Path},
+    http::{header, request::Parts, HeaderMap, StatusCode},
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
     Json, Router,
 };
 use miette::Diagnostic;
 use serde::Serialize;
+use serde_json::json;
 use tower_http::cors::{Any, CorsLayer};
 use utoipa::OpenApi;
+use weaver_common::http_auth::is_bearer_authorized;
+use weaver_common::vdir::VirtualDirectoryPath;
 use weaver_forge::v2::{
     attribute::Attribute, entity::Entity, event::Event, metric::Metric,
     registry::ForgeResolvedRegistry, span::Span,
 };
+use weaver_live_check::advice::RegoAdvisor;
+use weaver_live_check::VersionedRegistry;
+use weaver_mcp::{McpConfig, WeaverMcpService};
+use weaver_resolved_schema::v2::ResolvedTelemetrySchema;
 use weaver_semconv::stability::Stability;
 
 use super::handlers;
-use super::types::{RegistryCounts, RegistryStats, SearchResponse};
+use super::live_check::{self, LiveCheckSettings};
+use super::types::{RegistryCounts, RegistryStats, RegistrySummary, SearchResponse};
 use super::ui::UI_DIST;
+use weaver_common::diagnostic::DiagnosticMessages;
+use weaver_common::{log_error, log_info, log_success};
 use weaver_search::{ScoredResult, SearchContext, SearchResult, SearchType};
 
+/// Name of the main registry, served under `/api/v1/registry/...`.
+pub const DEFAULT_REGISTRY_NAME: &str = "default";
+
+/// Interval between two checks of the watched registries.
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A loaded registry: the resolved schema and its template form.
+pub struct LoadedRegistry {
+    /// The resolved schema, used to compute diffs.
+    pub resolved: ResolvedTelemetrySchema,
+    /// The resolved registry, as served by the API.
+    pub registry: ForgeResolvedRegistry,
+}
+
 /// Shared application state for all request handlers.
 pub struct AppState {
-    /// The resolved registry loaded at startup.
+    /// The resolved registry.
     pub registry: ForgeResolvedRegistry,
+    /// The resolved schema of the registry.
+    pub resolved: Arc<ResolvedTelemetrySchema>,
     /// Pre-built search context for fast lookups.
     pub search_ctx: SearchContext,
+    /// The registry as used by the live checker, built on first use.
+    live_check_registry: OnceLock<Arc<VersionedRegistry>>,
+    /// The MCP service of the registry, built on first use.
+    mcp_service: OnceLock<WeaverMcpService>,
+    /// The Rego advisor of the live-check endpoints, built on first use.
+    rego_advisor: Mutex<Option<RegoAdvisor>>,
+}
+
+impl AppState {
+    /// Builds the state (including the search context) of a loaded registry.
+    #[must_use]
+    pub fn new(loaded: LoadedRegistry) -> Self {
+        let LoadedRegistry { resolved, registry } = loaded;
+        let search_ctx = SearchContext::from_registry(&registry);
+        Self {
+            registry,
+            resolved: Arc::new(resolved),
+            search_ctx,
+            live_check_registry: OnceLock::new(),
+            mcp_service: OnceLock::new(),
+            rego_advisor: Mutex::new(None),
+        }
+    }
+
+    /// Returns the registry as used by the live checker.
+    pub fn live_check_registry(&self) -> Arc<VersionedRegistry> {
+        self.live_check_registry
+            .get_or_init(|| Arc::new(VersionedRegistry::V2(Box::new(self.registry.clone()))))
+            .clone()
+    }
+
+    /// Returns the Rego advisor of the live-check endpoints. It is built once per
+    /// registry state, its clones share the cache of the Rego findings.
+    pub fn rego_advisor(
+        &self,
+        settings: &LiveCheckSettings,
+    ) -> Result<RegoAdvisor, weaver_live_check::Error> {
+        let mut rego_advisor = self
+            .rego_advisor
+            .lock()
+            .expect("Rego advisor lock poisoned");
+        if let Some(rego_advisor) = rego_advisor.as_ref() {
+            return Ok(rego_advisor.clone());
+        }
+        let built = live_check::rego_advisor(self.live_check_registry(), settings)?;
+        *rego_advisor = Some(built.clone());
+        Ok(built)
+    }
+
+    /// Returns the MCP service of the registry.
+    pub fn mcp_service(&self, config: &McpConfig) -> WeaverMcpService {
+        self.mcp_service
+            .get_or_init(|| WeaverMcpService::new(Arc::new(self.registry.clone()), config.clone()))
+            .clone()
+    }
+}
+
+/// Loads and resolves a registry, called at startup and on every reload.
+pub type RegistryLoader = Arc<dyn Fn() -> Result<LoadedRegistry, DiagnosticMessages> + Send + Sync>;
+
+/// Loads and resolves a baseline registry that is not served by the server.
+pub type BaselineLoader = Arc<
+    dyn Fn(VirtualDirectoryPath) -> Result<ResolvedTelemetrySchema, DiagnosticMessages>
+        + Send
+        + Sync,
+>;
+
+/// A registry served by the server. Its state is replaced atomically on
+/// reload: in-flight requests keep using the state they started with.
+pub struct RegistrySlot {
+    /// The name of the registry.
+    pub name: String,
+    /// The local directory to watch for changes, if any.
+    pub watch_dir: Option<PathBuf>,
+    state: RwLock<Arc<AppState>>,
+    loader: RegistryLoader,
+    reload_lock: tokio::sync::Mutex<()>,
+}
+
+impl RegistrySlot {
+    /// Creates a slot by loading the registry a first time.
+    pub fn load(
+        name: String,
+        watch_dir: Option<PathBuf>,
+        loader: RegistryLoader,
+    ) -> Result<Self, DiagnosticMessages> {
+        let registry = loader()?;
+        Ok(Self {
+            name,
+            watch_dir,
+            state: RwLock::new(Arc::new(AppState::new(registry))),
+            loader,
+            reload_lock: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    /// Returns the current state of the registry.
+    pub fn current(&self) -> Arc<AppState> {
+        self.state
+            .read()
+            .expect("Registry state lock poisoned")
+            .clone()
+    }
+
+    /// Reloads the registry and swaps in the new state. The current state is
+    /// kept if the registry can't be loaded.
+    pub async fn reload(&self) -> Result<Arc<AppState>, DiagnosticMessages> {
+        // Concurrent reloads of the same registry are serialized.
+        let _guard = self.reload_lock.lock().await;
+        let loader = self.loader.clone();
+        let state = tokio::task::spawn_blocking(move || loader().map(AppState::new))
+            .await
+            .map_err(|e| {
+                DiagnosticMessages::from_error(Error::ReloadFailed {
+                    registry: self.name.clone(),
+                    error: e.to_string(),
+                })
+            })??;
+        let state = Arc::new(state);
+        *self.state.write().expect("Registry state lock poisoned") = state.clone();
+        Ok(state)
+    }
+}
+
+/// State shared by the whole server: the served registries, the reload and
+/// the live-check settings.
+pub struct ServerState {
+    registries: BTreeMap<String, Arc<RegistrySlot>>,
+    reload_token: Option<String>,
+    /// The settings of the live-check endpoints.
+    pub live_check: LiveCheckSettings,
+    baseline_loader: Option<BaselineLoader>,
+    /// Registry paths allowed as diff baselines, in addition to the served registries.
+    allowed_baselines: HashSet<String>,
+    /// Baselines loaded from a remote registry path, by path. Only allowed
+    /// baselines are cached, so the cache is bounded by the allowlist.
+    baselines: Mutex<HashMap<String, Arc<tokio::sync::OnceCell<Arc<ResolvedTelemetrySchema>>>>>,
+    mcp: Option<McpSettings>,
+}
+
+/// Settings of the MCP endpoint mounted on the server.
+pub struct McpSettings {
+    /// The configuration of the MCP service.
+    pub config: McpConfig,
+    /// The bearer token required by the MCP endpoint, if any.
+    pub bearer_token: Option<String>,
+}
+
+impl ServerState {
+    /// Creates the server state. `reload_token` is the bearer token required by
+    /// the reload endpoints, which are disabled if `None`.
+    #[must_use]
+    pub fn new(
+        registries: Vec<RegistrySlot>,
+        reload_token: Option<String>,
+        live_check: LiveCheckSettings,
+    ) -> Self {
+        Self {
+            registries: registries
+                .into_iter()
+                .map(|slot| (slot.name.clone(), Arc::new(slot)))
+                .collect(),
+            reload_token,
+            live_check,
+            baseline_loader: None,
+            allowed_baselines: HashSet::new(),
+            baselines: Mutex::new(HashMap::new()),
+            mcp: None,
+        }
+    }
+
+    /// Mounts the MCP endpoint, serving the main registry, on the server.
+    #[must_use]
+    pub fn with_mcp(mut self, mcp: McpSettings) -> Self {
+        self.mcp = Some(mcp);
+        self
+    }
+
+    /// Allows diffs against the baselines given as registry paths in `allowed`,
+    /// loaded with `loader`. Otherwise only the served registries can be used as
+    /// baselines.
+    #[must_use]
+    pub fn with_baseline_loader(
+        mut self,
+        loader: BaselineLoader,
+        allowed: impl IntoIterator<Item = String>,
+    ) -> Self {
+        self.baseline_loader = Some(loader);
+        self.allowed_baselines = allowed.into_iter().collect();
+        self
+    }
+
+    /// Returns the resolved schema of a diff baseline: a served registry by
+    /// name, or an allowed registry path. Registries loaded from a remote path
+    /// are cached for the lifetime of the server, local folders which may change
+    /// are loaded on every request.
+    pub async fn baseline(
+        &self,
+        baseline: &str,
+    ) -> Result<Arc<ResolvedTelemetrySchema>, DiagnosticMessages> {
+        if let Some(slot) = self.registry(baseline) {
+            return Ok(slot.current().resolved.clone());
+        }
+        let unknown_baseline = |error: String| {
+            DiagnosticMessages::from_error(Error::InvalidBaseline {
+                baseline: baseline.to_owned(),
+                error,
+            })
+        };
+        let loader = match &self.baseline_loader {
+            Some(loader) if self.allowed_baselines.contains(baseline) => loader.clone(),
+            _ => {
+                return Err(unknown_baseline(
+                    "Not a served registry nor an allowed diff baseline".to_owned(),
+                ))
+            }
+        };
+        let path: VirtualDirectoryPath = baseline
+            .parse()
+            .map_err(|e: weaver_common::Error| unknown_baseline(e.to_string()))?;
+        let local = matches!(path, VirtualDirectoryPath::LocalFolder { .. });
+        let load = || async {
+            tokio::task::spawn_blocking(move || loader(path))
+                .await
+                .map_err(|e| unknown_baseline(e.to_string()))?
+                .map(Arc::new)
+        };
+        if local {
+            return load().await;
+        }
+
+        // Concurrent requests for the same baseline load it only once, without
+        // blocking the requests for other baselines.
+        let cell = self
+            .baselines
+            .lock()
+            .expect("Baselines lock poisoned")
+            .entry(baseline.to_owned())
+            .or_default()
+            .clone();
+        cell.get_or_try_init(load).await.cloned()
+    }
+
+    /// Returns the registry with the given name.
+    #[must_use]
+    pub fn registry(&self, name: &str) -> Option<Arc<RegistrySlot>> {
+        self.registries.get(name).cloned()
+    }
+
+    /// Returns all the served registries, ordered by name.
+    pub fn registries(&self) -> impl Iterator<Item = &Arc<RegistrySlot>> {
+        self.registries.values()
+    }
+
+    /// Checks the bearer token of a reload request. Returns `None` if the reload
+    /// endpoints are disabled.
+    #[must_use]
+    pub fn is_reload_authorized(&self, headers: &HeaderMap) -> Option<bool> {
+        let expected = self.reload_token.as_deref()?;
+        let authorization = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok());
+        Some(is_bearer_authorized(authorization, expected))
+    }
+}
+
+/// Extractor selecting the registry targeted by a request: the one named by
+/// the `{registry}` path parameter, or the main registry.
+pub struct SelectedRegistry(pub Arc<RegistrySlot>);
+
+impl FromRequestParts<Arc<ServerState>> for SelectedRegistry {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<ServerState>,
+    ) -> Result<Self, Self::Rejection> {
+        let params = Path::<HashMap<String, String>>::from_request_parts(parts, state)
+            .await
+            .map(|Path(params)| params)
+            .unwrap_or_default();
+        let name = params
+            .get("registry")
+            .map_or(DEFAULT_REGISTRY_NAME, String::as_str);
+        state.registry(name).map(SelectedRegistry).ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(json!({"error": "Registry not found", "registry": name})),
+            )
+                .into_response()
+        })
+    }
 }
 
 /// Error type for server operations.
@@ -45,6 +367,24 @@ pub enum Error {
         /// The error message.
         error: String,
     },
+
+    /// A diff baseline couldn't be loaded.
+    #[error("Invalid baseline registry `{baseline}`: {error}")]
+    InvalidBaseline {
+        /// The requested baseline.
+        baseline: String,
+        /// The error message.
+        error: String,
+    },
+
+    /// A registry couldn't be reloaded.
+    #[error("Failed to reload the registry `{registry}`: {error}")]
+    ReloadFailed {
+        /// The name of the registry.
+        registry: String,
+        /// The error message.
+        error: String,
+    },
 }
 
 impl From<std::io::Error> for Error {
@@ -61,7 +401,7 @@ impl From<std::io::Error> for Error {
     info(
         title = "Weaver API",
         version = "0.0.1",
-        description = "REST API for OpenTelemetry Weaver registry, search, schemas and more.",
+        description = "REST API for OpenTelemetry Weaver registry, search, schemas and more.\n\nThe `/api/v1/registry/...` endpoints target the main registry. The same endpoints are available for the named registries under `/api/v1/registries/{registry}/...`.",
         contact(
             name = "OpenTelemetry",
             url = "https://github.com/open-telemetry/weaver"
@@ -80,13 +420,18 @@ impl From<std::io::Error> for Error {
         handlers::get_registry_span,
         handlers::get_registry_event,
         handlers::get_registry_entity,
+        handlers::diff_registry,
         handlers::search_registry,
         handlers::filter_registry,
+        handlers::reload_registry,
+        handlers::list_registries,
+        handlers::live_check,
     ),
     components(
         schemas(
             RegistryStats,
             RegistryCounts,
+            RegistrySummary,
             SearchType,
             SearchResponse,
             SearchResult,
@@ -107,6 +452,7 @@ impl From<std::io::Error> for Error {
         (name = "health", description = "Health check endpoints"),
         (name = "schemas", description = "JSON schema endpoints"),
         (name = "registry", description = "Registry stats, search, and resource lookup"),
+        (name = "live-check", description = "Live check of telemetry samples against the registry"),
     )
 )]
 pub struct ApiDoc;
@@ -116,63 +462,109 @@ async fn openapi_spec() -> Json<utoipa::openapi::OpenApi> {
     Json(ApiDoc::openapi())
 }
 
-/// Run the API server.
-///
-/// # Arguments
-///
-/// * `bind_addr` - The address to bind the server to.
-/// * `registry` - The resolved V2 registry to serve.
-/// * `cors_origins` - Optional CORS origins. Use "*" for any origin, comma-separated for specific origins, or None for no CORS.
-pub async fn run_server(
-    bind_addr: SocketAddr,
-    registry: ForgeResolvedRegistry,
-    cors_origins: Option<&str>,
-) -> Result<(), Error> {
-    // Build search context once at startup
-    let search_ctx = SearchContext::from_registry(&registry);
-
-    let state = Arc::new(AppState {
-        registry,
-        search_ctx,
-    });
-
-    let mut app = Router::new()
-        // Health check
-        .route("/health", get(handlers::health))
-        // Schemas
-        .route("/api/v1/schema/{name}", get(handlers::get_schema))
+/// Registers the routes of a registry under the given path prefix.
+fn registry_routes(router: Router<Arc<ServerState>>, prefix: &str) -> Router<Arc<ServerState>> {
+    router
         // Registry stats
-        .route("/api/v1/registry/stats", get(handlers::get_registry_stats))
+        .route(
+            &format!("{prefix}/stats"),
+            get(handlers::get_registry_stats),
+        )
         // Individual resources
         .route(
-            "/api/v1/registry/attribute/{key}",
+            &format!("{prefix}/attribute/{{key}}"),
             get(handlers::get_registry_attribute),
         )
         .route(
-            "/api/v1/registry/metric/{name}",
+            &format!("{prefix}/metric/{{name}}"),
             get(handlers::get_registry_metric),
         )
         .route(
-            "/api/v1/registry/span/{type}",
+            &format!("{prefix}/span/{{type}}"),
             get(handlers::get_registry_span),
         )
         .route(
-            "/api/v1/registry/event/{name}",
+            &format!("{prefix}/event/{{name}}"),
             get(handlers::get_registry_event),
         )
         .route(
-            "/api/v1/registry/entity/{type}",
+            &format!("{prefix}/entity/{{type}}"),
             get(handlers::get_registry_entity),
         )
+        // Diff
+        .route(&format!("{prefix}/diff"), get(handlers::diff_registry))
         // Search
-        .route("/api/v1/registry/search", get(handlers::search_registry))
+        .route(&format!("{prefix}/search"), get(handlers::search_registry))
         // Filter
-        .route("/api/v1/registry/filter", get(handlers::filter_registry))
+        .route(&format!("{prefix}/filter"), get(handlers::filter_registry))
+        // Reload
+        .route(&format!("{prefix}/reload"), post(handlers::reload_registry))
+}
+
+/// Run the API server.
+///
+/// # Arguments
+///
+/// * `bind_addr` - The address to bind the server to.
+/// * `state` - The registries to serve and the reload settings.
+/// * `cors_origins` - Optional CORS origins. Use "*" for any origin, comma-separated for specific origins, or None for no CORS.
+/// * `watch` - Whether to reload the registries when their local files change.
+pub async fn run_server(
+    bind_addr: SocketAddr,
+    state: ServerState,
+    cors_origins: Option<&str>,
+    watch: bool,
+) -> Result<(), Error> {
+    let state = Arc::new(state);
+
+    if watch {
+        for slot in state.registries() {
+            if let Some(dir) = slot.watch_dir.clone() {
+                drop(tokio::spawn(watch_registry(slot.clone(), dir)));
+            }
+        }
+    }
+
+    let mut app = Router::new()
+        // Health check
+        .route("/health", get(handlers::health))
+        // Schemas
+        .route("/api/v1/schema/{name}", get(handlers::get_schema))
+        // Served registries
+        .route("/api/v1/registries", get(handlers::list_registries))
+        // Live check
+        .route("/api/v1/live-check", post(handlers::live_check))
+        .route(
+            "/api/v1/registries/{registry}/live-check",
+            post(handlers::live_check),
+        );
+    // The main registry and the named registries expose the same routes.
+    app = registry_routes(app, "/api/v1/registry");
+    app = registry_routes(app, "/api/v1/registries/{registry}");
+    let mut app = app
         // OpenAPI specification
         .route("/api/v1/openapi.json", get(openapi_spec))
         // UI fallback - serves embedded static files
         .fallback(serve_ui)
-        .with_state(state);
+        .with_state(state.clone());
+
+    // MCP endpoint: every new session serves the current main registry.
+    if let Some(McpSettings {
+        config,
+        bearer_token,
+    }) = &state.mcp
+    {
+        let state = state.clone();
+        let config = config.clone();
+        let factory = move || {
+            state
+                .registry(DEFAULT_REGISTRY_NAME)
+                .expect("The main registry is always served")
+                .current()
+                .mcp_service(&config)
+        };
+        app = app.merge(weaver_mcp::http_router(factory, bearer_token.clone()));
+    }
 
     // Configure CORS if origins are specified
     if let Some(origins) = cors_origins {
@@ -207,6 +599,56 @@ pub async fn run_server(
     Ok(())
 }
 
+/// Reloads a registry whenever the files of its local directory change.
+#[allow(clippy::infinite_loop)] // The watcher runs for the lifetime of the server.
+async fn watch_registry(slot: Arc<RegistrySlot>, dir: PathBuf) {
+    let fingerprint = |dir: PathBuf| async move {
+        tokio::task::spawn_blocking(move || dir_fingerprint(&dir))
+            .await
+            .unwrap_or_default()
+    };
+    let mut last = fingerprint(dir.clone()).await;
+    loop {
+        tokio::time::sleep(WATCH_INTERVAL).await;
+        let current = fingerprint(dir.clone()).await;
+        if current == last {
+            continue;
+        }
+        last = current;
+        log_info(format!(
+            "Registry `{}` changed, reloading from `{}`",
+            slot.name,
+            dir.display()
+        ));
+        match slot.reload().await {
+            Ok(_) => log_success(format!("Registry `{}` reloaded", slot.name)),
+            Err(diag_msgs) => {
+                log_error(format!(
+                    "Failed to reload the registry `{}`, keeping the previous version",
+                    slot.name
+                ));
+                diag_msgs.log();
+            }
+        }
+    }
+}
+
+/// A cheap summary of the files of a directory (paths, sizes and modification
+/// times) used to detect changes.
+fn dir_fingerprint(dir: &std::path::Path) -> Vec<(PathBuf, u64, Option<SystemTime>)> {
+    let mut files: Vec<_> = walkdir::WalkDir::new(dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            Some((entry.into_path(), metadata.len(), metadata.modified().ok()))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
 /// Serve embedded UI files with SPA fallback.
 async fn serve_ui(uri: axum::http::Uri) -> impl IntoResponse {
     let path = uri.path().trim_start_matches('/');
@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
+use weaver_forge::v2::registry::ForgeResolvedRegistry;
 use weaver_search::{SearchResult, SearchType};
 use weaver_semconv::stability::Stability;
 
@@ -18,6 +19,33 @@ pub struct RegistryStats {
     // then we could draw graphs in the UI.
 }
 
+impl RegistryStats {
+    /// Computes the stats of a resolved registry.
+    #[must_use]
+    pub fn from_registry(registry: &ForgeResolvedRegistry) -> Self {
+        Self {
+            schema_url: registry.schema_url.to_string(),
+            counts: RegistryCounts {
+                attributes: registry.registry.attributes.len(),
+                metrics: registry.registry.metrics.len(),
+                spans: registry.registry.spans.len(),
+                events: registry.registry.events.len(),
+                entities: registry.registry.entities.len(),
+                attribute_groups: registry.registry.attribute_groups.len(),
+            },
+        }
+    }
+}
+
+/// A registry served by the server.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RegistrySummary {
+    /// The name of the registry (`default` for the main registry).
+    pub name: String,
+    /// The stats of the registry.
+    pub stats: RegistryStats,
+}
+
 /// Counts of different entity types in the registry.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct RegistryCounts {
@@ -35,6 +63,15 @@ pub struct RegistryCounts {
     pub attribute_groups: usize,
 }
 
+/// Query parameters for the diff endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DiffParams {
+    /// The baseline registry: the name of a served registry, or a registry path
+    /// (local folder, Git repo URL or archive URL) allowed with `--diff-baseline`.
+    #[param(example = "v1.30.0")]
+    pub baseline: String,
+}
+
 /// Query parameters for search endpoint.
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct SearchParams {
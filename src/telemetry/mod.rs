@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Commands to process telemetry data.
+
+mod translate;
+
+use crate::CmdResult;
+use clap::{Args, Subcommand};
+
+/// Parameters for the `telemetry` command
+#[derive(Debug, Args)]
+pub struct TelemetryCommand {
+    /// Define the sub-commands for the `telemetry` command
+    #[clap(subcommand)]
+    pub command: TelemetrySubCommand,
+}
+
+/// Sub-commands to process telemetry data.
+#[derive(Debug, Subcommand)]
+#[clap(verbatim_doc_comment)]
+pub enum TelemetrySubCommand {
+    /// Upgrades telemetry samples to a version of the semantic conventions with the
+    /// transformations of an OpenTelemetry schema file, as the schema processor of
+    /// the OpenTelemetry Collector does.
+    ///
+    /// The input is OTLP/JSON (an export request, or one per line as written by the
+    /// collector file exporter) or live-check JSON samples. The upgraded samples are
+    /// written in the live-check JSON format, which `weaver registry live-check
+    /// --input-format json` reads. Samples are upgraded from the version of the schema
+    /// URL of their instrumentation scope or resource; samples without a schema URL
+    /// are left unchanged.
+    Translate(translate::TranslateArgs),
+}
+
+/// Process telemetry data.
+pub fn telemetry(command: &TelemetryCommand) -> CmdResult {
+    match &command.command {
+        TelemetrySubCommand::Translate(args) => {
+            CmdResult::new(translate::command(args), Some(args.diagnostic.clone()))
+        }
+    }
+}
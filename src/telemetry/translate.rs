@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Upgrades telemetry samples with the transformations of an OpenTelemetry schema file.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+use clap::Args;
+use serde_json::Value;
+use weaver_common::diagnostic::DiagnosticMessages;
+use weaver_common::log_success;
+use weaver_live_check::upgrade::SampleUpgrader;
+use weaver_live_check::{Error, Sample};
+
+use crate::registry::otlp::otlp_ingester::samples_from_request;
+use crate::registry::otlp::otlp_json::{is_otlp_json, otlp_request_from_json};
+use crate::{DiagnosticArgs, ExitDirectives};
+
+/// Parameters for the `telemetry translate` sub-command
+#[derive(Debug, Args)]
+pub struct TranslateArgs {
+    /// Path to the OpenTelemetry schema file defining the transformations.
+    #[arg(long)]
+    pub schema_file: PathBuf,
+
+    /// The semantic conventions version to upgrade the samples to. Defaults to the
+    /// latest version of the schema file.
+    #[arg(long)]
+    pub to: Option<semver::Version>,
+
+    /// The samples to upgrade: a file path or "stdin".
+    #[arg(long, default_value = "stdin")]
+    pub input_source: String,
+
+    /// The file to write the upgraded samples to. Defaults to STDOUT.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Parameters to specify the diagnostic format.
+    #[command(flatten)]
+    pub diagnostic: DiagnosticArgs,
+}
+
+/// Upgrades the samples of the input and writes them to the output.
+pub(crate) fn command(args: &TranslateArgs) -> Result<ExitDirectives, DiagnosticMessages> {
+    let upgrader = SampleUpgrader::from_schema_file(&args.schema_file, args.to.clone())?;
+
+    let mut samples = if args.input_source.eq_ignore_ascii_case("stdin") {
+        read_samples(std::io::stdin().lock())?
+    } else {
+        let file = File::open(&args.input_source).map_err(|e| Error::IngestError {
+            error: format!("Failed to open file {}: {e}", args.input_source),
+        })?;
+        read_samples(BufReader::new(file))?
+    };
+
+    let mut upgraded = 0;
+    for sample in &mut samples {
+        if upgrader.upgrade(sample) {
+            upgraded += 1;
+        }
+    }
+
+    write_samples(&samples, args.output.as_ref())?;
+    log_success(format!(
+        "Upgraded {upgraded} of {} samples to version {}",
+        samples.len(),
+        upgrader.target()
+    ));
+
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+/// Reads a sequence of JSON values, each one an OTLP/JSON export request, an array
+/// of live-check samples or a single sample.
+fn read_samples<R: Read>(reader: R) -> Result<Vec<Sample>, Error> {
+    let mut samples = Vec::new();
+    for value in serde_json::Deserializer::from_reader(reader).into_iter::<Value>() {
+        let value = value.map_err(|e| Error::IngestError {
+            error: format!("Failed to parse the JSON input: {e}"),
+        })?;
+        if is_otlp_json(&value) {
+            let request = otlp_request_from_json(value).map_err(|e| Error::IngestError {
+                error: e.to_string(),
+            })?;
+            samples.extend(samples_from_request(request)?);
+        } else if value.is_array() {
+            let batch: Vec<Sample> =
+                serde_json::from_value(value).map_err(|e| Error::IngestError {
+                    error: format!("Failed to parse the samples: {e}"),
+                })?;
+            samples.extend(batch);
+        } else {
+            samples.push(
+                serde_json::from_value(value).map_err(|e| Error::IngestError {
+                    error: format!("Failed to parse the sample: {e}"),
+                })?,
+            );
+        }
+    }
+    Ok(samples)
+}
+
+/// Writes the samples as a JSON array to the given file or STDOUT.
+fn write_samples(samples: &[Sample], output: Option<&PathBuf>) -> Result<(), Error> {
+    let writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(File::create(path).map_err(|e| Error::OutputError {
+            error: format!("Failed to create file {}: {e}", path.display()),
+        })?),
+        None => Box::new(std::io::stdout().lock()),
+    };
+    let mut writer = BufWriter::new(writer);
+    serde_json::to_writer_pretty(&mut writer, samples)
+        .map_err(|e| Error::OutputError {
+            error: e.to_string(),
+        })
+        .and_then(|()| {
+            writeln!(writer).map_err(|e| Error::OutputError {
+                error: e.to_string(),
+            })
+        })
+}
@@ -1,12 +1,14 @@
 //! A module containing all the "process" of running weaver as components.
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use miette::Diagnostic;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use serde::Serialize;
-use weaver_checker::Error::{InvalidPolicyFile, PolicyViolation};
-use weaver_checker::{Engine, PolicyStage, SEMCONV_REGO};
+use weaver_checker::lint::{LintConfig, Linter};
+use weaver_checker::Error::{InvalidPolicyFile, PolicyViolation, SuppressedFinding};
+use weaver_checker::{BaselineTracker, Engine, PolicyFinding, PolicyStage, SEMCONV_REGO};
 use weaver_common::diagnostic::DiagnosticMessage;
 use weaver_common::http_auth::HttpAuthResolver;
 use weaver_common::log_success;
@@ -15,9 +17,10 @@ use weaver_common::{diagnostic::DiagnosticMessages, result::WResult};
 use weaver_forge::registry::ResolvedRegistry;
 use weaver_resolved_schema::ResolvedTelemetrySchema;
 use weaver_resolver::{LoadedSemconvRegistry, SchemaResolver};
+use weaver_semconv::provenance::Provenance;
 use weaver_semconv::semconv::Versioned;
 use weaver_semconv::{registry_repo::RegistryRepo, semconv::SemConvSpecWithProvenance};
-use weaver_version::schema_changes::SchemaChanges;
+use weaver_version::schema_changes::{renames, SchemaChanges, SchemaItemType};
 
 use crate::registry::{PolicyArgs, RegistryArgs};
 
@@ -28,6 +31,11 @@ pub struct WeaverEngine<'a> {
     policy_config: &'a PolicyArgs,
     /// Per-URL HTTP credential resolver built from `.weaver.toml` (`[[auth]]`).
     auth: &'a HttpAuthResolver,
+    /// Optional baseline of known policy findings, attached to every policy engine.
+    baseline_tracker: Option<BaselineTracker>,
+    /// Optional native lint rules, checked with the `after_resolution` policies (also
+    /// when the policies are skipped).
+    lint_config: Option<LintConfig>,
 }
 impl<'a> WeaverEngine<'a> {
     /// Engine that resolves credentials for remote registry / dependency /
@@ -41,9 +49,26 @@ impl<'a> WeaverEngine<'a> {
             registry_config: registry,
             policy_config: policy,
             auth,
+            baseline_tracker: None,
+            lint_config: None,
         }
     }
 
+    /// Filters policy findings through the given baseline tracker.
+    #[must_use]
+    pub fn with_baseline_tracker(mut self, tracker: BaselineTracker) -> Self {
+        self.baseline_tracker = Some(tracker);
+        self
+    }
+
+    /// Checks the native lint rules of the given configuration with the
+    /// `after_resolution` policies, also when the policies are skipped.
+    #[must_use]
+    pub fn with_lint_config(mut self, config: LintConfig) -> Self {
+        self.lint_config = Some(config);
+        self
+    }
+
     /// Loads  previously resolved schemas or loads and resolves "raw" definitions, executing all policies there-in.
     pub fn load_and_resolve_main(
         &self,
@@ -91,10 +116,23 @@ impl<'a> WeaverEngine<'a> {
         .capture_non_fatal_errors(diag_msgs)?;
 
         // Optionally init policy engine
-        let policy_engine = prepare_policy_engine(self.policy_config, &repo, self.auth)?;
+        let mut policy_engine = prepare_policy_engine(self.policy_config, &repo, self.auth)?;
+        if let (Some(engine), Some(tracker)) = (policy_engine.as_mut(), &self.baseline_tracker) {
+            engine.set_baseline_tracker(tracker.clone());
+        }
+
+        // Optionally init the native lint rules, independent of the policy engine
+        let mut linter = match &self.lint_config {
+            Some(config) => Linter::new(config)?,
+            None => None,
+        };
+        if let (Some(linter), Some(tracker)) = (linter.as_mut(), &self.baseline_tracker) {
+            linter.set_baseline_tracker(tracker.clone());
+        }
         Ok(Loaded {
             loaded,
             policy_engine,
+            linter,
         })
     }
 
@@ -124,6 +162,7 @@ impl<'a> WeaverEngine<'a> {
                     template_schema: template,
                     registry_path_repr,
                     policy_engine: loaded.policy_engine,
+                    linter: loaded.linter,
                 })
             }
             LoadedSemconvRegistry::ResolvedV2(resolved) => {
@@ -139,6 +178,7 @@ impl<'a> WeaverEngine<'a> {
                     template_schema: template,
                     registry_path_repr,
                     policy_engine: loaded.policy_engine,
+                    linter: loaded.linter,
                 }));
             }
         };
@@ -155,9 +195,11 @@ impl<'a> WeaverEngine<'a> {
 /// A loaded set of weaver definition files.
 ///
 /// Contains the repository definition and raw files and an optional policy engine with policies for this repo.
+#[derive(Clone)]
 pub struct Loaded {
     loaded: LoadedSemconvRegistry,
     policy_engine: Option<Engine>,
+    linter: Option<Linter>,
 }
 impl Loaded {
     /// Checks if we have any before resolution policies.
@@ -168,6 +210,52 @@ impl Loaded {
             .unwrap_or(false)
     }
 
+    /// Returns the before resolution policy findings instead of reporting
+    /// them as diagnostics.
+    pub fn before_resolution_findings(&self) -> Result<Vec<PolicyFinding>, Error> {
+        match (self.policy_engine.as_ref(), &self.loaded) {
+            (Some(policy_engine), LoadedSemconvRegistry::Unresolved { specs, .. }) => {
+                policy_findings(check_policy(policy_engine, specs))
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
+    /// Returns a copy of these definitions with an additional semantic
+    /// convention file, given as a YAML string (v1 or v2).
+    pub fn with_snippet(
+        &self,
+        file_name: &str,
+        content: &str,
+        diag_msgs: &mut DiagnosticMessages,
+    ) -> Result<Loaded, Error> {
+        let mut loaded = self.clone();
+        let LoadedSemconvRegistry::Unresolved { repo, specs, .. } = &mut loaded.loaded else {
+            return Err(Error::AlreadyResolved);
+        };
+        let provenance = Provenance::new(repo.schema_url().clone(), file_name);
+        let spec = SemConvSpecWithProvenance::from_string(provenance, content)
+            .capture_non_fatal_errors(diag_msgs)?;
+        specs.push(spec);
+        Ok(loaded)
+    }
+
+    /// Returns the before resolution policy findings of a single file of the
+    /// definitions, by path.
+    pub fn before_resolution_findings_of(&self, path: &str) -> Result<Vec<PolicyFinding>, Error> {
+        match (self.policy_engine.as_ref(), &self.loaded) {
+            (Some(policy_engine), LoadedSemconvRegistry::Unresolved { specs, .. }) => {
+                let specs: Vec<_> = specs
+                    .iter()
+                    .filter(|spec| spec.provenance.path == path)
+                    .cloned()
+                    .collect();
+                policy_findings(check_policy(policy_engine, &specs))
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
     /// Checks before resolution policies.
     pub fn check_before_resolution_policy(
         &self,
@@ -202,6 +290,18 @@ impl Resolved {
         }
     }
 
+    /// Returns the after resolution policy findings instead of reporting
+    /// them as diagnostics.
+    pub fn after_resolution_findings(&self) -> Result<Vec<PolicyFinding>, Error> {
+        let (policies, lint) = match self {
+            Resolved::V1(v) => (v.run_after_resolution_policy(), v.run_lint()),
+            Resolved::V2(v) => (v.run_after_resolution_policy(), v.run_lint()),
+        };
+        let mut findings = policies.map_or(Ok(vec![]), policy_findings)?;
+        findings.extend(lint.map_or(Ok(vec![]), policy_findings)?);
+        Ok(findings)
+    }
+
     pub fn diff(&self, baseline: &Resolved) -> Result<DiffResult, Error> {
         match (self, baseline) {
             (Resolved::V1(h), Resolved::V1(b)) => Ok(DiffResult::V1(h.diff(b))),
@@ -232,6 +332,32 @@ pub enum DiffResult {
     V2(DiffV2),
 }
 
+impl DiffResult {
+    /// Returns the semconv version of the head registry.
+    pub fn head_semconv_version(&self) -> &str {
+        match self {
+            DiffResult::V1(d) => &d.changes.head_manifest().semconv_version,
+            DiffResult::V2(d) => &d.head_semconv_version,
+        }
+    }
+
+    /// Returns the old to new names of the renamed registry attributes.
+    pub fn renamed_attributes(&self) -> BTreeMap<String, String> {
+        match self {
+            DiffResult::V1(d) => d.changes.renames(SchemaItemType::RegistryAttributes),
+            DiffResult::V2(d) => renames(&d.changes.registry.attribute_changes),
+        }
+    }
+
+    /// Returns the old to new names of the renamed metrics.
+    pub fn renamed_metrics(&self) -> BTreeMap<String, String> {
+        match self {
+            DiffResult::V1(d) => d.changes.renames(SchemaItemType::Metrics),
+            DiffResult::V2(d) => renames(&d.changes.registry.metric_changes),
+        }
+    }
+}
+
 /// A resolved weaver repository. Could have been derived from raw definitions or loaded directly.
 ///
 /// Contains the optimised schema, a template schema and optional policy engine.
@@ -240,6 +366,7 @@ pub struct ResolvedV1 {
     template_schema: ResolvedRegistry,
     registry_path_repr: String,
     policy_engine: Option<Engine>,
+    linter: Option<Linter>,
 }
 impl ResolvedV1 {
     /// Returns the resolved schema.
@@ -262,12 +389,9 @@ impl ResolvedV1 {
         self.template_schema
     }
 
-    /// Checks after resolution policies.
-    pub fn check_after_resolution_policy(
-        &self,
-        diag_msgs: &mut DiagnosticMessages,
-    ) -> Result<(), Error> {
-        if let Some(engine) = self.policy_engine.as_ref() {
+    /// Runs the after resolution policies, if any.
+    fn run_after_resolution_policy(&self) -> Option<WResult<(), weaver_checker::Error>> {
+        self.policy_engine.as_ref().map(|engine| {
             let mut e = engine.clone();
             check_policy_stage::<ResolvedRegistry, ()>(
                 &mut e,
@@ -276,17 +400,48 @@ impl ResolvedV1 {
                 &self.template_schema,
                 &[],
             )
-            .inspect(|_, violations| {
-                if let Some(violations) = violations {
-                    log_success(format!(
-                        "All `after_resolution` policies checked ({} violations found)",
-                        violations.len()
-                    ));
-                } else {
-                    log_success("No `after_resolution` policy violation");
-                }
-            })
-            .capture_non_fatal_errors(diag_msgs)?;
+        })
+    }
+
+    /// Runs the native lint rules, if any.
+    fn run_lint(&self) -> Option<WResult<(), weaver_checker::Error>> {
+        self.linter
+            .as_ref()
+            .map(|linter| check_lint(linter, &self.registry_path_repr, &self.template_schema))
+    }
+
+    /// Checks after resolution policies.
+    pub fn check_after_resolution_policy(
+        &self,
+        diag_msgs: &mut DiagnosticMessages,
+    ) -> Result<(), Error> {
+        if let Some(result) = self.run_after_resolution_policy() {
+            result
+                .inspect(|_, violations| {
+                    if let Some(violations) = violations {
+                        log_success(format!(
+                            "All `after_resolution` policies checked ({} violations found)",
+                            violations.len()
+                        ));
+                    } else {
+                        log_success("No `after_resolution` policy violation");
+                    }
+                })
+                .capture_non_fatal_errors(diag_msgs)?;
+        }
+        if let Some(result) = self.run_lint() {
+            result
+                .inspect(|_, findings| {
+                    if let Some(findings) = findings {
+                        log_success(format!(
+                            "All lint rules checked ({} findings)",
+                            findings.len()
+                        ));
+                    } else {
+                        log_success("No lint finding");
+                    }
+                })
+                .capture_non_fatal_errors(diag_msgs)?;
         }
         Ok(())
     }
@@ -333,6 +488,7 @@ pub struct ResolvedV2 {
     template_schema: weaver_forge::v2::registry::ForgeResolvedRegistry,
     registry_path_repr: String,
     policy_engine: Option<Engine>,
+    linter: Option<Linter>,
 }
 
 impl ResolvedV2 {
@@ -356,12 +512,19 @@ impl ResolvedV2 {
         self.template_schema
     }
 
-    /// Checks after resolution policies.
-    pub fn check_after_resolution_policy(
-        &self,
-        diag_msgs: &mut DiagnosticMessages,
-    ) -> Result<(), Error> {
-        if let Some(engine) = self.policy_engine.as_ref() {
+    /// Gives both the resolved schema and the template schema.
+    pub fn into_schemas(
+        self,
+    ) -> (
+        weaver_resolved_schema::v2::ResolvedTelemetrySchema,
+        weaver_forge::v2::registry::ForgeResolvedRegistry,
+    ) {
+        (self.resolved_schema, self.template_schema)
+    }
+
+    /// Runs the after resolution policies, if any.
+    fn run_after_resolution_policy(&self) -> Option<WResult<(), weaver_checker::Error>> {
+        self.policy_engine.as_ref().map(|engine| {
             let mut e = engine.clone();
             check_policy_stage::<weaver_forge::v2::registry::ForgeResolvedRegistry, ()>(
                 &mut e,
@@ -370,17 +533,48 @@ impl ResolvedV2 {
                 &self.template_schema,
                 &[],
             )
-            .inspect(|_, violations| {
-                if let Some(violations) = violations {
-                    log_success(format!(
-                        "All `after_resolution` policies checked ({} violations found)",
-                        violations.len()
-                    ));
-                } else {
-                    log_success("No `after_resolution` policy violation");
-                }
-            })
-            .capture_non_fatal_errors(diag_msgs)?;
+        })
+    }
+
+    /// Runs the native lint rules, if any.
+    fn run_lint(&self) -> Option<WResult<(), weaver_checker::Error>> {
+        self.linter
+            .as_ref()
+            .map(|linter| check_lint(linter, &self.registry_path_repr, &self.template_schema))
+    }
+
+    /// Checks after resolution policies.
+    pub fn check_after_resolution_policy(
+        &self,
+        diag_msgs: &mut DiagnosticMessages,
+    ) -> Result<(), Error> {
+        if let Some(result) = self.run_after_resolution_policy() {
+            result
+                .inspect(|_, violations| {
+                    if let Some(violations) = violations {
+                        log_success(format!(
+                            "All `after_resolution` policies checked ({} violations found)",
+                            violations.len()
+                        ));
+                    } else {
+                        log_success("No `after_resolution` policy violation");
+                    }
+                })
+                .capture_non_fatal_errors(diag_msgs)?;
+        }
+        if let Some(result) = self.run_lint() {
+            result
+                .inspect(|_, findings| {
+                    if let Some(findings) = findings {
+                        log_success(format!(
+                            "All lint rules checked ({} findings)",
+                            findings.len()
+                        ));
+                    } else {
+                        log_success("No lint finding");
+                    }
+                })
+                .capture_non_fatal_errors(diag_msgs)?;
         }
         Ok(())
     }
@@ -441,6 +635,7 @@ impl TryFrom<ResolvedV1> for ResolvedV2 {
             template_schema,
             registry_path_repr: value.registry_path_repr,
             policy_engine: value.policy_engine,
+            linter: value.linter,
         })
     }
 }
@@ -526,6 +721,8 @@ pub enum Error {
     ResolvedSchema(#[from] weaver_resolved_schema::error::Error),
     #[error("Cannot compare or diff a V1 registry with a V2 registry")]
     IncompatibleRegistries,
+    #[error("Cannot add definitions to a registry loaded already resolved")]
+    AlreadyResolved,
     #[error("Loaded a V2 resolved registry without the `--v2` flag. The V2 schema will be used.")]
     #[diagnostic(severity(warning))]
     V2FlagMissingWarning,
@@ -653,6 +850,23 @@ pub(crate) fn check_policy(
     WResult::with_non_fatal_errors((), nfes)
 }
 
+/// Extracts the policy findings from the result of a policy check. Suppressed
+/// findings are ignored, any other error is returned.
+fn policy_findings(
+    result: WResult<(), weaver_checker::Error>,
+) -> Result<Vec<PolicyFinding>, Error> {
+    let ((), errors) = result.into_result_with_non_fatal()?;
+    let mut findings = vec![];
+    for error in errors {
+        match error {
+            PolicyViolation { violation, .. } => findings.push(*violation),
+            SuppressedFinding { .. } => {}
+            error => return Err(error.into()),
+        }
+    }
+    Ok(findings)
+}
+
 /// Runs the policy engine on a serializable input and returns
 /// a list of policy violations represented as errors.
 ///
@@ -689,6 +903,13 @@ pub(crate) fn check_policy_stage<T: Serialize, U: Serialize>(
                         violation: Box::new(violation),
                     });
                 }
+                for suppressed in policy_engine.suppressed_findings() {
+                    errors.push(SuppressedFinding {
+                        provenance: policy_file.to_owned(),
+                        finding: Box::new(suppressed.finding.clone()),
+                        justification: suppressed.justification.clone(),
+                    });
+                }
             }
             Err(e) => errors.push(InvalidPolicyFile {
                 file: policy_file.to_owned(),
@@ -703,6 +924,39 @@ pub(crate) fn check_policy_stage<T: Serialize, U: Serialize>(
     WResult::with_non_fatal_errors((), errors)
 }
 
+/// Runs the native lint rules on a resolved registry and returns the findings
+/// represented as errors.
+fn check_lint<T: Serialize>(
+    linter: &Linter,
+    registry_path: &str,
+    registry: &T,
+) -> WResult<(), weaver_checker::Error> {
+    match linter.check(registry) {
+        Ok(result) => {
+            let mut errors: Vec<_> = result
+                .findings
+                .into_iter()
+                .map(|finding| PolicyViolation {
+                    provenance: registry_path.to_owned(),
+                    violation: Box::new(finding),
+                })
+                .collect();
+            errors.extend(
+                result
+                    .suppressed
+                    .into_iter()
+                    .map(|suppressed| SuppressedFinding {
+                        provenance: registry_path.to_owned(),
+                        finding: Box::new(suppressed.finding),
+                        justification: suppressed.justification,
+                    }),
+            );
+            WResult::with_non_fatal_errors((), errors)
+        }
+        Err(e) => WResult::FatalErr(e),
+    }
+}
+
 /// Errors that could occur in these utilities.
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Serialize, Diagnostic)]
 #[non_exhaustive]
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers shared by the tests of the weaver serve command.
+
+use std::process::{Child, Command as StdCommand};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+/// Kills the serve process when the test ends, even on failure.
+pub struct ServeProcess(Child);
+
+impl Drop for ServeProcess {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Starts `weaver serve` with the given arguments and environment variables,
+/// bound to `port`, and waits for the server to be ready.
+pub fn start_serve(port: u16, args: &[&str], envs: &[(&str, &str)]) -> ServeProcess {
+    let child = StdCommand::new(env!("CARGO_BIN_EXE_weaver"))
+        .arg("serve")
+        .args(args)
+        .args(["--bind", &format!("127.0.0.1:{port}")])
+        .envs(envs.iter().copied())
+        .spawn()
+        .expect("Failed to start weaver serve process");
+    let process = ServeProcess(child);
+
+    // Wait for the server to be ready
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while ureq::get(&format!("http://127.0.0.1:{port}/health"))
+        .call()
+        .is_err()
+    {
+        assert!(Instant::now() < deadline, "weaver serve did not start");
+        sleep(Duration::from_millis(200));
+    }
+    process
+}
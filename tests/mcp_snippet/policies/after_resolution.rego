@@ -0,0 +1,18 @@
+package after_resolution
+
+import rego.v1
+
+# Metrics must have at least one attribute.
+deny contains finding if {
+	group := input.groups[_]
+	group.type == "metric"
+	count(object.get(group, "attributes", [])) == 0
+	finding := {
+		"id": "metric_without_attributes",
+		"context": {"group": group.id},
+		"message": sprintf("Metric '%s' has no attributes", [group.metric_name]),
+		"level": "violation",
+		"signal_type": "metric",
+		"signal_name": group.metric_name,
+	}
+}
@@ -0,0 +1,16 @@
+package before_resolution
+
+import rego.v1
+
+# Attributes must not be defined in the legacy `shop.legacy` namespace.
+deny contains finding if {
+	group := input.groups[_]
+	attr := group.attributes[_]
+	startswith(attr.id, "shop.legacy.")
+	finding := {
+		"id": "legacy_namespace",
+		"context": {"group": group.id, "attribute_name": attr.id},
+		"message": sprintf("Attribute '%s' is in the legacy namespace", [attr.id]),
+		"level": "violation",
+	}
+}
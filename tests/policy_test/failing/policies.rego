@@ -0,0 +1,16 @@
+package before_resolution
+
+import rego.v1
+
+# Attribute briefs must not contain leftover TODO markers.
+deny contains finding if {
+	group := input.groups[_]
+	attr := group.attributes[_]
+	contains(attr.brief, "TODO")
+	finding := {
+		"id": "todo_in_brief",
+		"context": {"group": group.id, "attribute_name": attr.id},
+		"message": sprintf("Attribute '%s' has a TODO in its brief", [attr.id]),
+		"level": "violation",
+	}
+}
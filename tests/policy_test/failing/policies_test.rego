@@ -0,0 +1,8 @@
+package before_resolution
+
+import rego.v1
+
+# This test is expected to fail.
+test_clean_brief if {
+	count(deny) == 0 with input as {"groups": [{"id": "registry.app", "attributes": [{"id": "app.id", "brief": "TODO"}]}]}
+}
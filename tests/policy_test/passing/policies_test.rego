@@ -0,0 +1,12 @@
+package before_resolution
+
+import rego.v1
+
+test_todo_in_brief if {
+	findings := deny with input as {"groups": [{"id": "registry.app", "attributes": [{"id": "app.id", "brief": "TODO"}]}]}
+	count(findings) == 1
+}
+
+test_clean_brief if {
+	count(deny) == 0 with input as {"groups": [{"id": "registry.app", "attributes": [{"id": "app.id", "brief": "The app id."}]}]}
+}
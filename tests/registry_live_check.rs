@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test the registry live-check command.
+
+use assert_cmd::Command;
+use serde_json::Value;
+
+/// Checks a Prometheus exposition against the registry: known families are mapped
+/// to the registry metrics, unknown families are reported as missing.
+#[test]
+fn test_prometheus_input() {
+    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let output = cmd
+        .args([
+            "registry",
+            "live-check",
+            "-r",
+            "crates/weaver_live_check/data/model",
+            "--input-source",
+            "crates/weaver_live_check/data/metrics.prom",
+            "--input-format",
+            "prometheus",
+            "--format",
+            "json",
+            "--no-stream",
+        ])
+        .timeout(std::time::Duration::from_secs(60))
+        .output()
+        .expect("failed to execute process");
+
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+        panic!(
+            "Invalid JSON report ({e}). Stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+    });
+    let metrics = report["samples"]
+        .as_array()
+        .expect("samples should be an array")
+        .iter()
+        .filter_map(|sample| sample.get("metric"))
+        .collect::<Vec<_>>();
+    let names = metrics
+        .iter()
+        .map(|metric| metric["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["system.uptime", "system.memory.usage", "process_open_fds"]
+    );
+    assert_eq!(metrics[1]["instrument"], "updowncounter");
+    assert_eq!(
+        metrics[1]["data_points"][0]["attributes"][0]["name"],
+        "system.memory.state"
+    );
+
+    let finding_ids = |metric: &Value| {
+        metric["live_check_result"]["all_advice"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|advice| advice["id"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert!(!finding_ids(metrics[0]).contains(&"missing_metric".to_owned()));
+    assert!(finding_ids(metrics[2]).contains(&"missing_metric".to_owned()));
+    // The unknown family makes the check fail.
+    assert!(!output.status.success());
+}
+
+/// Checks telemetry produced with an older semconv version: the renamed metric and
+/// attribute are translated with the historical registry and reported as outdated.
+#[test]
+fn test_historical_registry() {
+    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let output = cmd
+        .args([
+            "registry",
+            "live-check",
+            "-r",
+            "crates/weaver_live_check/data/model",
+            "--input-source",
+            "crates/weaver_live_check/data/outdated_metrics.json",
+            "--input-format",
+            "json",
+            "--format",
+            "json",
+            "--no-stream",
+            "--historical-registry",
+            "crates/weaver_live_check/data/historical_model",
+        ])
+        .timeout(std::time::Duration::from_secs(60))
+        .output()
+        .expect("failed to execute process");
+
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+        panic!(
+            "Invalid JSON report ({e}). Stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+    });
+    let metric = &report["samples"][0]["metric"];
+    let finding_ids = |value: &Value| {
+        value["live_check_result"]["all_advice"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|advice| advice["id"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    let metric_findings = finding_ids(metric);
+    assert!(metric_findings.contains(&"outdated_semconv_version".to_owned()));
+    assert!(!metric_findings.contains(&"missing_metric".to_owned()));
+
+    let data_point = &metric["data_points"][0];
+    assert!(
+        finding_ids(&data_point["attributes"][0]).contains(&"outdated_semconv_version".to_owned())
+    );
+    assert!(!finding_ids(data_point).contains(&"recommended_attribute_not_present".to_owned()));
+}
+
+/// Replays a recording of OTLP requests: the trace windows are measured on the times
+/// the requests were received, not on the replay time. The second span of the first
+/// trace was received 11s after its first span, after its 5s window had elapsed.
+#[test]
+fn test_otlp_input() {
+    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let output = cmd
+        .args([
+            "registry",
+            "live-check",
+            "-r",
+            "crates/weaver_live_check/data/model",
+            "--input-source",
+            "crates/weaver_live_check/data/traffic.otlp",
+            "--input-format",
+            "otlp",
+            "--format",
+            "json",
+            "--no-stream",
+            "--otlp-trace-window",
+            "5",
+        ])
+        .timeout(std::time::Duration::from_secs(60))
+        .output()
+        .expect("failed to execute process");
+
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+        panic!(
+            "Invalid JSON report ({e}). Stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+    });
+    let traces = report["samples"]
+        .as_array()
+        .expect("samples should be an array")
+        .iter()
+        .map(|sample| {
+            let trace = &sample["trace"];
+            let span_ids = trace["spans"]
+                .as_array()
+                .expect("spans should be an array")
+                .iter()
+                .map(|span| span["span_id"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            (trace["trace_id"].as_str().unwrap(), span_ids)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        traces,
+        vec![
+            ("5b8efff798038103d269b633813fc60c", vec!["eee19b7ec3c1b174"]),
+            ("0af7651916cd43dd8448eb211c80319c", vec!["b7ad6b7169203331"]),
+            ("5b8efff798038103d269b633813fc60c", vec!["eee19b7ec3c1b175"]),
+        ]
+    );
+}
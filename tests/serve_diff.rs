@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test the registry diff endpoint of the weaver serve command.
+
+use serde_json::Value;
+
+use common::start_serve;
+
+mod common;
+
+fn diff(port: u16, baseline: &str) -> Value {
+    let body = ureq::get(&format!("http://127.0.0.1:{port}/api/v1/registry/diff"))
+        .query("baseline", baseline)
+        .call()
+        .expect("Diff should succeed")
+        .body_mut()
+        .read_to_string()
+        .expect("Failed to read the response");
+    serde_json::from_str(&body).expect("Invalid diff")
+}
+
+#[test]
+fn test_registry_diff_endpoint() {
+    let port = portpicker::pick_unused_port().expect("No free ports");
+    let _serve = start_serve(
+        port,
+        &[
+            "-r",
+            "tests/diff/registry_head",
+            "--named-registry",
+            "baseline=tests/diff/registry_baseline",
+            "--diff-baseline",
+            "tests/diff/registry_baseline",
+        ],
+        &[],
+    );
+
+    // Baseline by name of a served registry.
+    let changes = diff(port, "baseline");
+    let metric_changes = |changes: &Value| {
+        changes["registry"]["metric_changes"]
+            .as_array()
+            .expect("Missing metric changes")
+            .clone()
+    };
+    assert_eq!(
+        changes["baseline_schema_url"],
+        "https://opentelemetry.io/schemas/1.0"
+    );
+    assert!(metric_changes(&changes)
+        .iter()
+        .any(|change| change["type"] == "renamed"
+            && change["old_name"] == "metric4"
+            && change["new_name"] == "metric4_bis"));
+
+    // Baseline by registry path gives the same changes.
+    let by_path = diff(port, "tests/diff/registry_baseline");
+    assert_eq!(
+        metric_changes(&by_path).len(),
+        metric_changes(&changes).len()
+    );
+
+    // A registry compared to itself has no changes.
+    assert!(metric_changes(&diff(port, "default")).is_empty());
+
+    // Only the served registries and the allowed paths can be used as baselines.
+    for baseline in ["tests/diff/does_not_exist", "tests/diff/registry_head"] {
+        assert!(matches!(
+            ureq::get(&format!("http://127.0.0.1:{port}/api/v1/registry/diff"))
+                .query("baseline", baseline)
+                .call(),
+            Err(ureq::Error::StatusCode(400))
+        ));
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test the live-check endpoint of the weaver serve command.
+
+use serde_json::{json, Value};
+
+use common::start_serve;
+
+mod common;
+
+fn live_check(port: u16, body: &Value) -> Value {
+    let body = ureq::post(&format!("http://127.0.0.1:{port}/api/v1/live-check"))
+        .header("Content-Type", "application/json")
+        .send(body.to_string())
+        .expect("Live check should succeed")
+        .body_mut()
+        .read_to_string()
+        .expect("Failed to read the response");
+    serde_json::from_str(&body).expect("Invalid live check report")
+}
+
+fn advice_ids(result: &Value) -> Vec<&str> {
+    result["live_check_result"]["all_advice"]
+        .as_array()
+        .expect("Missing advice")
+        .iter()
+        .filter_map(|advice| advice["id"].as_str())
+        .collect()
+}
+
+#[test]
+fn test_live_check_endpoint() {
+    let port = portpicker::pick_unused_port().expect("No free ports");
+    let _serve = start_serve(port, &["-r", "crates/weaver_emit/data"], &[]);
+
+    // Samples in the live-check JSON format.
+    let report = live_check(
+        port,
+        &json!([
+            {"attribute": {"name": "event.id", "value": "42"}},
+            {"attribute": {"name": "not.in.registry", "value": "x"}}
+        ]),
+    );
+    let samples = report["samples"].as_array().expect("Missing samples");
+    assert_eq!(samples.len(), 2);
+    assert!(!advice_ids(&samples[0]["attribute"]).contains(&"missing_attribute"));
+    assert!(advice_ids(&samples[1]["attribute"]).contains(&"missing_attribute"));
+    assert_eq!(report["statistics"]["total_entities"], 2);
+
+    // OTLP/JSON export request.
+    let report = live_check(
+        port,
+        &json!({
+            "resourceSpans": [{
+                "scopeSpans": [{
+                    "spans": [{
+                        "traceId": "5b8efff798038103d269b633813fc60c",
+                        "spanId": "eee19b7ec3c1b174",
+                        "name": "unknown.span",
+                        "kind": 1,
+                        "attributes": [{"key": "not.in.registry", "value": {"stringValue": "x"}}]
+                    }]
+                }]
+            }]
+        }),
+    );
+    let span = &report["samples"][0]["span"];
+    assert_eq!(span["name"], "unknown.span");
+    assert!(advice_ids(&span["attributes"][0]).contains(&"missing_attribute"));
+
+    // Invalid bodies are rejected.
+    assert!(matches!(
+        ureq::post(&format!("http://127.0.0.1:{port}/api/v1/live-check"))
+            .send(json!({"not": "samples"}).to_string()),
+        Err(ureq::Error::StatusCode(400))
+    ));
+    assert!(matches!(
+        ureq::post(&format!("http://127.0.0.1:{port}/api/v1/live-check"))
+            .send(json!({"resourceSpans": [{"scopeSpans": "not a list"}]}).to_string()),
+        Err(ureq::Error::StatusCode(400))
+    ));
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test the MCP endpoint mounted by the weaver serve command.
+
+use common::start_serve;
+
+mod common;
+
+const MCP_TOKEN_ENV: &str = "WEAVER_TEST_MCP_TOKEN";
+const MCP_TOKEN: &str = "s3cr3t";
+
+fn initialize_request(url: &str) -> ureq::RequestBuilder<ureq::typestate::WithBody> {
+    ureq::post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, text/event-stream")
+}
+
+const INITIALIZE: &str = r#"{
+    "jsonrpc": "2.0",
+    "id": 1,
+    "method": "initialize",
+    "params": {
+        "protocolVersion": "2025-03-26",
+        "capabilities": {},
+        "clientInfo": {"name": "weaver-test", "version": "1.0.0"}
+    }
+}"#;
+
+#[test]
+fn test_mcp_endpoint() {
+    let port = portpicker::pick_unused_port().expect("No free ports");
+    let _serve = start_serve(
+        port,
+        &[
+            "-r",
+            "crates/weaver_emit/data",
+            "--mcp",
+            "--mcp-token-env",
+            MCP_TOKEN_ENV,
+        ],
+        &[(MCP_TOKEN_ENV, MCP_TOKEN)],
+    );
+    let mcp_url = format!("http://127.0.0.1:{port}/mcp");
+
+    // The MCP endpoint requires the bearer token.
+    assert!(matches!(
+        initialize_request(&mcp_url).send(INITIALIZE),
+        Err(ureq::Error::StatusCode(401))
+    ));
+    assert!(matches!(
+        initialize_request(&mcp_url)
+            .header("Authorization", "Bearer wrong")
+            .send(INITIALIZE),
+        Err(ureq::Error::StatusCode(401))
+    ));
+
+    let mut response = initialize_request(&mcp_url)
+        .header("Authorization", format!("Bearer {MCP_TOKEN}"))
+        .send(INITIALIZE)
+        .expect("Initialize should succeed");
+    assert!(response.headers().contains_key("mcp-session-id"));
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .expect("Failed to read the response");
+    // The response is sent as a server-sent event, after a priming event without data.
+    let data = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(str::trim)
+        .find(|data| !data.is_empty())
+        .expect("Missing event data");
+    let message: serde_json::Value = serde_json::from_str(data).expect("Invalid JSON");
+    assert_eq!(message["id"], 1);
+    assert!(message["result"]["capabilities"]["tools"].is_object());
+
+    // The rest of the API is still served, without the MCP token.
+    let _ = ureq::get(&format!("http://127.0.0.1:{port}/api/v1/registry/stats"))
+        .call()
+        .expect("The registry API should not require the MCP token");
+}
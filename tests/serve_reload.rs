@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test the registry reload and multi-registry support of the weaver serve command.
+
+use std::path::Path;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use common::start_serve;
+
+mod common;
+
+const RELOAD_TOKEN_ENV: &str = "WEAVER_TEST_RELOAD_TOKEN";
+const RELOAD_TOKEN: &str = "s3cr3t";
+
+fn get_json(url: &str) -> serde_json::Value {
+    let body = ureq::get(url)
+        .call()
+        .expect("Request failed")
+        .body_mut()
+        .read_to_string()
+        .expect("Failed to read the response");
+    serde_json::from_str(&body).expect("Invalid JSON response")
+}
+
+fn metric_count(port: u16, prefix: &str) -> u64 {
+    let stats = get_json(&format!("http://127.0.0.1:{port}{prefix}/stats"));
+    stats["counts"]["metrics"]
+        .as_u64()
+        .expect("Missing metric count")
+}
+
+fn copy_registry(target: &Path) {
+    for entry in std::fs::read_dir("crates/weaver_emit/data").expect("Failed to read registry") {
+        let entry = entry.expect("Invalid entry");
+        let _ = std::fs::copy(entry.path(), target.join(entry.file_name()))
+            .expect("Failed to copy registry file");
+    }
+}
+
+#[test]
+fn test_registry_reload() {
+    // Hidden directories are ignored by the registry loader, hence the explicit prefix.
+    let registry_dir = tempfile::Builder::new()
+        .prefix("weaver-serve-")
+        .tempdir()
+        .expect("Failed to create temp dir");
+    copy_registry(registry_dir.path());
+    let port = portpicker::pick_unused_port().expect("No free ports");
+    let _serve = start_serve(
+        port,
+        &[
+            "-r",
+            registry_dir.path().to_str().expect("Invalid registry path"),
+            "--named-registry",
+            "emit=crates/weaver_emit/data",
+            "--reload-token-env",
+            RELOAD_TOKEN_ENV,
+            "--watch",
+        ],
+        &[(RELOAD_TOKEN_ENV, RELOAD_TOKEN)],
+    );
+
+    let initial_metrics = metric_count(port, "/api/v1/registry");
+    assert!(initial_metrics > 0);
+
+    // Named registries are served side by side with the main one.
+    assert_eq!(
+        metric_count(port, "/api/v1/registries/emit"),
+        initial_metrics
+    );
+    let registries = get_json(&format!("http://127.0.0.1:{port}/api/v1/registries"));
+    assert_eq!(registries[0]["name"], "default");
+    assert_eq!(registries[1]["name"], "emit");
+    assert!(matches!(
+        ureq::get(&format!(
+            "http://127.0.0.1:{port}/api/v1/registries/unknown/stats"
+        ))
+        .call(),
+        Err(ureq::Error::StatusCode(404))
+    ));
+
+    // The reload endpoint requires the bearer token.
+    let reload_url = format!("http://127.0.0.1:{port}/api/v1/registry/reload");
+    assert!(matches!(
+        ureq::post(&reload_url).send_empty(),
+        Err(ureq::Error::StatusCode(401))
+    ));
+    assert!(matches!(
+        ureq::post(&reload_url)
+            .header("Authorization", "Bearer wrong")
+            .send_empty(),
+        Err(ureq::Error::StatusCode(401))
+    ));
+
+    // Removing the metrics file is picked up by an explicit reload.
+    std::fs::remove_file(registry_dir.path().join("metrics.yaml"))
+        .expect("Failed to remove metrics");
+    let _ = ureq::post(&reload_url)
+        .header("Authorization", format!("Bearer {RELOAD_TOKEN}"))
+        .send_empty()
+        .expect("Reload should succeed");
+    assert_eq!(metric_count(port, "/api/v1/registry"), 0);
+    // Other registries are not affected.
+    assert_eq!(
+        metric_count(port, "/api/v1/registries/emit"),
+        initial_metrics
+    );
+
+    // Restoring the metrics file is picked up by the file watcher.
+    copy_registry(registry_dir.path());
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while metric_count(port, "/api/v1/registry") != initial_metrics {
+        assert!(
+            Instant::now() < deadline,
+            "The registry change was not picked up by the watcher"
+        );
+        sleep(Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test the telemetry translate command.
+
+use assert_cmd::Command;
+use serde_json::Value;
+
+/// Upgrades OTLP/JSON requests (one per line) with a schema file: renamed span
+/// attributes, metrics and metric attributes get their new names.
+#[test]
+fn test_translate_otlp_json() {
+    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let output = cmd
+        .args([
+            "telemetry",
+            "translate",
+            "--schema-file",
+            "crates/weaver_live_check/data/schema-history.yaml",
+            "--input-source",
+            "tests/telemetry_translate/otlp.jsonl",
+        ])
+        .timeout(std::time::Duration::from_secs(60))
+        .output()
+        .expect("failed to execute process");
+    assert!(output.status.success());
+
+    let samples: Value = serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+        panic!(
+            "Invalid JSON samples ({e}). Stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+    });
+    let span = &samples[1]["span"];
+    assert_eq!(span["attributes"][0]["name"], "http.request.method");
+    assert_eq!(span["attributes"][1]["name"], "http.response.status_code");
+    assert_eq!(
+        span["schema_url"],
+        "https://opentelemetry.io/schemas/1.26.0"
+    );
+
+    let metric = &samples[2]["metric"];
+    assert_eq!(metric["name"], "system.memory.usage");
+    assert_eq!(
+        metric["data_points"][0]["attributes"][0]["name"],
+        "system.memory.state"
+    );
+}
+
+/// Upgrades to an intermediate version: the later transformations are not applied
+/// and the output can be read back as live-check samples.
+#[test]
+fn test_translate_to_version() {
+    let dir = tempfile::tempdir().unwrap();
+    let output_path = dir.path().join("samples.json");
+    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let output = cmd
+        .args([
+            "telemetry",
+            "translate",
+            "--schema-file",
+            "crates/weaver_live_check/data/schema-history.yaml",
+            "--to",
+            "1.21.0",
+            "--input-source",
+            "tests/telemetry_translate/otlp.jsonl",
+            "--output",
+        ])
+        .arg(&output_path)
+        .timeout(std::time::Duration::from_secs(60))
+        .output()
+        .expect("failed to execute process");
+    assert!(output.status.success());
+
+    let samples: Value =
+        serde_json::from_str(&std::fs::read_to_string(&output_path).unwrap()).unwrap();
+    assert_eq!(
+        samples[1]["span"]["attributes"][0]["name"],
+        "http.request.method"
+    );
+    // The metric was produced with a later version than the target.
+    assert_eq!(samples[2]["metric"]["name"], "system.memory.used");
+    assert_eq!(
+        samples[2]["metric"]["schema_url"],
+        "https://opentelemetry.io/schemas/1.24.0"
+    );
+
+    // The upgraded samples are valid live-check input.
+    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let output = cmd
+        .args([
+            "telemetry",
+            "translate",
+            "--schema-file",
+            "crates/weaver_live_check/data/schema-history.yaml",
+            "--input-source",
+        ])
+        .arg(&output_path)
+        .timeout(std::time::Duration::from_secs(60))
+        .output()
+        .expect("failed to execute process");
+    assert!(output.status.success());
+    let samples: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(samples[2]["metric"]["name"], "system.memory.usage");
+}
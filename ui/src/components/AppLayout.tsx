@@ -153,6 +153,17 @@ export function AppLayout() {
               Stats
             </button>
           </li>
+          <li>
+            <button
+              onClick={() => {
+                navigate({ to: '/diff' })
+                closeSidebar()
+              }}
+              className={isActive('/diff') ? 'active' : ''}
+            >
+              Diff
+            </button>
+          </li>
           <li>
             <button
               onClick={() => {
@@ -283,3 +283,41 @@ export async function filterRegistry(filter: string): Promise<unknown> {
   return fetchJSON<unknown>(`${BASE_URL}/registry/filter?${searchParams.toString()}`);
 }
 
+
+export interface RegistrySummary {
+  name: string;
+  stats: RegistryStats;
+}
+
+export type SchemaItemChange =
+  | { type: 'added'; name: string }
+  | { type: 'renamed'; old_name: string; new_name: string; note: string }
+  | { type: 'updated' }
+  | { type: 'obsoleted'; name: string; note: string }
+  | { type: 'uncategorized'; name: string; note: string }
+  | { type: 'removed'; name: string };
+
+export interface RegistryChanges {
+  attribute_changes: SchemaItemChange[];
+  attribute_group_changes: SchemaItemChange[];
+  entity_changes: SchemaItemChange[];
+  event_changes: SchemaItemChange[];
+  metric_changes: SchemaItemChange[];
+  span_changes: SchemaItemChange[];
+}
+
+export interface RegistryDiffResponse {
+  head_schema_url: string;
+  baseline_schema_url: string;
+  registry: RegistryChanges;
+}
+
+export async function getRegistries(): Promise<RegistrySummary[]> {
+  return fetchJSON<RegistrySummary[]>(`${BASE_URL}/registries`);
+}
+
+export async function getRegistryDiff(baseline: string): Promise<RegistryDiffResponse> {
+  const searchParams = new URLSearchParams();
+  searchParams.set('baseline', baseline);
+  return fetchJSON<RegistryDiffResponse>(`${BASE_URL}/registry/diff?${searchParams.toString()}`);
+}
@@ -2,6 +2,7 @@ import { Route as RootRoute } from './routes/__root'
 import { Route as IndexRoute } from './routes/index'
 import { Route as SearchRoute } from './routes/search'
 import { Route as StatsRoute } from './routes/stats'
+import { Route as DiffRoute } from './routes/diff'
 import { Route as SchemaRoute } from './routes/schema'
 import { Route as ApiDocsRoute } from './routes/api-docs'
 import { Route as AttributeRoute } from './routes/attribute.$key'
@@ -15,6 +16,7 @@ export const routeTree = RootRoute.addChildren([
   IndexRoute,
   SearchRoute,
   StatsRoute,
+  DiffRoute,
   SchemaRoute,
   ApiDocsRoute,
   AttributeRoute,
@@ -0,0 +1,200 @@
+import { createRoute, Link, useNavigate, useSearch } from '@tanstack/react-router'
+import { type FormEvent, useEffect, useState } from 'react'
+import { getRegistries, getRegistryDiff } from '../lib/api'
+import type {
+  RegistryChanges,
+  RegistryDiffResponse,
+  RegistrySummary,
+  SchemaItemChange,
+} from '../lib/api'
+import { Route as RootRoute } from './__root'
+
+type DiffSearch = {
+  baseline?: string
+}
+
+export const Route = createRoute({
+  getParentRoute: () => RootRoute,
+  path: 'diff',
+  validateSearch: (search: Record<string, unknown>): DiffSearch => ({
+    baseline: typeof search.baseline === 'string' ? search.baseline : undefined,
+  }),
+  component: Diff,
+})
+
+const sections: Array<{
+  key: keyof RegistryChanges
+  label: string
+  detailPath?: string
+}> = [
+  { key: 'attribute_changes', label: 'Attributes', detailPath: '/attribute' },
+  { key: 'metric_changes', label: 'Metrics', detailPath: '/metric' },
+  { key: 'span_changes', label: 'Spans', detailPath: '/span' },
+  { key: 'event_changes', label: 'Events', detailPath: '/event' },
+  { key: 'entity_changes', label: 'Entities', detailPath: '/entity' },
+  { key: 'attribute_group_changes', label: 'Attribute Groups' },
+]
+
+const changeBadges: Record<SchemaItemChange['type'], string> = {
+  added: 'badge-success',
+  renamed: 'badge-info',
+  updated: 'badge-primary',
+  obsoleted: 'badge-warning',
+  uncategorized: 'badge-ghost',
+  removed: 'badge-error',
+}
+
+function ItemName({ name, detailPath }: { name: string; detailPath?: string }) {
+  if (!detailPath) {
+    return <span className="font-mono font-semibold">{name}</span>
+  }
+  return (
+    <Link to={`${detailPath}/${name}`} className="link font-mono font-semibold">
+      {name}
+    </Link>
+  )
+}
+
+function ChangeRow({ change, detailPath }: { change: SchemaItemChange; detailPath?: string }) {
+  return (
+    <li className="flex items-center gap-2 flex-wrap py-1">
+      <span className={`badge badge-sm ${changeBadges[change.type]}`}>{change.type}</span>
+      {change.type === 'renamed' ? (
+        <>
+          <span className="font-mono line-through text-base-content/60">{change.old_name}</span>
+          <span aria-hidden="true">→</span>
+          <ItemName name={change.new_name} detailPath={detailPath} />
+        </>
+      ) : change.type === 'removed' ? (
+        // Removed items no longer exist in the registry.
+        <span className="font-mono font-semibold line-through">{change.name}</span>
+      ) : change.type === 'updated' ? null : (
+        <ItemName name={change.name} detailPath={detailPath} />
+      )}
+      {'note' in change && change.note ? (
+        <span className="text-sm text-base-content/70">{change.note}</span>
+      ) : null}
+    </li>
+  )
+}
+
+function Diff() {
+  const navigate = useNavigate()
+  const search = useSearch({ from: '/diff' })
+  const [baselineInput, setBaselineInput] = useState(search.baseline ?? '')
+  const [registries, setRegistries] = useState<RegistrySummary[]>([])
+  const [diff, setDiff] = useState<RegistryDiffResponse | null>(null)
+  const [error, setError] = useState<string | null>(null)
+  const [loading, setLoading] = useState(false)
+
+  useEffect(() => {
+    getRegistries()
+      .then(setRegistries)
+      .catch(() => setRegistries([]))
+  }, [])
+
+  useEffect(() => {
+    const baseline = search.baseline
+    setBaselineInput(baseline ?? '')
+    if (!baseline) {
+      setDiff(null)
+      return
+    }
+
+    let isMounted = true
+    setLoading(true)
+    setError(null)
+    getRegistryDiff(baseline)
+      .then((data) => {
+        if (isMounted) setDiff(data)
+      })
+      .catch((err: unknown) => {
+        if (isMounted) {
+          setDiff(null)
+          setError(err instanceof Error ? err.message : 'Unknown error')
+        }
+      })
+      .finally(() => {
+        if (isMounted) setLoading(false)
+      })
+
+    return () => {
+      isMounted = false
+    }
+  }, [search.baseline])
+
+  const handleSubmit = (e: FormEvent) => {
+    e.preventDefault()
+    const baseline = baselineInput.trim()
+    navigate({ to: '/diff', search: baseline ? { baseline } : {} })
+  }
+
+  return (
+    <div className="space-y-6">
+      <h1 className="text-3xl font-bold">Registry Diff</h1>
+
+      <form className="flex gap-2 flex-wrap" onSubmit={handleSubmit}>
+        <input
+          type="text"
+          list="served-registries"
+          className="input input-bordered flex-1 min-w-64"
+          placeholder="Baseline: a served registry name or a registry path"
+          value={baselineInput}
+          onChange={(e) => setBaselineInput(e.target.value)}
+          aria-label="Baseline registry"
+        />
+        <datalist id="served-registries">
+          {registries.map((registry) => (
+            <option key={registry.name} value={registry.name} />
+          ))}
+        </datalist>
+        <button type="submit" className="btn btn-primary" disabled={loading}>
+          Compare
+        </button>
+      </form>
+
+      {error ? (
+        <div className="alert alert-error" role="alert">
+          <span>Error: {error}</span>
+        </div>
+      ) : loading ? (
+        <div className="flex justify-center">
+          <span className="loading loading-spinner loading-lg"></span>
+        </div>
+      ) : diff ? (
+        <>
+          <p className="text-sm text-base-content/70">
+            Changes from <span className="font-mono">{diff.baseline_schema_url}</span> to{' '}
+            <span className="font-mono">{diff.head_schema_url}</span>
+          </p>
+          {sections.map(({ key, label, detailPath }) => {
+            const changes = diff.registry[key]
+            return (
+              <div key={key} className="card bg-base-200">
+                <div className="card-body py-4">
+                  <h2 className="card-title">
+                    {label}
+                    <span className="badge badge-outline">{changes.length}</span>
+                  </h2>
+                  {changes.length === 0 ? (
+                    <p className="text-sm text-base-content/70">No changes.</p>
+                  ) : (
+                    <ul>
+                      {changes.map((change, index) => (
+                        <ChangeRow key={index} change={change} detailPath={detailPath} />
+                      ))}
+                    </ul>
+                  )}
+                </div>
+              </div>
+            )
+          })}
+        </>
+      ) : (
+        <div className="text-center text-base-content/70 py-8">
+          <p>Select a baseline registry to list the changes of the registry since that version.</p>
+        </div>
+      )}
+    </div>
+  )
+}